@@ -0,0 +1,381 @@
+use std::io::{self, IsTerminal, Write};
+
+use anyhow::{bail, Result};
+use codespan::Span as ByteSpan;
+use codespan_reporting::diagnostic::{
+    Diagnostic as MoveDiagnostic, LabelStyle, Severity as MoveSeverity,
+};
+use codespan_reporting::term::termcolor::{ColorChoice, NoColor, StandardStream};
+use colored::*;
+use move_core_types::account_address::AccountAddress;
+use move_model::model::{GlobalEnv, Loc};
+use serde::Serialize;
+
+/// How diagnostics are printed (`--message-format`).
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+pub enum MessageFormat {
+    /// Rendered for humans on stderr.
+    #[default]
+    Human,
+    /// One JSON record per line on stdout.
+    Json,
+}
+
+impl MessageFormat {
+    pub fn parse(s: &str) -> Result<Self> {
+        match s {
+            "human" => Ok(MessageFormat::Human),
+            "json" => Ok(MessageFormat::Json),
+            _ => bail!(
+                "Invalid message format \"{}\". Expected \"human\" or \"json\"",
+                s
+            ),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    Error,
+    Warning,
+    Note,
+}
+
+impl Severity {
+    fn label(&self) -> &'static str {
+        match self {
+            Severity::Error => "error",
+            Severity::Warning => "warning",
+            Severity::Note => "note",
+        }
+    }
+}
+
+/// Location of a diagnostic in a Move source file. Lines and columns are 1-based.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize)]
+pub struct Span {
+    pub file: String,
+    pub line: u32,
+    pub column: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_line: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_column: Option<u32>,
+}
+
+impl Span {
+    /// Returns the span of `loc` in `env`, if it points into a source file.
+    pub fn from_loc(env: &GlobalEnv, loc: &Loc) -> Option<Self> {
+        let (file, start) = env.get_file_and_location(loc)?;
+        let end = Loc::new(
+            loc.file_id(),
+            ByteSpan::new(loc.span().end(), loc.span().end()),
+        );
+        let end = env.get_location(&end);
+        Some(Span {
+            file: file.replace('\\', "/"),
+            line: start.line.0 + 1,
+            column: start.column.0 + 1,
+            end_line: end.as_ref().map(|end| end.line.0 + 1),
+            end_column: end.as_ref().map(|end| end.column.0 + 1),
+        })
+    }
+}
+
+/// A diagnostic reported by the generator.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize)]
+pub struct Diagnostic {
+    pub severity: Severity,
+    /// Identifies the kind of diagnostic, e.g. `naming::collision`. Move compiler diagnostics
+    /// carry the compiler's code (e.g. `E03002`).
+    pub code: String,
+    pub message: String,
+    /// ID of the package the diagnostic relates to.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub package: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub span: Option<Span>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub notes: Vec<String>,
+}
+
+impl Diagnostic {
+    pub fn new(severity: Severity, code: &str, message: impl Into<String>) -> Self {
+        Diagnostic {
+            severity,
+            code: code.to_string(),
+            message: message.into(),
+            package: None,
+            span: None,
+            notes: vec![],
+        }
+    }
+
+    pub fn error(code: &str, message: impl Into<String>) -> Self {
+        Diagnostic::new(Severity::Error, code, message)
+    }
+
+    pub fn warning(code: &str, message: impl Into<String>) -> Self {
+        Diagnostic::new(Severity::Warning, code, message)
+    }
+
+    pub fn note(code: &str, message: impl Into<String>) -> Self {
+        Diagnostic::new(Severity::Note, code, message)
+    }
+
+    pub fn with_package(mut self, package: &AccountAddress) -> Self {
+        self.package = Some(package.to_hex_literal());
+        self
+    }
+
+    pub fn with_span(mut self, span: Option<Span>) -> Self {
+        self.span = span;
+        self
+    }
+
+    pub fn with_note(mut self, note: impl Into<String>) -> Self {
+        self.notes.push(note.into());
+        self
+    }
+}
+
+/// Prints diagnostics in the selected message format and counts them by severity.
+pub struct Diagnostics {
+    format: MessageFormat,
+    color: bool,
+    out: Box<dyn Write + Send>,
+    errors: usize,
+    warnings: usize,
+}
+
+impl Diagnostics {
+    /// Human diagnostics go to stderr, colored if it's a terminal, and JSON records to stdout.
+    pub fn new(format: MessageFormat) -> Self {
+        match format {
+            MessageFormat::Human => {
+                Diagnostics::with_writer(format, io::stderr().is_terminal(), Box::new(io::stderr()))
+            }
+            MessageFormat::Json => Diagnostics::with_writer(format, false, Box::new(io::stdout())),
+        }
+    }
+
+    pub fn with_writer(format: MessageFormat, color: bool, out: Box<dyn Write + Send>) -> Self {
+        Diagnostics {
+            format,
+            color,
+            out,
+            errors: 0,
+            warnings: 0,
+        }
+    }
+
+    pub fn format(&self) -> MessageFormat {
+        self.format
+    }
+
+    pub fn error_count(&self) -> usize {
+        self.errors
+    }
+
+    pub fn warning_count(&self) -> usize {
+        self.warnings
+    }
+
+    pub fn emit(&mut self, diag: Diagnostic) -> Result<()> {
+        match diag.severity {
+            Severity::Error => self.errors += 1,
+            Severity::Warning => self.warnings += 1,
+            Severity::Note => (),
+        }
+        match self.format {
+            MessageFormat::Json => writeln!(self.out, "{}", serde_json::to_string(&diag)?)?,
+            MessageFormat::Human => self.emit_human(&diag)?,
+        }
+        Ok(())
+    }
+
+    fn emit_human(&mut self, diag: &Diagnostic) -> Result<()> {
+        let label = format!("{}[{}]", diag.severity.label(), diag.code);
+        let label = match (self.color, diag.severity) {
+            (false, _) => label,
+            (true, Severity::Error) => label.red().bold().to_string(),
+            (true, Severity::Warning) => label.yellow().bold().to_string(),
+            (true, Severity::Note) => label.cyan().bold().to_string(),
+        };
+        writeln!(self.out, "{}: {}", label, diag.message)?;
+        if let Some(span) = &diag.span {
+            writeln!(
+                self.out,
+                "  --> {}:{}:{}",
+                span.file, span.line, span.column
+            )?;
+        }
+        for note in diag.notes.iter() {
+            writeln!(self.out, "  = note: {}", note)?;
+        }
+        Ok(())
+    }
+
+    /// Reports the diagnostics accumulated in a Move model with at least `min_severity`. Human
+    /// output is rendered by the Move model itself, with source snippets.
+    pub fn emit_model_diags(&mut self, env: &GlobalEnv, min_severity: MoveSeverity) -> Result<()> {
+        let mut diags: Vec<MoveDiagnostic<_>> = vec![];
+        env.report_diag_with_filter(&mut NoColor::new(io::sink()), |diag| {
+            if diag.severity >= min_severity {
+                diags.push(diag.clone());
+            }
+            false
+        });
+
+        if self.format == MessageFormat::Human {
+            let color = match self.color {
+                true => ColorChoice::Always,
+                false => ColorChoice::Never,
+            };
+            env.report_diag(&mut StandardStream::stderr(color), min_severity);
+            for diag in diags.iter() {
+                match diag.severity {
+                    MoveSeverity::Bug | MoveSeverity::Error => self.errors += 1,
+                    MoveSeverity::Warning => self.warnings += 1,
+                    _ => (),
+                }
+            }
+            return Ok(());
+        }
+
+        for diag in diags {
+            let severity = match diag.severity {
+                MoveSeverity::Bug | MoveSeverity::Error => Severity::Error,
+                MoveSeverity::Warning => Severity::Warning,
+                MoveSeverity::Note | MoveSeverity::Help => Severity::Note,
+            };
+            let primary = diag
+                .labels
+                .iter()
+                .find(|label| label.style == LabelStyle::Primary)
+                .map(|label| {
+                    let span = ByteSpan::new(label.range.start as u32, label.range.end as u32);
+                    Loc::new(label.file_id, span)
+                });
+            let package = primary.as_ref().and_then(|loc| {
+                env.get_modules()
+                    .find(|module| module.get_loc().file_id() == loc.file_id())
+                    .map(|module| *module.self_address())
+            });
+
+            let mut record = Diagnostic::new(
+                severity,
+                diag.code.as_deref().unwrap_or("move"),
+                diag.message.clone(),
+            )
+            .with_span(primary.and_then(|loc| Span::from_loc(env, &loc)));
+            if let Some(package) = package {
+                record = record.with_package(&package);
+            }
+            for label in diag.labels.iter().filter(|label| !label.message.is_empty()) {
+                record = record.with_note(label.message.clone());
+            }
+            for note in diag.notes {
+                record = record.with_note(note);
+            }
+            self.emit(record)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use super::*;
+
+    /// A writer whose output can be read after it's been moved into `Diagnostics`.
+    #[derive(Clone, Default)]
+    struct Buffer(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for Buffer {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.lock().unwrap().write(buf)
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    impl Buffer {
+        fn contents(&self) -> String {
+            String::from_utf8(self.0.lock().unwrap().clone()).unwrap()
+        }
+    }
+
+    fn diag() -> Diagnostic {
+        Diagnostic::error("naming::collision", "name collision in module 0x1::pool")
+            .with_package(&AccountAddress::from_hex_literal("0x1").unwrap())
+            .with_span(Some(Span {
+                file: "sources/pool.move".to_string(),
+                line: 3,
+                column: 5,
+                end_line: None,
+                end_column: None,
+            }))
+            .with_note("clashes with struct `pool::Pool`")
+    }
+
+    #[test]
+    fn test_emit_json() {
+        let buf = Buffer::default();
+        let mut diags = Diagnostics::with_writer(MessageFormat::Json, false, Box::new(buf.clone()));
+
+        diags.emit(diag()).unwrap();
+        diags
+            .emit(Diagnostic::warning(
+                "resolution::unfetched_package",
+                "not found",
+            ))
+            .unwrap();
+
+        let lines = buf
+            .contents()
+            .lines()
+            .map(|line| serde_json::from_str::<serde_json::Value>(line).unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            lines,
+            vec![
+                serde_json::json!({
+                    "severity": "error",
+                    "code": "naming::collision",
+                    "message": "name collision in module 0x1::pool",
+                    "package": AccountAddress::from_hex_literal("0x1").unwrap().to_hex_literal(),
+                    "span": { "file": "sources/pool.move", "line": 3, "column": 5 },
+                    "notes": ["clashes with struct `pool::Pool`"],
+                }),
+                serde_json::json!({
+                    "severity": "warning",
+                    "code": "resolution::unfetched_package",
+                    "message": "not found",
+                }),
+            ]
+        );
+        assert_eq!((diags.error_count(), diags.warning_count()), (1, 1));
+    }
+
+    #[test]
+    fn test_emit_human() {
+        let buf = Buffer::default();
+        let mut diags =
+            Diagnostics::with_writer(MessageFormat::Human, false, Box::new(buf.clone()));
+
+        diags.emit(diag()).unwrap();
+
+        assert_eq!(
+            buf.contents(),
+            "error[naming::collision]: name collision in module 0x1::pool\n  \
+            --> sources/pool.move:3:5\n  \
+            = note: clashes with struct `pool::Pool`\n"
+        );
+    }
+}
@@ -101,12 +101,16 @@ registerClasses(loader)
 
 pub static UTIL: &str = r#"
 import { bcs, BcsType } from '@mysten/sui/bcs'
+import { SuiClient, SuiObjectData } from '@mysten/sui/client'
 import {
   Transaction,
   TransactionArgument,
   TransactionObjectArgument,
   TransactionObjectInput,
+  TransactionResult,
+  coinWithBalance,
 } from '@mysten/sui/transactions'
+import type { StructClass } from './reified'
 
 export interface FieldsWithTypes {
   fields: Record<string, any>
@@ -123,13 +127,47 @@ export type PureArg =
   | null
   | TransactionArgument
   | Array<PureArg>
+/** A decoded instance of a struct with the `key` ability, which can be passed as an object argument. */
+export interface ObjectInstance {
+  readonly $objectId: string
+}
+
+export type ObjectInput = TransactionObjectInput | ObjectInstance
+
 export type GenericArg =
-  | TransactionObjectInput
+  | ObjectInput
   | PureArg
-  | Array<TransactionObjectInput>
+  | Array<ObjectInput>
   | Array<PureArg>
   | Array<GenericArg>
 
+/**
+ * A value returned by a Move call, tagged with its Move type (e.g. `MoveResult<Coin<ToPhantom<SUI>>>`).
+ * It can be passed as an argument to later calls in the same transaction.
+ */
+export type MoveResult<T> = TransactionArgument & { readonly __moveType?: T }
+
+/** Splits the result of a Move call returning multiple values into a tuple of its values. */
+export function splitResult(res: TransactionResult, count: number): TransactionArgument[] {
+  return Array.from({ length: count }, (_, i) => res[i])
+}
+
+declare const objectType: unique symbol
+
+/** An object ID tagged with the struct class of the object, as returned by e.g. `Pool.id(...)`. */
+export type TypedObjectId<T> = string & { readonly [objectType]: T }
+
+/**
+ * An object argument of struct class `T` in bindings generated with `strict_object_args`: a decoded
+ * instance, a `MoveResult` of a previous call or a typed object ID.
+ */
+export type ObjectArg<T> = T | MoveResult<T> | TypedObjectId<T>
+
+/** Passes an untyped object ID or argument where a typed object argument is expected. */
+export function untypedObject<T>(arg: TransactionObjectInput): ObjectArg<T> {
+  return arg as any
+}
+
 export function splitGenericParameters(
   str: string,
   genericSeparators: [string, string] = ['<', '>']
@@ -197,10 +235,29 @@ export function isTransactionArgument(arg: GenericArg): arg is TransactionArgume
   return 'GasCoin' in arg || 'Input' in arg || 'Result' in arg || 'NestedResult' in arg
 }
 
-export function obj(tx: Transaction, arg: TransactionObjectInput) {
+export function isObjectInstance(arg: GenericArg): arg is ObjectInstance {
+  return !!arg && typeof arg === 'object' && '$objectId' in arg
+}
+
+export function obj(tx: Transaction, arg: ObjectInput) {
+  if (isObjectInstance(arg)) {
+    return tx.object(arg.$objectId)
+  }
   return isTransactionArgument(arg) ? arg : tx.object(arg)
 }
 
+/**
+ * Resolves a `Coin<T>` argument. When an amount is passed in, a coin with that balance is created
+ * from the sender's coins of type `T` (merging and splitting them as needed, or splitting the gas
+ * coin for SUI) when the transaction is built.
+ */
+export function coinArg(tx: Transaction, type: string, arg: ObjectInput | bigint) {
+  if (typeof arg === 'bigint') {
+    return tx.add(coinWithBalance({ type, balance: arg }))
+  }
+  return obj(tx, arg)
+}
+
 export function pure(tx: Transaction, arg: PureArg, type: string): TransactionArgument {
   if (isTransactionArgument(arg)) {
     return obj(tx, arg)
@@ -378,12 +435,10 @@ export function generic(tx: Transaction, type: string, arg: GenericArg) {
 
       return tx.makeMoveVec({
         type: itemType,
-        elements: arg.map(item =>
-          obj(tx, item as TransactionObjectInput)
-        ) as Array<TransactionObjectArgument>,
+        elements: arg.map(item => obj(tx, item as ObjectInput)) as Array<TransactionObjectArgument>,
       })
     } else {
-      return obj(tx, arg as TransactionObjectInput)
+      return obj(tx, arg as ObjectInput)
     }
   }
 }
@@ -415,7 +470,9 @@ export function vector(
 
     return tx.makeMoveVec({
       type: itemType,
-      elements: items as Array<TransactionObjectArgument>,
+      elements: (items as Array<ObjectInput>).map(item =>
+        obj(tx, item)
+      ) as Array<TransactionObjectArgument>,
     })
   }
 }
@@ -495,6 +552,129 @@ export function composeSuiType(typeName: string, ...typeArgs: string[]): string
   }
 }
 
+/** Maximum number of object IDs that can be fetched in a single `multiGetObjects` call. */
+export const MULTI_GET_OBJECTS_LIMIT = 50
+
+export type FetchObjectResult<T> =
+  | { id: string; data: T; error?: undefined }
+  | { id: string; data?: undefined; error: Error }
+
+/**
+ * Fetches the objects with the given IDs in batches of `MULTI_GET_OBJECTS_LIMIT` and decodes each
+ * of them with `decode`. If `decode` isn't provided, each object is decoded into the class of its
+ * on-chain type using the `loader`. The results are in the same order as `ids` and an error for
+ * one object (e.g. it doesn't exist or has an unexpected type) doesn't fail the whole batch.
+ */
+export async function fetchObjects<T = StructClass>(
+  client: SuiClient,
+  ids: string[],
+  decode?: (data: SuiObjectData) => T
+): Promise<Array<FetchObjectResult<T>>> {
+  const decodeData = decode ?? (await decodeWithLoader<T>())
+
+  const batches: string[][] = []
+  for (let i = 0; i < ids.length; i += MULTI_GET_OBJECTS_LIMIT) {
+    batches.push(ids.slice(i, i + MULTI_GET_OBJECTS_LIMIT))
+  }
+  const responses = await Promise.allSettled(
+    batches.map(batch => client.multiGetObjects({ ids: batch, options: { showBcs: true } }))
+  )
+
+  return responses.flatMap((batchRes, i): Array<FetchObjectResult<T>> => {
+    const batch = batches[i]
+    if (batchRes.status === 'rejected') {
+      // a failed request fails the objects in its batch only
+      const error = toError(batchRes.reason)
+      return batch.map(id => ({ id, error }))
+    }
+    return batch.map((id, j): FetchObjectResult<T> => {
+      const res = batchRes.value[j]
+      try {
+        if (!res) {
+          throw new Error(`object at id ${id} missing from response`)
+        }
+        if (res.error) {
+          throw new Error(`error fetching object at id ${id}: ${res.error.code}`)
+        }
+        if (!res.data) {
+          throw new Error(`object at id ${id} not found`)
+        }
+        return { id, data: decodeData(res.data) }
+      } catch (e) {
+        return { id, error: toError(e) }
+      }
+    })
+  })
+}
+
+/**
+ * Same as `fetchObjects` without `decode`, except that an object whose on-chain type isn't an
+ * instantiation of `typeName` (a struct type without type arguments) results in an error.
+ */
+export async function fetchObjectsOfType<T = StructClass>(
+  client: SuiClient,
+  ids: string[],
+  typeName: string
+): Promise<Array<FetchObjectResult<T>>> {
+  return fetchObjects(client, ids, await decodeWithLoader<T>(typeName))
+}
+
+function toError(e: unknown): Error {
+  return e instanceof Error ? e : new Error(String(e))
+}
+
+/**
+ * Iterates over the objects of type `structType` owned by `owner`, paging through
+ * `getOwnedObjects` results, and decodes each of them with `decode`. If `structType` doesn't
+ * include type arguments, objects of all its instantiations are returned. If `decode` isn't
+ * provided, each object is decoded into the class of its on-chain type using the `loader`.
+ */
+export async function* fetchOwnedObjects<T = StructClass>(
+  client: SuiClient,
+  owner: string,
+  structType: string,
+  decode?: (data: SuiObjectData) => T
+): AsyncGenerator<T> {
+  const decodeData = decode ?? (await decodeWithLoader<T>())
+
+  let cursor: string | null | undefined = null
+  do {
+    const page = await client.getOwnedObjects({
+      owner,
+      filter: { StructType: structType },
+      options: { showBcs: true },
+      cursor,
+    })
+    for (const res of page.data) {
+      if (res.error) {
+        throw new Error(`error fetching objects owned by ${owner}: ${res.error.code}`)
+      }
+      if (res.data) {
+        yield decodeData(res.data)
+      }
+    }
+    cursor = page.hasNextPage ? page.nextCursor : null
+  } while (cursor)
+}
+
+async function decodeWithLoader<T>(typeName?: string): Promise<(data: SuiObjectData) => T> {
+  // imported lazily because the loader imports all generated classes, which import this module
+  const { loader } = await import('./loader')
+  const expected = typeName === undefined ? undefined : compressSuiType(typeName)
+  return (data: SuiObjectData) => {
+    if (data.bcs?.dataType !== 'moveObject') {
+      throw new Error(`object at id ${data.objectId} is not a Move object`)
+    }
+    if (
+      expected !== undefined &&
+      compressSuiType(parseTypeName(data.bcs.type).typeName) !== expected
+    ) {
+      throw new Error(`object at id ${data.objectId} is not a ${typeName} object`)
+    }
+    return loader.reified(data.bcs.type).fromSuiObjectData(data) as T
+  }
+}
+
 "#;
 
 pub static REIFIED: &str = r#"
@@ -902,6 +1082,62 @@ export function fieldToJSON<T extends TypeArgument>(type: string, field: ToField
   }
 }
 
+export function fieldToBcs<T extends TypeArgument>(type: string, field: ToField<T>): Uint8Array {
+  const { typeName, typeArgs } = parseTypeName(type)
+  switch (typeName) {
+    case 'bool':
+      return bcs.bool().serialize(field as any).toBytes()
+    case 'u8':
+      return bcs.u8().serialize(field as any).toBytes()
+    case 'u16':
+      return bcs.u16().serialize(field as any).toBytes()
+    case 'u32':
+      return bcs.u32().serialize(field as any).toBytes()
+    case 'u64':
+      return bcs.u64().serialize(field as any).toBytes()
+    case 'u128':
+      return bcs.u128().serialize(field as any).toBytes()
+    case 'u256':
+      return bcs.u256().serialize(field as any).toBytes()
+    case 'address':
+    case 'signer':
+      return bcs.Address.serialize(field as any).toBytes()
+    case 'vector': {
+      const elements = field as any[]
+      return concatBytes([
+        bcs.uleb128().serialize(elements.length).toBytes(),
+        ...elements.map((item: any) => fieldToBcs(typeArgs[0], item)),
+      ])
+    }
+    // handle special types
+    case '0x1::string::String':
+    case '0x1::ascii::String':
+    case '0x2::url::Url':
+      return bcs.string().serialize(field as any).toBytes()
+    case '0x2::object::ID':
+    case '0x2::object::UID':
+      return bcs.Address.serialize(field as any).toBytes()
+    case '0x1::option::Option': {
+      if (field === null) {
+        return new Uint8Array([0])
+      }
+      return concatBytes([new Uint8Array([1]), fieldToBcs(typeArgs[0], field)])
+    }
+    default:
+      return (field as any).toBcs()
+  }
+}
+
+export function concatBytes(chunks: Uint8Array[]): Uint8Array {
+  const result = new Uint8Array(chunks.reduce((len, chunk) => len + chunk.length, 0))
+  let offset = 0
+  for (const chunk of chunks) {
+    result.set(chunk, offset)
+    offset += chunk.length
+  }
+  return result
+}
+
 export function decodeFromJSONField(typeArg: Reified<TypeArgument, any>, field: any) {
   switch (typeArg) {
     case 'bool':
@@ -955,6 +1191,7 @@ import {
   VectorClass,
   VectorClassReified,
   fieldToJSON,
+  fieldToBcs,
 } from './reified'
 import { composeSuiType, FieldsWithTypes } from './util'
 
@@ -1041,6 +1278,10 @@ export class Vector<T extends TypeArgument> implements VectorClass {
     return this.elements.map(element => fieldToJSON(this.$typeArgs[0], element))
   }
 
+  toBcs(): Uint8Array {
+    return fieldToBcs(this.$fullTypeName, this.elements as any)
+  }
+
   toJSON() {
     return {
       $typeName: this.$typeName,
@@ -1075,3 +1316,508 @@ export function vector<T extends Reified<TypeArgument, any>>(
 }
 
 "#;
+
+static KIOSK: &str = r#"
+import { bcs } from '@mysten/sui/bcs'
+import { SuiClient } from '@mysten/sui/client'
+import {
+  Transaction,
+  TransactionArgument,
+  TransactionObjectArgument,
+} from '@mysten/sui/transactions'
+import { fromB64, normalizeStructTag, normalizeSuiAddress } from '@mysten/sui/utils'
+import * as kiosk from '{{sui}}/kiosk/functions'
+import { confirmRequest } from '{{sui}}/transfer-policy/functions'
+import { StructClass, StructClassReified } from './reified'
+import { ObjectInput, fetchObjects, isObjectInstance } from './util'
+
+/**
+ * Address of the package with the standard transfer policy rules (`royalty_rule`,
+ * `kiosk_lock_rule`, `personal_kiosk_rule` and `floor_price_rule`) on mainnet.
+ */
+export const KIOSK_RULES_PACKAGE =
+  '0x434b5bd8f6a7b05fede0ff46c6e511d71ea326ed38056e3bcd681d2d7c2a7879'
+
+export interface KioskItem<T> {
+  id: string
+  data: T
+  /** The listing price, if the item is listed for sale (exclusive listings are not included). */
+  price?: bigint
+}
+
+export interface KioskPlaceArgs {
+  kiosk: ObjectInput
+  kioskCap: ObjectInput
+  item: ObjectInput
+}
+
+export interface KioskListArgs {
+  kiosk: ObjectInput
+  kioskCap: ObjectInput
+  itemId: string
+  price: bigint
+}
+
+export interface KioskPlaceAndListArgs {
+  kiosk: ObjectInput
+  kioskCap: ObjectInput
+  item: ObjectInput
+  price: bigint
+}
+
+export interface KioskTakeArgs {
+  kiosk: ObjectInput
+  kioskCap: ObjectInput
+  itemId: string
+}
+
+export interface KioskPurchaseArgs {
+  kiosk: ObjectInput
+  itemId: string
+  payment: ObjectInput
+}
+
+export interface KioskPurchaseAndResolveArgs {
+  kiosk: ObjectInput
+  itemId: string
+  /** The listing price, which is split from the gas coin to pay for the item. */
+  price: bigint
+  /** The `TransferPolicy<T>` to resolve the rules of. Looked up from events if not provided. */
+  policy?: string
+  /** The buyer's kiosk. Required by the kiosk lock and personal kiosk rules. */
+  buyerKiosk?: ObjectInput
+  /** The buyer's `KioskOwnerCap`. Required by the kiosk lock rule. */
+  buyerKioskCap?: ObjectInput
+  /** Resolvers for rules not covered by the built-in ones, keyed by the rule's full type name. */
+  resolvers?: Record<string, RuleResolver>
+  /** Address of the standard rules package. Defaults to `KIOSK_RULES_PACKAGE`. */
+  rulesPackage?: string
+}
+
+export interface RuleResolverContext {
+  tx: Transaction
+  itemType: string
+  item: TransactionObjectArgument
+  transferRequest: TransactionObjectArgument
+  policy: TransactionObjectArgument
+  price: bigint
+  buyerKiosk?: ObjectInput
+  buyerKioskCap?: ObjectInput
+}
+
+/**
+ * Adds the calls needed to satisfy a transfer policy rule to the transaction. Returns `true` if the
+ * item was moved (e.g. locked into the buyer's kiosk) and is no longer available to the caller.
+ */
+export type RuleResolver = (ctx: RuleResolverContext) => boolean | void
+
+export interface KioskHelpers<T extends StructClass> {
+  /** Fetches the items of this type placed in the kiosk, along with their listing prices. */
+  fetchItems: (client: SuiClient, kioskId: string) => Promise<Array<KioskItem<T>>>
+  place: (tx: Transaction, args: KioskPlaceArgs) => TransactionArgument
+  list: (tx: Transaction, args: KioskListArgs) => TransactionArgument
+  placeAndList: (tx: Transaction, args: KioskPlaceAndListArgs) => TransactionArgument
+  take: (tx: Transaction, args: KioskTakeArgs) => TransactionArgument
+  /** Returns the purchased item and the `TransferRequest<T>` that needs to be confirmed. */
+  purchase: (
+    tx: Transaction,
+    args: KioskPurchaseArgs
+  ) => [TransactionObjectArgument, TransactionObjectArgument]
+  /**
+   * Purchases the item, resolves the rules of its transfer policy and confirms the request.
+   * Returns the item, or `undefined` if a rule moved it (e.g. locked it into the buyer's kiosk).
+   */
+  purchaseAndResolve: (
+    tx: Transaction,
+    client: SuiClient,
+    args: KioskPurchaseAndResolveArgs
+  ) => Promise<TransactionObjectArgument | undefined>
+}
+
+export function kioskHelpers<T extends StructClass>(
+  reified: StructClassReified<T, any>
+): KioskHelpers<T> {
+  const itemType = reified.fullTypeName as string
+  return {
+    fetchItems: (client, kioskId) => fetchKioskItems(client, reified, kioskId),
+    place: (tx, args) => kioskPlace(tx, itemType, args),
+    list: (tx, args) => kioskList(tx, itemType, args),
+    placeAndList: (tx, args) => kioskPlaceAndList(tx, itemType, args),
+    take: (tx, args) => kioskTake(tx, itemType, args),
+    purchase: (tx, args) => kioskPurchase(tx, itemType, args),
+    purchaseAndResolve: (tx, client, args) => kioskPurchaseAndResolve(tx, client, itemType, args),
+  }
+}
+
+const ListingField = bcs.struct('Field', {
+  id: bcs.Address,
+  name: bcs.struct('Listing', { id: bcs.Address, is_exclusive: bcs.bool() }),
+  value: bcs.u64(),
+})
+
+export async function fetchKioskItems<T extends StructClass>(
+  client: SuiClient,
+  reified: StructClassReified<T, any>,
+  kioskId: string
+): Promise<Array<KioskItem<T>>> {
+  const itemType = normalizeStructTag(reified.fullTypeName as string)
+  const itemIds: string[] = []
+  const listingFieldIds: string[] = []
+
+  let cursor: string | null | undefined = null
+  do {
+    const page = await client.getDynamicFields({ parentId: kioskId, cursor })
+    for (const field of page.data) {
+      const nameType = normalizeStructTag(field.name.type)
+      if (
+        nameType === normalizeStructTag('0x2::kiosk::Item') &&
+        normalizeStructTag(field.objectType) === itemType
+      ) {
+        itemIds.push(field.objectId)
+      } else if (nameType === normalizeStructTag('0x2::kiosk::Listing')) {
+        listingFieldIds.push(field.objectId)
+      }
+    }
+    cursor = page.hasNextPage ? page.nextCursor : null
+  } while (cursor)
+
+  const [items, listings] = await Promise.all([
+    fetchObjects(client, itemIds, data => reified.fromSuiObjectData(data)),
+    fetchObjects(client, listingFieldIds, data => {
+      if (data.bcs?.dataType !== 'moveObject') {
+        throw new Error(`object ${data.objectId} is not a move object`)
+      }
+      return ListingField.parse(fromB64(data.bcs.bcsBytes))
+    }),
+  ])
+
+  const prices = new Map<string, bigint>()
+  for (const listing of listings) {
+    if (listing.error) {
+      throw listing.error
+    }
+    if (!listing.data.name.is_exclusive) {
+      prices.set(normalizeSuiAddress(listing.data.name.id), BigInt(listing.data.value))
+    }
+  }
+
+  return items.map(item => {
+    if (item.error) {
+      throw item.error
+    }
+    return { id: item.id, data: item.data, price: prices.get(normalizeSuiAddress(item.id)) }
+  })
+}
+
+/**
+ * Turns a decoded object instance into its ID. The argument is passed on untyped, so that it's
+ * accepted by bindings generated with `strict_object_args` too.
+ */
+function objectInput(arg: ObjectInput): any {
+  return isObjectInstance(arg) ? arg.$objectId : arg
+}
+
+export function kioskPlace(tx: Transaction, itemType: string, args: KioskPlaceArgs) {
+  return kiosk.place(tx, itemType, {
+    self: objectInput(args.kiosk),
+    cap: objectInput(args.kioskCap),
+    item: args.item,
+  })
+}
+
+export function kioskList(tx: Transaction, itemType: string, args: KioskListArgs) {
+  return kiosk.list(tx, itemType, {
+    self: objectInput(args.kiosk),
+    cap: objectInput(args.kioskCap),
+    id: args.itemId,
+    price: args.price,
+  })
+}
+
+export function kioskPlaceAndList(tx: Transaction, itemType: string, args: KioskPlaceAndListArgs) {
+  return kiosk.placeAndList(tx, itemType, {
+    self: objectInput(args.kiosk),
+    cap: objectInput(args.kioskCap),
+    item: args.item,
+    price: args.price,
+  })
+}
+
+export function kioskTake(tx: Transaction, itemType: string, args: KioskTakeArgs) {
+  return kiosk.take(tx, itemType, {
+    self: objectInput(args.kiosk),
+    cap: objectInput(args.kioskCap),
+    id: args.itemId,
+  })
+}
+
+export function kioskPurchase(
+  tx: Transaction,
+  itemType: string,
+  args: KioskPurchaseArgs
+): [TransactionObjectArgument, TransactionObjectArgument] {
+  const [item, transferRequest] = kiosk.purchase(tx, itemType, {
+    self: objectInput(args.kiosk),
+    id: args.itemId,
+    payment: objectInput(args.payment),
+  })
+  return [item, transferRequest]
+}
+
+/** Finds the ID of the first `TransferPolicy<T>` created for `itemType`. */
+export async function findTransferPolicy(client: SuiClient, itemType: string): Promise<string> {
+  const res = await client.queryEvents({
+    query: { MoveEventType: `0x2::transfer_policy::TransferPolicyCreated<${itemType}>` },
+    limit: 1,
+  })
+  if (res.data.length === 0) {
+    throw new Error(`no transfer policy found for ${itemType}`)
+  }
+  return (res.data[0].parsedJson as { id: string }).id
+}
+
+const TransferPolicyBcs = bcs.struct('TransferPolicy', {
+  id: bcs.Address,
+  balance: bcs.u64(),
+  rules: bcs.vector(bcs.string()),
+})
+
+/** Fetches the full type names of the rules set on a `TransferPolicy`. */
+export async function fetchPolicyRules(client: SuiClient, policyId: string): Promise<string[]> {
+  const res = await client.getObject({ id: policyId, options: { showBcs: true } })
+  if (res.data?.bcs?.dataType !== 'moveObject') {
+    throw new Error(`transfer policy at id ${policyId} not found`)
+  }
+  return TransferPolicyBcs.parse(fromB64(res.data.bcs.bcsBytes)).rules.map(rule =>
+    normalizeStructTag(rule)
+  )
+}
+
+function standardRuleResolvers(rulesPackage: string): Record<string, RuleResolver> {
+  const pkg = normalizeSuiAddress(rulesPackage)
+  const requireBuyerKiosk = (ctx: RuleResolverContext) => {
+    if (!ctx.buyerKiosk) {
+      throw new Error('the transfer policy requires the item to be placed in the buyer kiosk')
+    }
+    return ctx.buyerKiosk
+  }
+
+  return {
+    // `pay` splits the royalty off the coin it's given, so the gas coin can be passed directly
+    [`${pkg}::royalty_rule::Rule`]: ({ tx, itemType, policy, transferRequest }) => {
+      tx.moveCall({
+        target: `${pkg}::royalty_rule::pay`,
+        typeArguments: [itemType],
+        arguments: [policy, transferRequest, tx.gas],
+      })
+    },
+    [`${pkg}::kiosk_lock_rule::Rule`]: ctx => {
+      const { tx, itemType, policy, item, transferRequest } = ctx
+      const buyerKiosk = requireBuyerKiosk(ctx)
+      if (!ctx.buyerKioskCap) {
+        throw new Error('the transfer policy requires the buyer kiosk cap to lock the item')
+      }
+      const buyerKioskArg = tx.object(objectInput(buyerKiosk))
+      kiosk.lock(tx, itemType, {
+        self: buyerKioskArg,
+        cap: objectInput(ctx.buyerKioskCap),
+        policy,
+        item,
+      })
+      tx.moveCall({
+        target: `${pkg}::kiosk_lock_rule::prove`,
+        typeArguments: [itemType],
+        arguments: [transferRequest, buyerKioskArg],
+      })
+      return true
+    },
+    [`${pkg}::personal_kiosk_rule::Rule`]: ctx => {
+      const { tx, itemType, transferRequest } = ctx
+      tx.moveCall({
+        target: `${pkg}::personal_kiosk_rule::prove`,
+        typeArguments: [itemType],
+        arguments: [tx.object(objectInput(requireBuyerKiosk(ctx))), transferRequest],
+      })
+    },
+    [`${pkg}::floor_price_rule::Rule`]: ({ tx, itemType, policy, transferRequest }) => {
+      tx.moveCall({
+        target: `${pkg}::floor_price_rule::prove`,
+        typeArguments: [itemType],
+        arguments: [policy, transferRequest],
+      })
+    },
+  }
+}
+
+/**
+ * Purchases an item from a kiosk, paying the price from the gas coin, and resolves all the rules of
+ * the item type's transfer policy before confirming the `TransferRequest`. The standard rules are
+ * resolved automatically; other rules need a resolver in `args.resolvers`.
+ */
+export async function kioskPurchaseAndResolve(
+  tx: Transaction,
+  client: SuiClient,
+  itemType: string,
+  args: KioskPurchaseAndResolveArgs
+): Promise<TransactionObjectArgument | undefined> {
+  const policyId = args.policy ?? (await findTransferPolicy(client, itemType))
+  const rules = await fetchPolicyRules(client, policyId)
+
+  const resolvers: Record<string, RuleResolver> = {
+    ...standardRuleResolvers(args.rulesPackage ?? KIOSK_RULES_PACKAGE),
+  }
+  for (const [rule, resolver] of Object.entries(args.resolvers ?? {})) {
+    resolvers[normalizeStructTag(rule)] = resolver
+  }
+  const missing = rules.filter(rule => !resolvers[rule])
+  if (missing.length > 0) {
+    throw new Error(`no resolver for transfer policy rules: ${missing.join(', ')}`)
+  }
+
+  const [payment] = tx.splitCoins(tx.gas, [args.price])
+  const [item, transferRequest] = kioskPurchase(tx, itemType, {
+    kiosk: args.kiosk,
+    itemId: args.itemId,
+    payment,
+  })
+  const policy = tx.object(policyId)
+
+  let moved = false
+  for (const rule of rules) {
+    const ctx: RuleResolverContext = {
+      tx,
+      itemType,
+      item,
+      transferRequest,
+      policy,
+      price: args.price,
+      buyerKiosk: args.buyerKiosk,
+      buyerKioskCap: args.buyerKioskCap,
+    }
+    moved = resolvers[rule](ctx) === true || moved
+  }
+
+  confirmRequest(tx, itemType, { self: policy, request: transferRequest })
+
+  return moved ? undefined : item
+}
+
+"#;
+
+/// The kiosk helpers, with the generated sui framework bindings imported from `sui_path` (relative
+/// to `_framework`, e.g. `../sui`).
+pub fn kiosk(sui_path: &str) -> String {
+    KIOSK.replace("{{sui}}", sui_path)
+}
+
+pub static DISPLAY: &str = r#"
+import { bcs } from '@mysten/sui/bcs'
+import { SuiClient } from '@mysten/sui/client'
+import { fromB64 } from '@mysten/sui/utils'
+import { StructClass } from './reified'
+
+export interface DisplayTemplate<T extends StructClass> {
+  /** ID of the `Display<T>` object. */
+  id: string
+  version: number
+  /** The `{field.path}` templates of the display, keyed by display field name. */
+  fields: Record<string, string>
+  /** Renders the templates of the display for a decoded instance of `T`. */
+  renderDisplay: (instance: T) => Record<string, string>
+}
+
+const DisplayBcs = bcs.struct('Display', {
+  id: bcs.Address,
+  fields: bcs.struct('VecMap', {
+    contents: bcs.vector(bcs.struct('Entry', { key: bcs.string(), value: bcs.string() })),
+  }),
+  version: bcs.u16(),
+})
+
+/**
+ * Fetches the `Display<T>` object for the exact type `fullTypeName` (including type arguments).
+ * Like the RPC's `showDisplay`, the display whose version was published last is used. Returns
+ * `null` if no display has been published for the type.
+ */
+export async function fetchDisplay<T extends StructClass>(
+  client: SuiClient,
+  fullTypeName: string
+): Promise<DisplayTemplate<T> | null> {
+  const events = await client.queryEvents({
+    query: { MoveEventType: `0x2::display::VersionUpdated<${fullTypeName}>` },
+    order: 'descending',
+    limit: 1,
+  })
+  if (events.data.length === 0) {
+    return null
+  }
+
+  const id = (events.data[0].parsedJson as { id: string }).id
+  const res = await client.getObject({ id, options: { showBcs: true } })
+  if (res.error) {
+    throw new Error(`error fetching display object at id ${id}: ${res.error.code}`)
+  }
+  if (res.data?.bcs?.dataType !== 'moveObject') {
+    throw new Error(`object at id ${id} is not a display object`)
+  }
+
+  const display = DisplayBcs.parse(fromB64(res.data.bcs.bcsBytes))
+  const fields: Record<string, string> = {}
+  for (const { key, value } of display.fields.contents) {
+    fields[key] = value
+  }
+
+  return {
+    id,
+    version: display.version,
+    fields,
+    renderDisplay: (instance: T) => renderDisplay(fields, instance),
+  }
+}
+
+function resolveDisplayPath(instance: StructClass, path: string): string {
+  let value: any = instance
+  for (const name of path.split('.')) {
+    const fieldNames: Record<string, string> | undefined = value?.$moveFieldNames
+    if (fieldNames === undefined || !(name in fieldNames)) {
+      throw new Error(`cannot resolve display field path "${path}" for ${instance.$fullTypeName}`)
+    }
+    value = value[fieldNames[name]]
+  }
+
+  switch (typeof value) {
+    case 'string':
+      return value
+    case 'number':
+    case 'bigint':
+    case 'boolean':
+      return String(value)
+  }
+  if (value === null) {
+    return ''
+  }
+  throw new Error(
+    `display field path "${path}" of ${instance.$fullTypeName} doesn't resolve to a primitive value`
+  )
+}
+
+/**
+ * Renders display templates for a decoded instance. Each `{field.path}` in a template is replaced
+ * with the value found by following the Move field names of the path through the instance and its
+ * nested structs, so objects can be rendered from their BCS without the RPC's `showDisplay`.
+ * Paths must lead to a primitive, string, ID, address or `Option` (rendered empty when `none`).
+ */
+export function renderDisplay(
+  fields: Record<string, string>,
+  instance: StructClass
+): Record<string, string> {
+  const rendered: Record<string, string> = {}
+  for (const [key, template] of Object.entries(fields)) {
+    rendered[key] = template.replace(/\{([^{}]*)\}/g, (_, path: string) =>
+      resolveDisplayPath(instance, path.trim())
+    )
+  }
+  return rendered
+}
+
+"#;
@@ -4,52 +4,15 @@ use anyhow::{bail, Result};
 use convert_case::{Case, Casing};
 use genco::prelude::*;
 use genco::tokens::{Item, ItemStr};
+use move_binary_format::file_format::{AbilitySet, Visibility};
 use move_binary_format::normalized::Type as MType;
 use move_core_types::account_address::AccountAddress;
-use move_model::model::{DatatypeId, FieldEnv, FunctionEnv, GlobalEnv, ModuleEnv, StructEnv};
+use move_model::model::{DatatypeId, FieldEnv, FunctionEnv, GlobalEnv, Loc, ModuleEnv, StructEnv};
 use move_model::symbol::{Symbol, SymbolPool};
-use move_model::ty::{PrimitiveType, Type};
+use move_model::ty::{PrimitiveType, Type, TypeDisplayContext};
 
 use crate::model_builder::{TypeOriginTable, VersionTable};
-
-#[rustfmt::skip]
-const JS_RESERVED_WORDS: [&str; 64] = [
-    "abstract", "arguments", "await", "boolean", "break", "byte", "case", "catch",
-    "char", "class", "const", "continue", "debugger", "default", "delete", "do",
-    "double", "else", "enum", "eval", "export", "extends", "false", "final",
-    "finally", "float", "for", "function", "goto", "if", "implements", "import",
-    "in", "instanceof", "int", "interface", "let", "long", "native", "new",
-    "null", "package", "private", "protected", "public", "return", "short", "static",
-    "super", "switch", "synchronized", "this", "throw", "throws", "transient", "true",
-    "try", "typeof", "var", "void", "volatile", "while", "with", "yield"
-];
-
-#[rustfmt::skip]
-const JS_STRICTLY_RESERVED_WORDS: [&str; 37] = [
-    "await", "break", "case", "catch", "class", "const", "continue", "debugger",
-    "default", "delete", "do", "else", "export", "extends", "false", "finally",
-    "for", "function", "if", "import", "in", "instanceof", "new", "null", "return",
-    "super", "switch", "this", "throw", "true", "try", "typeof", "var", "void",
-    "while", "with", "yield"
-];
-
-/// Returns module name that's used in import paths (converts kebab case as that's idiomatic in TS).
-pub fn module_import_name(module: &ModuleEnv) -> String {
-    module
-        .get_name()
-        .display(module.env.symbol_pool())
-        .to_string()
-        .from_case(Case::Snake)
-        .to_case(Case::Kebab)
-}
-
-/// Returns package name that's used in import paths (converts to kebab case as that's idiomatic in TS).
-pub fn package_import_name(pkg_name: move_symbol_pool::Symbol) -> String {
-    pkg_name
-        .to_string()
-        .from_case(Case::Pascal)
-        .to_case(Case::Kebab)
-}
+use crate::naming::{move_field_key, Naming, JS_RESERVED_WORDS, JS_STRICTLY_RESERVED_WORDS};
 
 pub struct FrameworkImportCtx {
     framework_rel_path: String,
@@ -86,6 +49,7 @@ pub struct StructClassImportCtx<'env, 'a> {
     is_source: bool,
     top_level_pkg_names: &'a BTreeMap<AccountAddress, move_symbol_pool::Symbol>,
     is_structs_gen: bool,
+    naming: &'a Naming,
 }
 
 impl<'env, 'a> StructClassImportCtx<'env, 'a> {
@@ -95,6 +59,7 @@ impl<'env, 'a> StructClassImportCtx<'env, 'a> {
         is_source: bool,
         top_level_pkg_names: &'a BTreeMap<AccountAddress, move_symbol_pool::Symbol>,
         is_structs_gen: bool,
+        naming: &'a Naming,
     ) -> Self {
         StructClassImportCtx {
             reserved_names: reserved_names
@@ -106,6 +71,7 @@ impl<'env, 'a> StructClassImportCtx<'env, 'a> {
             is_source,
             top_level_pkg_names,
             is_structs_gen,
+            naming,
         }
     }
 
@@ -113,6 +79,7 @@ impl<'env, 'a> StructClassImportCtx<'env, 'a> {
         module: &'env ModuleEnv,
         is_source: bool,
         top_level_pkg_names: &'a BTreeMap<AccountAddress, move_symbol_pool::Symbol>,
+        naming: &'a Naming,
     ) -> Self {
         let reserved_names = vec![];
         let is_structs_gen = false;
@@ -122,6 +89,7 @@ impl<'env, 'a> StructClassImportCtx<'env, 'a> {
             is_source,
             top_level_pkg_names,
             is_structs_gen,
+            naming,
         )
     }
 
@@ -129,13 +97,11 @@ impl<'env, 'a> StructClassImportCtx<'env, 'a> {
         module: &'env ModuleEnv,
         is_source: bool,
         top_level_pkg_names: &'a BTreeMap<AccountAddress, move_symbol_pool::Symbol>,
+        naming: &'a Naming,
     ) -> Self {
-        let struct_names = module.get_structs().map(|strct| {
-            strct
-                .get_name()
-                .display(module.env.symbol_pool())
-                .to_string()
-        });
+        let struct_names = module
+            .get_structs()
+            .map(|strct| naming.struct_class_name(&strct));
         let enum_names = module
             .get_enums()
             .map(|enm| enm.get_name().display(module.env.symbol_pool()).to_string());
@@ -149,13 +115,14 @@ impl<'env, 'a> StructClassImportCtx<'env, 'a> {
             is_source,
             top_level_pkg_names,
             is_structs_gen,
+            naming,
         )
     }
 
     /// Returns the import path for a struct. If the struct is defined in the current module,
     /// returns `None`.
     pub fn import_path_for_struct(&self, strct: &StructEnv) -> Option<String> {
-        let module_name = module_import_name(&strct.module_env);
+        let module_name = self.naming.module_import_name(&strct.module_env);
 
         if strct.module_env.self_address() == self.module.self_address()
             && strct.module_env.get_id() == self.module.get_id()
@@ -176,7 +143,7 @@ impl<'env, 'a> StructClassImportCtx<'env, 'a> {
                 .contains_key(strct.module_env.self_address());
 
             if self.is_top_level && strct_is_top_level {
-                let strct_pkg_name = package_import_name(
+                let strct_pkg_name = self.naming.package_import_name(
                     *self
                         .top_level_pkg_names
                         .get(strct.module_env.self_address())
@@ -194,7 +161,7 @@ impl<'env, 'a> StructClassImportCtx<'env, 'a> {
                     module_name
                 ))
             } else if strct_is_top_level {
-                let strct_pkg_name = package_import_name(
+                let strct_pkg_name = self.naming.package_import_name(
                     *self
                         .top_level_pkg_names
                         .get(strct.module_env.self_address())
@@ -225,7 +192,7 @@ impl<'env, 'a> StructClassImportCtx<'env, 'a> {
     /// Returns the class name for a struct and imports it if necessary. If a class with the same name
     /// has already been imported, imports it with an alias (e.g. Foo1, Foo2, etc.).
     pub fn get_class(&mut self, strct: &StructEnv) -> js::Tokens {
-        let class_name = strct.get_name().display(strct.symbol_pool()).to_string();
+        let class_name = self.naming.struct_class_name(strct);
         let import_path = self.import_path_for_struct(strct);
 
         let import_path = match import_path {
@@ -335,34 +302,213 @@ fn gen_full_name_with_address(
     toks
 }
 
-pub fn gen_package_init_ts(modules: &[ModuleEnv], framework: &FrameworkImportCtx) -> js::Tokens {
+/// Returns the identifier a module is imported under as a namespace (e.g. `import * as pool`).
+pub fn module_namespace_name(module: &ModuleEnv) -> String {
+    let module_name = module
+        .get_name()
+        .display(module.env.symbol_pool())
+        .to_string();
+
+    let mut name = module_name.to_case(Case::Camel);
+    if JS_RESERVED_WORDS.contains(&name.as_str()) {
+        name.push('_');
+    }
+    name
+}
+
+/// Returns the identifier a top-level package is exported under in the root barrel file.
+pub fn package_namespace_name(pkg_name: move_symbol_pool::Symbol) -> String {
+    let mut name = pkg_name
+        .as_str()
+        .from_case(Case::Pascal)
+        .to_case(Case::Camel);
+    if JS_RESERVED_WORDS.contains(&name.as_str()) {
+        name.push('_');
+    }
+    name
+}
+
+/// Appends a TSDoc comment (`/** ... */`) with the given lines. Does nothing if there are no lines.
+fn gen_doc_comment(tokens: &mut js::Tokens, lines: &[String]) {
+    if lines.is_empty() {
+        return;
+    }
+
+    tokens.push();
+    tokens.append("/**");
+    for line in lines {
+        tokens.push();
+        // make sure the doc can't terminate the comment early
+        let line = line.replace("*/", "*\\/");
+        if line.is_empty() {
+            tokens.append(" *");
+        } else {
+            tokens.append(format!(" * {}", line));
+        }
+    }
+    tokens.push();
+    tokens.append(" */");
+    tokens.push();
+}
+
+/// Returns the lines of a Move doc comment (`///`) with trailing whitespace removed.
+fn doc_lines(doc: &str) -> Vec<String> {
+    let doc = doc.trim();
+    if doc.is_empty() {
+        return vec![];
+    }
+    doc.lines()
+        .map(|line| line.trim_end().to_string())
+        .collect()
+}
+
+/// Returns the `file:line` of a Move declaration. The file path is relative to the package
+/// root (e.g. `sources/pool.move`) so that the generated code doesn't depend on where the
+/// package was fetched to.
+fn loc_str(env: &GlobalEnv, loc: &Loc) -> Option<String> {
+    let loc = MoveLoc::new(env, loc)?;
+    Some(format!("{}:{}", loc.rel_file, loc.line))
+}
+
+/// Location of a Move declaration in its source file.
+pub(crate) struct MoveLoc {
+    /// Path of the source file relative to its package root (e.g. `sources/pool.move`).
+    pub(crate) rel_file: String,
+    /// 1-based line number.
+    pub(crate) line: u32,
+    /// 1-based column number.
+    pub(crate) column: u32,
+}
+
+impl MoveLoc {
+    pub(crate) fn new(env: &GlobalEnv, loc: &Loc) -> Option<Self> {
+        let (file, location) = env.get_file_and_location(loc)?;
+        let file = file.replace('\\', "/");
+        let rel_file = ["/sources/", "/tests/"]
+            .iter()
+            .filter_map(|dir| file.rfind(dir).map(|idx| &file[idx + 1..]))
+            .next()
+            .unwrap_or_else(|| file.rsplit('/').next().unwrap_or(&file))
+            .to_string();
+
+        Some(MoveLoc {
+            rel_file,
+            line: location.line.0 + 1,
+            column: location.column.0 + 1,
+        })
+    }
+}
+
+/// Generates the source map (`.move-map.json`) for a module which maps each generated function
+/// binding and struct class back to its Move declaration. For source packages, the entries
+/// contain the location of the declaration in the source file, while for on-chain packages
+/// only the package ID and the module and declaration names are available.
+pub fn gen_module_source_map(
+    module: &ModuleEnv,
+    published_at: &AccountAddress,
+    is_source: bool,
+    with_functions: bool,
+    naming: &Naming,
+) -> serde_json::Value {
+    let env = module.env;
+    let module_name = module.get_name().display(env.symbol_pool()).to_string();
+
+    let entry = |ts_file: &str, ts_name: String, kind: &str, move_name: String, loc: Loc| {
+        let mut move_decl = serde_json::json!({
+            "kind": kind,
+            "packageId": module.self_address().to_hex_literal(),
+            "publishedAt": published_at.to_hex_literal(),
+            "module": &module_name,
+            "name": move_name,
+        });
+        if is_source {
+            if let Some(loc) = MoveLoc::new(env, &loc) {
+                move_decl["file"] = loc.rel_file.into();
+                move_decl["line"] = loc.line.into();
+                move_decl["column"] = loc.column.into();
+            }
+        }
+        serde_json::json!({
+            "ts": { "file": ts_file, "name": ts_name },
+            "move": move_decl,
+        })
+    };
+
+    let mut entries = vec![];
+    if with_functions {
+        for func in module.get_functions() {
+            entries.push(entry(
+                "functions.ts",
+                naming.fun_name(&func),
+                "function",
+                func.get_name_str(),
+                func.get_loc(),
+            ));
+        }
+    }
+    for strct in module.get_structs() {
+        let struct_name = strct.get_name().display(env.symbol_pool()).to_string();
+        entries.push(entry(
+            "structs.ts",
+            naming.struct_class_name(&strct),
+            "struct",
+            struct_name,
+            strct.get_loc(),
+        ));
+    }
+
+    serde_json::json!({
+        "version": 1,
+        "packageId": module.self_address().to_hex_literal(),
+        "publishedAt": published_at.to_hex_literal(),
+        "module": module_name,
+        "source": is_source,
+        "entries": entries,
+    })
+}
+
+fn abilities_str(abilities: AbilitySet) -> String {
+    let mut names = vec![];
+    if abilities.has_copy() {
+        names.push("copy");
+    }
+    if abilities.has_drop() {
+        names.push("drop");
+    }
+    if abilities.has_store() {
+        names.push("store");
+    }
+    if abilities.has_key() {
+        names.push("key");
+    }
+    names.join(", ")
+}
+
+/// Type parameters in on-chain modules don't have names and are displayed as `unknown#0`,
+/// so rename them to `T0`, `T1`, etc. the same way they're named in the generated code.
+fn type_display_str(ty: &Type, ctx: &TypeDisplayContext) -> String {
+    ty.display(ctx).to_string().replace("unknown#", "T")
+}
+
+pub fn gen_package_init_ts(
+    modules: &[ModuleEnv],
+    framework: &FrameworkImportCtx,
+    naming: &Naming,
+) -> js::Tokens {
     let struct_class_loader = &framework.import("loader", "StructClassLoader");
     // TODO use canonical module names
     quote! {
         export function registerClasses(loader: $struct_class_loader) {
             $(ref toks {
                 for module in modules.iter() {
-                    let module_name = module
-                        .get_name()
-                        .display(module.env.symbol_pool())
-                        .to_string();
-
-                    let mut imported_name = module_name.to_case(Case::Camel);
-                    if JS_RESERVED_WORDS.contains(&imported_name.as_str()) {
-                        imported_name.push('_');
-                    }
-
                     let module_import = &js::import(
-                        format!("./{}/structs", module_import_name(module)),
-                        imported_name,
+                        format!("./{}/structs", naming.module_import_name(module)),
+                        module_namespace_name(module),
                     )
                     .into_wildcard();
 
                     for strct in module.get_structs() {
-                        let strct_name = strct
-                            .get_name()
-                            .display(module.env.symbol_pool())
-                            .to_string();
+                        let strct_name = naming.struct_class_name(&strct);
 
                         quote_in! { *toks =>
                             loader.register($(module_import).$(strct_name));$['\r']
@@ -374,16 +520,140 @@ pub fn gen_package_init_ts(modules: &[ModuleEnv], framework: &FrameworkImportCtx
     }
 }
 
+/// A name exported from a generated file. Used to generate barrel files.
+pub struct BarrelExport {
+    pub name: String,
+    /// Whether the export is type-only (e.g. an interface) and has to be re-exported with
+    /// `export type` (required by `isolatedModules`).
+    pub is_type: bool,
+}
+
+impl BarrelExport {
+    pub fn value(name: impl Into<String>) -> Self {
+        BarrelExport {
+            name: name.into(),
+            is_type: false,
+        }
+    }
+
+    pub fn type_(name: impl Into<String>) -> Self {
+        BarrelExport {
+            name: name.into(),
+            is_type: true,
+        }
+    }
+}
+
+/// Generates a barrel file re-exporting the given names from each of the given paths. If a name
+/// is exported from more than one path, it's re-exported with an alias the same way
+/// `StructClassImportCtx::get_class` aliases imports (e.g. `isFoo`, `isFoo1`, etc.).
+pub fn gen_barrel_ts(exports: Vec<(String, Vec<BarrelExport>)>) -> js::Tokens {
+    let mut toks = js::Tokens::new();
+    let mut reserved_names: HashMap<String, usize> = HashMap::new();
+
+    for (path, names) in exports {
+        let mut values = vec![];
+        let mut types = vec![];
+        for export in names {
+            let count = reserved_names.entry(export.name.clone()).or_insert(0);
+            let item = match *count {
+                0 => export.name.clone(),
+                idx => format!("{} as {}{}", export.name, export.name, idx),
+            };
+            *count += 1;
+
+            if export.is_type {
+                types.push(item);
+            } else {
+                values.push(item);
+            }
+        }
+
+        if !values.is_empty() {
+            quote_in! { toks =>
+                export { $(for item in values join (, ) => $item) } from $[str]($[const](path.clone()));$['\r']
+            }
+        }
+        if !types.is_empty() {
+            quote_in! { toks =>
+                export type { $(for item in types join (, ) => $item) } from $[str]($[const](path.clone()));$['\r']
+            }
+        }
+    }
+
+    toks
+}
+
+/// Returns whether a module gets an `index.ts` barrel. Modules without structs or functions have
+/// nothing to export, so they're left out.
+pub fn module_has_barrel(module: &ModuleEnv) -> bool {
+    module.get_structs().next().is_some() || module.get_functions().next().is_some()
+}
+
+/// Generates the package-level barrel file (`barrel.ts`) which re-exports the package constants
+/// from `index.ts` and each module (`<module>/index.ts`) as a namespace.
+pub fn gen_package_barrel_ts(modules: &[ModuleEnv], naming: &Naming) -> js::Tokens {
+    let mut toks = js::Tokens::new();
+    let mut reserved_names: HashMap<String, usize> = HashMap::new();
+
+    quote_in! { toks =>
+        export * from "./index";$['\r']
+    }
+    for module in modules.iter() {
+        if !module_has_barrel(module) {
+            continue;
+        }
+
+        let name = module_namespace_name(module);
+        let count = reserved_names.entry(name.clone()).or_insert(0);
+        let name = match *count {
+            0 => name,
+            idx => format!("{}{}", name, idx),
+        };
+        *count += 1;
+
+        quote_in! { toks =>
+            export * as $name from $[str]($[const](format!("./{}", naming.module_import_name(module))));$['\r']
+        }
+    }
+
+    toks
+}
+
+/// Generates the root barrel file (`index.ts` in the output root) which re-exports each top-level
+/// package as a namespace.
+pub fn gen_root_barrel_ts(pkg_names: &[move_symbol_pool::Symbol], naming: &Naming) -> js::Tokens {
+    let mut toks = js::Tokens::new();
+    let mut reserved_names: HashMap<String, usize> = HashMap::new();
+
+    for pkg_name in pkg_names {
+        let name = package_namespace_name(*pkg_name);
+        let count = reserved_names.entry(name.clone()).or_insert(0);
+        let name = match *count {
+            0 => name,
+            idx => format!("{}{}", name, idx),
+        };
+        *count += 1;
+
+        quote_in! { toks =>
+            export * as $name from $[str]($[const](format!("./{}/barrel", naming.package_import_name(*pkg_name))));$['\r']
+        }
+    }
+
+    toks
+}
+
 fn gen_init_loader_register_classes_fn_body_toks(
     pkg_ids: Vec<AccountAddress>,
     top_level_pkg_names: &BTreeMap<AccountAddress, move_symbol_pool::Symbol>,
     is_source: bool,
+    naming: &Naming,
     toks: &mut js::Tokens,
 ) {
     for pkg_id in pkg_ids {
         let pkg_init_path = match top_level_pkg_names.get(&pkg_id) {
             Some(pkg_name) => {
-                format!("../{}/init", package_import_name(*pkg_name))
+                format!("../{}/init", naming.package_import_name(*pkg_name))
             }
             None => {
                 if is_source {
@@ -421,6 +691,7 @@ pub fn gen_init_loader_ts(
         Vec<AccountAddress>,
         &BTreeMap<AccountAddress, move_symbol_pool::Symbol>,
     )>,
+    naming: &Naming,
 ) -> js::Tokens {
     let struct_class_loader = &js::import("./loader", "StructClassLoader");
 
@@ -430,7 +701,7 @@ pub fn gen_init_loader_ts(
         Some((pkg_ids, top_level_pkg_names)) => quote_in!(toks =>
             function registerClassesSource(loader: $struct_class_loader) {
                 $(ref toks {
-                    gen_init_loader_register_classes_fn_body_toks(pkg_ids.clone(), top_level_pkg_names, true, toks)
+                    gen_init_loader_register_classes_fn_body_toks(pkg_ids.clone(), top_level_pkg_names, true, naming, toks)
                 })
             }$['\n']
         ),
@@ -441,7 +712,7 @@ pub fn gen_init_loader_ts(
         Some((pkg_ids, top_level_pkg_names)) => quote_in!(toks =>
             function registerClassesOnchain(loader: $struct_class_loader) {
                 $(ref toks {
-                    gen_init_loader_register_classes_fn_body_toks(pkg_ids.clone(), top_level_pkg_names, false, toks)
+                    gen_init_loader_register_classes_fn_body_toks(pkg_ids.clone(), top_level_pkg_names, false, naming, toks)
                 })
             }$['\n']
         ),
@@ -577,10 +848,61 @@ fn gen_bcs_def_for_type(
     toks
 }
 
+/// Well-known shared system objects (type, object ID, `tx.object` method of the SDK referencing
+/// the object) which function bindings pass in when the caller doesn't.
+pub const DEFAULT_SYSTEM_OBJECTS: [(&str, &str, &str); 4] = [
+    ("0x2::clock::Clock", "0x6", "clock"),
+    ("0x2::random::Random", "0x8", "random"),
+    ("0x2::deny_list::DenyList", "0x403", "denyList"),
+    ("0x3::sui_system::SuiSystemState", "0x5", "system"),
+];
+
+/// Params of function bindings other than the ones of the Move function.
+const BINDING_PARAM_NAMES: [&str; 3] = ["tx", "typeArg", "typeArgs"];
+
+/// Returns the name of the param of a binding for a function with a single (non-implicit) param.
+/// The param is passed positionally, so it can't be named after a reserved word or one of the
+/// other params of the binding.
+fn single_param_name(name: &str) -> String {
+    if JS_STRICTLY_RESERVED_WORDS.contains(&name) || BINDING_PARAM_NAMES.contains(&name) {
+        name.to_owned() + "_"
+    } else {
+        name.to_owned()
+    }
+}
+
+/// Options for generating function bindings.
+#[derive(Debug, Clone)]
+pub struct FunctionsGenOptions {
+    /// Whether object parameters only accept arguments typed with the parameter's struct class
+    /// (`ObjectArg<Pool>`) instead of any `TransactionObjectInput`.
+    pub strict_object_args: bool,
+    /// Whether by-value `Coin<T>` params also accept an amount, in which case a coin with that
+    /// balance is created from the sender's coins when the transaction is built.
+    pub coin_amount_args: bool,
+    /// Shared objects by type (e.g. `0x2::clock::Clock` -> `0x6`) whose binding params are
+    /// optional, defaulting to the object.
+    pub system_objects: BTreeMap<String, String>,
+}
+
+impl Default for FunctionsGenOptions {
+    fn default() -> Self {
+        FunctionsGenOptions {
+            strict_object_args: false,
+            coin_amount_args: false,
+            system_objects: DEFAULT_SYSTEM_OBJECTS
+                .iter()
+                .map(|(type_, id, _)| (type_.to_string(), id.to_string()))
+                .collect(),
+        }
+    }
+}
+
 pub struct FunctionsGen<'env, 'a> {
     env: &'env GlobalEnv,
     framework: FrameworkImportCtx,
     import_ctx: StructClassImportCtx<'env, 'a>,
+    opts: FunctionsGenOptions,
 }
 
 impl<'env, 'a> FunctionsGen<'env, 'a> {
@@ -588,11 +910,13 @@ impl<'env, 'a> FunctionsGen<'env, 'a> {
         env: &'env GlobalEnv,
         framework: FrameworkImportCtx,
         import_ctx: StructClassImportCtx<'env, 'a>,
+        opts: FunctionsGenOptions,
     ) -> Self {
         FunctionsGen {
             env,
             framework,
             import_ctx,
+            opts,
         }
     }
 
@@ -644,10 +968,10 @@ impl<'env, 'a> FunctionsGen<'env, 'a> {
         type_param_names: Vec<Symbol>,
     ) -> String {
         if let Some(name) = name {
-            let mut name = name
-                .display(self.symbol_pool())
-                .to_string()
-                .to_case(Case::Camel);
+            let mut name = self
+                .import_ctx
+                .naming
+                .field_name(&name.display(self.symbol_pool()).to_string());
 
             // When the param name is `_` we use the type as the field name.
             if name.is_empty() {
@@ -687,6 +1011,40 @@ impl<'env, 'a> FunctionsGen<'env, 'a> {
         }
     }
 
+    /// Returns the argument a param defaults to if its type is one of the configured shared
+    /// system objects, e.g. `tx.object.clock()` for `&Clock`.
+    fn system_object_arg(&self, ty: &Type) -> Option<js::Tokens> {
+        match ty {
+            Type::Datatype(mid, sid, ts) if ts.is_empty() => {
+                let module = self.env.get_module(*mid);
+                if module.find_enum(sid.symbol()).is_some() {
+                    return None;
+                }
+                let type_ = module.get_struct(*sid).get_full_name_with_address();
+                let id = self.opts.system_objects.get(type_.as_str())?;
+                // the SDK method only references the object at its well-known ID
+                let method = DEFAULT_SYSTEM_OBJECTS
+                    .iter()
+                    .find(|(method_type, method_id, _)| *method_type == type_ && method_id == id)
+                    .map(|(_, _, method)| *method);
+                Some(match method {
+                    Some(method) => quote!(tx.object.$method()),
+                    None => quote!(tx.object($[str]($[const](id)))),
+                })
+            }
+            Type::Reference(_, ty) => self.system_object_arg(ty),
+            _ => None,
+        }
+    }
+
+    /// Returns `?` for params which can be omitted by the caller (system objects).
+    fn optional_marker(&self, ty: &Type) -> &'static str {
+        match self.system_object_arg(ty) {
+            Some(_) => "?",
+            None => "",
+        }
+    }
+
     /// Returns type parameter names for a function. If type parameter names are not defined
     /// it will return `T0`, `T1`, etc.
     fn func_type_param_names(&self, func: &FunctionEnv) -> Vec<Symbol> {
@@ -773,21 +1131,47 @@ impl<'env, 'a> FunctionsGen<'env, 'a> {
             .collect()
     }
 
-    fn fun_arg_if_name(func: &FunctionEnv) -> String {
-        let name = func.get_name_str();
+    fn fun_arg_if_name(&self, func: &FunctionEnv) -> String {
+        self.import_ctx.naming.fun_arg_if_name(func)
+    }
 
-        // function names ending with `_` are common, so handle this specifically
-        // TODO: remove this once there's a more general way to handle this
-        if name.ends_with('_') {
-            return name.from_case(Case::Snake).to_case(Case::Pascal) + "_Args";
-        } else {
-            return name.from_case(Case::Snake).to_case(Case::Pascal) + "Args";
+    /// Returns the coin type if `ty` is a by-value `0x2::coin::Coin<T>` param which can also be
+    /// passed in as an amount.
+    fn coin_amount_param<'b>(&self, ty: &'b Type) -> Option<&'b Type> {
+        if !self.opts.coin_amount_args {
+            return None;
+        }
+        match ty {
+            Type::Datatype(mid, sid, ts) => {
+                let module = self.env.get_module(*mid);
+                if module.find_enum(sid.symbol()).is_some() {
+                    return None;
+                }
+                let strct = module.get_struct(*sid);
+                match strct.get_full_name_with_address().as_ref() {
+                    "0x2::coin::Coin" => Some(&ts[0]),
+                    _ => None,
+                }
+            }
+            _ => None,
+        }
+    }
+
+    /// Generates a TS type for a function's parameter. Same as `param_type_to_field_type` except
+    /// that by-value coins can also be passed in as an amount.
+    fn param_type(&mut self, ty: &Type) -> js::Tokens {
+        let field_type = self.param_type_to_field_type(ty);
+        match self.coin_amount_param(ty) {
+            Some(_) => quote!($field_type | bigint),
+            None => field_type,
         }
     }
 
     /// Generates a TS type for a function's parameter type. Used in the `<..>Args` interface.
-    fn param_type_to_field_type(&self, ty: &Type) -> js::Tokens {
+    fn param_type_to_field_type(&mut self, ty: &Type) -> js::Tokens {
         let generic_arg = &self.framework.import("util", "GenericArg");
+        let object_arg = &self.framework.import("util", "ObjectArg");
+        let move_result = &self.framework.import("util", "MoveResult");
         let transaction_argument = &js::import("@mysten/sui/transactions", "TransactionArgument");
         let transaction_object_input =
             &js::import("@mysten/sui/transactions", "TransactionObjectInput");
@@ -822,6 +1206,14 @@ impl<'env, 'a> FunctionsGen<'env, 'a> {
                     "0x1::option::Option" => {
                         quote!(($(self.param_type_to_field_type(&ts[0])) | $(transaction_argument) | null))
                     }
+                    _ if self.opts.strict_object_args => {
+                        let tag = self.move_type_tag(ty);
+                        if strct.get_abilities().has_key() {
+                            quote!($object_arg<$tag>)
+                        } else {
+                            quote!($move_result<$tag>)
+                        }
+                    }
                     _ => quote!($transaction_object_input),
                 }
             }
@@ -831,9 +1223,96 @@ impl<'env, 'a> FunctionsGen<'env, 'a> {
         }
     }
 
+    /// Returns the Move signature of a function, e.g.
+    /// `public fun swap<A, B>(pool: &mut Pool<A, B>, input: Coin<A>): Coin<B>`.
+    fn fun_signature(&self, func: &FunctionEnv) -> String {
+        let visibility = match func.visibility() {
+            Visibility::Public => "public ",
+            Visibility::Friend => "public(package) ",
+            Visibility::Private => "",
+        };
+        let entry = if func.is_entry() { "entry " } else { "" };
+
+        let type_params = self
+            .func_type_param_names(func)
+            .into_iter()
+            .zip(func.get_named_type_parameters())
+            .map(|(name, param)| {
+                let name = name.display(self.symbol_pool()).to_string();
+                let abilities = abilities_str(param.1 .0).replace(", ", " + ");
+                if abilities.is_empty() {
+                    name
+                } else {
+                    format!("{}: {}", name, abilities)
+                }
+            })
+            .collect::<Vec<_>>();
+        let type_params = if type_params.is_empty() {
+            "".to_string()
+        } else {
+            format!("<{}>", type_params.join(", "))
+        };
+
+        let type_display_ctx = func.get_type_display_ctx();
+        let params = func.get_parameters();
+        let param_types = func.get_parameter_types();
+        let params = param_types
+            .iter()
+            .enumerate()
+            .map(|(idx, ty)| {
+                let ty = type_display_str(ty, &type_display_ctx);
+                // on-chain modules don't have parameter names
+                if params.len() == param_types.len() {
+                    format!("{}: {}", params[idx].0.display(self.symbol_pool()), ty)
+                } else {
+                    ty
+                }
+            })
+            .collect::<Vec<_>>();
+
+        let return_types = func
+            .get_return_types()
+            .iter()
+            .map(|ty| type_display_str(ty, &type_display_ctx))
+            .collect::<Vec<_>>();
+        let returns = match return_types.len() {
+            0 => "".to_string(),
+            1 => format!(": {}", return_types[0]),
+            _ => format!(": ({})", return_types.join(", ")),
+        };
+
+        format!(
+            "{}{}fun {}{}({}){}",
+            visibility,
+            entry,
+            func.get_name_str(),
+            type_params,
+            params.join(", "),
+            returns
+        )
+    }
+
+    /// Generates the TSDoc comment for a function binding from the function's Move doc comment,
+    /// its signature and source location.
+    fn gen_fun_doc(&self, func: &FunctionEnv, tokens: &mut js::Tokens) {
+        let mut lines = doc_lines(func.get_doc());
+        if !lines.is_empty() {
+            lines.push("".to_string());
+        }
+        lines.push(format!("`{}`", self.fun_signature(func)));
+        if self.import_ctx.is_source {
+            if let Some(loc) = loc_str(self.env, &func.get_loc()) {
+                lines.push("".to_string());
+                lines.push(format!("Defined in `{}`", loc));
+            }
+        }
+
+        gen_doc_comment(tokens, &lines);
+    }
+
     /// Generates the `<..>Args` interface for a function.
     pub fn gen_fun_args_if(
-        &self,
+        &mut self,
         func: &FunctionEnv,
         tokens: &mut Tokens<JavaScript>,
     ) -> Result<()> {
@@ -843,9 +1322,14 @@ impl<'env, 'a> FunctionsGen<'env, 'a> {
         }
 
         quote_in! { *tokens =>
-            export interface $(FunctionsGen::fun_arg_if_name(func)) {
+            export interface $(self.fun_arg_if_name(func)) {
                 $(for (field_name, param_type) in param_field_names join (; )=>
-                    $field_name: $(self.param_type_to_field_type(&param_type))
+                    $(ref toks {
+                        gen_doc_comment(toks, &[format!(
+                            "`{}`", type_display_str(&param_type, &func.get_type_display_ctx())
+                        )])
+                    })
+                    $field_name$(self.optional_marker(&param_type)): $(self.param_type(&param_type))
                 )
             }$['\n']
         };
@@ -923,6 +1407,7 @@ impl<'env, 'a> FunctionsGen<'env, 'a> {
         let generic = import_with_possible_alias("generic");
         let vector = import_with_possible_alias("vector");
         let option = import_with_possible_alias("option");
+        let coin_arg = import_with_possible_alias("coinArg");
 
         let num_type_params = func_type_param_names.len();
         let type_param_names = match num_type_params {
@@ -933,6 +1418,11 @@ impl<'env, 'a> FunctionsGen<'env, 'a> {
                 .collect::<Vec<_>>(),
         };
 
+        if let Some(coin_type) = self.coin_amount_param(&ty) {
+            let coin_type_tok = self.gen_bcs_def_for_type(coin_type, &type_param_names);
+            return quote!($coin_arg(tx, $coin_type_tok, $arg_field_name));
+        }
+
         let ty = self.type_strip_ref(ty);
         let ty_tok = self.gen_bcs_def_for_type(&ty, &type_param_names);
 
@@ -958,21 +1448,77 @@ impl<'env, 'a> FunctionsGen<'env, 'a> {
         }
     }
 
+    /// Generates the TS type a Move type is tagged with in `MoveResult`. It's the same type that is
+    /// used for struct type arguments (e.g. `Coin<ToPhantom<SUI>>`), with function type parameters
+    /// mapped to `any` since they're not tracked by the bindings. Types that don't have a TS class
+    /// (enums) are mapped to `any` as well, leaving the result untyped.
+    fn move_type_tag(&mut self, ty: &Type) -> js::Tokens {
+        let vector = &self.framework.import("vector", "Vector");
+        let to_phantom = &self
+            .framework
+            .import("reified", "ToTypeStr")
+            .with_alias("ToPhantom");
+
+        match ty {
+            Type::Primitive(ty) => match ty {
+                PrimitiveType::U8 => quote!($[str](u8)),
+                PrimitiveType::U16 => quote!($[str](u16)),
+                PrimitiveType::U32 => quote!($[str](u32)),
+                PrimitiveType::U64 => quote!($[str](u64)),
+                PrimitiveType::U128 => quote!($[str](u128)),
+                PrimitiveType::U256 => quote!($[str](u256)),
+                PrimitiveType::Bool => quote!($[str](bool)),
+                PrimitiveType::Address => quote!($[str](address)),
+                PrimitiveType::Signer => quote!($[str](signer)),
+                _ => quote!(any),
+            },
+            Type::Vector(ty) => quote!($vector<$(self.move_type_tag(ty))>),
+            Type::Datatype(mid, sid, ts) => {
+                let module = self.env.get_module(*mid);
+                if module.find_enum(sid.symbol()).is_some() {
+                    return quote!(any);
+                }
+
+                let strct = module.into_struct(*sid);
+                let class = self.import_ctx.get_class(&strct);
+
+                let type_arg_toks = ts
+                    .iter()
+                    .enumerate()
+                    .map(|(idx, ty)| {
+                        let inner = self.move_type_tag(ty);
+                        match ty {
+                            Type::Datatype(..) | Type::Vector(_)
+                                if strct.is_phantom_parameter(idx) =>
+                            {
+                                quote!($to_phantom<$inner>)
+                            }
+                            _ => inner,
+                        }
+                    })
+                    .collect::<Vec<_>>();
+
+                quote!($class$(if !type_arg_toks.is_empty() {
+                    <$(for tok in type_arg_toks join (, ) => $tok)>
+                }))
+            }
+            Type::Reference(_, ty) => self.move_type_tag(ty),
+            _ => quote!(any),
+        }
+    }
+
     /// Returns the TS function binding name for a function.
-    pub fn fun_name(func: &FunctionEnv) -> String {
-        let mut fun_name_str = func
-            .get_name_str()
-            .from_case(Case::Snake)
-            .to_case(Case::Camel);
-        if JS_RESERVED_WORDS.contains(&fun_name_str.as_str()) {
-            fun_name_str.push('_');
-        };
-        // function names ending with `_` are common, so handle this specifically
-        // TODO: remove this once there's a more general way to handle this
-        if func.get_name_str().ends_with('_') {
-            fun_name_str.push('_');
+    pub fn fun_name(&self, func: &FunctionEnv) -> String {
+        self.import_ctx.naming.fun_name(func)
+    }
+
+    /// Returns the names exported from `functions.ts` for a function.
+    pub fn exported_names(&self, func: &FunctionEnv) -> Vec<BarrelExport> {
+        let mut names = vec![BarrelExport::value(self.fun_name(func))];
+        if self.params_to_field_names(func, true).len() >= 2 {
+            names.push(BarrelExport::type_(self.fun_arg_if_name(func)));
         }
-        fun_name_str
+        names
     }
 
     /// Generates a function binding for a function.
@@ -992,46 +1538,78 @@ impl<'env, 'a> FunctionsGen<'env, 'a> {
         let func_type_param_names = self.func_type_param_names(func);
         let single_param = param_field_names.len() == 1;
 
-        let convert_reserved_if_needed = |name: &str| {
-            if JS_STRICTLY_RESERVED_WORDS.contains(&name) {
-                name.to_owned() + "_"
+        let move_result = &self.framework.import("util", "MoveResult");
+        let split_result = if single_param && param_field_names[0].0 == "splitResult" {
+            self.framework
+                .import("util", "splitResult")
+                .with_alias("splitResult_")
+        } else {
+            self.framework.import("util", "splitResult")
+        };
+        let return_tags = func
+            .get_return_types()
+            .iter()
+            .map(|ty| self.move_type_tag(ty))
+            .collect::<Vec<_>>();
+
+        // system object params are optional, defaulting to the object
+        let mut tx_args = vec![];
+        for (field_name, type_) in param_field_names.iter() {
+            let arg_field_name = if single_param {
+                single_param_name(field_name)
             } else {
-                name.to_owned()
+                "args.".to_string() + field_name
+            };
+            if let Some(default) = self.system_object_arg(type_) {
+                let obj = if single_param && arg_field_name == "obj" {
+                    self.framework.import("util", "obj").with_alias("obj_")
+                } else {
+                    self.framework.import("util", "obj")
+                };
+                tx_args.push(quote!($obj(tx, $arg_field_name ?? $default)));
+                continue;
             }
+            tx_args.push(self.param_to_tx_arg(
+                type_.clone(),
+                arg_field_name,
+                func_type_param_names.clone(),
+                single_param,
+            ));
+        }
+
+        let move_call = quote! {
+            tx.moveCall({
+                target: $[str]($($published_at)::$[const](func.get_full_name_str())),
+                $(match type_arg_count {
+                    0 => (),
+                    1 => { typeArguments: [typeArg], },
+                    _ => { typeArguments: typeArgs, },
+                })
+                arguments: [
+                    $(for arg in tx_args join (, ) => $arg)
+                ],
+            })
         };
 
+        self.gen_fun_doc(func, tokens);
         quote_in! { *tokens =>
-            export function $(FunctionsGen::fun_name(func))(
+            export function $(self.fun_name(func))(
                 tx: $transaction,
                 $(gen_type_args_param(type_arg_count, None::<&str>, ","))
                 $(match param_field_names.len() {
                     0 => (),
-                    1 => $(convert_reserved_if_needed(&param_field_names[0].0)): $(self.param_type_to_field_type(&param_field_names[0].1)),
-                    _ => args: $(FunctionsGen::fun_arg_if_name(func))
+                    1 => $(single_param_name(&param_field_names[0].0))$(self.optional_marker(&param_field_names[0].1)): $(self.param_type(&param_field_names[0].1)),
+                    _ => args: $(self.fun_arg_if_name(func))
                 })
             ) {
-                return tx.moveCall({
-                    target: $[str]($($published_at)::$[const](func.get_full_name_str())),
-                    $(match type_arg_count {
-                        0 => (),
-                        1 => { typeArguments: [typeArg], },
-                        _ => { typeArguments: typeArgs, },
-                    })
-                    arguments: [
-                        $(if param_field_names.len() == 1 {
-                            $(self.param_to_tx_arg(
-                                param_field_names[0].1.clone(), convert_reserved_if_needed(&param_field_names[0].0),
-                                func_type_param_names, single_param
-                            ))
-                        } else {
-                            $(for (field_name, type_) in param_field_names join (, ) =>
-                                $(self.param_to_tx_arg(
-                                    type_, "args.".to_string() + &field_name,
-                                    func_type_param_names.clone(), single_param
-                                ))
-                            )
-                        })
-                    ],
+                $(match return_tags.len() {
+                    0 => return $(&move_call),
+                    1 => return $(&move_call) as $move_result<$(&return_tags[0])>,
+                    n => {
+                        return $split_result($(&move_call), $n) as [
+                            $(for tag in return_tags.iter() join (, ) => $move_result<$tag>)
+                        ]
+                    }
                 })
             }$['\n']
         };
@@ -1052,6 +1630,9 @@ pub struct StructsGen<'env, 'a> {
     framework: FrameworkImportCtx,
     type_origin_table: &'env TypeOriginTable,
     version_table: &'env VersionTable,
+    /// Whether `_framework/kiosk.ts` is generated, in which case `key + store` structs get a
+    /// `kiosk` helper.
+    kiosk_helpers: bool,
 }
 
 impl<'env, 'a> StructsGen<'env, 'a> {
@@ -1061,6 +1642,7 @@ impl<'env, 'a> StructsGen<'env, 'a> {
         framework: FrameworkImportCtx,
         type_origin_table: &'env TypeOriginTable,
         version_table: &'env VersionTable,
+        kiosk_helpers: bool,
     ) -> Self {
         StructsGen {
             env,
@@ -1068,6 +1650,7 @@ impl<'env, 'a> StructsGen<'env, 'a> {
             framework,
             type_origin_table,
             version_table,
+            kiosk_helpers,
         }
     }
 
@@ -1098,18 +1681,27 @@ impl<'env, 'a> StructsGen<'env, 'a> {
         self.env.symbol_pool()
     }
 
-    /// Generates a TS interface field name from a struct field.
-    fn gen_field_name(&self, field: &FieldEnv) -> impl FormatInto<JavaScript> {
-        let name = field
-            .get_name()
-            .display(self.symbol_pool())
-            .to_string()
-            .to_case(Case::Camel);
-        quote_fn! {
-            $name
+    /// Returns the TS name of a struct field. Fields of enum variants aren't supported yet.
+    fn gen_field_name(&self, field: &FieldEnv) -> Result<String> {
+        match &field.parent_env {
+            move_model::model::EnclosingEnv::Struct(strct) => {
+                Ok(self.import_ctx.naming.struct_field_name(strct, field))
+            }
+            move_model::model::EnclosingEnv::Variant(_) => bail!(
+                "field `{}` of an enum variant: enums are not supported yet",
+                field.get_name().display(self.symbol_pool())
+            ),
         }
     }
 
+    /// Returns the TS names of the fields of a struct, in declaration order.
+    fn gen_field_names(&self, strct: &StructEnv) -> Result<Vec<String>> {
+        strct
+            .get_fields()
+            .map(|field| self.gen_field_name(&field))
+            .collect()
+    }
+
     /// Generates a TS interface field type for a struct field. References class structs generated
     /// in other modules by importing them when needed.
     fn gen_struct_class_field_type(
@@ -1377,8 +1969,7 @@ impl<'env, 'a> StructsGen<'env, 'a> {
 
         match strct {
             move_model::model::EnclosingEnv::Struct(strct) => {
-                let field_arg_name =
-                    format!("fields.{}", field.get_name().display(self.symbol_pool()));
+                let field_arg_name = format!("fields.{}", move_field_key(strct, field));
 
                 let type_param_names = match strct.get_type_parameters().len() {
                     0 => vec![],
@@ -1402,13 +1993,9 @@ impl<'env, 'a> StructsGen<'env, 'a> {
 
         let strct = &field.parent_env;
 
-        let field_arg_name = format!(
-            "item.fields.{}",
-            field.get_name().display(self.symbol_pool())
-        );
-
         match strct {
             move_model::model::EnclosingEnv::Struct(strct) => {
+                let field_arg_name = format!("item.fields.{}", move_field_key(strct, field));
                 let type_param_names = match strct.get_type_parameters().len() {
                     0 => vec![],
                     1 => vec![quote!(typeArg)],
@@ -1424,12 +2011,18 @@ impl<'env, 'a> StructsGen<'env, 'a> {
         }
     }
 
-    fn gen_from_json_field_field_decode(&mut self, field: &FieldEnv) -> js::Tokens {
+    /// Generates the decoding of a field in `fromJSONField`. Fields of enum variants aren't supported
+    /// yet.
+    fn gen_from_json_field_field_decode(
+        &mut self,
+        field: &FieldEnv,
+        name: &str,
+    ) -> Result<js::Tokens> {
         let decode_from_json_field = &self.framework.import("reified", "decodeFromJSONField");
 
         let strct = &field.parent_env;
 
-        let field_arg_name = quote!(field.$(self.gen_field_name(field)));
+        let field_arg_name = quote!(field.$name);
 
         match strct {
             move_model::model::EnclosingEnv::Struct(strct) => {
@@ -1440,11 +2033,14 @@ impl<'env, 'a> StructsGen<'env, 'a> {
                 };
                 let reified = self.gen_reified(strct, &field.get_type(), &type_param_names);
 
-                quote!(
+                Ok(quote!(
                     $decode_from_json_field($(reified), $(field_arg_name))
-                )
+                ))
             }
-            move_model::model::EnclosingEnv::Variant(_) => todo!(),
+            move_model::model::EnclosingEnv::Variant(_) => bail!(
+                "field `{}` of an enum variant: enums are not supported yet",
+                field.get_name().display(self.symbol_pool())
+            ),
         }
     }
 
@@ -1452,7 +2048,7 @@ impl<'env, 'a> StructsGen<'env, 'a> {
     pub fn gen_is_type_func(&self, tokens: &mut js::Tokens, strct: &StructEnv) {
         let compress_sui_type = &self.framework.import("util", "compressSuiType");
 
-        let struct_name = strct.get_name().display(self.symbol_pool()).to_string();
+        let struct_name = self.struct_class_name(strct);
         let type_params = self.strct_type_param_names(strct);
 
         quote_in! { *tokens =>
@@ -1511,9 +2107,81 @@ impl<'env, 'a> StructsGen<'env, 'a> {
         quote!(<$(for param in param_toks join (, ) => $param)>)
     }
 
+    /// Returns the Move signature of a struct, e.g. `struct Pool<phantom A, phantom B> has key`.
+    fn struct_signature(&self, strct: &StructEnv) -> String {
+        let type_params = self
+            .strct_type_param_names(strct)
+            .iter()
+            .enumerate()
+            .map(|(idx, name)| {
+                let name = name.display(self.symbol_pool()).to_string();
+                if strct.is_phantom_parameter(idx) {
+                    format!("phantom {}", name)
+                } else {
+                    name
+                }
+            })
+            .collect::<Vec<_>>();
+        let type_params = if type_params.is_empty() {
+            "".to_string()
+        } else {
+            format!("<{}>", type_params.join(", "))
+        };
+
+        let abilities = abilities_str(strct.get_abilities());
+        let abilities = if abilities.is_empty() {
+            "".to_string()
+        } else {
+            format!(" has {}", abilities)
+        };
+
+        format!(
+            "struct {}{}{}",
+            strct.get_name().display(self.symbol_pool()),
+            type_params,
+            abilities
+        )
+    }
+
+    /// Generates the TSDoc comment for a struct class from the struct's Move doc comment,
+    /// its signature (including abilities) and source location.
+    fn gen_struct_doc(&self, tokens: &mut js::Tokens, strct: &StructEnv) {
+        let mut lines = doc_lines(strct.get_doc());
+        if !lines.is_empty() {
+            lines.push("".to_string());
+        }
+        lines.push(format!("`{}`", self.struct_signature(strct)));
+        if self.import_ctx.is_source {
+            if let Some(loc) = loc_str(self.env, &strct.get_loc()) {
+                lines.push("".to_string());
+                lines.push(format!("Defined in `{}`", loc));
+            }
+        }
+
+        gen_doc_comment(tokens, &lines);
+    }
+
+    /// Generates the TSDoc comment for a struct field from the field's Move doc comment and type.
+    fn gen_field_doc(&self, tokens: &mut js::Tokens, strct: &StructEnv, field: &FieldEnv) {
+        let mut lines = doc_lines(field.get_doc());
+        if !lines.is_empty() {
+            lines.push("".to_string());
+        }
+        lines.push(format!(
+            "`{}: {}`",
+            field.get_name().display(self.symbol_pool()),
+            type_display_str(&field.get_type(), &strct.get_type_display_ctx())
+        ));
+
+        gen_doc_comment(tokens, &lines);
+    }
+
+    fn struct_class_name(&self, strct: &StructEnv) -> String {
+        self.import_ctx.naming.struct_class_name(strct)
+    }
+
     fn fields_if_name(&self, strct: &StructEnv) -> String {
-        let struct_name = strct.get_name().display(self.symbol_pool()).to_string();
-        format!("{}Fields", &struct_name)
+        format!("{}Fields", self.struct_class_name(strct))
     }
 
     /// Generates the `<StructName>Fields` interface name including its type parameters.
@@ -1534,24 +2202,27 @@ impl<'env, 'a> StructsGen<'env, 'a> {
     }
 
     /// Generates the `<StructName>Fields` interface.
-    pub fn gen_fields_if(&mut self, tokens: &mut js::Tokens, strct: &StructEnv) {
+    pub fn gen_fields_if(&mut self, tokens: &mut js::Tokens, strct: &StructEnv) -> Result<()> {
         let type_argument = &self.framework.import("reified", "TypeArgument");
         let phantom_type_argument = &self.framework.import("reified", "PhantomTypeArgument");
 
         let extends_non_phantom = ExtendsOrWraps::Extends(quote!($type_argument));
         let extends_phantom = ExtendsOrWraps::Extends(quote!($phantom_type_argument));
+        let field_names = self.gen_field_names(strct)?;
 
         tokens.push();
         quote_in! { *tokens =>
             export interface $(self.gen_fields_if_name_with_params(strct, &extends_non_phantom, &extends_phantom)) {
-                $(for field in strct.get_fields() join (; )=>
-                    $(self.gen_field_name(&field)): $(
+                $(for (field, name) in strct.get_fields().zip(&field_names) join (; )=>
+                    $(ref toks => self.gen_field_doc(toks, strct, &field))
+                    $name: $(
                         self.gen_struct_class_field_type(strct, &field.get_type(), self.strct_type_param_names(strct), None, None)
                     )
                 )
             }
         };
         tokens.line();
+        Ok(())
     }
 
     fn interpolate(&self, str: String) -> js::Tokens {
@@ -1564,11 +2235,13 @@ impl<'env, 'a> StructsGen<'env, 'a> {
     }
 
     /// Generates the struct class for a struct.
-    pub fn gen_struct_class(&mut self, tokens: &mut js::Tokens, strct: &StructEnv) {
+    pub fn gen_struct_class(&mut self, tokens: &mut js::Tokens, strct: &StructEnv) -> Result<()> {
         let fields_with_types = &self.framework.import("util", "FieldsWithTypes");
         let compose_sui_type = &self.framework.import("util", "composeSuiType");
         let struct_class = &self.framework.import("reified", "StructClass");
         let field_to_json = &self.framework.import("reified", "fieldToJSON");
+        let field_to_bcs = &self.framework.import("reified", "fieldToBcs");
+        let concat_bytes = &self.framework.import("reified", "concatBytes");
         let type_argument = &self.framework.import("reified", "TypeArgument");
         let phantom_type_argument = &self.framework.import("reified", "PhantomTypeArgument");
         let reified = &self.framework.import("reified", "Reified");
@@ -1594,16 +2267,30 @@ impl<'env, 'a> StructsGen<'env, 'a> {
         let bcs_type = &js::import("@mysten/sui/bcs", "BcsType");
         let from_b64 = &js::import("@mysten/sui/utils", "fromB64");
         let compress_sui_type = &self.framework.import("util", "compressSuiType");
-
-        strct.get_abilities().has_key();
-
-        let struct_name = strct.get_name().display(self.symbol_pool()).to_string();
+        let fetch_objects = &self.framework.import("util", "fetchObjects");
+        let fetch_objects_of_type = &self.framework.import("util", "fetchObjectsOfType");
+        let fetch_object_result = &self.framework.import("util", "FetchObjectResult");
+        let fetch_owned_objects = &self.framework.import("util", "fetchOwnedObjects");
+        let typed_object_id = &self.framework.import("util", "TypedObjectId");
+        let fetch_display = &self.framework.import("display", "fetchDisplay");
+        let display_template = &self.framework.import("display", "DisplayTemplate");
+        let kiosk_helpers = &self.framework.import("kiosk", "kioskHelpers");
+        let kiosk_helpers_type = &self.framework.import("kiosk", "KioskHelpers");
+
+        let struct_name = self.struct_class_name(strct);
+        let move_struct_name = strct.get_name().display(self.symbol_pool()).to_string();
         let type_params = self.strct_type_param_names(strct);
         let type_params_str = type_params
             .iter()
             .map(|param| param.display(self.symbol_pool()).to_string())
             .collect::<Vec<_>>();
         let fields = strct.get_fields().collect::<Vec<_>>();
+        let field_names = self.gen_field_names(strct)?;
+        let json_field_decodes = fields
+            .iter()
+            .zip(&field_names)
+            .map(|(field, name)| self.gen_from_json_field_field_decode(field, name))
+            .collect::<Result<Vec<_>>>()?;
         let non_phantom_params = self.strct_non_phantom_type_param_names(strct);
         let non_phantom_param_strs = non_phantom_params
             .iter()
@@ -1614,11 +2301,11 @@ impl<'env, 'a> StructsGen<'env, 'a> {
             .collect::<Vec<_>>();
 
         let bcs_def_name = if non_phantom_params.is_empty() {
-            quote!($[str]($[const](&struct_name)))
+            quote!($[str]($[const](&move_struct_name)))
         } else {
             self.interpolate(format!(
                 "{}<{}>",
-                &struct_name,
+                &move_struct_name,
                 non_phantom_param_strs
                     .iter()
                     .map(|param| format!("${{{}.name}}", param))
@@ -1649,6 +2336,17 @@ impl<'env, 'a> StructsGen<'env, 'a> {
                 )],),
         };
 
+        // same as above but optional, used when the type args can be resolved from the object
+        let optional_type_args_param: &js::Tokens = &match type_params.len() {
+            0 => quote!(),
+            1 => quote!(
+                typeArg?: $(type_params[0].display(self.symbol_pool()).to_string()),
+            ),
+            _ => quote!(typeArgs?: [$(for idx in 0..type_params.len() join (, ) =>
+                    $(&type_params[idx].display(self.symbol_pool()).to_string())
+                )],),
+        };
+
         let extends_type_argument = ExtendsOrWraps::Extends(quote!($type_argument));
         let extends_phantom_type_argument = ExtendsOrWraps::Extends(quote!($phantom_type_argument));
         let wraps_to_type_argument = ExtendsOrWraps::Wraps(quote!($to_type_argument));
@@ -1761,6 +2459,17 @@ impl<'env, 'a> StructsGen<'env, 'a> {
 
         let is_option = self.get_full_name_with_address_str(strct) == "0x1::option::Option";
 
+        // `{ move_name: 'tsName' }`, used to resolve Move field paths (e.g. in display templates)
+        let move_field_names = fields
+            .iter()
+            .map(|field| {
+                (
+                    move_field_key(strct, field),
+                    self.import_ctx.naming.struct_field_name(strct, field),
+                )
+            })
+            .collect::<Vec<_>>();
+
         quote_in! { *tokens =>
             export type $(&struct_name)Reified$(self.gen_params_toks(
                 strct, type_params_str.clone(), &extends_type_argument, &extends_phantom_type_argument
@@ -1771,13 +2480,17 @@ impl<'env, 'a> StructsGen<'env, 'a> {
         }
 
         tokens.push();
+        self.gen_struct_doc(tokens, strct);
         quote_in! { *tokens =>
             export class $(&struct_name)$(self.gen_params_toks(strct, type_params_str.clone(), &extends_type_argument, &extends_phantom_type_argument)) implements $struct_class {
                 __StructClass = true as const;$['\n']
 
                 static readonly $$typeName = $(self.gen_full_name_with_address(strct, true, false));
                 static readonly $$numTypeParams = $(type_params.len());
-                static readonly $$isPhantom = $is_phantom_value_toks as const;$['\n']
+                static readonly $$isPhantom = $is_phantom_value_toks as const;
+                static readonly $$moveFieldNames = {
+                    $(for (move_name, name) in &move_field_names join (, ) => $move_name: $[str]($[const](name.clone())))
+                } as const;$['\n']
 
                 $(if is_option {
                     __inner: $(&type_params_str[0]) = null as unknown as $(&type_params_str[0]); $(ref toks => {
@@ -1788,10 +2501,12 @@ impl<'env, 'a> StructsGen<'env, 'a> {
                 readonly $$typeName = $(&struct_name).$$typeName;
                 readonly $$fullTypeName: $static_full_type_name_as_toks;
                 readonly $$typeArgs: $type_args_field_type;
-                readonly $$isPhantom = $(&struct_name).$$isPhantom;$['\n']
+                readonly $$isPhantom = $(&struct_name).$$isPhantom;
+                readonly $$moveFieldNames = $(&struct_name).$$moveFieldNames;$['\n']
 
-                $(for field in strct.get_fields() join (; ) =>
-                    readonly $(self.gen_field_name(&field)):
+                $(for (field, name) in fields.iter().zip(&field_names) join (; ) =>
+                    $(ref toks => self.gen_field_doc(toks, strct, field))
+                    readonly $name:
                         $(self.gen_struct_class_field_type(
                             strct, &field.get_type(), self.strct_type_param_names(strct), None, None
                         ))
@@ -1811,13 +2526,24 @@ impl<'env, 'a> StructsGen<'env, 'a> {
                     $(match fields.len() {
                         0 => (),
                         _ => {
-                            $(for field in &fields join (; ) =>
-                                this.$(self.gen_field_name(field)) = fields.$(self.gen_field_name(field));
+                            $(for name in &field_names join (; ) =>
+                                this.$name = fields.$name;
                             )
                         }
                     })
                 }$['\n']
 
+                $(if strct.get_abilities().has_key() {
+                    get $$objectId(): string {
+                        return this.$(&field_names[0]);
+                    }$['\n']
+
+                    static id$(self.gen_params_toks(strct, type_params_str.clone(), &extends_type_argument, &extends_phantom_type_argument))(
+                        id: string
+                    ): $typed_object_id<$(&struct_name)$(self.gen_params_toks(strct, type_params_str.clone(), &ExtendsOrWraps::None, &ExtendsOrWraps::None))> {
+                        return id as $typed_object_id<$(&struct_name)$(self.gen_params_toks(strct, type_params_str.clone(), &ExtendsOrWraps::None, &ExtendsOrWraps::None))>;
+                    }$['\n']
+                })
 
                 static reified$(params_toks_for_reified)(
                     $(for param in type_params_str.iter() join (, ) => $param: $param)
@@ -1961,7 +2687,7 @@ impl<'env, 'a> StructsGen<'env, 'a> {
                         )) =>
                     }) $bcs.struct($bcs_def_name, {$['\n']
                         $(for field in strct.get_fields() join (, ) =>
-                            $(field.get_name().display(self.symbol_pool()).to_string()):
+                            $(move_field_key(strct, &field)):
                                 $(self.gen_struct_bcs_def_field_value(&field.get_type(), self.strct_type_param_names(strct)))
                         )$['\n']
                     $['\n']})
@@ -1980,8 +2706,8 @@ impl<'env, 'a> StructsGen<'env, 'a> {
                         $(match fields.len() {
                             0 => (),
                             _ => {{
-                                $(for field in &fields join (, ) =>
-                                    $(self.gen_field_name(field)): $(self.gen_from_fields_field_decode(field))
+                                $(for (field, name) in fields.iter().zip(&field_names) join (, ) =>
+                                    $name: $(self.gen_from_fields_field_decode(field))
                                 )
                             }}
                         })
@@ -2016,8 +2742,8 @@ impl<'env, 'a> StructsGen<'env, 'a> {
                         $(match fields.len() {
                             0 => (),
                             _ => {{
-                                $(for field in &fields join (, ) =>
-                                    $(self.gen_field_name(field)): $(self.gen_from_fields_with_types_field_decode(field))
+                                $(for (field, name) in fields.iter().zip(&field_names) join (, ) =>
+                                    $name: $(self.gen_from_fields_with_types_field_decode(field))
                                 )
                             }}
                         })
@@ -2054,9 +2780,8 @@ impl<'env, 'a> StructsGen<'env, 'a> {
                                 .map(|idx| QuoteItem::Interpolated(this_type_args(idx)))
                                 .collect::<Vec<_>>();
 
-                            for field in fields.iter() {
-                                let name = self.gen_field_name(field);
-                                let this_name = quote!(this.$(self.gen_field_name(field)));
+                            for (field, name) in fields.iter().zip(&field_names) {
+                                let this_name = quote!(this.$name);
 
                                 let field_type_param = self.gen_struct_class_field_type_inner(
                                     strct, &field.get_type(), self.strct_type_param_names(strct), None, None, false
@@ -2108,7 +2833,6 @@ impl<'env, 'a> StructsGen<'env, 'a> {
                                         quote_in!(*toks => $name: $field_to_json<$field_type_param>($(this_type_args(i as usize)), $this_name),)
                                     }
                                     _ => {
-                                        let name = self.gen_field_name(field);
                                         quote_in!(*toks => $name: $this_name.toJSONField(),)
                                     },
 
@@ -2126,6 +2850,26 @@ impl<'env, 'a> StructsGen<'env, 'a> {
                     }
                 }$['\n']
 
+                toBcs(): Uint8Array {
+                    return $concat_bytes([$['\n']
+                        $(ref toks {
+                            let type_param_names = (0..strct.get_type_parameters().len())
+                                .map(|idx| QuoteItem::Interpolated(quote!(this.$$typeArgs[$idx])))
+                                .collect::<Vec<_>>();
+
+                            for (field, name) in fields.iter().zip(&field_names) {
+                                let this_name = quote!(this.$name);
+                                let field_type_param = self.gen_struct_class_field_type_inner(
+                                    strct, &field.get_type(), self.strct_type_param_names(strct), None, None, false
+                                );
+                                let type_name = self.gen_bcs_def_for_type(&field.get_type(), &type_param_names);
+
+                                quote_in!(*toks => $field_to_bcs<$field_type_param>($type_name, $this_name),)
+                            }
+                        })
+                    $['\n']])
+                }$['\n']
+
                 static fromJSONField$(params_toks_for_reified)(
                     $type_args_param_if_any field: any
                 ): $(&struct_name)$(params_toks_for_to_type_argument) {
@@ -2139,8 +2883,8 @@ impl<'env, 'a> StructsGen<'env, 'a> {
                         $(match fields.len() {
                             0 => (),
                             _ => {{
-                                $(for field in &fields join (, ) =>
-                                    $(self.gen_field_name(field)): $(self.gen_from_json_field_field_decode(field))
+                                $(for (name, decode) in field_names.iter().zip(&json_field_decodes) join (, ) =>
+                                    $name: $decode
                                 )
                             }}
                         })
@@ -2298,13 +3042,123 @@ impl<'env, 'a> StructsGen<'env, 'a> {
                         res.data
                     );
                 }$['\n']
+
+                static async fetchMany$(params_toks_for_reified)(
+                    client: $sui_client, ids: string[], $optional_type_args_param
+                ): Promise<Array<$fetch_object_result<$(&struct_name)$(params_toks_for_to_type_argument)>>> {
+                    $(match type_params.len() {
+                        0 => {
+                            return $fetch_objects(client, ids, (data: $sui_object_data) =>
+                                $(&struct_name).fromSuiObjectData(data)
+                            );
+                        },
+                        1 => {
+                            if (typeArg === undefined) {
+                                return $fetch_objects_of_type<$(&struct_name)$(params_toks_for_to_type_argument)>(
+                                    client, ids, $(&struct_name).$$typeName
+                                );
+                            }$['\n']
+                            return $fetch_objects(client, ids, (data: $sui_object_data) =>
+                                $(&struct_name).fromSuiObjectData(typeArg, data)
+                            );
+                        },
+                        _ => {
+                            if (typeArgs === undefined) {
+                                return $fetch_objects_of_type<$(&struct_name)$(params_toks_for_to_type_argument)>(
+                                    client, ids, $(&struct_name).$$typeName
+                                );
+                            }$['\n']
+                            return $fetch_objects(client, ids, (data: $sui_object_data) =>
+                                $(&struct_name).fromSuiObjectData(typeArgs, data)
+                            );
+                        },
+                    })
+                }$['\n']
+
+                $(if strct.get_abilities().has_key() {
+                    static async *fetchOwned$(params_toks_for_reified)(
+                        client: $sui_client, owner: string, $optional_type_args_param
+                    ): AsyncGenerator<$(&struct_name)$(params_toks_for_to_type_argument)> {
+                        $(match type_params.len() {
+                            0 => {
+                                yield* $fetch_owned_objects(client, owner, $(&struct_name).$$typeName, (data: $sui_object_data) =>
+                                    $(&struct_name).fromSuiObjectData(data)
+                                );
+                            },
+                            1 => {
+                                if (typeArg === undefined) {
+                                    yield* $fetch_owned_objects<$(&struct_name)$(params_toks_for_to_type_argument)>(
+                                        client, owner, $(&struct_name).$$typeName
+                                    );
+                                    return;
+                                }$['\n']
+                                yield* $fetch_owned_objects(
+                                    client,
+                                    owner,
+                                    $compose_sui_type($(&struct_name).$$typeName, $extract_type(typeArg)),
+                                    (data: $sui_object_data) => $(&struct_name).fromSuiObjectData(typeArg, data)
+                                );
+                            },
+                            _ => {
+                                if (typeArgs === undefined) {
+                                    yield* $fetch_owned_objects<$(&struct_name)$(params_toks_for_to_type_argument)>(
+                                        client, owner, $(&struct_name).$$typeName
+                                    );
+                                    return;
+                                }$['\n']
+                                yield* $fetch_owned_objects(
+                                    client,
+                                    owner,
+                                    $compose_sui_type($(&struct_name).$$typeName, ...typeArgs.map(typeArg => $extract_type(typeArg))),
+                                    (data: $sui_object_data) => $(&struct_name).fromSuiObjectData(typeArgs, data)
+                                );
+                            },
+                        })
+                    }$['\n']
+                })
+
+                $(if strct.get_abilities().has_key() {
+                    static async fetchDisplay$(params_toks_for_reified)(
+                        client: $sui_client, $type_args_param_if_any
+                    ): Promise<$display_template<$(&struct_name)$(params_toks_for_to_type_argument)> | null> {
+                        return $fetch_display(client, $(&struct_name).reified(
+                            $(match type_params.len() {
+                                0 => (),
+                                1 => { typeArg },
+                                _ => { ...typeArgs },
+                            })
+                        ).fullTypeName);
+                    }$['\n']
+                })
+
+                $(if self.kiosk_helpers && strct.get_abilities().has_key() && strct.get_abilities().has_store() {
+                    static kiosk$(params_toks_for_reified)(
+                        $(for param in type_params_str.iter() join (, ) => $param: $param)
+                    ): $kiosk_helpers_type<$(&struct_name)$(params_toks_for_to_type_argument)> {
+                        return $kiosk_helpers($(&struct_name).reified(
+                            $(for param in type_params_str.iter() join (, ) => $param)
+                        ));
+                    }$['\n']
+                })
             }
         }
-        tokens.line()
+        tokens.line();
+        Ok(())
+    }
+
+    /// Returns the names exported from `structs.ts` for a struct.
+    pub fn exported_names(&self, strct: &StructEnv) -> Vec<BarrelExport> {
+        let struct_name = self.struct_class_name(strct);
+        vec![
+            BarrelExport::value(format!("is{}", &struct_name)),
+            BarrelExport::type_(self.fields_if_name(strct)),
+            BarrelExport::type_(format!("{}Reified", &struct_name)),
+            BarrelExport::value(struct_name),
+        ]
     }
 
     pub fn gen_struct_sep_comment(&self, tokens: &mut js::Tokens, strct: &StructEnv) {
-        let struct_name = strct.get_name().display(self.symbol_pool()).to_string();
+        let struct_name = self.struct_class_name(strct);
         tokens.line();
         tokens.append(format!(
             "/* ============================== {} =============================== */",
@@ -2313,3 +3167,23 @@ impl<'env, 'a> StructsGen<'env, 'a> {
         tokens.line()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_single_param_name() {
+        assert_eq!(single_param_name("pool"), "pool");
+        assert_eq!(single_param_name("coinIn"), "coinIn");
+
+        // reserved words
+        assert_eq!(single_param_name("new"), "new_");
+        assert_eq!(single_param_name("delete"), "delete_");
+
+        // params of the binding itself
+        assert_eq!(single_param_name("tx"), "tx_");
+        assert_eq!(single_param_name("typeArg"), "typeArg_");
+        assert_eq!(single_param_name("typeArgs"), "typeArgs_");
+    }
+}
@@ -0,0 +1,693 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, Result};
+use colored::*;
+use genco::prelude::*;
+use move_core_types::account_address::AccountAddress;
+use move_model::model::ModuleEnv;
+use move_package::source_package::parsed_manifest::PackageName;
+use move_symbol_pool::Symbol;
+use sui_move_build::SuiPackageHooks;
+
+use crate::diagnostics::{Diagnostics, MessageFormat};
+use crate::framework_sources;
+use crate::gen::{
+    gen_barrel_ts, gen_init_loader_ts, gen_module_source_map, gen_package_barrel_ts,
+    gen_package_init_ts, gen_root_barrel_ts, module_has_barrel, FrameworkImportCtx, FunctionsGen,
+    FunctionsGenOptions, StructClassImportCtx, StructsGen,
+};
+use crate::lock::{GenLock, LockedModel};
+use crate::manifest::{GenManifest, Package};
+use crate::model_builder::{build_models, on_chain_package_specs, ModelResult};
+use crate::naming::Naming;
+use crate::package_cache::PackageCache;
+use crate::package_source::PackageSource;
+
+/// Receives the files emitted by the generator. Paths are relative to the output root.
+pub trait FileSink {
+    fn write_file(&mut self, path: &Path, contents: &str) -> Result<()>;
+}
+
+/// Generated files kept in memory, keyed by their path relative to the output root.
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct GeneratedFiles {
+    files: BTreeMap<PathBuf, String>,
+}
+
+impl GeneratedFiles {
+    pub fn get(&self, path: impl AsRef<Path>) -> Option<&str> {
+        self.files.get(path.as_ref()).map(String::as_str)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&Path, &str)> {
+        self.files
+            .iter()
+            .map(|(path, contents)| (path.as_path(), contents.as_str()))
+    }
+
+    pub fn len(&self) -> usize {
+        self.files.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.files.is_empty()
+    }
+
+    /// Writes all files to the directory at `out_root`.
+    pub fn write_to(&self, out_root: &Path) -> Result<()> {
+        let mut sink = DirSink::new(out_root);
+        for (path, contents) in self.iter() {
+            sink.write_file(path, contents)?;
+        }
+        Ok(())
+    }
+}
+
+impl FileSink for GeneratedFiles {
+    fn write_file(&mut self, path: &Path, contents: &str) -> Result<()> {
+        self.files.insert(path.to_path_buf(), contents.to_string());
+        Ok(())
+    }
+}
+
+/// Writes generated files to a directory, creating parent directories as needed.
+pub struct DirSink {
+    root: PathBuf,
+}
+
+impl DirSink {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        DirSink { root: root.into() }
+    }
+}
+
+impl FileSink for DirSink {
+    fn write_file(&mut self, path: &Path, contents: &str) -> Result<()> {
+        let path = self.root.join(path);
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(path, contents)?;
+        Ok(())
+    }
+}
+
+/// Generates TS code for the packages in a `GenManifest`. Packages are fetched through
+/// `source`, and the generated files are either returned in memory (`generate`) or written to a
+/// `FileSink` (`generate_to`).
+///
+/// ```ignore
+/// let source = JsonRpcSource::new(client);
+/// let (files, lock) = Generator::new(manifest, &source)
+///     .manifest_path("move/gen.toml")
+///     .lock(GenLock::read(Path::new("move/gen.lock"))?)
+///     .generate()
+///     .await?;
+/// files.write_to(Path::new("src/gen"))?;
+/// ```
+pub struct Generator<'a> {
+    manifest: GenManifest,
+    source: &'a dyn PackageSource,
+    manifest_path: PathBuf,
+    lock: Option<GenLock>,
+    diags: Option<&'a mut Diagnostics>,
+    progress: Box<dyn Write + 'a>,
+}
+
+impl<'a> Generator<'a> {
+    pub fn new(manifest: GenManifest, source: &'a dyn PackageSource) -> Self {
+        Generator {
+            manifest,
+            source,
+            manifest_path: PathBuf::from("gen.toml"),
+            lock: None,
+            diags: None,
+            progress: Box::new(io::sink()),
+        }
+    }
+
+    /// Path of the manifest, against which the paths of local packages are resolved. Defaults
+    /// to `gen.toml` in the working directory.
+    pub fn manifest_path(mut self, path: impl Into<PathBuf>) -> Self {
+        self.manifest_path = path.into();
+        self
+    }
+
+    /// A previous resolution to reuse where it's still up to date (see `GenLock`).
+    pub fn lock(mut self, lock: Option<GenLock>) -> Self {
+        self.lock = lock;
+        self
+    }
+
+    /// Where diagnostics are reported. By default they're printed for humans on stderr.
+    pub fn diagnostics(mut self, diags: &'a mut Diagnostics) -> Self {
+        self.diags = Some(diags);
+        self
+    }
+
+    /// Where progress messages are written. Discarded by default.
+    pub fn progress(mut self, progress: impl Write + 'a) -> Self {
+        self.progress = Box::new(progress);
+        self
+    }
+
+    /// Generates the files in memory. Returns them along with the resolution of the packages,
+    /// which can be saved as `gen.lock` and passed to `lock` in later runs.
+    pub async fn generate(self) -> Result<(GeneratedFiles, GenLock)> {
+        let mut files = GeneratedFiles::default();
+        let lock = self.generate_to(&mut files).await?;
+        Ok((files, lock))
+    }
+
+    /// Generates the files into `sink`. Returns the resolution of the packages (see `generate`).
+    pub async fn generate_to(mut self, sink: &mut dyn FileSink) -> Result<GenLock> {
+        let mut default_diags = Diagnostics::new(MessageFormat::Human);
+        let diags = match self.diags.take() {
+            Some(diags) => diags,
+            None => &mut default_diags,
+        };
+        let manifest = &self.manifest;
+        let config = manifest.config.clone().unwrap_or_default();
+
+        move_package::package_hooks::register_package_hooks(Box::new(SuiPackageHooks));
+
+        // build models
+        let mut cache = PackageCache::new(self.source, config.fetch.clone());
+        let (source_model, on_chain_model) = build_models(
+            &mut cache,
+            &manifest.packages,
+            &self.manifest_path,
+            &config.build,
+            self.lock.as_ref(),
+            diags,
+            &mut self.progress,
+        )
+        .await?;
+
+        // record the resolution so that later runs reproduce it
+        let on_chain_pkgs = manifest
+            .packages
+            .iter()
+            .filter_map(|(name, pkg)| match pkg {
+                Package::OnChain(pkg) => Some((*name, pkg.clone())),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        let lock = GenLock::new(
+            source_model
+                .as_ref()
+                .map(|m| LockedModel::new(m, BTreeMap::new())),
+            on_chain_model
+                .as_ref()
+                .map(|m| LockedModel::new(m, on_chain_package_specs(&on_chain_pkgs))),
+        );
+
+        if source_model.is_none() && on_chain_model.is_none() {
+            return Ok(lock);
+        }
+
+        // separate modules by package
+        let mut source_pkgs: BTreeMap<AccountAddress, Vec<ModuleEnv>> = BTreeMap::new();
+        if let Some(m) = &source_model {
+            for module in m.env.get_modules() {
+                source_pkgs
+                    .entry(*module.self_address())
+                    .or_default()
+                    .push(module);
+            }
+        }
+
+        let mut on_chain_pkgs: BTreeMap<AccountAddress, Vec<ModuleEnv>> = BTreeMap::new();
+        if let Some(m) = &on_chain_model {
+            for module in m.env.get_modules() {
+                on_chain_pkgs
+                    .entry(*module.self_address())
+                    .or_default()
+                    .push(module);
+            }
+        }
+
+        // gen top-level packages and dependencies
+        let (source_top_level_addr_map, on_chain_top_level_addr_map) =
+            resolve_top_level_pkg_addr_map(&source_model, &on_chain_model, manifest);
+
+        // resolve names
+        let mut naming = {
+            let pkg_names: BTreeMap<_, _> = source_top_level_addr_map
+                .iter()
+                .chain(on_chain_top_level_addr_map.iter())
+                .map(|(addr, name)| (*addr, *name))
+                .collect();
+            Naming::new(config.naming.clone(), &manifest.rename, &pkg_names)?
+        };
+        let envs = source_model
+            .iter()
+            .chain(on_chain_model.iter())
+            .map(|m| &m.env)
+            .collect::<Vec<_>>();
+        let unmatched_renames = naming.unmatched_renames(&envs);
+        if !unmatched_renames.is_empty() {
+            bail!(
+                "The following renames in gen.toml don't match any function or struct: {}",
+                unmatched_renames.join(", ")
+            );
+        }
+        if let Some(m) = &source_model {
+            naming.validate(&m.env, &source_top_level_addr_map, diags)?;
+        }
+        if let Some(m) = &on_chain_model {
+            naming.validate(&m.env, &on_chain_top_level_addr_map, diags)?;
+        }
+        let mut functions_opts = FunctionsGenOptions {
+            strict_object_args: config.strict_object_args,
+            coin_amount_args: config.coin_amount_args,
+            ..FunctionsGenOptions::default()
+        };
+        for (type_, id) in config.system_objects.iter() {
+            match id {
+                Some(id) => functions_opts
+                    .system_objects
+                    .insert(type_.clone(), id.clone()),
+                None => functions_opts.system_objects.remove(type_),
+            };
+        }
+        // the kiosk helpers are built on the sui framework `kiosk` and `transfer_policy` bindings,
+        // so they're only emitted when the sui framework is in the model with both modules
+        let sui_framework = if has_kiosk_modules(source_pkgs.get(&AccountAddress::TWO)) {
+            Some((&source_top_level_addr_map, true))
+        } else if has_kiosk_modules(on_chain_pkgs.get(&AccountAddress::TWO)) {
+            Some((&on_chain_top_level_addr_map, false))
+        } else {
+            None
+        };
+        let opts = GenOptions {
+            barrels: config.barrels,
+            source_maps: config.source_maps,
+            naming: &naming,
+            functions: functions_opts,
+            kiosk_helpers: sui_framework.is_some(),
+        };
+
+        // gen _framework
+        writeln!(self.progress, "{}", "GENERATING FRAMEWORK".green().bold())?;
+
+        let framework = PathBuf::from("_framework");
+        for (name, contents) in [
+            ("loader.ts", framework_sources::LOADER),
+            ("util.ts", framework_sources::UTIL),
+            ("reified.ts", framework_sources::REIFIED),
+            ("vector.ts", framework_sources::VECTOR),
+            ("display.ts", framework_sources::DISPLAY),
+        ] {
+            write_str(sink, contents, &framework.join(name))?;
+        }
+        if let Some((top_level_addr_map, is_source)) = sui_framework {
+            let sui_path = Path::new("..")
+                .join(package_dir(
+                    &AccountAddress::TWO,
+                    top_level_addr_map,
+                    is_source,
+                    &naming,
+                ))
+                .iter()
+                .map(|c| c.to_string_lossy())
+                .collect::<Vec<_>>()
+                .join("/");
+            write_str(
+                sink,
+                &framework_sources::kiosk(&sui_path),
+                &framework.join("kiosk.ts"),
+            )?;
+        }
+        write_tokens(
+            sink,
+            &gen_init_loader_ts(
+                match source_pkgs.is_empty() {
+                    false => Some((
+                        source_pkgs.keys().copied().collect::<Vec<_>>(),
+                        &source_top_level_addr_map,
+                    )),
+                    true => None,
+                },
+                match on_chain_pkgs.is_empty() {
+                    false => Some((
+                        on_chain_pkgs.keys().copied().collect::<Vec<_>>(),
+                        &on_chain_top_level_addr_map,
+                    )),
+                    true => None,
+                },
+                &naming,
+            ),
+            &framework.join("init-loader.ts"),
+        )?;
+
+        if let Some(m) = &source_model {
+            writeln!(
+                self.progress,
+                "{}",
+                "GENERATING SOURCE PACKAGES".green().bold()
+            )?;
+            gen_packages_for_model(
+                sink,
+                source_pkgs,
+                &source_top_level_addr_map,
+                m,
+                true,
+                &opts,
+            )?;
+        }
+
+        if let Some(m) = &on_chain_model {
+            writeln!(
+                self.progress,
+                "{}",
+                "GENERATING ON-CHAIN PACKAGES".green().bold()
+            )?;
+            gen_packages_for_model(
+                sink,
+                on_chain_pkgs,
+                &on_chain_top_level_addr_map,
+                m,
+                false,
+                &opts,
+            )?;
+        }
+
+        // gen root barrel (index.ts)
+        if config.barrels {
+            let pkg_names = source_top_level_addr_map
+                .values()
+                .chain(on_chain_top_level_addr_map.values())
+                .copied()
+                .collect::<Vec<_>>();
+            write_tokens(
+                sink,
+                &gen_root_barrel_ts(&pkg_names, &naming),
+                Path::new("index.ts"),
+            )?;
+        }
+
+        // gen .eslintrc.json
+        write_str(
+            sink,
+            framework_sources::ESLINTRC,
+            Path::new(".eslintrc.json"),
+        )?;
+
+        Ok(lock)
+    }
+}
+
+fn write_tokens(sink: &mut dyn FileSink, tokens: &Tokens<JavaScript>, path: &Path) -> Result<()> {
+    if tokens.is_empty() {
+        return Ok(());
+    }
+    sink.write_file(path, &tokens.to_file_string()?)
+}
+
+fn write_str(sink: &mut dyn FileSink, s: &str, path: &Path) -> Result<()> {
+    if s.is_empty() {
+        return Ok(());
+    }
+    sink.write_file(path, s)
+}
+
+/// Creates a mapping between address and package name for top-level packages.
+fn resolve_top_level_pkg_addr_map(
+    source_model: &Option<ModelResult>,
+    on_chain_model: &Option<ModelResult>,
+    manifest: &GenManifest,
+) -> (
+    BTreeMap<AccountAddress, Symbol>,
+    BTreeMap<AccountAddress, Symbol>,
+) {
+    let mut source_top_level_package_names: BTreeSet<PackageName> = BTreeSet::new();
+    let mut on_chain_top_level_package_names: BTreeSet<PackageName> = BTreeSet::new();
+    for (name, pkg) in manifest.packages.iter() {
+        match pkg {
+            Package::Dependency(_) => {
+                source_top_level_package_names.insert(*name);
+            }
+            Package::OnChain(_) => {
+                on_chain_top_level_package_names.insert(*name);
+            }
+        }
+    }
+
+    let source_top_level_id_map: BTreeMap<AccountAddress, Symbol> = if let Some(m) = source_model {
+        m.id_map
+            .iter()
+            .filter_map(|(id, name)| {
+                if source_top_level_package_names.contains(name) {
+                    Some((*id, *name))
+                } else {
+                    None
+                }
+            })
+            .collect()
+    } else {
+        BTreeMap::new()
+    };
+
+    let on_chain_top_level_id_map: BTreeMap<AccountAddress, Symbol> =
+        if let Some(m) = on_chain_model {
+            m.id_map
+                .iter()
+                .filter_map(|(id, name)| {
+                    if on_chain_top_level_package_names.contains(name) {
+                        Some((*id, *name))
+                    } else {
+                        None
+                    }
+                })
+                .collect()
+        } else {
+            BTreeMap::new()
+        };
+
+    (source_top_level_id_map, on_chain_top_level_id_map)
+}
+
+/// Directory of a package's generated files, relative to the output root.
+fn package_dir(
+    pkg_id: &AccountAddress,
+    top_level_pkg_names: &BTreeMap<AccountAddress, Symbol>,
+    is_source: bool,
+    naming: &Naming,
+) -> PathBuf {
+    match top_level_pkg_names.get(pkg_id) {
+        Some(pkg_name) => PathBuf::from(naming.package_import_name(*pkg_name)),
+        None => PathBuf::from("_dependencies")
+            .join(match is_source {
+                true => "source",
+                false => "onchain",
+            })
+            .join(pkg_id.to_hex_literal()),
+    }
+}
+
+/// Sui framework modules `_framework/kiosk.ts` is built on. Their function bindings are generated
+/// even when the sui framework is only a dependency.
+const KIOSK_BINDING_MODULES: &[&str] = &["kiosk", "transfer_policy"];
+
+/// Returns whether the sui framework modules contain everything the kiosk helpers need.
+fn has_kiosk_modules(sui_modules: Option<&Vec<ModuleEnv>>) -> bool {
+    let Some(modules) = sui_modules else {
+        return false;
+    };
+    KIOSK_BINDING_MODULES.iter().all(|name| {
+        modules.iter().any(|module| {
+            module
+                .get_name()
+                .display(module.env.symbol_pool())
+                .to_string()
+                == *name
+        })
+    })
+}
+
+/// Options for generating packages that are the same for source and on-chain packages.
+struct GenOptions<'a> {
+    barrels: bool,
+    source_maps: bool,
+    naming: &'a Naming,
+    functions: FunctionsGenOptions,
+    /// Whether `_framework/kiosk.ts` is generated.
+    kiosk_helpers: bool,
+}
+
+fn gen_packages_for_model(
+    sink: &mut dyn FileSink,
+    pkgs: BTreeMap<AccountAddress, Vec<ModuleEnv>>,
+    top_level_pkg_names: &BTreeMap<AccountAddress, Symbol>,
+    model: &ModelResult,
+    is_source: bool,
+    opts: &GenOptions,
+) -> Result<()> {
+    if pkgs.is_empty() {
+        return Ok(());
+    }
+
+    let published_at_map = &model.published_at;
+    let type_origin_table = &model.type_origin_table;
+    let version_table = &model.version_table;
+    let naming = opts.naming;
+
+    for (pkg_id, modules) in pkgs.iter() {
+        let is_top_level = top_level_pkg_names.contains_key(pkg_id);
+        let levels_from_root = if is_top_level { 0 } else { 2 };
+
+        let package_path = package_dir(pkg_id, top_level_pkg_names, is_source, naming);
+
+        // generate index.ts
+        let published_at = published_at_map.get(pkg_id).unwrap_or(pkg_id);
+        let versions = version_table.get(pkg_id).unwrap();
+        let tokens: js::Tokens = quote!(
+            export const PACKAGE_ID = $[str]($[const](pkg_id.to_hex_literal()));
+            export const PUBLISHED_AT = $[str]($[const](published_at.to_hex_literal()));
+            $(for (published_at, version) in versions {
+                export const PKG_V$(version.value()) = $[str]($[const](published_at.to_hex_literal()));
+            })
+        );
+        write_tokens(sink, &tokens, &package_path.join("index.ts"))?;
+
+        // generate init.ts
+        let tokens = gen_package_init_ts(
+            modules,
+            &FrameworkImportCtx::new(levels_from_root + 1),
+            naming,
+        );
+        write_tokens(sink, &tokens, &package_path.join("init.ts"))?;
+
+        // generate barrel.ts
+        let gen_barrels = opts.barrels && is_top_level;
+        if gen_barrels {
+            let tokens = gen_package_barrel_ts(modules, naming);
+            write_tokens(sink, &tokens, &package_path.join("barrel.ts"))?;
+        }
+
+        // generate modules
+        for module in modules {
+            let module_path = package_path.join(naming.module_import_name(module));
+
+            let mut function_exports = vec![];
+            let mut struct_exports = vec![];
+
+            // generate <module>/functions.ts
+            let is_kiosk_binding_module = opts.kiosk_helpers
+                && *pkg_id == AccountAddress::TWO
+                && KIOSK_BINDING_MODULES.contains(
+                    &module
+                        .get_name()
+                        .display(module.env.symbol_pool())
+                        .to_string()
+                        .as_str(),
+                );
+            if is_top_level || is_kiosk_binding_module {
+                let mut tokens = js::Tokens::new();
+                let mut func_gen = FunctionsGen::new(
+                    module.env,
+                    FrameworkImportCtx::new(levels_from_root + 2),
+                    StructClassImportCtx::for_func_gen(
+                        module,
+                        is_source,
+                        top_level_pkg_names,
+                        naming,
+                    ),
+                    opts.functions.clone(),
+                );
+                for func in module.get_functions() {
+                    func_gen.gen_fun_args_if(&func, &mut tokens)?;
+                    func_gen.gen_fun_binding(&func, &mut tokens)?;
+                    function_exports.extend(func_gen.exported_names(&func));
+                }
+                write_tokens(sink, &tokens, &module_path.join("functions.ts"))?;
+            }
+
+            // generate <module>/structs.ts
+            let mut tokens = js::Tokens::new();
+            let mut structs_gen = StructsGen::new(
+                module.env,
+                StructClassImportCtx::for_struct_gen(
+                    module,
+                    is_source,
+                    top_level_pkg_names,
+                    naming,
+                ),
+                FrameworkImportCtx::new(levels_from_root + 2),
+                type_origin_table,
+                version_table,
+                opts.kiosk_helpers,
+            );
+
+            for strct in module.get_structs() {
+                structs_gen.gen_struct_sep_comment(&mut tokens, &strct);
+
+                // type check function
+                structs_gen.gen_is_type_func(&mut tokens, &strct);
+
+                // fields interface
+                structs_gen.gen_fields_if(&mut tokens, &strct)?;
+
+                // struct class
+                structs_gen.gen_struct_class(&mut tokens, &strct)?;
+
+                struct_exports.extend(structs_gen.exported_names(&strct));
+            }
+            write_tokens(sink, &tokens, &module_path.join("structs.ts"))?;
+
+            // generate <module>/index.ts
+            if gen_barrels && module_has_barrel(module) {
+                let mut exports = vec![("./structs".to_string(), struct_exports)];
+                if !function_exports.is_empty() {
+                    exports.push(("./functions".to_string(), function_exports));
+                }
+                write_tokens(sink, &gen_barrel_ts(exports), &module_path.join("index.ts"))?;
+            }
+
+            // generate <module>/.move-map.json
+            if opts.source_maps {
+                let source_map =
+                    gen_module_source_map(module, published_at, is_source, is_top_level, naming);
+                write_str(
+                    sink,
+                    &serde_json::to_string_pretty(&source_map)?,
+                    &module_path.join(".move-map.json"),
+                )?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generated_files_write_to() {
+        let mut files = GeneratedFiles::default();
+        files
+            .write_file(Path::new("amm/pool/structs.ts"), "export {}\n")
+            .unwrap();
+        files
+            .write_file(Path::new(".eslintrc.json"), "{}\n")
+            .unwrap();
+        assert_eq!(files.get("amm/pool/structs.ts"), Some("export {}\n"));
+
+        let dir = tempfile::tempdir().unwrap();
+        files.write_to(dir.path()).unwrap();
+
+        assert_eq!(
+            std::fs::read_to_string(dir.path().join("amm/pool/structs.ts")).unwrap(),
+            "export {}\n"
+        );
+        assert_eq!(
+            std::fs::read_to_string(dir.path().join(".eslintrc.json")).unwrap(),
+            "{}\n"
+        );
+    }
+}
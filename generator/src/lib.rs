@@ -1,5 +1,12 @@
+pub mod diagnostics;
 pub mod framework_sources;
 pub mod gen;
+pub mod generator;
+pub mod lock;
 pub mod manifest;
 pub mod model_builder;
+pub mod naming;
 pub mod package_cache;
+pub mod package_source;
+
+pub use generator::{DirSink, FileSink, GeneratedFiles, Generator};
@@ -0,0 +1,331 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use move_core_types::account_address::AccountAddress;
+use move_package::source_package::parsed_manifest::PackageName;
+use serde::{Deserialize, Serialize};
+
+use crate::model_builder::{ModelResult, TypeOriginTable, VersionTable};
+
+pub const LOCK_FILE_NAME: &str = "gen.lock";
+
+/// Format version of `gen.lock`. Lock files of other versions are ignored.
+const LOCK_VERSION: u64 = 1;
+
+/// Contents of `gen.lock`, which records how packages were resolved so that later runs generate
+/// the same output without resolving them again.
+#[derive(Debug, Clone, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct GenLock {
+    pub version: u64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source: Option<LockedModel>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub on_chain: Option<LockedModel>,
+}
+
+/// Resolution results for the source or on-chain model. Addresses are stored as hex literals.
+#[derive(Debug, Clone, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct LockedModel {
+    /// The on-chain packages as specified in gen.toml (`<id>` or `<id>@<version>`), by name. If
+    /// they change, the on-chain packages are resolved again.
+    #[serde(default)]
+    pub packages: BTreeMap<String, String>,
+    /// Package names by original package ID.
+    pub names: BTreeMap<String, String>,
+    /// The ID each package is published at, by original package ID.
+    pub published_at: BTreeMap<String, String>,
+    /// The resolved ID of every on-chain package (including dependencies), by original package ID.
+    #[serde(default)]
+    pub resolved: BTreeMap<String, String>,
+    pub type_origins: BTreeMap<String, BTreeMap<String, String>>,
+    pub versions: BTreeMap<String, BTreeMap<String, u64>>,
+    /// The source packages (including dependencies) by name.
+    #[serde(default)]
+    pub sources: BTreeMap<String, LockedSource>,
+}
+
+#[derive(Debug, Clone, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct LockedSource {
+    /// Digest of the package's sources and manifest, as recorded in Move.lock.
+    pub digest: String,
+    /// The commit SHA of a git dependency.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub git: Option<String>,
+}
+
+fn parse_addr(s: &str) -> Result<AccountAddress> {
+    AccountAddress::from_hex_literal(s)
+        .with_context(|| format!("Invalid address \"{}\" in {}", s, LOCK_FILE_NAME))
+}
+
+fn addr_map_to_strings(map: &BTreeMap<AccountAddress, AccountAddress>) -> BTreeMap<String, String> {
+    map.iter()
+        .map(|(k, v)| (k.to_hex_literal(), v.to_hex_literal()))
+        .collect()
+}
+
+/// Appends `<prefix>.<key>` for every key whose value differs between the maps.
+fn diff_maps<V: PartialEq>(
+    prefix: &str,
+    a: &BTreeMap<String, V>,
+    b: &BTreeMap<String, V>,
+    out: &mut Vec<String>,
+) {
+    let keys: BTreeSet<&String> = a.keys().chain(b.keys()).collect();
+    out.extend(
+        keys.into_iter()
+            .filter(|key| a.get(*key) != b.get(*key))
+            .map(|key| format!("{}.{}", prefix, key)),
+    );
+}
+
+fn addr_map_from_strings(
+    map: &BTreeMap<String, String>,
+) -> Result<BTreeMap<AccountAddress, AccountAddress>> {
+    map.iter()
+        .map(|(k, v)| Ok((parse_addr(k)?, parse_addr(v)?)))
+        .collect()
+}
+
+impl LockedModel {
+    pub fn new(model: &ModelResult, packages: BTreeMap<String, String>) -> Self {
+        LockedModel {
+            packages,
+            names: model
+                .id_map
+                .iter()
+                .map(|(id, name)| (id.to_hex_literal(), name.to_string()))
+                .collect(),
+            published_at: addr_map_to_strings(&model.published_at),
+            resolved: addr_map_to_strings(&model.resolved),
+            type_origins: model
+                .type_origin_table
+                .iter()
+                .map(|(id, origins)| {
+                    (
+                        id.to_hex_literal(),
+                        origins
+                            .iter()
+                            .map(|(name, origin)| (name.clone(), origin.to_hex_literal()))
+                            .collect(),
+                    )
+                })
+                .collect(),
+            versions: model
+                .version_table
+                .iter()
+                .map(|(id, versions)| {
+                    (
+                        id.to_hex_literal(),
+                        versions
+                            .iter()
+                            .map(|(id, version)| (id.to_hex_literal(), version.value()))
+                            .collect(),
+                    )
+                })
+                .collect(),
+            sources: model
+                .sources
+                .iter()
+                .map(|(name, info)| {
+                    let source = LockedSource {
+                        digest: info.digest.clone(),
+                        git: info.git_rev.clone(),
+                    };
+                    (name.to_string(), source)
+                })
+                .collect(),
+        }
+    }
+
+    fn diff(&self, other: &LockedModel, section: &str, out: &mut Vec<String>) {
+        let field = |name: &str| format!("{}.{}", section, name);
+        diff_maps(&field("packages"), &self.packages, &other.packages, out);
+        diff_maps(&field("names"), &self.names, &other.names, out);
+        diff_maps(
+            &field("published_at"),
+            &self.published_at,
+            &other.published_at,
+            out,
+        );
+        diff_maps(&field("resolved"), &self.resolved, &other.resolved, out);
+        diff_maps(
+            &field("type_origins"),
+            &self.type_origins,
+            &other.type_origins,
+            out,
+        );
+        diff_maps(&field("versions"), &self.versions, &other.versions, out);
+        diff_maps(&field("sources"), &self.sources, &other.sources, out);
+    }
+
+    pub fn id_map(&self) -> Result<BTreeMap<AccountAddress, PackageName>> {
+        self.names
+            .iter()
+            .map(|(id, name)| Ok((parse_addr(id)?, PackageName::from(name.as_str()))))
+            .collect()
+    }
+
+    pub fn published_at(&self) -> Result<BTreeMap<AccountAddress, AccountAddress>> {
+        addr_map_from_strings(&self.published_at)
+    }
+
+    pub fn resolved(&self) -> Result<BTreeMap<AccountAddress, AccountAddress>> {
+        addr_map_from_strings(&self.resolved)
+    }
+
+    pub fn type_origin_table(&self) -> Result<TypeOriginTable> {
+        self.type_origins
+            .iter()
+            .map(|(id, origins)| {
+                let origins = origins
+                    .iter()
+                    .map(|(name, origin)| Ok((name.clone(), parse_addr(origin)?)))
+                    .collect::<Result<_>>()?;
+                Ok((parse_addr(id)?, origins))
+            })
+            .collect()
+    }
+
+    pub fn version_table(&self) -> Result<VersionTable> {
+        self.versions
+            .iter()
+            .map(|(id, versions)| {
+                let versions = versions
+                    .iter()
+                    .map(|(id, version)| Ok((parse_addr(id)?, (*version).into())))
+                    .collect::<Result<_>>()?;
+                Ok((parse_addr(id)?, versions))
+            })
+            .collect()
+    }
+}
+
+impl GenLock {
+    pub fn new(source: Option<LockedModel>, on_chain: Option<LockedModel>) -> Self {
+        GenLock {
+            version: LOCK_VERSION,
+            source,
+            on_chain,
+        }
+    }
+
+    /// Reads the lock file at `path`. Returns `None` if it doesn't exist or is of another version.
+    pub fn read(path: &Path) -> Result<Option<Self>> {
+        if !path.is_file() {
+            return Ok(None);
+        }
+        let contents = std::fs::read_to_string(path)?;
+        let lock: GenLock = toml::from_str(&contents)
+            .with_context(|| format!("Unable to parse {}", path.display()))?;
+        if lock.version != LOCK_VERSION {
+            return Ok(None);
+        }
+        Ok(Some(lock))
+    }
+
+    /// Lists the entries that differ from `other`, e.g. `on_chain.resolved.0x2` or
+    /// `source.sources.Sui`. Empty if the locks are equal.
+    pub fn diff(&self, other: &GenLock) -> Vec<String> {
+        let mut out = vec![];
+        let sections = [
+            ("source", &self.source, &other.source),
+            ("on_chain", &self.on_chain, &other.on_chain),
+        ];
+        for (section, a, b) in sections {
+            match (a, b) {
+                (Some(a), Some(b)) => a.diff(b, section, &mut out),
+                (None, None) => (),
+                _ => out.push(section.to_string()),
+            }
+        }
+        out
+    }
+
+    pub fn write(&self, path: &Path) -> Result<()> {
+        let contents = format!(
+            "# This file is generated by sui-client-gen. Do not edit it manually.\n\n{}",
+            toml::to_string(self)?
+        );
+        std::fs::write(path, contents)
+            .with_context(|| format!("Unable to write {}", path.display()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lock_roundtrip() {
+        let addr = |s: &str| AccountAddress::from_hex_literal(s).unwrap();
+        let model = LockedModel {
+            packages: BTreeMap::from([("amm".to_string(), "0xa1@latest".to_string())]),
+            names: BTreeMap::from([("0xa1".to_string(), "amm".to_string())]),
+            published_at: BTreeMap::from([("0xa1".to_string(), "0xa2".to_string())]),
+            resolved: BTreeMap::from([("0xa1".to_string(), "0xa2".to_string())]),
+            type_origins: BTreeMap::from([(
+                "0xa1".to_string(),
+                BTreeMap::from([("pool::Pool".to_string(), "0xa1".to_string())]),
+            )]),
+            versions: BTreeMap::from([(
+                "0xa1".to_string(),
+                BTreeMap::from([("0xa1".to_string(), 1), ("0xa2".to_string(), 2)]),
+            )]),
+            sources: BTreeMap::new(),
+        };
+        let lock = GenLock::new(None, Some(model));
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(LOCK_FILE_NAME);
+        lock.write(&path).unwrap();
+        assert_eq!(GenLock::read(&path).unwrap(), Some(lock.clone()));
+
+        let model = lock.on_chain.unwrap();
+        assert_eq!(
+            model.published_at().unwrap(),
+            BTreeMap::from([(addr("0xa1"), addr("0xa2"))])
+        );
+        assert_eq!(
+            model.version_table().unwrap(),
+            BTreeMap::from([(
+                addr("0xa1"),
+                BTreeMap::from([(addr("0xa1"), 1.into()), (addr("0xa2"), 2.into())])
+            )])
+        );
+    }
+
+    #[test]
+    fn test_lock_diff() {
+        let source = |git: &str| LockedSource {
+            digest: "ABCD".to_string(),
+            git: Some(git.to_string()),
+        };
+        let old = GenLock::new(
+            Some(LockedModel {
+                sources: BTreeMap::from([
+                    ("Sui".to_string(), source("aaaa")),
+                    ("MoveStdlib".to_string(), source("aaaa")),
+                ]),
+                ..Default::default()
+            }),
+            None,
+        );
+        let mut new = old.clone();
+        assert!(old.diff(&new).is_empty());
+
+        let model = new.source.as_mut().unwrap();
+        model.sources.insert("Sui".to_string(), source("bbbb"));
+        model.sources.remove("MoveStdlib");
+        new.on_chain = Some(LockedModel::default());
+        assert_eq!(
+            old.diff(&new),
+            vec![
+                "source.sources.MoveStdlib".to_string(),
+                "source.sources.Sui".to_string(),
+                "on_chain".to_string(),
+            ]
+        );
+    }
+}
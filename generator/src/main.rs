@@ -1,28 +1,22 @@
-use std::collections::{BTreeMap, BTreeSet};
 use std::path::{Path, PathBuf};
 
-use anyhow::Result;
+use anyhow::{anyhow, bail, Context, Result};
 use clap::*;
-use colored::*;
-use genco::fmt;
-use genco::prelude::*;
+use move_compiler::editions::Edition;
 use move_core_types::account_address::AccountAddress;
-use move_model::model::ModuleEnv;
-use move_package::source_package::parsed_manifest::PackageName;
-use move_symbol_pool::Symbol;
-use std::io::Write;
-use sui_client_gen::framework_sources;
-use sui_client_gen::gen::{
-    gen_init_loader_ts, gen_package_init_ts, module_import_name, package_import_name,
+use std::io::{IsTerminal, Write};
+use sui_client_gen::diagnostics::{Diagnostic, Diagnostics, MessageFormat};
+use sui_client_gen::lock::{GenLock, LOCK_FILE_NAME};
+use sui_client_gen::manifest::parse_gen_manifest_from_file;
+use sui_client_gen::model_builder::{BuildConfig, LintLevel};
+use sui_client_gen::package_source::{
+    FixtureSource, GraphQlSource, JsonRpcSource, PackageSource, PackageSourceKind, RecordingSource,
 };
-use sui_client_gen::gen::{FrameworkImportCtx, FunctionsGen, StructClassImportCtx, StructsGen};
-use sui_client_gen::manifest::{parse_gen_manifest_from_file, GenManifest, Package};
-use sui_client_gen::model_builder::{build_models, ModelResult, TypeOriginTable, VersionTable};
-use sui_client_gen::package_cache::PackageCache;
-use sui_move_build::SuiPackageHooks;
+use sui_client_gen::Generator;
 use sui_sdk::SuiClientBuilder;
 
 const DEFAULT_RPC: &str = "https://fullnode.mainnet.sui.io:443";
+const DEFAULT_GRAPHQL: &str = "https://sui-mainnet.mystenlabs.com/graphql";
 
 #[derive(Parser)]
 #[clap(
@@ -49,175 +43,249 @@ struct Args {
 
     #[arg(
         long,
-        help = "Remove all contents of the output directory before generating, except for gen.toml. Use with caution."
+        help = "Remove all contents of the output directory before generating, except for gen.toml, gen.lock and the fixtures directory. Use with caution."
     )]
     clean: bool,
+
+    #[arg(
+        long,
+        value_name = "DIR",
+        help = "Write all packages fetched from chain to DIR as fixtures, which can be used with `source = \"fixtures\"` in gen.toml."
+    )]
+    record: Option<String>,
+
+    #[arg(
+        long,
+        conflicts_with = "update",
+        help = "Fail if the packages resolve differently than recorded in gen.lock instead of updating it."
+    )]
+    locked: bool,
+
+    #[arg(
+        long,
+        help = "Resolve all packages again, fetching the latest commits of git dependencies, and update gen.lock."
+    )]
+    update: bool,
+
+    #[arg(
+        long = "dev",
+        help = "Build source packages in dev mode, using `dev-addresses` and `dev-dependencies`."
+    )]
+    dev_mode: bool,
+
+    #[arg(
+        long = "test",
+        help = "Build source packages in test mode, generating bindings for `#[test_only]` modules and functions."
+    )]
+    test_mode: bool,
+
+    #[arg(
+        long,
+        help = "Move edition for source packages that don't specify one in their Move.toml."
+    )]
+    edition: Option<String>,
+
+    #[arg(
+        long = "named-address",
+        value_name = "NAME=ADDRESS",
+        help = "Assign an additional named address in source packages. Can be passed multiple times."
+    )]
+    named_addresses: Vec<String>,
+
+    #[arg(
+        long,
+        value_name = "LEVEL",
+        help = "Lint level of the Move build (\"none\", \"default\" or \"all\")."
+    )]
+    lint: Option<String>,
+
+    #[arg(
+        long,
+        help = "Fetch the latest commits of git dependencies instead of using the ones already checked out."
+    )]
+    fetch_deps: bool,
+
+    #[arg(
+        long,
+        value_name = "DIR",
+        help = "Installation directory for Move build artifacts."
+    )]
+    install_dir: Option<String>,
+
+    #[arg(
+        long,
+        value_name = "FORMAT",
+        default_value = "human",
+        help = "Format of diagnostics: \"human\" (on stderr) or \"json\" (one record per line on stdout)."
+    )]
+    message_format: String,
+}
+
+impl Args {
+    /// Applies the Move build flags on top of the `[config.build]` section of gen.toml.
+    fn apply_build_flags(&self, build: &mut BuildConfig) -> Result<()> {
+        build.dev_mode |= self.dev_mode;
+        build.test_mode |= self.test_mode;
+        // `--update` refreshes git dependencies along with everything else in gen.lock
+        build.fetch_deps |= self.fetch_deps || self.update;
+        if let Some(edition) = &self.edition {
+            build.edition = Some(
+                edition
+                    .parse::<Edition>()
+                    .map_err(|e| anyhow!("Invalid edition \"{}\": {}", edition, e))?,
+            );
+        }
+        for assignment in &self.named_addresses {
+            let Some((name, addr)) = assignment.split_once('=') else {
+                bail!(
+                    "Invalid named address \"{}\". Expected NAME=ADDRESS",
+                    assignment
+                );
+            };
+            let addr = AccountAddress::from_hex_literal(addr)
+                .with_context(|| format!("Invalid address for named address \"{}\"", name))?;
+            build
+                .additional_named_addresses
+                .insert(name.to_string(), addr);
+        }
+        if let Some(lint) = &self.lint {
+            build.lint = LintLevel::parse(lint)?;
+        }
+        if let Some(dir) = &self.install_dir {
+            // unlike in gen.toml, the path is relative to the working directory
+            build.install_dir = Some(std::env::current_dir()?.join(dir));
+        }
+        Ok(())
+    }
 }
 
 #[tokio::main]
 async fn main() -> Result<()> {
     let args = Args::parse();
 
-    move_package::package_hooks::register_package_hooks(Box::new(SuiPackageHooks));
+    // progress and human diagnostics go to stderr, so color them only if it's a terminal
+    colored::control::set_override(std::io::stderr().is_terminal());
+    let mut diags = Diagnostics::new(MessageFormat::parse(&args.message_format)?);
 
-    let manifest = parse_gen_manifest_from_file(Path::new(&args.manifest))?;
-    let rpc_url = match &manifest.config {
-        Some(config) => config
-            .rpc
-            .clone()
-            .unwrap_or_else(|| DEFAULT_RPC.to_string()),
-        None => DEFAULT_RPC.to_string(),
-    };
-    let rpc_client = SuiClientBuilder::default().build(rpc_url).await?;
-
-    let mut progress_output = std::io::stderr();
+    match run(args, &mut diags).await {
+        Ok(()) => Ok(()),
+        Err(e) if diags.format() == MessageFormat::Json => {
+            diags.emit(Diagnostic::error("fatal", format!("{:#}", e)))?;
+            std::process::exit(1);
+        }
+        Err(e) => Err(e),
+    }
+}
 
-    // build models
-    let mut cache = PackageCache::new(rpc_client.read_api());
-    let (source_model, on_chain_model) = build_models(
-        &mut cache,
-        &manifest.packages,
-        &PathBuf::from(&args.manifest),
-        &mut progress_output,
-    )
-    .await?;
+async fn run(args: Args, diags: &mut Diagnostics) -> Result<()> {
+    let mut manifest = parse_gen_manifest_from_file(Path::new(&args.manifest))?;
+    let mut config = manifest.config.clone().unwrap_or_default();
+    args.apply_build_flags(&mut config.build)?;
+    manifest.config = Some(config.clone());
+
+    let mut source: Box<dyn PackageSource> = match config.source {
+        PackageSourceKind::JsonRpc => {
+            let rpc_url = config
+                .rpc
+                .clone()
+                .unwrap_or_else(|| DEFAULT_RPC.to_string());
+            let rpc_client = SuiClientBuilder::default().build(rpc_url).await?;
+            Box::new(JsonRpcSource::new(rpc_client))
+        }
+        PackageSourceKind::GraphQl => Box::new(GraphQlSource::new(
+            config
+                .graphql
+                .clone()
+                .unwrap_or_else(|| DEFAULT_GRAPHQL.to_string()),
+        )),
+        PackageSourceKind::Fixtures => {
+            // `parse_config` ensures the directory is set for this source
+            let fixtures = config.fixtures.as_ref().unwrap();
+            Box::new(FixtureSource::new(
+                manifest_dir(&args.manifest).join(fixtures),
+            ))
+        }
+    };
 
-    if source_model.is_none() && on_chain_model.is_none() {
-        writeln!(std::io::stderr(), "No packages to generate.")?;
-        return Ok(());
+    if let Some(dir) = &args.record {
+        source = Box::new(RecordingSource::new(source, PathBuf::from(dir)));
     }
 
-    // clean output
-    if args.clean {
-        clean_output(&PathBuf::from(&args.out))?;
+    let lock_path = manifest_dir(&args.manifest).join(LOCK_FILE_NAME);
+    let lock = GenLock::read(&lock_path)?;
+    if args.locked && lock.is_none() {
+        bail!(
+            "--locked was passed but {} doesn't exist or was written by another version.",
+            lock_path.display()
+        );
     }
-
-    // separate modules by package
-    let mut source_pkgs: BTreeMap<AccountAddress, Vec<ModuleEnv>> = BTreeMap::new();
-    match &source_model {
-        Some(m) => {
-            for module in m.env.get_modules() {
-                let pkg_id = *module.self_address();
-                match source_pkgs.get_mut(&pkg_id) {
-                    Some(modules) => modules.push(module),
-                    None => {
-                        let modules = vec![module];
-                        source_pkgs.insert(pkg_id, modules);
-                    }
-                }
-            }
-        }
-        None => (),
+    // with `--locked` everything is resolved again to verify the lock, and with `--update` to
+    // refresh it
+    let reused_lock = if args.locked || args.update {
+        None
+    } else {
+        lock.clone()
     };
 
-    let mut on_chain_pkgs: BTreeMap<AccountAddress, Vec<ModuleEnv>> = BTreeMap::new();
-    match &on_chain_model {
-        Some(m) => {
-            for module in m.env.get_modules() {
-                let pkg_id = *module.self_address();
-                match on_chain_pkgs.get_mut(&pkg_id) {
-                    Some(modules) => modules.push(module),
-                    None => {
-                        let modules = vec![module];
-                        on_chain_pkgs.insert(pkg_id, modules);
-                    }
-                }
-            }
+    let (files, new_lock) = Generator::new(manifest, source.as_ref())
+        .manifest_path(&args.manifest)
+        .lock(reused_lock)
+        .diagnostics(diags)
+        .progress(std::io::stderr())
+        .generate()
+        .await?;
+
+    if args.locked {
+        // `lock` is checked to exist above
+        let diff = lock.as_ref().unwrap().diff(&new_lock);
+        if !diff.is_empty() {
+            bail!(
+                "Packages resolved differently than recorded in {}:\n  {}\nRun with --update to update it.",
+                LOCK_FILE_NAME,
+                diff.join("\n  ")
+            );
         }
-        None => (),
-    };
+    } else if lock.as_ref() != Some(&new_lock) {
+        new_lock.write(&lock_path)?;
+    }
 
-    // gen top-level packages and dependencies
-    let (source_top_level_addr_map, on_chain_top_level_addr_map) =
-        resolve_top_level_pkg_addr_map(&source_model, &on_chain_model, &manifest);
-
-    // gen _framework
-    writeln!(progress_output, "{}", "GENERATING FRAMEWORK".green().bold())?;
-
-    let out_root = PathBuf::from(args.out);
-    std::fs::create_dir_all(&out_root)?;
-
-    std::fs::create_dir_all(out_root.join("_framework"))?;
-    write_str_to_file(
-        framework_sources::LOADER,
-        out_root.join("_framework").join("loader.ts").as_ref(),
-    )?;
-    write_str_to_file(
-        framework_sources::UTIL,
-        out_root.join("_framework").join("util.ts").as_ref(),
-    )?;
-    write_str_to_file(
-        framework_sources::REIFIED,
-        out_root.join("_framework").join("reified.ts").as_ref(),
-    )?;
-    write_str_to_file(
-        framework_sources::VECTOR,
-        out_root.join("_framework").join("vector.ts").as_ref(),
-    )?;
-    write_tokens_to_file(
-        &gen_init_loader_ts(
-            match source_pkgs.is_empty() {
-                false => Some((
-                    source_pkgs.keys().copied().collect::<Vec<_>>(),
-                    &source_top_level_addr_map,
-                )),
-                true => None,
-            },
-            match on_chain_pkgs.is_empty() {
-                false => Some((
-                    on_chain_pkgs.keys().copied().collect::<Vec<_>>(),
-                    &on_chain_top_level_addr_map,
-                )),
-                true => None,
-            },
-        ),
-        out_root.join("_framework").join("init-loader.ts").as_ref(),
-    )?;
-
-    if let Some(m) = &source_model {
-        writeln!(
-            progress_output,
-            "{}",
-            "GENERATING SOURCE PACKAGES".green().bold()
-        )?;
-        gen_packages_for_model(
-            source_pkgs,
-            &source_top_level_addr_map,
-            &m.published_at,
-            &m.type_origin_table,
-            &m.version_table,
-            true,
-            &out_root,
-        )?;
+    if files.is_empty() {
+        writeln!(std::io::stderr(), "No packages to generate.")?;
+        return Ok(());
     }
 
-    if let Some(m) = &on_chain_model {
-        writeln!(
-            progress_output,
-            "{}",
-            "GENERATING ON-CHAIN PACKAGES".green().bold()
-        )?;
-        gen_packages_for_model(
-            on_chain_pkgs,
-            &on_chain_top_level_addr_map,
-            &m.published_at,
-            &m.type_origin_table,
-            &m.version_table,
-            false,
-            &out_root,
-        )?;
+    // clean output, keeping the lock (just written above) and the fixtures if they're in it
+    let out_root = PathBuf::from(&args.out);
+    if args.clean {
+        let mut keep = vec![lock_path];
+        if let Some(fixtures) = &config.fixtures {
+            keep.push(manifest_dir(&args.manifest).join(fixtures));
+        }
+        clean_output(&out_root, &keep)?;
     }
 
-    // gen .eslintrc.json
-    write_str_to_file(
-        framework_sources::ESLINTRC,
-        &out_root.join(".eslintrc.json"),
-    )?;
+    files.write_to(&out_root)
+}
 
-    Ok(())
+/// Directory containing the manifest, which can be passed in as a file or a directory.
+fn manifest_dir(manifest: &str) -> PathBuf {
+    let path = Path::new(manifest);
+    if path.is_file() {
+        path.parent().map(Path::to_path_buf).unwrap_or_default()
+    } else {
+        path.to_path_buf()
+    }
 }
 
-fn clean_output(out_root: &Path) -> Result<()> {
+/// Removes everything in `out_root` except `gen.toml` and the `keep` paths. Directories containing
+/// a `keep` path are left as is.
+fn clean_output(out_root: &Path, keep: &[PathBuf]) -> Result<()> {
+    // paths that don't exist can't be removed either
+    let keep = keep
+        .iter()
+        .filter_map(|path| path.canonicalize().ok())
+        .collect::<Vec<_>>();
+
     let mut paths_to_remove = vec![];
     for entry in std::fs::read_dir(out_root)? {
         let entry = entry?;
@@ -225,6 +293,10 @@ fn clean_output(out_root: &Path) -> Result<()> {
         if path.is_file() && path.file_name().unwrap() == "gen.toml" {
             continue;
         }
+        let canonical = path.canonicalize()?;
+        if keep.iter().any(|kept| kept.starts_with(&canonical)) {
+            continue;
+        }
         paths_to_remove.push(path);
     }
 
@@ -239,176 +311,44 @@ fn clean_output(out_root: &Path) -> Result<()> {
     Ok(())
 }
 
-fn write_tokens_to_file(tokens: &Tokens<JavaScript>, path: &Path) -> Result<()> {
-    if tokens.is_empty() {
-        return Ok(());
-    }
-
-    let file = std::fs::File::create(path)?;
-    let mut w = fmt::IoWriter::new(file);
-    let fmt = fmt::Config::from_lang::<JavaScript>();
-    let config = js::Config::default();
-    tokens.format_file(&mut w.as_formatter(&fmt), &config)?;
-    Ok(())
-}
-
-fn write_str_to_file(s: &str, path: &Path) -> Result<()> {
-    if s.is_empty() {
-        return Ok(());
-    }
-
-    let file = std::fs::File::create(path)?;
-    let mut w = fmt::IoWriter::new(file);
-    std::fmt::Write::write_str(&mut w, s)?;
-    Ok(())
-}
-
-/// Creates a mapping between address and package name for top-level packages.
-fn resolve_top_level_pkg_addr_map(
-    source_model: &Option<ModelResult>,
-    on_chain_model: &Option<ModelResult>,
-    manifest: &GenManifest,
-) -> (
-    BTreeMap<AccountAddress, Symbol>,
-    BTreeMap<AccountAddress, Symbol>,
-) {
-    let mut source_top_level_package_names: BTreeSet<PackageName> = BTreeSet::new();
-    let mut on_chain_top_level_package_names: BTreeSet<PackageName> = BTreeSet::new();
-    for (name, pkg) in manifest.packages.iter() {
-        match pkg {
-            Package::Dependency(_) => {
-                source_top_level_package_names.insert(*name);
-            }
-            Package::OnChain(_) => {
-                on_chain_top_level_package_names.insert(*name);
-            }
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_clean_output_keeps_lock_and_fixtures() {
+        let dir = tempfile::tempdir().unwrap();
+        let out = dir.path();
+        for file in [
+            "gen.toml",
+            LOCK_FILE_NAME,
+            "index.ts",
+            "amm/index.ts",
+            "_framework/util.ts",
+            "fixtures/0x2/package.json",
+            "data/fixtures/0x2/package.json",
+            "data/other.json",
+        ] {
+            let path = out.join(file);
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(path, "").unwrap();
         }
-    }
-
-    let source_top_level_id_map: BTreeMap<AccountAddress, Symbol> = if let Some(m) = source_model {
-        m.id_map
-            .iter()
-            .filter_map(|(id, name)| {
-                if source_top_level_package_names.contains(name) {
-                    Some((*id, *name))
-                } else {
-                    None
-                }
-            })
-            .collect()
-    } else {
-        BTreeMap::new()
-    };
-
-    let on_chain_top_level_id_map: BTreeMap<AccountAddress, Symbol> =
-        if let Some(m) = on_chain_model {
-            m.id_map
-                .iter()
-                .filter_map(|(id, name)| {
-                    if on_chain_top_level_package_names.contains(name) {
-                        Some((*id, *name))
-                    } else {
-                        None
-                    }
-                })
-                .collect()
-        } else {
-            BTreeMap::new()
-        };
-
-    (source_top_level_id_map, on_chain_top_level_id_map)
-}
 
-fn gen_packages_for_model(
-    pkgs: BTreeMap<AccountAddress, Vec<ModuleEnv>>,
-    top_level_pkg_names: &BTreeMap<AccountAddress, Symbol>,
-    published_at_map: &BTreeMap<AccountAddress, AccountAddress>,
-    type_origin_table: &TypeOriginTable,
-    version_table: &VersionTable,
-    is_source: bool,
-    out_root: &Path,
-) -> Result<()> {
-    if pkgs.is_empty() {
-        return Ok(());
-    }
-
-    for (pkg_id, modules) in pkgs.iter() {
-        let is_top_level = top_level_pkg_names.contains_key(pkg_id);
-        let levels_from_root = if is_top_level { 0 } else { 2 };
-
-        let package_path = out_root.join(match top_level_pkg_names.get(pkg_id) {
-            Some(pkg_name) => PathBuf::from(package_import_name(*pkg_name)),
-            None => PathBuf::from("_dependencies")
-                .join(match is_source {
-                    true => "source",
-                    false => "onchain",
-                })
-                .join(pkg_id.to_hex_literal()),
-        });
-
-        std::fs::create_dir_all(&package_path)?;
-
-        // generate index.ts
-        let published_at = published_at_map.get(pkg_id).unwrap_or(pkg_id);
-        let versions = version_table.get(pkg_id).unwrap();
-        let tokens: js::Tokens = quote!(
-            export const PACKAGE_ID = $[str]($[const](pkg_id.to_hex_literal()));
-            export const PUBLISHED_AT = $[str]($[const](published_at.to_hex_literal()));
-            $(for (published_at, version) in versions {
-                export const PKG_V$(version.value()) = $[str]($[const](published_at.to_hex_literal()));
-            })
-        );
-        write_tokens_to_file(&tokens, &package_path.join("index.ts"))?;
-
-        // generate init.ts
-        let tokens = gen_package_init_ts(modules, &FrameworkImportCtx::new(levels_from_root + 1));
-        write_tokens_to_file(&tokens, &package_path.join("init.ts"))?;
-
-        // generate modules
-        for module in modules {
-            let module_path = package_path.join(module_import_name(module));
-            std::fs::create_dir_all(&module_path)?;
-
-            // generate <module>/functions.ts
-            if is_top_level {
-                let mut tokens = js::Tokens::new();
-                let mut func_gen = FunctionsGen::new(
-                    module.env,
-                    FrameworkImportCtx::new(levels_from_root + 2),
-                    StructClassImportCtx::for_func_gen(module, is_source, top_level_pkg_names),
-                );
-                for func in module.get_functions() {
-                    func_gen.gen_fun_args_if(&func, &mut tokens)?;
-                    func_gen.gen_fun_binding(&func, &mut tokens)?;
-                }
-                write_tokens_to_file(&tokens, &module_path.join("functions.ts"))?;
-            }
-
-            // generate <module>/structs.ts
-            let mut tokens = js::Tokens::new();
-            let mut structs_gen = StructsGen::new(
-                module.env,
-                StructClassImportCtx::for_struct_gen(module, is_source, top_level_pkg_names),
-                FrameworkImportCtx::new(levels_from_root + 2),
-                type_origin_table,
-                version_table,
-            );
-
-            for strct in module.get_structs() {
-                structs_gen.gen_struct_sep_comment(&mut tokens, &strct);
-
-                // type check function
-                structs_gen.gen_is_type_func(&mut tokens, &strct);
-
-                // fields interface
-                structs_gen.gen_fields_if(&mut tokens, &strct);
-
-                // struct class
-                structs_gen.gen_struct_class(&mut tokens, &strct);
-            }
-            write_tokens_to_file(&tokens, &module_path.join("structs.ts"))?;
+        clean_output(out, &[out.join(LOCK_FILE_NAME), out.join("fixtures")]).unwrap();
+        for file in ["gen.toml", LOCK_FILE_NAME, "fixtures/0x2/package.json"] {
+            assert!(out.join(file).exists(), "{} was removed", file);
+        }
+        for file in ["index.ts", "amm", "_framework", "data"] {
+            assert!(!out.join(file).exists(), "{} wasn't removed", file);
         }
-    }
 
-    Ok(())
+        // a fixtures dir nested in the output keeps the directories containing it
+        std::fs::create_dir_all(out.join("data/fixtures/0x2")).unwrap();
+        std::fs::write(out.join("data/fixtures/0x2/package.json"), "").unwrap();
+        std::fs::write(out.join("index.ts"), "").unwrap();
+        clean_output(out, &[out.join("data/../data/fixtures")]).unwrap();
+        assert!(out.join("data/fixtures/0x2/package.json").exists());
+        assert!(!out.join("index.ts").exists());
+        assert!(!out.join(LOCK_FILE_NAME).exists());
+    }
 }
@@ -1,25 +1,91 @@
-use anyhow::{bail, Context, Result};
+use anyhow::{anyhow, bail, Context, Result};
 use std::collections::BTreeMap;
-use std::path::Path;
+use std::fmt;
+use std::path::{Path, PathBuf};
 use toml::Value as TV;
 
+use move_compiler::editions::Edition;
 use move_core_types::account_address::AccountAddress;
 use move_package::source_package::manifest_parser::parse_dependency;
 use move_package::source_package::parsed_manifest::{Dependency, PackageName};
 
+use crate::model_builder::{BuildConfig, LintLevel};
+use crate::naming::{Casing, NamingConfig, OnCollision, Renames};
+use crate::package_cache::FetchConfig;
+use crate::package_source::PackageSourceKind;
+
 const PACKAGES_NAME: &str = "packages";
 const CONFIG_NAME: &str = "config";
+const RENAME_NAME: &str = "rename";
 
 pub type Packages = BTreeMap<PackageName, Package>;
 
-#[derive(Debug, Clone, Eq, PartialEq)]
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
 pub struct Config {
+    /// Backend used to fetch on-chain packages.
+    pub source: PackageSourceKind,
     pub rpc: Option<String>,
+    /// URL of the GraphQL RPC, used with `source = "graphql"`.
+    pub graphql: Option<String>,
+    /// Directory with package fixtures (relative to `gen.toml`), used with `source = "fixtures"`.
+    pub fixtures: Option<String>,
+    /// Chunking, concurrency, retries and rate limiting of package fetching (`[config.fetch]`).
+    pub fetch: FetchConfig,
+    /// Options of the Move build for source packages (`[config.build]`).
+    pub build: BuildConfig,
+    /// Whether to generate barrel files (`index.ts` in the output root, `barrel.ts` per package
+    /// and `index.ts` per module) which re-export the generated code as namespaces.
+    pub barrels: bool,
+    /// Whether to generate a `.move-map.json` source map for each module which maps generated
+    /// functions and classes back to their Move declarations.
+    pub source_maps: bool,
+    /// Whether object parameters of function bindings only accept arguments typed with the
+    /// parameter's struct class (decoded instances, `MoveResult`s or IDs from `<Class>.id(..)`).
+    pub strict_object_args: bool,
+    /// Whether by-value `Coin<T>` params of function bindings also accept an amount.
+    pub coin_amount_args: bool,
+    /// Overrides of the shared system objects (e.g. `0x2::clock::Clock`) which function bindings
+    /// pass in when the caller doesn't, keyed by type (`[config.system_objects]`). `None` turns
+    /// it off for the type so that it has to be passed in by the caller.
+    pub system_objects: BTreeMap<String, Option<String>>,
+    /// Naming conventions for the generated code (`[config.naming]`).
+    pub naming: NamingConfig,
+}
+
+/// Version of an on-chain package pinned in gen.toml.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum PackageVersion {
+    Exact(u64),
+    /// The latest version on chain at the time of resolution (recorded in `gen.lock`).
+    Latest,
 }
 
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub struct OnChainPackage {
+    /// The ID of the package (`id`), or of any of its versions when `version` is set
+    /// (`original_id` can be used instead of `id` in that case).
     pub id: AccountAddress,
+    pub version: Option<PackageVersion>,
+}
+
+impl fmt::Display for PackageVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PackageVersion::Exact(version) => write!(f, "{}", version),
+            PackageVersion::Latest => f.write_str("latest"),
+        }
+    }
+}
+
+/// Formats the package as `<id>` or `<id>@<version>`, used to tell if `gen.lock` is up to date.
+impl fmt::Display for OnChainPackage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.id.to_hex_literal())?;
+        if let Some(version) = &self.version {
+            write!(f, "@{}", version)?;
+        }
+        Ok(())
+    }
 }
 
 #[derive(Debug, Clone, Eq, PartialEq)]
@@ -32,6 +98,9 @@ pub enum Package {
 pub struct GenManifest {
     pub config: Option<Config>,
     pub packages: Packages,
+    /// Explicit names for generated functions and classes, keyed by
+    /// `<package>::<module>::<function or struct>`.
+    pub rename: Renames,
 }
 
 pub fn parse_gen_manifest_from_file(path: &Path) -> Result<GenManifest> {
@@ -67,7 +136,19 @@ pub fn parse_gen_manifest(tval: TV) -> Result<GenManifest> {
                 .transpose()
                 .context("Error parsing '[packages]' section of manifest")?
                 .unwrap();
-            Ok(GenManifest { config, packages })
+
+            let rename = table
+                .remove(RENAME_NAME)
+                .map(parse_rename)
+                .transpose()
+                .context("Error parsing '[rename]' section of manifest")?
+                .unwrap_or_default();
+
+            Ok(GenManifest {
+                config,
+                packages,
+                rename,
+            })
         }
         x => {
             bail!("Malformed generator manifest {}. Expected a table at top level, but encountered a {}", x, x.type_str())
@@ -78,11 +159,282 @@ pub fn parse_gen_manifest(tval: TV) -> Result<GenManifest> {
 pub fn parse_config(tval: TV) -> Result<Config> {
     match tval {
         TV::Table(table) => {
+            let source = table
+                .get("source")
+                .and_then(|tval| tval.as_str())
+                .map(PackageSourceKind::parse)
+                .transpose()?
+                .unwrap_or_default();
             let rpc = table
                 .get("rpc")
                 .and_then(|tval| tval.as_str())
                 .map(|s| s.to_string());
-            Ok(Config { rpc })
+            let graphql = table
+                .get("graphql")
+                .and_then(|tval| tval.as_str())
+                .map(|s| s.to_string());
+            let fixtures = table
+                .get("fixtures")
+                .and_then(|tval| tval.as_str())
+                .map(|s| s.to_string());
+            let fetch = table
+                .get("fetch")
+                .cloned()
+                .map(parse_fetch)
+                .transpose()
+                .context("Error parsing '[config.fetch]' section of manifest")?
+                .unwrap_or_default();
+            let build = table
+                .get("build")
+                .cloned()
+                .map(parse_build)
+                .transpose()
+                .context("Error parsing '[config.build]' section of manifest")?
+                .unwrap_or_default();
+            if source == PackageSourceKind::Fixtures && fixtures.is_none() {
+                bail!("Missing \"fixtures\" directory for the \"fixtures\" package source");
+            }
+            let barrels = table
+                .get("barrels")
+                .and_then(|tval| tval.as_bool())
+                .unwrap_or(false);
+            let source_maps = table
+                .get("source_maps")
+                .and_then(|tval| tval.as_bool())
+                .unwrap_or(false);
+            let strict_object_args = table
+                .get("strict_object_args")
+                .and_then(|tval| tval.as_bool())
+                .unwrap_or(false);
+            let coin_amount_args = table
+                .get("coin_amount_args")
+                .and_then(|tval| tval.as_bool())
+                .unwrap_or(false);
+            let system_objects = table
+                .get("system_objects")
+                .cloned()
+                .map(parse_system_objects)
+                .transpose()
+                .context("Error parsing '[config.system_objects]' section of manifest")?
+                .unwrap_or_default();
+            let naming = table
+                .get("naming")
+                .cloned()
+                .map(parse_naming)
+                .transpose()
+                .context("Error parsing '[config.naming]' section of manifest")?
+                .unwrap_or_default();
+            Ok(Config {
+                source,
+                rpc,
+                graphql,
+                fixtures,
+                fetch,
+                build,
+                barrels,
+                source_maps,
+                strict_object_args,
+                coin_amount_args,
+                system_objects,
+                naming,
+            })
+        }
+        x => {
+            bail!(
+                "Malformed section in manifest {}. Expected a table, but encountered a {}",
+                x,
+                x.type_str()
+            )
+        }
+    }
+}
+
+pub fn parse_naming(tval: TV) -> Result<NamingConfig> {
+    match tval {
+        TV::Table(table) => {
+            let mut naming = NamingConfig::default();
+            for (key, value) in table.into_iter() {
+                let Some(value) = value.as_str() else {
+                    bail!("Invalid value for \"{}\". Expected a string", key);
+                };
+                match key.as_str() {
+                    "directories" => naming.directories = Casing::parse(value)?,
+                    "functions" => naming.functions = parse_identifier_casing(&key, value)?,
+                    "fields" => naming.fields = parse_identifier_casing(&key, value)?,
+                    "args_suffix" => naming.args_suffix = value.to_string(),
+                    "positional_fields" => naming.positional_fields = value.to_string(),
+                    "on_collision" => naming.on_collision = OnCollision::parse(value)?,
+                    _ => bail!("Unknown naming option \"{}\"", key),
+                }
+            }
+            Ok(naming)
+        }
+        x => {
+            bail!(
+                "Malformed section in manifest {}. Expected a table, but encountered a {}",
+                x,
+                x.type_str()
+            )
+        }
+    }
+}
+
+/// Parses the casing of names that are used as TS identifiers, which can't be kebab case.
+fn parse_identifier_casing(key: &str, value: &str) -> Result<Casing> {
+    match Casing::parse(value)? {
+        Casing::Kebab => bail!(
+            "Invalid casing \"{}\" for \"{}\". Kebab case can only be used for \"directories\"",
+            value,
+            key
+        ),
+        casing => Ok(casing),
+    }
+}
+
+pub fn parse_build(tval: TV) -> Result<BuildConfig> {
+    match tval {
+        TV::Table(table) => {
+            let mut build = BuildConfig::default();
+            for (key, value) in table.into_iter() {
+                match (key.as_str(), value) {
+                    ("dev_mode", TV::Boolean(value)) => build.dev_mode = value,
+                    ("test_mode", TV::Boolean(value)) => build.test_mode = value,
+                    ("fetch_deps", TV::Boolean(value)) => build.fetch_deps = value,
+                    ("edition", TV::String(value)) => {
+                        build.edition = Some(
+                            value
+                                .parse::<Edition>()
+                                .map_err(|e| anyhow!("Invalid edition \"{}\": {}", value, e))?,
+                        )
+                    }
+                    ("lint", TV::String(value)) => build.lint = LintLevel::parse(&value)?,
+                    ("install_dir", TV::String(value)) => {
+                        build.install_dir = Some(PathBuf::from(value))
+                    }
+                    ("additional_named_addresses", TV::Table(addresses)) => {
+                        for (name, addr) in addresses.into_iter() {
+                            let Some(addr) = addr
+                                .as_str()
+                                .and_then(|addr| AccountAddress::from_hex_literal(addr).ok())
+                            else {
+                                bail!("Invalid address for named address \"{}\"", name);
+                            };
+                            build.additional_named_addresses.insert(name, addr);
+                        }
+                    }
+                    (
+                        "dev_mode"
+                        | "test_mode"
+                        | "fetch_deps"
+                        | "edition"
+                        | "lint"
+                        | "install_dir"
+                        | "additional_named_addresses",
+                        value,
+                    ) => bail!("Invalid value {} for \"{}\"", value, key),
+                    _ => bail!("Unknown build option \"{}\"", key),
+                }
+            }
+            Ok(build)
+        }
+        x => {
+            bail!(
+                "Malformed section in manifest {}. Expected a table, but encountered a {}",
+                x,
+                x.type_str()
+            )
+        }
+    }
+}
+
+pub fn parse_fetch(tval: TV) -> Result<FetchConfig> {
+    match tval {
+        TV::Table(table) => {
+            let mut fetch = FetchConfig::default();
+            for (key, value) in table.into_iter() {
+                let Some(value) = value.as_integer().filter(|value| *value >= 0) else {
+                    bail!(
+                        "Invalid value for \"{}\". Expected a non-negative integer",
+                        key
+                    );
+                };
+                match key.as_str() {
+                    "chunk_size" => fetch.chunk_size = value as usize,
+                    "concurrency" => fetch.concurrency = value as usize,
+                    "retries" => fetch.retries = value as u32,
+                    "retry_delay_ms" => fetch.retry_delay_ms = value as u64,
+                    "requests_per_second" => fetch.requests_per_second = Some(value as u32),
+                    _ => bail!("Unknown fetch option \"{}\"", key),
+                }
+            }
+            if fetch.chunk_size == 0 || fetch.concurrency == 0 {
+                bail!("\"chunk_size\" and \"concurrency\" must be greater than 0");
+            }
+            Ok(fetch)
+        }
+        x => {
+            bail!(
+                "Malformed section in manifest {}. Expected a table, but encountered a {}",
+                x,
+                x.type_str()
+            )
+        }
+    }
+}
+
+pub fn parse_system_objects(tval: TV) -> Result<BTreeMap<String, Option<String>>> {
+    match tval {
+        TV::Table(table) => {
+            let mut system_objects = BTreeMap::new();
+            for (key, value) in table.into_iter() {
+                let parts = key.split("::").collect::<Vec<_>>();
+                let &[addr, module, name] = parts.as_slice() else {
+                    bail!(
+                        "Invalid type \"{}\". Expected <address>::<module>::<struct>",
+                        key
+                    );
+                };
+                let addr = AccountAddress::from_hex_literal(addr)
+                    .with_context(|| format!("Invalid address in type \"{}\"", key))?;
+                let type_ = format!("{}::{}::{}", addr.to_hex_literal(), module, name);
+
+                let id = match value {
+                    TV::String(id) => Some(
+                        AccountAddress::from_hex_literal(&id)
+                            .with_context(|| format!("Invalid object ID for \"{}\"", key))?
+                            .to_hex_literal(),
+                    ),
+                    TV::Boolean(false) => None,
+                    _ => bail!(
+                        "Invalid value for \"{}\". Expected an object ID or `false`",
+                        key
+                    ),
+                };
+                system_objects.insert(type_, id);
+            }
+            Ok(system_objects)
+        }
+        x => {
+            bail!(
+                "Malformed section in manifest {}. Expected a table, but encountered a {}",
+                x,
+                x.type_str()
+            )
+        }
+    }
+}
+
+pub fn parse_rename(tval: TV) -> Result<Renames> {
+    match tval {
+        TV::Table(table) => {
+            let mut rename = BTreeMap::new();
+            for (key, value) in table.into_iter() {
+                let Some(value) = value.as_str() else {
+                    bail!("Invalid rename for \"{}\". Expected a string", key);
+                };
+                rename.insert(key, value.to_string());
+            }
+            Ok(rename)
         }
         x => {
             bail!(
@@ -120,16 +472,31 @@ pub fn parse_package(tval: TV) -> Result<Package> {
         bail!("Malformed dependency {}", tval);
     };
 
-    if table.contains_key("id") {
+    if table.contains_key("id") || table.contains_key("original_id") {
+        if table.contains_key("id") && table.contains_key("original_id") {
+            bail!("Only one of \"id\" and \"original_id\" can be specified");
+        }
         let Some(Ok(id)) = table
             .get("id")
+            .or_else(|| table.get("original_id"))
             .unwrap()
             .as_str()
             .map(AccountAddress::from_hex_literal)
         else {
             bail!("Invalid address");
         };
-        Ok(Package::OnChain(OnChainPackage { id }))
+        let version = match table.get("version") {
+            None => None,
+            Some(TV::Integer(version)) if *version > 0 => {
+                Some(PackageVersion::Exact(*version as u64))
+            }
+            Some(TV::String(version)) if version == "latest" => Some(PackageVersion::Latest),
+            Some(version) => bail!(
+                "Invalid package version {}. Expected a positive integer or \"latest\"",
+                version
+            ),
+        };
+        Ok(Package::OnChain(OnChainPackage { id, version }))
     } else {
         Ok(Package::Dependency(parse_dependency(tval)?))
     }
@@ -145,13 +512,44 @@ mod tests {
     fn test_parse_gen_manifest() {
         let manifest_str = r#"
         [config]
+        source = "graphql"
         rpc = "https://fullnode.mainnet.sui.io:443"
+        graphql = "https://sui-mainnet.mystenlabs.com/graphql"
+        barrels = true
+        strict_object_args = true
+
+        [config.fetch]
+        chunk_size = 25
+        retries = 5
+        requests_per_second = 10
+
+        [config.build]
+        test_mode = true
+        edition = "2024.alpha"
+        lint = "none"
+        install_dir = "build"
+        additional_named_addresses = { amm = "0xa1" }
+
+        [config.system_objects]
+        "0x2::random::Random" = false
+        "0x0000000000000000000000000000000000000000000000000000000000000002::clock::Clock" = "0x6"
+
+        [config.naming]
+        functions = "snake"
+        args_suffix = "Params"
+        positional_fields = "field"
+        on_collision = "suffix"
 
         [packages]
         deepbook = { id = "0xdee9" }
+        deepbook_v3 = { id = "0xdee9", version = 3 }
+        deepbook_latest = { original_id = "0xdee9", version = "latest" }
         amm = { local = "../move/amm" }
         fixture = { local = "../move/fixture" }
         framework = { git = "https://github.com/MystenLabs/sui.git", subdir = "crates/sui-framework/packages/sui-framework", rev = "releases/sui-v1.0.0-release" }
+
+        [rename]
+        "amm::pool::swap_a_to_b" = "swapAForB"
         "#;
 
         let act =
@@ -159,7 +557,42 @@ mod tests {
 
         let exp = GenManifest {
             config: Some(Config {
+                source: PackageSourceKind::GraphQl,
                 rpc: Some("https://fullnode.mainnet.sui.io:443".to_string()),
+                graphql: Some("https://sui-mainnet.mystenlabs.com/graphql".to_string()),
+                fixtures: None,
+                fetch: FetchConfig {
+                    chunk_size: 25,
+                    retries: 5,
+                    requests_per_second: Some(10),
+                    ..FetchConfig::default()
+                },
+                build: BuildConfig {
+                    test_mode: true,
+                    edition: Some(Edition::E2024_ALPHA),
+                    lint: LintLevel::None,
+                    install_dir: Some(PathBuf::from("build")),
+                    additional_named_addresses: BTreeMap::from([(
+                        "amm".to_string(),
+                        AccountAddress::from_hex_literal("0xa1").unwrap(),
+                    )]),
+                    ..BuildConfig::default()
+                },
+                barrels: true,
+                source_maps: false,
+                strict_object_args: true,
+                coin_amount_args: false,
+                system_objects: BTreeMap::from([
+                    ("0x2::clock::Clock".to_string(), Some("0x6".to_string())),
+                    ("0x2::random::Random".to_string(), None),
+                ]),
+                naming: NamingConfig {
+                    functions: Casing::Snake,
+                    args_suffix: "Params".to_string(),
+                    positional_fields: "field".to_string(),
+                    on_collision: OnCollision::Suffix,
+                    ..NamingConfig::default()
+                },
             }),
             packages: vec![
                 (
@@ -175,6 +608,21 @@ mod tests {
                     "deepbook".into(),
                     Package::OnChain(OnChainPackage {
                         id: AccountAddress::from_hex_literal("0xdee9").unwrap(),
+                        version: None,
+                    }),
+                ),
+                (
+                    "deepbook_latest".into(),
+                    Package::OnChain(OnChainPackage {
+                        id: AccountAddress::from_hex_literal("0xdee9").unwrap(),
+                        version: Some(PackageVersion::Latest),
+                    }),
+                ),
+                (
+                    "deepbook_v3".into(),
+                    Package::OnChain(OnChainPackage {
+                        id: AccountAddress::from_hex_literal("0xdee9").unwrap(),
+                        version: Some(PackageVersion::Exact(3)),
                     }),
                 ),
                 (
@@ -202,8 +650,45 @@ mod tests {
             ]
             .into_iter()
             .collect(),
+            rename: vec![(
+                "amm::pool::swap_a_to_b".to_string(),
+                "swapAForB".to_string(),
+            )]
+            .into_iter()
+            .collect(),
         };
 
         assert_eq!(act, exp);
     }
+    #[test]
+    fn test_parse_naming_rejects_kebab_identifiers() {
+        let parse = |s: &str| parse_naming(parse_gen_manifest_string(s.into()).unwrap());
+
+        let naming = parse(r#"directories = "kebab""#).unwrap();
+        assert_eq!(naming.directories, Casing::Kebab);
+
+        let naming = parse(
+            r#"
+            functions = "snake_case"
+            fields = "preserve"
+            "#,
+        )
+        .unwrap();
+        assert_eq!(naming.functions, Casing::Snake);
+        assert_eq!(naming.fields, Casing::Preserve);
+
+        for key in ["functions", "fields"] {
+            for value in ["kebab", "kebab-case"] {
+                let err = parse(&format!("{} = \"{}\"", key, value)).unwrap_err();
+                assert!(
+                    err.to_string().contains(&format!("for \"{}\"", key)),
+                    "{}",
+                    err
+                );
+            }
+        }
+
+        assert!(parse(r#"functions = "SCREAMING""#).is_err());
+        assert!(parse(r#"unknown = "camel""#).is_err());
+    }
 }
@@ -1,16 +1,17 @@
+use crate::diagnostics::{Diagnostic, Diagnostics, Span};
+use crate::lock::{GenLock, LockedModel, LOCK_FILE_NAME};
 use crate::manifest::{self as GM};
 use crate::package_cache::PackageCache;
-use anyhow::{bail, Context, Result};
-use codespan_reporting::{
-    diagnostic::Severity,
-    term::termcolor::{ColorChoice, StandardStream},
-};
+use anyhow::{anyhow, bail, Context, Result};
+use codespan_reporting::diagnostic::Severity;
 use colored::*;
 use core::fmt;
 use futures::future;
 use move_binary_format::file_format::CompiledModule;
 use move_bytecode_utils::Modules;
+use move_command_line_common::env::MOVE_HOME;
 use move_compiler::editions as ME;
+use move_compiler::linters::LintFlag;
 use move_core_types::account_address::AccountAddress;
 use move_model::model::GlobalEnv;
 use move_model::{self, run_bytecode_model_builder};
@@ -18,10 +19,11 @@ use move_package::compilation::model_builder::ModelBuilder;
 use move_package::resolution::resolution_graph::ResolvedGraph;
 use move_package::source_package::parsed_manifest as PM;
 use move_package::{BuildConfig as MoveBuildConfig, ModelConfig};
-use std::collections::{BTreeMap, HashSet, VecDeque};
+use std::collections::{BTreeMap, BTreeSet, HashSet, VecDeque};
 use std::fs::{self, File};
 use std::io::{self, Write};
-use std::path::Path;
+use std::path::{Path, PathBuf};
+use std::process::Command;
 use sui_json_rpc_types::SuiRawMovePackage;
 use sui_move_build::gather_published_ids;
 use sui_sdk::types::base_types::SequenceNumber;
@@ -39,6 +41,68 @@ struct SubstTOML<'a>(&'a PM::Substitution);
 pub type TypeOriginTable = BTreeMap<AccountAddress, BTreeMap<String, AccountAddress>>;
 pub type VersionTable = BTreeMap<AccountAddress, BTreeMap<AccountAddress, SequenceNumber>>;
 
+/// Lint level of the Move build.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+pub enum LintLevel {
+    None,
+    #[default]
+    Default,
+    All,
+}
+
+impl LintLevel {
+    pub fn parse(s: &str) -> Result<Self> {
+        match s {
+            "none" => Ok(LintLevel::None),
+            "default" => Ok(LintLevel::Default),
+            "all" => Ok(LintLevel::All),
+            _ => bail!(
+                "Invalid lint level \"{}\". Expected one of \"none\", \"default\" or \"all\"",
+                s
+            ),
+        }
+    }
+}
+
+/// Options of the Move build used to resolve and compile source packages (`[config.build]`).
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct BuildConfig {
+    /// Compile in dev mode, using `dev-addresses` and `dev-dependencies`.
+    pub dev_mode: bool,
+    /// Compile in test mode, which includes `#[test_only]` modules and functions so that bindings
+    /// are generated for them (e.g. for test harnesses).
+    pub test_mode: bool,
+    /// Edition used for packages that don't specify one in their Move.toml.
+    pub edition: Option<ME::Edition>,
+    /// Named addresses assigned in addition to the ones in the packages' Move.toml.
+    pub additional_named_addresses: BTreeMap<String, AccountAddress>,
+    pub lint: LintLevel,
+    /// Fetch the latest commits of git dependencies instead of using the ones already checked out.
+    pub fetch_deps: bool,
+    /// Installation directory for build artifacts (relative to gen.toml).
+    pub install_dir: Option<PathBuf>,
+}
+
+impl BuildConfig {
+    fn move_build_config(&self, manifest_dir: &Path) -> MoveBuildConfig {
+        MoveBuildConfig {
+            dev_mode: self.dev_mode,
+            test_mode: self.test_mode,
+            install_dir: self.install_dir.as_ref().map(|dir| manifest_dir.join(dir)),
+            skip_fetch_latest_git_deps: !self.fetch_deps,
+            default_flavor: Some(ME::Flavor::Sui),
+            default_edition: self.edition,
+            additional_named_addresses: self.additional_named_addresses.clone(),
+            lint_flag: match self.lint {
+                LintLevel::None => LintFlag::LEVEL_NONE,
+                LintLevel::Default => LintFlag::LEVEL_DEFAULT,
+                LintLevel::All => LintFlag::LEVEL_ALL,
+            },
+            ..Default::default()
+        }
+    }
+}
+
 pub struct ModelResult {
     /// Move model for packages defined in gen.toml
     pub env: GlobalEnv,
@@ -50,12 +114,33 @@ pub struct ModelResult {
     pub type_origin_table: TypeOriginTable,
     /// Map from original package ID to all versions referenced by type origins
     pub version_table: VersionTable,
+    /// Map from original package ID to the resolved ID of every on-chain package (including
+    /// dependencies). Empty for the source model.
+    pub resolved: BTreeMap<AccountAddress, AccountAddress>,
+    /// Digests and git commits of all source packages (including dependencies), by package name.
+    /// Empty for the on-chain model.
+    pub sources: BTreeMap<PM::PackageName, SourcePackageInfo>,
+}
+
+/// What a source package was resolved to, used to detect changes between runs.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct SourcePackageInfo {
+    /// Digest of the package's sources and manifest, as recorded in Move.lock.
+    pub digest: String,
+    /// The commit the package is checked out at, for git dependencies.
+    pub git_rev: Option<String>,
 }
 
+/// Builds the source and on-chain models for the packages in gen.toml. Resolution results recorded
+/// in `lock` are reused where they're still up to date. Source packages are built with `build`.
+/// Compile errors and resolution warnings are reported to `diags`.
 pub async fn build_models<Progress: Write>(
     cache: &mut PackageCache<'_>,
     packages: &GM::Packages,
     manifest_path: &Path,
+    build: &BuildConfig,
+    lock: Option<&GenLock>,
+    diags: &mut Diagnostics,
     progress_output: &mut Progress,
 ) -> Result<(Option<ModelResult>, Option<ModelResult>)> {
     // separate source and on-chain packages
@@ -95,13 +180,26 @@ pub async fn build_models<Progress: Write>(
     }
 
     let source_model = if !source_pkgs.is_empty() {
-        Some(build_source_model(source_pkgs, cache, progress_output).await?)
+        let locked = lock.and_then(|lock| lock.source.as_ref());
+        let build_config = build.move_build_config(manifest_path.parent().unwrap());
+        Some(
+            build_source_model(
+                source_pkgs,
+                cache,
+                build_config,
+                locked,
+                diags,
+                progress_output,
+            )
+            .await?,
+        )
     } else {
         None
     };
 
     let on_chain_model = if !on_chain_pkgs.is_empty() {
-        Some(build_on_chain_model(on_chain_pkgs, cache, progress_output).await?)
+        let locked = lock.and_then(|lock| lock.on_chain.as_ref());
+        Some(build_on_chain_model(on_chain_pkgs, cache, locked, diags, progress_output).await?)
     } else {
         None
     };
@@ -114,6 +212,9 @@ pub async fn build_models<Progress: Write>(
 async fn build_source_model<Progress: Write>(
     pkgs: Vec<(PM::PackageName, PM::InternalDependency)>,
     cache: &mut PackageCache<'_>,
+    build_config: MoveBuildConfig,
+    locked: Option<&LockedModel>,
+    diags: &mut Diagnostics,
     progress_output: &mut Progress,
 ) -> Result<ModelResult> {
     writeln!(
@@ -137,17 +238,12 @@ async fn build_source_model<Progress: Write>(
         writeln!(stub_manifest, " {}", DependencyTOML(*name, dep))?;
     }
 
-    // TODO: allow some of these options to be passed in as flags
-    let build_config = MoveBuildConfig {
-        skip_fetch_latest_git_deps: true,
-        default_flavor: Some(ME::Flavor::Sui),
-        ..Default::default()
-    };
     let resolved_graph =
         build_config.resolution_graph_for_package(stub_path, None, &mut io::stderr())?;
 
     let source_id_map = find_address_origins(&resolved_graph);
     let source_published_at = resolve_published_at(&resolved_graph, &source_id_map);
+    let sources = resolve_source_packages(&resolved_graph)?;
 
     let source_env = ModelBuilder::create(
         resolved_graph,
@@ -158,23 +254,37 @@ async fn build_source_model<Progress: Write>(
     )
     .build_model()?;
 
-    let mut stderr = StandardStream::stderr(ColorChoice::Always);
-    source_env.report_diag(&mut stderr, Severity::Warning);
+    diags.emit_model_diags(&source_env, Severity::Warning)?;
 
     if source_env.has_errors() {
         bail!("Source model has errors.");
     }
 
-    // resolve type origins
-    let type_origin_table = resolve_type_origin_table(
-        cache,
-        &source_id_map,
-        &source_published_at,
-        &source_env,
-        progress_output,
-    )
-    .await?;
-    let version_table = resolve_version_table(cache, &type_origin_table).await?;
+    // resolve type origins, unless they're locked for the same packages published at the same IDs
+    let locked = match locked {
+        Some(locked)
+            if locked.id_map()? == source_id_map
+                && locked.published_at()? == source_published_at =>
+        {
+            Some(locked)
+        }
+        _ => None,
+    };
+    let (type_origin_table, version_table) = match locked {
+        Some(locked) => (locked.type_origin_table()?, locked.version_table()?),
+        None => {
+            let type_origin_table = resolve_type_origin_table(
+                cache,
+                &source_id_map,
+                &source_published_at,
+                &source_env,
+                diags,
+            )
+            .await?;
+            let version_table = resolve_version_table(cache, &type_origin_table).await?;
+            (type_origin_table, version_table)
+        }
+    };
 
     Ok(ModelResult {
         env: source_env,
@@ -182,12 +292,16 @@ async fn build_source_model<Progress: Write>(
         published_at: source_published_at,
         type_origin_table,
         version_table,
+        resolved: BTreeMap::new(),
+        sources,
     })
 }
 
 async fn build_on_chain_model<Progress: Write>(
     pkgs: Vec<(PM::PackageName, GM::OnChainPackage)>,
     cache: &mut PackageCache<'_>,
+    locked: Option<&LockedModel>,
+    diags: &mut Diagnostics,
     progress_output: &mut Progress,
 ) -> Result<ModelResult> {
     writeln!(
@@ -196,8 +310,54 @@ async fn build_on_chain_model<Progress: Write>(
         "BUILDING ON-CHAIN MODEL".green().bold()
     )?;
 
-    let (pkg_ids, original_map) =
-        resolve_on_chain_packages(cache, pkgs.iter().map(|(_, pkg)| pkg.id).collect()).await?;
+    // the lock is only used if the packages in gen.toml haven't changed since it was written
+    let specs = on_chain_package_specs(&pkgs);
+    let locked = locked.filter(|locked| locked.packages == specs);
+
+    let (resolved, on_chain_id_map, on_chain_published_at) = match locked {
+        Some(locked) => {
+            writeln!(
+                progress_output,
+                "{}",
+                format!("USING ON-CHAIN PACKAGES FROM {}", LOCK_FILE_NAME)
+                    .green()
+                    .bold()
+            )?;
+            (locked.resolved()?, locked.id_map()?, locked.published_at()?)
+        }
+        None => {
+            let mut top_level_ids = vec![];
+            let mut pinned = BTreeSet::new();
+            for (name, pkg) in pkgs.iter() {
+                let id = resolve_pinned_package(cache, pkg)
+                    .await
+                    .with_context(|| format!("gen.toml: Failed to resolve package \"{}\"", name))?;
+                if pkg.version.is_some() {
+                    pinned.insert(id);
+                }
+                top_level_ids.push(id);
+            }
+
+            let (pkg_ids, original_map) =
+                resolve_on_chain_packages(cache, top_level_ids.clone(), &pinned).await?;
+
+            let mut on_chain_id_map: BTreeMap<AccountAddress, PM::PackageName> = BTreeMap::new();
+            let mut on_chain_published_at: BTreeMap<AccountAddress, AccountAddress> =
+                BTreeMap::new();
+            for ((name, _), id) in pkgs.iter().zip(top_level_ids) {
+                let original_id = original_map.get(&id).unwrap();
+
+                on_chain_id_map.insert(*original_id, *name);
+                on_chain_published_at.insert(*original_id, id);
+            }
+            let resolved = pkg_ids
+                .into_iter()
+                .map(|id| (*original_map.get(&id).unwrap(), id))
+                .collect();
+
+            (resolved, on_chain_id_map, on_chain_published_at)
+        }
+    };
 
     writeln!(
         progress_output,
@@ -205,7 +365,9 @@ async fn build_on_chain_model<Progress: Write>(
         "FETCHING ON-CHAIN PACKAGES".green().bold()
     )?;
     let mut modules = vec![];
-    let raw_pkgs = cache.get_multi(pkg_ids).await?;
+    let raw_pkgs = cache
+        .get_multi(resolved.values().copied().collect())
+        .await?;
     for pkg in raw_pkgs {
         let pkg = pkg?;
         let SuiRawMovePackage { module_map, .. } = pkg;
@@ -220,31 +382,27 @@ async fn build_on_chain_model<Progress: Write>(
 
     let on_chain_env = run_bytecode_model_builder(topo_order)?;
 
-    let mut stderr = StandardStream::stderr(ColorChoice::Always);
-    on_chain_env.report_diag(&mut stderr, Severity::Warning);
+    diags.emit_model_diags(&on_chain_env, Severity::Warning)?;
     if on_chain_env.has_errors() {
         bail!("On-chain model has errors.");
     }
 
-    let mut on_chain_id_map: BTreeMap<AccountAddress, PM::PackageName> = BTreeMap::new();
-    let mut on_chain_published_at: BTreeMap<AccountAddress, AccountAddress> = BTreeMap::new();
-    for (name, pkg) in pkgs {
-        let original_id = original_map.get(&pkg.id).unwrap();
-
-        on_chain_id_map.insert(*original_id, name);
-        on_chain_published_at.insert(*original_id, pkg.id);
-    }
-
     // resolve type origins
-    let type_origin_table = resolve_type_origin_table(
-        cache,
-        &on_chain_id_map,
-        &on_chain_published_at,
-        &on_chain_env,
-        progress_output,
-    )
-    .await?;
-    let version_table = resolve_version_table(cache, &type_origin_table).await?;
+    let (type_origin_table, version_table) = match locked {
+        Some(locked) => (locked.type_origin_table()?, locked.version_table()?),
+        None => {
+            let type_origin_table = resolve_type_origin_table(
+                cache,
+                &on_chain_id_map,
+                &on_chain_published_at,
+                &on_chain_env,
+                diags,
+            )
+            .await?;
+            let version_table = resolve_version_table(cache, &type_origin_table).await?;
+            (type_origin_table, version_table)
+        }
+    };
 
     Ok(ModelResult {
         env: on_chain_env,
@@ -252,6 +410,50 @@ async fn build_on_chain_model<Progress: Write>(
         published_at: on_chain_published_at,
         type_origin_table,
         version_table,
+        resolved,
+        sources: BTreeMap::new(),
+    })
+}
+
+/// The on-chain packages as specified in gen.toml, recorded in `gen.lock`.
+pub fn on_chain_package_specs(
+    pkgs: &[(PM::PackageName, GM::OnChainPackage)],
+) -> BTreeMap<String, String> {
+    pkgs.iter()
+        .map(|(name, pkg)| (name.to_string(), pkg.to_string()))
+        .collect()
+}
+
+/// Resolves the ID of an on-chain package. If a version is pinned in gen.toml, this is the ID of
+/// that version (or of the latest one), found by listing the versions of the package.
+async fn resolve_pinned_package(
+    cache: &mut PackageCache<'_>,
+    pkg: &GM::OnChainPackage,
+) -> Result<AccountAddress> {
+    let Some(version) = pkg.version else {
+        return Ok(pkg.id);
+    };
+    let Some(versions) = cache.get_versions(pkg.id).await? else {
+        bail!(
+            "Pinning a package version requires a package source that can list package versions (e.g. `source = \"graphql\"`)"
+        );
+    };
+    let found = match version {
+        GM::PackageVersion::Latest => versions
+            .iter()
+            .max_by_key(|(_, version)| **version)
+            .map(|(id, _)| *id),
+        GM::PackageVersion::Exact(exact) => versions
+            .iter()
+            .find(|(_, version)| version.value() == exact)
+            .map(|(id, _)| *id),
+    };
+    found.ok_or_else(|| {
+        anyhow!(
+            "Version {} of package {} not found",
+            version,
+            pkg.id.to_hex_literal()
+        )
     })
 }
 
@@ -280,6 +482,52 @@ fn find_address_origins(graph: &ResolvedGraph) -> BTreeMap<AccountAddress, PM::P
     addr_map
 }
 
+/// Collects the digest of every package in the graph (except the stub root) and the commit of those
+/// checked out from git.
+fn resolve_source_packages(
+    graph: &ResolvedGraph,
+) -> Result<BTreeMap<PM::PackageName, SourcePackageInfo>> {
+    // git dependencies are checked out to the move home directory
+    let move_home = PathBuf::from(&*MOVE_HOME);
+    let move_home = fs::canonicalize(&move_home).unwrap_or(move_home);
+
+    graph
+        .package_table
+        .iter()
+        .filter(|(name, _)| name.as_str() != STUB_PACKAGE_NAME)
+        .map(|(name, pkg)| {
+            let git_rev = if pkg.package_path.starts_with(&move_home) {
+                Some(git_head(&pkg.package_path).with_context(|| {
+                    format!("Failed to resolve the git commit of package {}.", name)
+                })?)
+            } else {
+                None
+            };
+            let info = SourcePackageInfo {
+                digest: pkg.source_digest.to_string(),
+                git_rev,
+            };
+            Ok((*name, info))
+        })
+        .collect()
+}
+
+fn git_head(path: &Path) -> Result<String> {
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(path)
+        .args(["rev-parse", "HEAD"])
+        .output()?;
+    if !output.status.success() {
+        bail!(
+            "git rev-parse failed in {}: {}",
+            path.display(),
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+    Ok(String::from_utf8(output.stdout)?.trim().to_string())
+}
+
 /// Resolve published_at addresses by gathering published ids from the graph and matching
 /// them with package ids using the package name -> address map.
 fn resolve_published_at(
@@ -300,21 +548,31 @@ fn resolve_published_at(
 
 /**
  * Returns a list of all packages (including dependencies) where each package is mentioned only once
- * (resolved so that the highest version is used), and a mapping of all package ids to their original
- * package id (address of version 1 of the package).
+ * (resolved so that the highest version is used, unless the package is in `pinned`), and a mapping
+ * of all package ids to their original package id (address of version 1 of the package).
  */
 async fn resolve_on_chain_packages(
     dl: &mut PackageCache<'_>,
     ids: Vec<AccountAddress>,
+    pinned: &BTreeSet<AccountAddress>,
 ) -> Result<(
     Vec<AccountAddress>,
     BTreeMap<AccountAddress, AccountAddress>,
 )> {
-    let top_level_origins = future::join_all(ids.iter().map(|addr| {
+    let top_level_origins = future::try_join_all(ids.iter().map(|addr| {
         let mut dl = dl.clone();
-        async move { resolve_original_package_id(&mut dl, *addr).await.unwrap() }
+        async move {
+            resolve_original_package_id(&mut dl, *addr)
+                .await
+                .with_context(|| {
+                    format!(
+                        "Unable to resolve the original ID of package {}",
+                        addr.to_hex_literal()
+                    )
+                })
+        }
     }))
-    .await;
+    .await?;
 
     let mut original_map: BTreeMap<_, _> = ids.clone().into_iter().zip(top_level_origins).collect();
     let mut highest_versions: BTreeMap<AccountAddress, UpgradeInfo> = BTreeMap::new();
@@ -346,6 +604,13 @@ async fn resolve_on_chain_packages(
         }
     }
 
+    // pinned packages keep their version even if a dependency links to a higher one
+    let pinned_originals: BTreeSet<AccountAddress> = pinned
+        .iter()
+        .filter_map(|id| original_map.get(id))
+        .copied()
+        .collect();
+
     let mut processed: HashSet<AccountAddress> = HashSet::new();
     let mut pkg_queue: HashSet<AccountAddress> = ids.into_iter().collect();
 
@@ -359,6 +624,9 @@ async fn resolve_on_chain_packages(
             let pkg = pkg?;
             for (original_id, info) in pkg.linkage_table {
                 original_map.insert(info.upgraded_id.into(), original_id.into());
+                if pinned_originals.contains(&original_id.into()) {
+                    continue;
+                }
                 match highest_versions.get(&original_id) {
                     None => {
                         highest_versions.insert(original_id.into(), info.clone());
@@ -416,12 +684,12 @@ async fn resolve_original_package_id(
     Ok(id)
 }
 
-async fn resolve_type_origin_table<Progress: Write>(
+async fn resolve_type_origin_table(
     cache: &mut PackageCache<'_>,
     id_map: &BTreeMap<AccountAddress, PM::PackageName>,
     published_at: &BTreeMap<AccountAddress, AccountAddress>,
     model: &GlobalEnv,
-    progress_output: &mut Progress,
+    diags: &mut Diagnostics,
 ) -> Result<TypeOriginTable> {
     let mut type_origin_table = BTreeMap::new();
     let mut packages_to_fetch = vec![];
@@ -445,11 +713,18 @@ async fn resolve_type_origin_table<Progress: Write>(
     for (pkg_res, (original_id, name, published_at)) in raw_pkgs {
         let pkg = match pkg_res {
             Ok(pkg) => pkg,
-            Err(_) => {
-                writeln!(
-                    progress_output,
-                    "{} Package \"{}\" published at {} couldn't be fetched from chain for type origin resolution", "WARNING ".yellow().bold(),
-                    name, published_at.to_hex_literal()
+            Err(e) => {
+                diags.emit(
+                    Diagnostic::warning(
+                        "resolution::unfetched_package",
+                        format!(
+                            "Package \"{}\" published at {} couldn't be fetched from chain for type origin resolution",
+                            name,
+                            published_at.to_hex_literal()
+                        ),
+                    )
+                    .with_package(original_id)
+                    .with_note(format!("{:#}", e)),
                 )?;
                 continue;
             }
@@ -468,17 +743,33 @@ async fn resolve_type_origin_table<Progress: Write>(
     }
     // populate type origin table with remaining modules from the model (that couldn't be resolved from chain).
     // in this case we set origin of all types to the package's original id.
+    let fetched = type_origin_table.keys().copied().collect::<BTreeSet<_>>();
     for module in model.get_modules() {
         let original_id = *module.self_address();
-        type_origin_table
+        let origin_map = type_origin_table
             .entry(original_id)
             .or_insert_with(BTreeMap::new);
-        let origin_map = type_origin_table.get_mut(&original_id).unwrap();
         for strct in module.get_structs() {
             if origin_map.contains_key(&strct.get_full_name_str()) {
                 continue;
             };
             origin_map.insert(strct.get_full_name_str(), original_id);
+            // structs of published packages without an on-chain origin were likely added after
+            // the last upgrade
+            if fetched.contains(&original_id) {
+                diags.emit(
+                    Diagnostic::note(
+                        "resolution::type_origin_fallback",
+                        format!(
+                            "Struct `{}` has no type origin on chain, using the package's original ID {}",
+                            strct.get_full_name_str(),
+                            original_id.to_hex_literal()
+                        ),
+                    )
+                    .with_package(&original_id)
+                    .with_span(Span::from_loc(model, &strct.get_loc())),
+                )?;
+            }
         }
     }
 
@@ -489,31 +780,48 @@ async fn resolve_version_table(
     cache: &mut PackageCache<'_>,
     type_origin_table: &TypeOriginTable,
 ) -> Result<VersionTable> {
-    // this is slow and inefficient but can be made faster by fetching package versions
-    // from an index (if there is such an index) or using graphql api
+    // use the versions listed by the package source where possible (e.g. GraphQL's
+    // `packageVersions`), otherwise fetch each package referenced in type origins
     let mut version_table = BTreeMap::new();
     let mut packages_to_fetch = vec![];
-    for (_, origins) in type_origin_table.iter() {
-        for origin in origins.values() {
-            packages_to_fetch.push(*origin);
-        }
-    }
-    // pre-fetch
-    cache.get_multi(packages_to_fetch).await?;
-
     for (original_id, origins) in type_origin_table.iter() {
+        let listed_versions = match original_id == &AccountAddress::ZERO {
+            true => BTreeMap::new(),
+            false => cache.get_versions(*original_id).await?.unwrap_or_default(),
+        };
+
         let mut versions = BTreeMap::new();
         versions.insert(*original_id, 1.into());
-        for (_, origin) in origins.iter() {
+        for origin in origins.values() {
             if origin == &AccountAddress::ZERO {
                 continue;
             }
-            let pkg = cache.get(*origin).await?;
-            versions.insert(*origin, pkg.version);
+            match listed_versions.get(origin) {
+                Some(version) => {
+                    versions.insert(*origin, *version);
+                }
+                None => packages_to_fetch.push((*original_id, *origin)),
+            }
         }
         version_table.insert(*original_id, versions);
     }
 
+    let raw_pkgs = cache
+        .get_multi(
+            packages_to_fetch
+                .iter()
+                .map(|(_, origin)| *origin)
+                .collect(),
+        )
+        .await?;
+    for (pkg, (original_id, origin)) in raw_pkgs.into_iter().zip(packages_to_fetch) {
+        let pkg = pkg?;
+        version_table
+            .get_mut(&original_id)
+            .unwrap()
+            .insert(origin, pkg.version);
+    }
+
     Ok(version_table)
 }
 
@@ -628,3 +936,289 @@ fn str_escape(s: &str) -> Result<String, fmt::Error> {
 fn path_escape(p: &Path) -> Result<String, fmt::Error> {
     str_escape(p.to_str().ok_or(fmt::Error)?)
 }
+
+#[cfg(test)]
+mod tests {
+    use move_symbol_pool::Symbol;
+    use sui_sdk::types::base_types::ObjectID;
+    use sui_sdk::types::move_package::TypeOrigin;
+
+    use super::*;
+    use crate::diagnostics::MessageFormat;
+    use crate::package_cache::FetchConfig;
+    use crate::package_source::{FixtureSource, PackageSource, RecordingSource};
+
+    fn addr(s: &str) -> AccountAddress {
+        AccountAddress::from_hex_literal(s).unwrap()
+    }
+
+    /// Builds a package without modules, which is enough for resolving versions and type origins.
+    fn package(
+        id: &str,
+        version: u64,
+        origins: &[(&str, &str)],
+        linkage: &[(&str, &str, u64)],
+    ) -> SuiRawMovePackage {
+        SuiRawMovePackage {
+            id: ObjectID::from(addr(id)),
+            version: version.into(),
+            module_map: BTreeMap::new(),
+            type_origin_table: origins
+                .iter()
+                .map(|(name, package)| {
+                    let (module_name, datatype_name) = name.split_once("::").unwrap();
+                    TypeOrigin {
+                        module_name: module_name.to_string(),
+                        datatype_name: datatype_name.to_string(),
+                        package: ObjectID::from(addr(package)),
+                    }
+                })
+                .collect(),
+            linkage_table: linkage
+                .iter()
+                .map(|(original_id, upgraded_id, upgraded_version)| {
+                    (
+                        ObjectID::from(addr(original_id)),
+                        UpgradeInfo {
+                            upgraded_id: ObjectID::from(addr(upgraded_id)),
+                            upgraded_version: (*upgraded_version).into(),
+                        },
+                    )
+                })
+                .collect(),
+        }
+    }
+
+    /// Writes the packages to a fixtures directory served by a `FixtureSource`.
+    fn fixtures(dir: &Path, pkgs: &[SuiRawMovePackage]) -> FixtureSource {
+        let source = FixtureSource::new(dir.to_path_buf());
+        for pkg in pkgs {
+            source.write_package(pkg).unwrap();
+        }
+        source
+    }
+
+    /// `a` upgraded once (0xa1 -> 0xa2, adding `a::B`) and `b` depending on the upgraded `a`.
+    fn upgraded_packages() -> Vec<SuiRawMovePackage> {
+        vec![
+            package("0xa1", 1, &[("a::A", "0xa1")], &[]),
+            package("0xa2", 2, &[("a::A", "0xa1"), ("a::B", "0xa2")], &[]),
+            package("0xb1", 1, &[("b::C", "0xb1")], &[("0xa1", "0xa2", 2)]),
+        ]
+    }
+
+    #[tokio::test]
+    async fn test_resolve_on_chain_packages_uses_highest_linked_version() {
+        let dir = tempdir().unwrap();
+        let source = fixtures(dir.path(), &upgraded_packages());
+        let mut cache = PackageCache::new(&source, FetchConfig::default());
+
+        let (ids, original_map) = resolve_on_chain_packages(
+            &mut cache,
+            vec![addr("0xa1"), addr("0xb1")],
+            &BTreeSet::new(),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(ids, vec![addr("0xa2"), addr("0xb1")]);
+        assert_eq!(
+            original_map,
+            BTreeMap::from([
+                (addr("0xa1"), addr("0xa1")),
+                (addr("0xa2"), addr("0xa1")),
+                (addr("0xb1"), addr("0xb1")),
+            ])
+        );
+    }
+
+    #[tokio::test]
+    async fn test_resolve_original_package_id_when_first_version_is_not_1() {
+        // framework packages upgraded through system upgrades can start at a version other than 1
+        let dir = tempdir().unwrap();
+        let source = fixtures(
+            dir.path(),
+            &[
+                package("0x51", 3, &[("s::X", "0x51")], &[]),
+                package("0x52", 4, &[("s::X", "0x51"), ("s::Y", "0x52")], &[]),
+            ],
+        );
+        let mut cache = PackageCache::new(&source, FetchConfig::default());
+
+        let original_id = resolve_original_package_id(&mut cache, addr("0x52"))
+            .await
+            .unwrap();
+        assert_eq!(original_id, addr("0x51"));
+
+        let (ids, original_map) =
+            resolve_on_chain_packages(&mut cache, vec![addr("0x52")], &BTreeSet::new())
+                .await
+                .unwrap();
+        assert_eq!(ids, vec![addr("0x52")]);
+        assert_eq!(original_map.get(&addr("0x52")), Some(&addr("0x51")));
+    }
+
+    #[tokio::test]
+    async fn test_resolve_on_chain_packages_keeps_pinned_version() {
+        let dir = tempdir().unwrap();
+        let source = fixtures(dir.path(), &upgraded_packages());
+        let mut cache = PackageCache::new(&source, FetchConfig::default());
+
+        let pinned = BTreeSet::from([addr("0xa1")]);
+        let (ids, _) =
+            resolve_on_chain_packages(&mut cache, vec![addr("0xa1"), addr("0xb1")], &pinned)
+                .await
+                .unwrap();
+
+        assert_eq!(ids, vec![addr("0xa1"), addr("0xb1")]);
+    }
+
+    #[tokio::test]
+    async fn test_resolve_pinned_package() {
+        let dir = tempdir().unwrap();
+        let source = fixtures(dir.path(), &[]);
+        let mut cache = PackageCache::new(&source, FetchConfig::default());
+        let pkg = |version| GM::OnChainPackage {
+            id: addr("0xa1"),
+            version,
+        };
+
+        // without a version listing only unpinned packages can be resolved
+        assert_eq!(
+            resolve_pinned_package(&mut cache, &pkg(None))
+                .await
+                .unwrap(),
+            addr("0xa1")
+        );
+        assert!(
+            resolve_pinned_package(&mut cache, &pkg(Some(GM::PackageVersion::Latest)))
+                .await
+                .is_err()
+        );
+
+        source
+            .write_package_versions(
+                &addr("0xa1"),
+                &BTreeMap::from([
+                    (addr("0xa1"), 1.into()),
+                    (addr("0xa2"), 2.into()),
+                    (addr("0xa3"), 3.into()),
+                ]),
+            )
+            .unwrap();
+        assert_eq!(
+            resolve_pinned_package(&mut cache, &pkg(Some(GM::PackageVersion::Exact(2))))
+                .await
+                .unwrap(),
+            addr("0xa2")
+        );
+        assert_eq!(
+            resolve_pinned_package(&mut cache, &pkg(Some(GM::PackageVersion::Latest)))
+                .await
+                .unwrap(),
+            addr("0xa3")
+        );
+        assert!(
+            resolve_pinned_package(&mut cache, &pkg(Some(GM::PackageVersion::Exact(4))))
+                .await
+                .is_err()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_resolve_type_origin_and_version_tables() {
+        let dir = tempdir().unwrap();
+        let source = fixtures(dir.path(), &upgraded_packages());
+        let mut cache = PackageCache::new(&source, FetchConfig::default());
+
+        let id_map = BTreeMap::from([(addr("0xa1"), Symbol::from("a"))]);
+        let published_at = BTreeMap::from([(addr("0xa1"), addr("0xa2"))]);
+        let type_origin_table = resolve_type_origin_table(
+            &mut cache,
+            &id_map,
+            &published_at,
+            &GlobalEnv::new(),
+            &mut Diagnostics::with_writer(MessageFormat::Json, false, Box::new(io::sink())),
+        )
+        .await
+        .unwrap();
+        assert_eq!(
+            type_origin_table,
+            BTreeMap::from([(
+                addr("0xa1"),
+                BTreeMap::from([
+                    ("a::A".to_string(), addr("0xa1")),
+                    ("a::B".to_string(), addr("0xa2")),
+                ])
+            )])
+        );
+
+        let version_table = resolve_version_table(&mut cache, &type_origin_table)
+            .await
+            .unwrap();
+        assert_eq!(
+            version_table,
+            BTreeMap::from([(
+                addr("0xa1"),
+                BTreeMap::from([(addr("0xa1"), 1.into()), (addr("0xa2"), 2.into())])
+            )])
+        );
+    }
+
+    #[tokio::test]
+    async fn test_resolve_version_table_from_listed_versions() {
+        // with a version listing (as fetched from GraphQL) the packages aren't fetched
+        let dir = tempdir().unwrap();
+        let source = fixtures(dir.path(), &[]);
+        source
+            .write_package_versions(
+                &addr("0xa1"),
+                &BTreeMap::from([(addr("0xa1"), 1.into()), (addr("0xa2"), 2.into())]),
+            )
+            .unwrap();
+        let mut cache = PackageCache::new(&source, FetchConfig::default());
+
+        let type_origin_table = BTreeMap::from([(
+            addr("0xa1"),
+            BTreeMap::from([
+                ("a::A".to_string(), addr("0xa1")),
+                ("a::B".to_string(), addr("0xa2")),
+            ]),
+        )]);
+        let version_table = resolve_version_table(&mut cache, &type_origin_table)
+            .await
+            .unwrap();
+        assert_eq!(
+            version_table,
+            BTreeMap::from([(
+                addr("0xa1"),
+                BTreeMap::from([(addr("0xa1"), 1.into()), (addr("0xa2"), 2.into())])
+            )])
+        );
+    }
+
+    #[tokio::test]
+    async fn test_recorded_packages_are_replayed() {
+        let src_dir = tempdir().unwrap();
+        let record_dir = tempdir().unwrap();
+        let recording = RecordingSource::new(
+            Box::new(fixtures(src_dir.path(), &upgraded_packages())),
+            record_dir.path().to_path_buf(),
+        );
+
+        let ids = vec![ObjectID::from(addr("0xa2")), ObjectID::from(addr("0xb1"))];
+        let recorded = recording.get_packages(ids.clone()).await.unwrap();
+        let replayed = FixtureSource::new(record_dir.path().to_path_buf())
+            .get_packages(ids)
+            .await
+            .unwrap();
+
+        assert_eq!(recorded.len(), 2);
+        for (recorded, replayed) in recorded.into_iter().zip(replayed) {
+            assert_eq!(
+                serde_json::to_value(recorded.unwrap()).unwrap(),
+                serde_json::to_value(replayed.unwrap()).unwrap()
+            );
+        }
+    }
+}
@@ -0,0 +1,1106 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use anyhow::{bail, Result};
+use convert_case::{Case, Casing as _};
+use move_core_types::account_address::AccountAddress;
+use move_model::model::{FieldEnv, FunctionEnv, GlobalEnv, Loc, ModuleEnv, StructEnv};
+
+use crate::diagnostics::{Diagnostic, Diagnostics, Span};
+use crate::gen::MoveLoc;
+
+#[rustfmt::skip]
+pub const JS_RESERVED_WORDS: [&str; 64] = [
+    "abstract", "arguments", "await", "boolean", "break", "byte", "case", "catch",
+    "char", "class", "const", "continue", "debugger", "default", "delete", "do",
+    "double", "else", "enum", "eval", "export", "extends", "false", "final",
+    "finally", "float", "for", "function", "goto", "if", "implements", "import",
+    "in", "instanceof", "int", "interface", "let", "long", "native", "new",
+    "null", "package", "private", "protected", "public", "return", "short", "static",
+    "super", "switch", "synchronized", "this", "throw", "throws", "transient", "true",
+    "try", "typeof", "var", "void", "volatile", "while", "with", "yield"
+];
+
+#[rustfmt::skip]
+pub const JS_STRICTLY_RESERVED_WORDS: [&str; 37] = [
+    "await", "break", "case", "catch", "class", "const", "continue", "debugger",
+    "default", "delete", "do", "else", "export", "extends", "false", "finally",
+    "for", "function", "if", "import", "in", "instanceof", "new", "null", "return",
+    "super", "switch", "this", "throw", "true", "try", "typeof", "var", "void",
+    "while", "with", "yield"
+];
+
+/// Names imported into `functions.ts` which can't be used for function bindings.
+#[rustfmt::skip]
+const FUNCTIONS_TS_IMPORTS: [&str; 16] = [
+    "Transaction", "TransactionArgument", "TransactionObjectInput", "GenericArg", "PUBLISHED_AT",
+    "obj", "pure", "generic", "vector", "option", "MoveResult", "splitResult", "Vector",
+    "ToPhantom", "ObjectArg", "coinArg",
+];
+
+/// Names imported into `structs.ts` which can't be used for struct classes.
+#[rustfmt::skip]
+const STRUCTS_TS_IMPORTS: [&str; 45] = [
+    "BcsType", "bcs", "SuiClient", "SuiObjectData", "SuiParsedData", "fromB64", "fromHEX",
+    "toHEX", "PhantomReified", "PhantomToTypeStr", "PhantomTypeArgument", "Reified",
+    "StructClass", "ToField", "ToPhantomTypeArgument", "ToTypeArgument", "ToTypeStr",
+    "TypeArgument", "assertFieldsWithTypesArgsMatch", "assertReifiedTypeArgsMatch",
+    "decodeFromFields", "decodeFromFieldsWithTypes", "decodeFromJSONField", "extractType",
+    "fieldToJSON", "phantom", "reified", "toBcs", "FieldsWithTypes", "composeSuiType",
+    "compressSuiType", "parseTypeName", "Vector", "vector", "fetchObjects", "FetchObjectResult",
+    "fetchObjectsOfType", "fetchOwnedObjects", "fieldToBcs", "concatBytes", "TypedObjectId",
+    "kioskHelpers", "KioskHelpers", "fetchDisplay", "DisplayTemplate",
+];
+
+/// Instance members of the generated struct classes which can't be used for fields.
+#[rustfmt::skip]
+const STRUCT_INSTANCE_MEMBERS: [&str; 12] = [
+    "__StructClass", "__inner", "$typeName", "$fullTypeName", "$typeArgs", "$isPhantom",
+    "$moveFieldNames", "$objectId", "toJSONField", "toJSON", "toBcs", "constructor",
+];
+
+/// Files generated in a package directory next to the module directories.
+const PACKAGE_FILES: [&str; 3] = ["index", "init", "barrel"];
+
+/// Casing used for a generated name.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Casing {
+    Kebab,
+    Snake,
+    Camel,
+    Pascal,
+    /// Keep the name as it is in Move.
+    Preserve,
+}
+
+impl Casing {
+    pub fn parse(s: &str) -> Result<Self> {
+        match s {
+            "kebab" | "kebab-case" => Ok(Casing::Kebab),
+            "snake" | "snake_case" => Ok(Casing::Snake),
+            "camel" | "camelCase" => Ok(Casing::Camel),
+            "pascal" | "PascalCase" => Ok(Casing::Pascal),
+            "preserve" => Ok(Casing::Preserve),
+            _ => bail!(
+                "Invalid casing \"{}\". Expected one of \"kebab\", \"snake\", \"camel\", \"pascal\" or \"preserve\"",
+                s
+            ),
+        }
+    }
+
+    /// Converts `name`, which is in `from` case, to this casing.
+    fn convert(&self, name: &str, from: Case) -> String {
+        let to = match self {
+            Casing::Kebab => Case::Kebab,
+            Casing::Snake => Case::Snake,
+            Casing::Camel => Case::Camel,
+            Casing::Pascal => Case::Pascal,
+            Casing::Preserve => return name.to_string(),
+        };
+        name.from_case(from).to_case(to)
+    }
+}
+
+/// Naming conventions for the generated code (`[config.naming]` in gen.toml).
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct NamingConfig {
+    /// Casing of package and module directories.
+    pub directories: Casing,
+    /// Casing of function bindings.
+    pub functions: Casing,
+    /// Suffix of the function `<..>Args` interfaces.
+    pub args_suffix: String,
+    /// Casing of struct fields and function `<..>Args` interface fields.
+    pub fields: Casing,
+    /// Prefix of the fields of positional structs, followed by the field index (e.g. `_0`).
+    pub positional_fields: String,
+    /// What to do when generated names collide.
+    pub on_collision: OnCollision,
+}
+
+/// What to do when generated names collide (e.g. two Move functions that only differ in `_`
+/// placement, or a field named like a class member).
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum OnCollision {
+    /// Fail with a diagnostic pointing at the colliding Move declarations.
+    Error,
+    /// Append a numeric suffix to all but the first declaration (ordered by Move name).
+    Suffix,
+}
+
+impl OnCollision {
+    pub fn parse(s: &str) -> Result<Self> {
+        match s {
+            "error" => Ok(OnCollision::Error),
+            "suffix" => Ok(OnCollision::Suffix),
+            _ => bail!(
+                "Invalid on_collision value \"{}\". Expected \"error\" or \"suffix\"",
+                s
+            ),
+        }
+    }
+}
+
+impl Default for NamingConfig {
+    fn default() -> Self {
+        NamingConfig {
+            directories: Casing::Kebab,
+            functions: Casing::Camel,
+            args_suffix: "Args".to_string(),
+            fields: Casing::Camel,
+            positional_fields: "_".to_string(),
+            on_collision: OnCollision::Error,
+        }
+    }
+}
+
+/// Explicit renames of functions and structs (`[rename]` in gen.toml), e.g.
+/// `"amm::pool::swap_a_to_b" = "swapAForB"`.
+pub type Renames = BTreeMap<String, String>;
+
+/// A declaration that a name is generated for. Used to store names resolved during validation.
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd)]
+enum NameKey {
+    /// (package address, module name)
+    Module(AccountAddress, String),
+    /// (package address, module name, function name)
+    Function(AccountAddress, String, String),
+    /// (package address, module name, function name)
+    ArgsInterface(AccountAddress, String, String),
+    /// (package address, module name, struct name)
+    Struct(AccountAddress, String, String),
+    /// (package address, module name, struct name, field name)
+    Field(AccountAddress, String, String, String),
+}
+
+/// A generated name to be validated.
+struct Decl {
+    key: NameKey,
+    /// Move name of the declaration, used to order declarations deterministically.
+    move_name: String,
+    /// Description of the declaration used in diagnostics, e.g. "function `pool::swap`".
+    desc: String,
+    /// Location of the Move declaration, if available.
+    span: Option<Span>,
+    /// The generated name is `base + suffix`. When disambiguating, the number goes in between.
+    base: String,
+    suffix: String,
+}
+
+/// Resolves names used in the generated code according to the naming config and renames.
+pub struct Naming {
+    config: NamingConfig,
+    /// (package address, module name, function or struct name) -> name
+    renames: BTreeMap<(AccountAddress, String, String), String>,
+    /// Names assigned during validation to resolve collisions.
+    overrides: BTreeMap<NameKey, String>,
+}
+
+impl Naming {
+    /// Creates a new `Naming`. Package names in rename keys are resolved using `pkg_names`
+    /// (either as written in gen.toml or as their import name, e.g. `AMM` or `amm`). Addresses
+    /// can also be used in place of package names (e.g. `0x2::coin::Coin`).
+    pub fn new(
+        config: NamingConfig,
+        renames: &Renames,
+        pkg_names: &BTreeMap<AccountAddress, move_symbol_pool::Symbol>,
+    ) -> Result<Self> {
+        let mut naming = Naming {
+            config,
+            renames: BTreeMap::new(),
+            overrides: BTreeMap::new(),
+        };
+
+        for (key, name) in renames.iter() {
+            let parts = key.split("::").collect::<Vec<_>>();
+            let &[pkg, module, member] = parts.as_slice() else {
+                bail!(
+                    "Invalid rename key \"{}\". Expected \"<package>::<module>::<function or struct>\"",
+                    key
+                );
+            };
+
+            let addr = match AccountAddress::from_hex_literal(pkg) {
+                Ok(addr) => addr,
+                Err(_) => {
+                    let found = pkg_names.iter().find(|(_, pkg_name)| {
+                        pkg_name.as_str() == pkg || naming.package_import_name(**pkg_name) == pkg
+                    });
+                    match found {
+                        Some((addr, _)) => *addr,
+                        None => bail!(
+                            "Invalid rename key \"{}\". Package \"{}\" is not listed in gen.toml",
+                            key,
+                            pkg
+                        ),
+                    }
+                }
+            };
+
+            naming.renames.insert(
+                (addr, module.to_string(), member.to_string()),
+                name.to_string(),
+            );
+        }
+
+        Ok(naming)
+    }
+
+    fn get_rename(&self, module: &ModuleEnv, name: &str) -> Option<&String> {
+        self.renames.get(&(
+            *module.self_address(),
+            module_name(module),
+            name.to_string(),
+        ))
+    }
+
+    fn module_key(module: &ModuleEnv) -> NameKey {
+        NameKey::Module(*module.self_address(), module_name(module))
+    }
+
+    fn function_key(func: &FunctionEnv) -> NameKey {
+        NameKey::Function(
+            *func.module_env.self_address(),
+            module_name(&func.module_env),
+            func.get_name_str(),
+        )
+    }
+
+    fn args_interface_key(func: &FunctionEnv) -> NameKey {
+        NameKey::ArgsInterface(
+            *func.module_env.self_address(),
+            module_name(&func.module_env),
+            func.get_name_str(),
+        )
+    }
+
+    fn struct_key(strct: &StructEnv) -> NameKey {
+        NameKey::Struct(
+            *strct.module_env.self_address(),
+            module_name(&strct.module_env),
+            strct.get_name().display(strct.symbol_pool()).to_string(),
+        )
+    }
+
+    fn field_key(strct: &StructEnv, field: &FieldEnv) -> NameKey {
+        NameKey::Field(
+            *strct.module_env.self_address(),
+            module_name(&strct.module_env),
+            strct.get_name().display(strct.symbol_pool()).to_string(),
+            field.get_name().display(strct.symbol_pool()).to_string(),
+        )
+    }
+
+    /// Returns rename keys that don't match any function or struct in the given models.
+    pub fn unmatched_renames(&self, envs: &[&GlobalEnv]) -> Vec<String> {
+        let mut matched = BTreeSet::new();
+        for env in envs {
+            for module in env.get_modules() {
+                let module_name = module.get_name().display(env.symbol_pool()).to_string();
+                let members = module
+                    .get_functions()
+                    .map(|func| func.get_name_str())
+                    .chain(
+                        module
+                            .get_structs()
+                            .map(|strct| strct.get_name().display(env.symbol_pool()).to_string()),
+                    );
+                for member in members {
+                    matched.insert((*module.self_address(), module_name.clone(), member));
+                }
+            }
+        }
+
+        self.renames
+            .keys()
+            .filter(|key| !matched.contains(*key))
+            .map(|(addr, module, member)| {
+                format!("{}::{}::{}", addr.to_hex_literal(), module, member)
+            })
+            .collect()
+    }
+
+    /// Returns module name that's used in import paths (converts to kebab case by default as
+    /// that's idiomatic in TS).
+    pub fn module_import_name(&self, module: &ModuleEnv) -> String {
+        if let Some(name) = self.overrides.get(&Naming::module_key(module)) {
+            return name.clone();
+        }
+        self.config
+            .directories
+            .convert(&module_name(module), Case::Snake)
+    }
+
+    /// Returns package name that's used in import paths (converts to kebab case by default as
+    /// that's idiomatic in TS).
+    pub fn package_import_name(&self, pkg_name: move_symbol_pool::Symbol) -> String {
+        self.config
+            .directories
+            .convert(pkg_name.as_str(), Case::Pascal)
+    }
+
+    /// Returns the TS function binding name for a function.
+    pub fn fun_name(&self, func: &FunctionEnv) -> String {
+        if let Some(name) = self.overrides.get(&Naming::function_key(func)) {
+            return name.clone();
+        }
+
+        let move_name = func.get_name_str();
+        if let Some(name) = self.get_rename(&func.module_env, &move_name) {
+            return name.clone();
+        }
+
+        let mut fun_name_str = self.config.functions.convert(&move_name, Case::Snake);
+        // function names ending with `_` are common, so handle this specifically
+        // TODO: remove this once there's a more general way to handle this
+        let trailing_underscore_lost = move_name.ends_with('_') && !fun_name_str.ends_with('_');
+        if JS_RESERVED_WORDS.contains(&fun_name_str.as_str()) {
+            fun_name_str.push('_');
+        };
+        if trailing_underscore_lost {
+            fun_name_str.push('_');
+        }
+        fun_name_str
+    }
+
+    /// Returns the name of the `<..>Args` interface for a function.
+    pub fn fun_arg_if_name(&self, func: &FunctionEnv) -> String {
+        if let Some(name) = self.overrides.get(&Naming::args_interface_key(func)) {
+            return name.clone();
+        }
+        self.fun_arg_if_base_name(func) + &self.config.args_suffix
+    }
+
+    /// Returns the name of the `<..>Args` interface for a function without the suffix.
+    fn fun_arg_if_base_name(&self, func: &FunctionEnv) -> String {
+        let move_name = func.get_name_str();
+        if let Some(name) = self.get_rename(&func.module_env, &move_name) {
+            return name.from_case(Case::Camel).to_case(Case::Pascal);
+        }
+
+        // function names ending with `_` are common, so handle this specifically
+        // TODO: remove this once there's a more general way to handle this
+        let name = move_name.from_case(Case::Snake).to_case(Case::Pascal);
+        if move_name.ends_with('_') {
+            name + "_"
+        } else {
+            name
+        }
+    }
+
+    /// Returns the TS class name for a struct.
+    pub fn struct_class_name(&self, strct: &StructEnv) -> String {
+        if let Some(name) = self.overrides.get(&Naming::struct_key(strct)) {
+            return name.clone();
+        }
+
+        let move_name = strct.get_name().display(strct.symbol_pool()).to_string();
+        match self.get_rename(&strct.module_env, &move_name) {
+            Some(name) => name.clone(),
+            None => move_name,
+        }
+    }
+
+    /// Returns the TS name for a struct field or a function `<..>Args` interface field.
+    pub fn field_name(&self, name: &str) -> String {
+        match self.config.fields {
+            Casing::Preserve => name.to_string(),
+            // the field name can be in any case so let `convert_case` detect it
+            Casing::Kebab => name.to_case(Case::Kebab),
+            Casing::Snake => name.to_case(Case::Snake),
+            Casing::Camel => name.to_case(Case::Camel),
+            Casing::Pascal => name.to_case(Case::Pascal),
+        }
+    }
+
+    /// Returns the TS name for a struct field.
+    pub fn struct_field_name(&self, strct: &StructEnv, field: &FieldEnv) -> String {
+        if let Some(name) = self.overrides.get(&Naming::field_key(strct, field)) {
+            return name.clone();
+        }
+        self.default_struct_field_name(strct, field)
+    }
+
+    /// Returns the TS name for a struct field before collisions are resolved. Fields of
+    /// positional structs are named by their index (e.g. `_0`, `_1`).
+    fn default_struct_field_name(&self, strct: &StructEnv, field: &FieldEnv) -> String {
+        let name = field.get_name().display(strct.symbol_pool()).to_string();
+        if is_positional_struct(strct) {
+            if let Some(idx) = positional_field_index(&name) {
+                return format!("{}{}", self.config.positional_fields, idx);
+            }
+        }
+        self.field_name(&name)
+    }
+
+    /// Validates the names generated for the modules in `env` before any code is emitted. Checks
+    /// that module directories are unique within a package, that function bindings, `<..>Args`
+    /// interfaces and struct classes are unique within a module and don't clash with imported
+    /// names or JS reserved words, and that struct fields don't clash with each other or with
+    /// class members. Depending on `on_collision`, collisions are either resolved by appending a
+    /// numeric suffix or reported to `diags` as errors pointing at the Move declarations.
+    pub fn validate(
+        &mut self,
+        env: &GlobalEnv,
+        top_level_pkg_names: &BTreeMap<AccountAddress, move_symbol_pool::Symbol>,
+        diags: &mut Diagnostics,
+    ) -> Result<()> {
+        let mut issues = vec![];
+
+        let mut pkgs: BTreeMap<AccountAddress, Vec<ModuleEnv>> = BTreeMap::new();
+        for module in env.get_modules() {
+            pkgs.entry(*module.self_address()).or_default().push(module);
+        }
+
+        for (addr, modules) in pkgs.iter() {
+            let decls = modules
+                .iter()
+                .map(|module| Decl {
+                    key: Naming::module_key(module),
+                    move_name: module_name(module),
+                    desc: decl_desc(
+                        env,
+                        format!("module `{}`", module_name(module)),
+                        &module.get_loc(),
+                    ),
+                    span: Span::from_loc(env, &module.get_loc()),
+                    base: self.module_import_name(module),
+                    suffix: "".to_string(),
+                })
+                .collect();
+            self.resolve_scope(
+                addr,
+                &format!("package {}", addr.to_hex_literal()),
+                decls,
+                &|name| vec![name.to_string()],
+                &PACKAGE_FILES,
+                is_valid_dir_name,
+                &mut issues,
+            );
+
+            for module in modules {
+                let with_functions = top_level_pkg_names.contains_key(addr);
+                self.validate_module(module, with_functions, &mut issues);
+            }
+        }
+
+        if !issues.is_empty() {
+            let count = issues.len();
+            for issue in issues {
+                diags.emit(issue)?;
+            }
+            bail!(
+                "Found {} naming issue(s). \
+                Use the [rename] section in gen.toml to resolve them, or set \
+                `on_collision = \"suffix\"` in [config.naming] to disambiguate collisions automatically.",
+                count
+            );
+        }
+
+        Ok(())
+    }
+
+    fn validate_module(
+        &mut self,
+        module: &ModuleEnv,
+        with_functions: bool,
+        diags: &mut Vec<Diagnostic>,
+    ) {
+        let env = module.env;
+        let addr = module.self_address();
+        let scope = format!(
+            "module {}::{}",
+            module.self_address().to_hex_literal(),
+            module_name(module)
+        );
+
+        // functions.ts
+        if with_functions {
+            let mut decls = vec![];
+            for func in module.get_functions() {
+                let desc = decl_desc(
+                    env,
+                    format!("function `{}`", func.get_full_name_str()),
+                    &func.get_loc(),
+                );
+                decls.push(Decl {
+                    key: Naming::function_key(&func),
+                    move_name: func.get_name_str(),
+                    desc: desc.clone(),
+                    span: Span::from_loc(env, &func.get_loc()),
+                    base: self.fun_name(&func),
+                    suffix: "".to_string(),
+                });
+                decls.push(Decl {
+                    key: Naming::args_interface_key(&func),
+                    move_name: func.get_name_str(),
+                    desc: format!("args interface of {}", desc),
+                    span: Span::from_loc(env, &func.get_loc()),
+                    base: self.fun_arg_if_base_name(&func),
+                    suffix: self.config.args_suffix.clone(),
+                });
+            }
+            let reserved = FUNCTIONS_TS_IMPORTS
+                .iter()
+                .chain(JS_RESERVED_WORDS.iter())
+                .copied()
+                .collect::<Vec<_>>();
+            self.resolve_scope(
+                addr,
+                &format!("{} (functions.ts)", scope),
+                decls,
+                &|name| vec![name.to_string()],
+                &reserved,
+                is_valid_identifier,
+                diags,
+            );
+        }
+
+        // structs.ts
+        let decls = module
+            .get_structs()
+            .map(|strct| Decl {
+                key: Naming::struct_key(&strct),
+                move_name: strct.get_name().display(env.symbol_pool()).to_string(),
+                desc: decl_desc(
+                    env,
+                    format!("struct `{}`", strct.get_full_name_str()),
+                    &strct.get_loc(),
+                ),
+                span: Span::from_loc(env, &strct.get_loc()),
+                base: self.struct_class_name(&strct),
+                suffix: "".to_string(),
+            })
+            .collect();
+        let reserved = STRUCTS_TS_IMPORTS
+            .iter()
+            .chain(JS_RESERVED_WORDS.iter())
+            .copied()
+            .collect::<Vec<_>>();
+        self.resolve_scope(
+            addr,
+            &format!("{} (structs.ts)", scope),
+            decls,
+            &|name| {
+                vec![
+                    format!("is{}", name),
+                    format!("{}Fields", name),
+                    format!("{}Reified", name),
+                    name.to_string(),
+                ]
+            },
+            &reserved,
+            is_valid_identifier,
+            diags,
+        );
+
+        // struct fields
+        for strct in module.get_structs() {
+            let decls = strct
+                .get_fields()
+                .map(|field| {
+                    let field_name = field.get_name().display(env.symbol_pool()).to_string();
+                    Decl {
+                        key: Naming::field_key(&strct, &field),
+                        desc: decl_desc(
+                            env,
+                            format!(
+                                "field `{}` of struct `{}`",
+                                field_name,
+                                strct.get_full_name_str()
+                            ),
+                            &strct.get_loc(),
+                        ),
+                        span: Span::from_loc(env, &strct.get_loc()),
+                        base: self.default_struct_field_name(&strct, &field),
+                        move_name: field_name,
+                        suffix: "".to_string(),
+                    }
+                })
+                .collect();
+            self.resolve_scope(
+                addr,
+                &format!("struct {}", strct.get_full_name_str()),
+                decls,
+                &|name| vec![name.to_string()],
+                &STRUCT_INSTANCE_MEMBERS,
+                is_valid_identifier,
+                diags,
+            );
+        }
+    }
+
+    /// Checks that the names generated for `decls` are valid and unique within a scope and don't
+    /// clash with `reserved` names. `derive` returns all names generated for a declaration
+    /// (e.g. a struct class also generates `is<Class>`, `<Class>Fields`, etc.).
+    fn resolve_scope(
+        &mut self,
+        package: &AccountAddress,
+        scope: &str,
+        mut decls: Vec<Decl>,
+        derive: &dyn Fn(&str) -> Vec<String>,
+        reserved: &[&str],
+        is_valid: fn(&str) -> bool,
+        diags: &mut Vec<Diagnostic>,
+    ) {
+        decls.sort_by(|a, b| a.move_name.cmp(&b.move_name));
+
+        // name -> declaration it was generated for (`None` for reserved names)
+        let mut taken: BTreeMap<String, Option<String>> = reserved
+            .iter()
+            .map(|name| (name.to_string(), None))
+            .collect();
+
+        for decl in decls {
+            let names = derive(&format!("{}{}", decl.base, decl.suffix));
+            if let Some(invalid) = names.iter().find(|name| !is_valid(name)) {
+                diags.push(
+                    Diagnostic::error(
+                        "naming::invalid_name",
+                        format!(
+                            "invalid name `{}` in {} generated for {}",
+                            invalid, scope, decl.desc
+                        ),
+                    )
+                    .with_package(package)
+                    .with_span(decl.span.clone()),
+                );
+                continue;
+            }
+
+            let conflicts = names
+                .iter()
+                .filter(|name| taken.contains_key(*name))
+                .collect::<Vec<_>>();
+            if conflicts.is_empty() {
+                for name in names {
+                    taken.insert(name, Some(decl.desc.clone()));
+                }
+                continue;
+            }
+
+            match self.config.on_collision {
+                OnCollision::Error => {
+                    for name in conflicts {
+                        let other = match &taken[name] {
+                            Some(other) => other.clone(),
+                            None => "a reserved word or imported name".to_string(),
+                        };
+                        diags.push(
+                            Diagnostic::error(
+                                "naming::collision",
+                                format!(
+                                    "name collision in {}: `{}` is generated for {}",
+                                    scope, name, decl.desc
+                                ),
+                            )
+                            .with_package(package)
+                            .with_span(decl.span.clone())
+                            .with_note(format!("which clashes with {}", other)),
+                        );
+                    }
+                }
+                OnCollision::Suffix => {
+                    let (name, names) = (1..)
+                        .map(|idx| {
+                            let name = format!("{}{}{}", decl.base, idx, decl.suffix);
+                            let names = derive(&name);
+                            (name, names)
+                        })
+                        .find(|(_, names)| names.iter().all(|name| !taken.contains_key(name)))
+                        .unwrap();
+                    for name in names {
+                        taken.insert(name, Some(decl.desc.clone()));
+                    }
+                    self.overrides.insert(decl.key, name);
+                }
+            }
+        }
+    }
+}
+
+fn module_name(module: &ModuleEnv) -> String {
+    module
+        .get_name()
+        .display(module.env.symbol_pool())
+        .to_string()
+}
+
+/// Describes a declaration for diagnostics, including its source location if available.
+fn decl_desc(env: &GlobalEnv, desc: String, loc: &Loc) -> String {
+    match MoveLoc::new(env, loc) {
+        Some(loc) => format!("{} at {}:{}:{}", desc, loc.rel_file, loc.line, loc.column),
+        None => desc,
+    }
+}
+
+/// Returns the index of a positional struct field named `pos<i>` (or `<i>`).
+fn positional_field_index(name: &str) -> Option<usize> {
+    let digits = name.strip_prefix("pos").unwrap_or(name);
+    if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    digits.parse().ok()
+}
+
+/// Returns true if `strct` is a Move 2024 positional struct (e.g. `struct Wrapper(u64, ID)`).
+/// Positional fields are named `pos0`, `pos1`, .. in the model.
+///
+/// The model doesn't record whether a struct was declared positional, so this is inferred from the
+/// field names. A struct declared with named fields that are exactly `pos0`, `pos1`, .. in order
+/// can't be told apart from a positional one and is treated as positional. Since positional fields
+/// are keyed by those same names in BCS and JSON, only the generated field names are affected.
+pub fn is_positional_struct(strct: &StructEnv) -> bool {
+    let names = strct
+        .get_fields()
+        .map(|field| field.get_name().display(strct.symbol_pool()).to_string())
+        .collect::<Vec<_>>();
+    are_positional_field_names(&names)
+}
+
+/// Returns true if `names` are exactly `pos0`, `pos1`, .. (or `0`, `1`, ..) in declaration order.
+fn are_positional_field_names(names: &[String]) -> bool {
+    !names.is_empty()
+        && (names
+            .iter()
+            .enumerate()
+            .all(|(i, name)| *name == format!("pos{}", i))
+            || names
+                .iter()
+                .enumerate()
+                .all(|(i, name)| *name == i.to_string()))
+}
+
+/// Returns the key of a struct field in the values decoded from BCS and in the RPC JSON
+/// representation. Positional fields are keyed `pos<i>` regardless of how they're named in the
+/// model.
+pub fn move_field_key(strct: &StructEnv, field: &FieldEnv) -> String {
+    let name = field.get_name().display(strct.symbol_pool()).to_string();
+    if is_positional_struct(strct) {
+        if let Some(idx) = positional_field_index(&name) {
+            return format!("pos{}", idx);
+        }
+    }
+    name
+}
+
+fn is_valid_identifier(name: &str) -> bool {
+    let mut chars = name.chars();
+    match chars.next() {
+        Some(c) if c.is_ascii_alphabetic() || c == '_' || c == '$' => (),
+        _ => return false,
+    }
+    chars.all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$')
+}
+
+fn is_valid_dir_name(name: &str) -> bool {
+    !name.is_empty() && name != "." && name != ".." && !name.contains(['/', '\\'])
+}
+
+impl Default for Naming {
+    fn default() -> Self {
+        Naming {
+            config: NamingConfig::default(),
+            renames: BTreeMap::new(),
+            overrides: BTreeMap::new(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use move_symbol_pool::Symbol;
+
+    use super::*;
+
+    fn addr(s: &str) -> AccountAddress {
+        AccountAddress::from_hex_literal(s).unwrap()
+    }
+
+    fn naming(config: NamingConfig) -> Naming {
+        Naming::new(config, &Renames::new(), &BTreeMap::new()).unwrap()
+    }
+
+    #[test]
+    fn test_casing_parse() {
+        assert_eq!(Casing::parse("kebab").unwrap(), Casing::Kebab);
+        assert_eq!(Casing::parse("kebab-case").unwrap(), Casing::Kebab);
+        assert_eq!(Casing::parse("snake_case").unwrap(), Casing::Snake);
+        assert_eq!(Casing::parse("camelCase").unwrap(), Casing::Camel);
+        assert_eq!(Casing::parse("PascalCase").unwrap(), Casing::Pascal);
+        assert_eq!(Casing::parse("preserve").unwrap(), Casing::Preserve);
+        assert!(Casing::parse("Camel").is_err());
+    }
+
+    #[test]
+    fn test_casing_convert() {
+        let cases = [
+            (Casing::Kebab, "swap-a-to-b"),
+            (Casing::Snake, "swap_a_to_b"),
+            (Casing::Camel, "swapAToB"),
+            (Casing::Pascal, "SwapAToB"),
+            (Casing::Preserve, "swap_a_to_b"),
+        ];
+        for (casing, exp) in cases {
+            assert_eq!(casing.convert("swap_a_to_b", Case::Snake), exp);
+        }
+    }
+
+    #[test]
+    fn test_field_name() {
+        let cases = [
+            (Casing::Camel, ["myValue", "myValue"]),
+            (Casing::Snake, ["my_value", "my_value"]),
+            (Casing::Pascal, ["MyValue", "MyValue"]),
+            (Casing::Preserve, ["my_value", "myValue"]),
+        ];
+        for (fields, [exp_snake, exp_camel]) in cases {
+            let naming = naming(NamingConfig {
+                fields,
+                ..NamingConfig::default()
+            });
+            // Move fields can be in any case
+            assert_eq!(naming.field_name("my_value"), exp_snake);
+            assert_eq!(naming.field_name("myValue"), exp_camel);
+        }
+    }
+
+    #[test]
+    fn test_package_import_name() {
+        let cases = [
+            (Casing::Kebab, "move-stdlib"),
+            (Casing::Snake, "move_stdlib"),
+            (Casing::Camel, "moveStdlib"),
+            (Casing::Pascal, "MoveStdlib"),
+            (Casing::Preserve, "MoveStdlib"),
+        ];
+        for (directories, exp) in cases {
+            let naming = naming(NamingConfig {
+                directories,
+                ..NamingConfig::default()
+            });
+            assert_eq!(naming.package_import_name(Symbol::from("MoveStdlib")), exp);
+        }
+    }
+
+    #[test]
+    fn test_renames() {
+        let pkg_names = BTreeMap::from([
+            (addr("0xa1"), Symbol::from("AMM")),
+            (addr("0x2"), Symbol::from("Sui")),
+        ]);
+        let renames = Renames::from([
+            // package name as written in gen.toml
+            (
+                "AMM::pool::swap_a_to_b".to_string(),
+                "swapAForB".to_string(),
+            ),
+            // package import name
+            ("amm::pool::Pool".to_string(), "AmmPool".to_string()),
+            // address
+            ("0x2::coin::Coin".to_string(), "SuiCoin".to_string()),
+        ]);
+
+        let naming = Naming::new(NamingConfig::default(), &renames, &pkg_names).unwrap();
+        assert_eq!(
+            naming.renames,
+            BTreeMap::from([
+                (
+                    (addr("0xa1"), "pool".to_string(), "swap_a_to_b".to_string()),
+                    "swapAForB".to_string()
+                ),
+                (
+                    (addr("0xa1"), "pool".to_string(), "Pool".to_string()),
+                    "AmmPool".to_string()
+                ),
+                (
+                    (addr("0x2"), "coin".to_string(), "Coin".to_string()),
+                    "SuiCoin".to_string()
+                ),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_invalid_renames() {
+        let pkg_names = BTreeMap::from([(addr("0xa1"), Symbol::from("AMM"))]);
+        let new = |key: &str| {
+            let renames = Renames::from([(key.to_string(), "name".to_string())]);
+            Naming::new(NamingConfig::default(), &renames, &pkg_names)
+        };
+
+        assert!(new("AMM::pool").is_err());
+        assert!(new("AMM::pool::swap::extra").is_err());
+        let err = new("deepbook::pool::swap").err().unwrap();
+        assert!(
+            err.to_string().contains("\"deepbook\" is not listed"),
+            "{}",
+            err
+        );
+    }
+
+    fn decl(name: &str, base: &str, suffix: &str) -> Decl {
+        Decl {
+            key: NameKey::Function(addr("0xa1"), "pool".to_string(), name.to_string()),
+            move_name: name.to_string(),
+            desc: format!("function `pool::{}`", name),
+            span: None,
+            base: base.to_string(),
+            suffix: suffix.to_string(),
+        }
+    }
+
+    /// Resolves the names of functions `swap_a_to_b`, `swapAToB` and `new` in a scope where
+    /// `Transaction` is reserved and each function also generates a `<Name>Args` name.
+    fn resolve(on_collision: OnCollision) -> (Naming, Vec<Diagnostic>) {
+        let mut naming = naming(NamingConfig {
+            on_collision,
+            ..NamingConfig::default()
+        });
+        let decls = vec![
+            decl("swapAToB", "swapAToB", ""),
+            decl("swap_a_to_b", "swapAToB", ""),
+            decl("new", "Transaction", ""),
+        ];
+        let mut diags = vec![];
+        naming.resolve_scope(
+            &addr("0xa1"),
+            "module 0xa1::pool",
+            decls,
+            &|name| vec![name.to_string(), format!("{}Args", name)],
+            &["Transaction"],
+            is_valid_identifier,
+            &mut diags,
+        );
+        (naming, diags)
+    }
+
+    fn override_of(naming: &Naming, name: &str) -> Option<String> {
+        naming
+            .overrides
+            .get(&decl(name, "", "").key)
+            .map(String::to_string)
+    }
+
+    #[test]
+    fn test_resolve_scope_error() {
+        let (naming, diags) = resolve(OnCollision::Error);
+
+        // declarations are resolved in order of their Move names
+        let messages = diags
+            .iter()
+            .map(|diag| (diag.code.as_str(), diag.message.as_str()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            messages,
+            vec![
+                (
+                    "naming::collision",
+                    "name collision in module 0xa1::pool: `Transaction` is generated for function `pool::new`"
+                ),
+                (
+                    "naming::collision",
+                    "name collision in module 0xa1::pool: `swapAToB` is generated for function `pool::swap_a_to_b`"
+                ),
+                (
+                    "naming::collision",
+                    "name collision in module 0xa1::pool: `swapAToBArgs` is generated for function `pool::swap_a_to_b`"
+                ),
+            ]
+        );
+        assert_eq!(
+            diags[0].notes,
+            vec!["which clashes with a reserved word or imported name".to_string()]
+        );
+        assert_eq!(
+            diags[2].notes,
+            vec!["which clashes with function `pool::swapAToB`".to_string()]
+        );
+        assert!(naming.overrides.is_empty());
+    }
+
+    #[test]
+    fn test_resolve_scope_suffix() {
+        let (naming, diags) = resolve(OnCollision::Suffix);
+
+        assert!(diags.is_empty());
+        assert_eq!(override_of(&naming, "swapAToB"), None);
+        assert_eq!(
+            override_of(&naming, "new"),
+            Some("Transaction1".to_string())
+        );
+        assert_eq!(
+            override_of(&naming, "swap_a_to_b"),
+            Some("swapAToB1".to_string())
+        );
+    }
+
+    #[test]
+    fn test_resolve_scope_suffix_goes_before_the_decl_suffix() {
+        let mut naming = naming(NamingConfig {
+            on_collision: OnCollision::Suffix,
+            ..NamingConfig::default()
+        });
+        let mut diags = vec![];
+        naming.resolve_scope(
+            &addr("0xa1"),
+            "module 0xa1::pool",
+            vec![decl("swap", "Swap", "Args"), decl("swap_", "Swap", "Args")],
+            &|name| vec![name.to_string()],
+            &[],
+            is_valid_identifier,
+            &mut diags,
+        );
+
+        assert!(diags.is_empty());
+        assert_eq!(override_of(&naming, "swap_"), Some("Swap1Args".to_string()));
+    }
+
+    #[test]
+    fn test_resolve_scope_invalid_name() {
+        for on_collision in [OnCollision::Error, OnCollision::Suffix] {
+            let mut naming = naming(NamingConfig {
+                on_collision,
+                ..NamingConfig::default()
+            });
+            let mut diags = vec![];
+            naming.resolve_scope(
+                &addr("0xa1"),
+                "module 0xa1::pool",
+                vec![decl("swap", "swap-a", "")],
+                &|name| vec![name.to_string()],
+                &[],
+                is_valid_identifier,
+                &mut diags,
+            );
+
+            assert_eq!(diags.len(), 1);
+            assert_eq!(diags[0].code, "naming::invalid_name");
+            assert!(naming.overrides.is_empty());
+        }
+    }
+
+    #[test]
+    fn test_positional_field_index() {
+        assert_eq!(positional_field_index("pos0"), Some(0));
+        assert_eq!(positional_field_index("pos12"), Some(12));
+        assert_eq!(positional_field_index("1"), Some(1));
+        assert_eq!(positional_field_index("pos"), None);
+        assert_eq!(positional_field_index("position"), None);
+        assert_eq!(positional_field_index("value"), None);
+    }
+
+    #[test]
+    fn test_are_positional_field_names() {
+        let names = |names: &[&str]| names.iter().map(|n| n.to_string()).collect::<Vec<_>>();
+        assert!(are_positional_field_names(&names(&[
+            "pos0", "pos1", "pos2"
+        ])));
+        assert!(are_positional_field_names(&names(&["0", "1"])));
+        assert!(!are_positional_field_names(&names(&[])));
+        assert!(!are_positional_field_names(&names(&["pos1", "pos0"])));
+        assert!(!are_positional_field_names(&names(&["pos0", "pos2"])));
+        assert!(!are_positional_field_names(&names(&["pos0", "1"])));
+        assert!(!are_positional_field_names(&names(&["pos00"])));
+        assert!(!are_positional_field_names(&names(&["pos0", "value"])));
+    }
+}
@@ -1,39 +1,109 @@
 use std::{
     collections::{BTreeMap, BTreeSet},
+    future::Future,
     sync::Arc,
+    time::Duration,
 };
 
 use anyhow::{anyhow, Result};
+use futures::stream::{self, StreamExt};
 use move_core_types::account_address::AccountAddress;
-use sui_json_rpc_types::{SuiObjectDataOptions, SuiObjectResponse, SuiRawData, SuiRawMovePackage};
-use sui_sdk::{apis::ReadApi, types::base_types::ObjectID};
-use tokio::sync::RwLock;
+use sui_json_rpc_types::SuiRawMovePackage;
+use sui_sdk::types::base_types::{ObjectID, SequenceNumber};
+use tokio::sync::{Mutex, RwLock};
+use tokio::time::Instant;
+
+use crate::package_source::PackageSource;
+
+/// Options for fetching packages from the package source (`[config.fetch]`).
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct FetchConfig {
+    /// Maximum number of packages fetched in a single request.
+    pub chunk_size: usize,
+    /// Maximum number of requests in flight at the same time.
+    pub concurrency: usize,
+    /// Number of times a failed request is retried.
+    pub retries: u32,
+    /// Delay before the first retry in milliseconds, doubled with every further retry.
+    pub retry_delay_ms: u64,
+    /// Maximum number of requests started per second. Unlimited if `None`.
+    pub requests_per_second: Option<u32>,
+}
+
+impl Default for FetchConfig {
+    fn default() -> Self {
+        Self {
+            chunk_size: 50,
+            concurrency: 4,
+            retries: 3,
+            retry_delay_ms: 500,
+            requests_per_second: None,
+        }
+    }
+}
 
 pub struct PackageCache<'a> {
-    rpc_client: &'a ReadApi,
+    source: &'a dyn PackageSource,
+    config: FetchConfig,
     cache: Arc<RwLock<BTreeMap<ObjectID, SuiRawMovePackage>>>,
+    // the earliest time the next request can be started at when rate limiting
+    next_request_at: Arc<Mutex<Instant>>,
 }
 
 impl<'a> PackageCache<'a> {
-    pub fn new(rpc_client: &'a ReadApi) -> Self {
+    pub fn new(source: &'a dyn PackageSource, config: FetchConfig) -> Self {
         Self {
-            rpc_client,
+            source,
+            config,
             cache: Arc::new(RwLock::new(BTreeMap::new())),
+            next_request_at: Arc::new(Mutex::new(Instant::now())),
         }
     }
 
-    fn get_package_from_result(&self, obj_read: SuiObjectResponse) -> Result<SuiRawMovePackage> {
-        let obj = obj_read
-            .into_object()
-            .map_err(|e| anyhow!("package object does not exist or was deleted: {}", e))?;
-        let object_id = obj.object_id;
-        let obj = obj.bcs.ok_or_else(|| anyhow!("bcs field not found"))?;
-        match obj {
-            SuiRawData::Package(pkg) => Ok(pkg),
-            SuiRawData::MoveObject(_) => Err(anyhow!(
-                "dependency ID contains a Sui object, not a Move package: {}",
-                object_id
-            )),
+    /// Waits until a request can be started without exceeding `requests_per_second`.
+    async fn wait_for_rate_limit(&self) {
+        let Some(rps) = self.config.requests_per_second.filter(|rps| *rps > 0) else {
+            return;
+        };
+        let mut next_request_at = self.next_request_at.lock().await;
+        let start = (*next_request_at).max(Instant::now());
+        *next_request_at = start + Duration::from_secs(1) / rps;
+        drop(next_request_at);
+
+        tokio::time::sleep_until(start).await;
+    }
+
+    /// Sends a request to the source, retrying it with exponential backoff when it fails.
+    async fn request<T, F, Fut>(&self, f: F) -> Result<T>
+    where
+        F: Fn() -> Fut,
+        Fut: Future<Output = Result<T>>,
+    {
+        let mut attempt = 0;
+        loop {
+            self.wait_for_rate_limit().await;
+            match f().await {
+                Ok(res) => return Ok(res),
+                Err(_) if attempt < self.config.retries => {
+                    let delay = self.config.retry_delay_ms << attempt.min(16);
+                    tokio::time::sleep(Duration::from_millis(delay)).await;
+                    attempt += 1;
+                }
+                Err(e) => {
+                    return Err(e.context(format!("request failed after {} attempts", attempt + 1)))
+                }
+            }
+        }
+    }
+
+    /// Fetches a chunk of packages. If the request fails, the error is returned for each of them.
+    async fn fetch_chunk(&self, ids: Vec<ObjectID>) -> Vec<Result<SuiRawMovePackage>> {
+        match self.request(|| self.source.get_packages(ids.clone())).await {
+            Ok(res) => res,
+            Err(e) => {
+                let msg = format!("{:#}", e);
+                ids.iter().map(|_| Err(anyhow!("{}", msg))).collect()
+            }
         }
     }
 
@@ -62,15 +132,15 @@ impl<'a> PackageCache<'a> {
 
         let to_fetch = to_fetch.into_iter().collect::<Vec<_>>();
 
-        let fetch_res = self
-            .rpc_client
-            .multi_get_object_with_options(to_fetch.clone(), SuiObjectDataOptions::new().with_bcs())
-            .await?
+        let fetch_res = stream::iter(to_fetch.chunks(self.config.chunk_size.max(1)))
+            .map(|chunk| self.fetch_chunk(chunk.to_vec()))
+            .buffered(self.config.concurrency.max(1))
+            .collect::<Vec<_>>()
+            .await
             .into_iter()
-            .map(|obj_read| self.get_package_from_result(obj_read))
-            .collect::<Vec<Result<_>>>();
+            .flatten();
 
-        res_map.extend(to_fetch.into_iter().zip(fetch_res.into_iter()));
+        res_map.extend(to_fetch.iter().copied().zip(fetch_res));
 
         let mut cache = self.cache.write().await;
         for (id, res) in res_map.iter() {
@@ -82,9 +152,13 @@ impl<'a> PackageCache<'a> {
 
         let ret = ids
             .iter()
-            .map(|id| match res_map.get(id).unwrap() {
-                Ok(pkg) => Ok(pkg.clone()),
-                Err(e) => Err(anyhow!("error fetching package: {}", e)),
+            .map(|id| match res_map.get(id) {
+                Some(Ok(pkg)) => Ok(pkg.clone()),
+                Some(Err(e)) => Err(anyhow!("error fetching package {}: {:#}", id, e)),
+                None => Err(anyhow!(
+                    "error fetching package {}: missing from response",
+                    id
+                )),
             })
             .collect();
 
@@ -94,13 +168,114 @@ impl<'a> PackageCache<'a> {
     pub async fn get(&mut self, addr: AccountAddress) -> Result<SuiRawMovePackage> {
         self.get_multi(vec![addr]).await?.pop().unwrap()
     }
+
+    /// Returns all versions of the package at `addr` if the source can list them directly.
+    pub async fn get_versions(
+        &self,
+        addr: AccountAddress,
+    ) -> Result<Option<BTreeMap<AccountAddress, SequenceNumber>>> {
+        self.request(|| self.source.get_package_versions(addr))
+            .await
+            .map_err(|e| {
+                anyhow!(
+                    "error fetching versions of package {}: {:#}",
+                    addr.to_hex_literal(),
+                    e
+                )
+            })
+    }
 }
 
 impl Clone for PackageCache<'_> {
     fn clone(&self) -> Self {
         Self {
-            rpc_client: self.rpc_client,
+            source: self.source,
+            config: self.config.clone(),
             cache: self.cache.clone(),
+            next_request_at: self.next_request_at.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    use anyhow::bail;
+    use async_trait::async_trait;
+
+    use super::*;
+
+    /// Fails the first `failures` requests, then returns an error per package for the rest.
+    struct FlakySource {
+        failures: u32,
+        requests: AtomicU32,
+        chunk_sizes: std::sync::Mutex<Vec<usize>>,
+    }
+
+    #[async_trait]
+    impl PackageSource for FlakySource {
+        async fn get_packages(&self, ids: Vec<ObjectID>) -> Result<Vec<Result<SuiRawMovePackage>>> {
+            if self.requests.fetch_add(1, Ordering::SeqCst) < self.failures {
+                bail!("connection reset");
+            }
+            self.chunk_sizes.lock().unwrap().push(ids.len());
+            Ok(ids.iter().map(|_| Err(anyhow!("not found"))).collect())
+        }
+    }
+
+    fn source(failures: u32) -> FlakySource {
+        FlakySource {
+            failures,
+            requests: AtomicU32::new(0),
+            chunk_sizes: std::sync::Mutex::new(vec![]),
+        }
+    }
+
+    fn config() -> FetchConfig {
+        FetchConfig {
+            chunk_size: 2,
+            retry_delay_ms: 1,
+            ..FetchConfig::default()
+        }
+    }
+
+    fn addrs(count: u64) -> Vec<AccountAddress> {
+        (1..=count)
+            .map(|i| AccountAddress::from_hex_literal(&format!("{:#x}", i)).unwrap())
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_get_multi_chunks_and_retries() {
+        let source = source(2);
+        let mut cache = PackageCache::new(&source, config());
+
+        let res = cache.get_multi(addrs(5)).await.unwrap();
+
+        assert_eq!(res.len(), 5);
+        let mut chunk_sizes = source.chunk_sizes.lock().unwrap().clone();
+        chunk_sizes.sort();
+        assert_eq!(chunk_sizes, vec![1, 2, 2]);
+        let err = res[0].as_ref().unwrap_err().to_string();
+        let id = ObjectID::from(addrs(1)[0]).to_string();
+        assert!(err.contains(&id) && err.contains("not found"), "{}", err);
+    }
+
+    #[tokio::test]
+    async fn test_get_multi_reports_failed_ids_after_retries() {
+        let source = source(u32::MAX);
+        let mut cache = PackageCache::new(&source, config());
+
+        let res = cache.get_multi(addrs(3)).await.unwrap();
+
+        // 2 chunks, each tried 1 + `retries` times
+        assert_eq!(source.requests.load(Ordering::SeqCst), 8);
+        for (res, addr) in res.iter().zip(addrs(3)) {
+            let err = res.as_ref().unwrap_err().to_string();
+            assert!(err.contains(&ObjectID::from(addr).to_string()), "{}", err);
+            assert!(err.contains("failed after 4 attempts"), "{}", err);
+            assert!(err.contains("connection reset"), "{}", err);
         }
     }
 }
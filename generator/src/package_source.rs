@@ -0,0 +1,453 @@
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+use anyhow::{anyhow, bail, Context, Result};
+use async_trait::async_trait;
+use base64::Engine;
+use move_core_types::account_address::AccountAddress;
+use serde::{de::DeserializeOwned, Deserialize};
+use serde_json::json;
+use sui_json_rpc_types::{SuiObjectDataOptions, SuiObjectResponse, SuiRawData, SuiRawMovePackage};
+use sui_sdk::types::base_types::{ObjectID, SequenceNumber};
+use sui_sdk::types::move_package::MovePackage;
+use sui_sdk::SuiClient;
+
+/// Backend from which on-chain packages are fetched, selected with `source` in `[config]`.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+pub enum PackageSourceKind {
+    /// The fullnode JSON-RPC API at `rpc`.
+    #[default]
+    JsonRpc,
+    /// The GraphQL RPC at `graphql`.
+    GraphQl,
+    /// Packages stored as JSON files in the `fixtures` directory.
+    Fixtures,
+}
+
+impl PackageSourceKind {
+    pub fn parse(s: &str) -> Result<Self> {
+        match s {
+            "json-rpc" => Ok(PackageSourceKind::JsonRpc),
+            "graphql" => Ok(PackageSourceKind::GraphQl),
+            "fixtures" => Ok(PackageSourceKind::Fixtures),
+            _ => bail!(
+                "Invalid package source \"{}\". Expected one of \"json-rpc\", \"graphql\" or \"fixtures\"",
+                s
+            ),
+        }
+    }
+}
+
+/// A source of on-chain packages. Packages fetched through it are cached by `PackageCache`.
+#[async_trait]
+pub trait PackageSource: Send + Sync {
+    /// Fetches the packages at the given IDs. The results are in the same order as `ids` and an
+    /// error for one package doesn't fail the others.
+    async fn get_packages(&self, ids: Vec<ObjectID>) -> Result<Vec<Result<SuiRawMovePackage>>>;
+
+    /// Returns the IDs and versions of all versions of the package at `id`, if the source can
+    /// look them up without fetching each version. Otherwise the versions are found by fetching
+    /// the packages referenced in type origins.
+    async fn get_package_versions(
+        &self,
+        _id: AccountAddress,
+    ) -> Result<Option<BTreeMap<AccountAddress, SequenceNumber>>> {
+        Ok(None)
+    }
+}
+
+/// Fetches packages from the fullnode JSON-RPC API.
+pub struct JsonRpcSource {
+    client: SuiClient,
+}
+
+impl JsonRpcSource {
+    pub fn new(client: SuiClient) -> Self {
+        Self { client }
+    }
+
+    fn get_package_from_result(obj_read: SuiObjectResponse) -> Result<SuiRawMovePackage> {
+        let obj = obj_read
+            .into_object()
+            .map_err(|e| anyhow!("package object does not exist or was deleted: {}", e))?;
+        let object_id = obj.object_id;
+        let obj = obj.bcs.ok_or_else(|| anyhow!("bcs field not found"))?;
+        match obj {
+            SuiRawData::Package(pkg) => Ok(pkg),
+            SuiRawData::MoveObject(_) => Err(anyhow!(
+                "dependency ID contains a Sui object, not a Move package: {}",
+                object_id
+            )),
+        }
+    }
+}
+
+#[async_trait]
+impl PackageSource for JsonRpcSource {
+    async fn get_packages(&self, ids: Vec<ObjectID>) -> Result<Vec<Result<SuiRawMovePackage>>> {
+        Ok(self
+            .client
+            .read_api()
+            .multi_get_object_with_options(ids, SuiObjectDataOptions::new().with_bcs())
+            .await?
+            .into_iter()
+            .map(Self::get_package_from_result)
+            .collect())
+    }
+}
+
+/// Maximum number of packages fetched in a single GraphQL query.
+const GRAPHQL_PACKAGES_PER_QUERY: usize = 20;
+
+/// Fetches packages from the GraphQL RPC. Packages are fetched in batches of
+/// `GRAPHQL_PACKAGES_PER_QUERY` per query, and all versions of a package are listed with
+/// `packageVersions` instead of fetching every version referenced in its type origins.
+pub struct GraphQlSource {
+    client: reqwest::Client,
+    url: String,
+}
+
+#[derive(Deserialize)]
+struct GraphQlResponse<T> {
+    data: Option<T>,
+    errors: Option<Vec<GraphQlError>>,
+}
+
+#[derive(Deserialize)]
+struct GraphQlError {
+    message: String,
+    /// Path of the response field the error is for, starting with the field's alias if any.
+    #[serde(default)]
+    path: Vec<serde_json::Value>,
+}
+
+impl GraphQlError {
+    fn join_messages<'a>(errors: impl IntoIterator<Item = &'a GraphQlError>) -> String {
+        errors
+            .into_iter()
+            .map(|e| e.message.as_str())
+            .collect::<Vec<_>>()
+            .join("; ")
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct GraphQlPackage {
+    package_bcs: Option<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct GraphQlPackageVersions {
+    package_versions: GraphQlConnection<GraphQlPackageVersion>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct GraphQlConnection<T> {
+    nodes: Vec<T>,
+    page_info: GraphQlPageInfo,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct GraphQlPageInfo {
+    has_next_page: bool,
+    end_cursor: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct GraphQlPackageVersion {
+    address: String,
+    version: u64,
+}
+
+const PACKAGE_VERSIONS_QUERY: &str = r#"
+query ($address: SuiAddress!, $after: String) {
+  packageVersions(address: $address, first: 50, after: $after) {
+    nodes { address version }
+    pageInfo { hasNextPage endCursor }
+  }
+}
+"#;
+
+impl GraphQlSource {
+    pub fn new(url: String) -> Self {
+        Self {
+            client: reqwest::Client::new(),
+            url,
+        }
+    }
+
+    /// Sends a query and returns the response as is, i.e. with the data of the fields that were
+    /// resolved and the errors of the ones that weren't.
+    async fn query_partial<T: DeserializeOwned>(
+        &self,
+        query: &str,
+        variables: serde_json::Value,
+    ) -> Result<GraphQlResponse<T>> {
+        Ok(self
+            .client
+            .post(&self.url)
+            .json(&json!({ "query": query, "variables": variables }))
+            .send()
+            .await
+            .with_context(|| format!("GraphQL request to {} failed", self.url))?
+            .error_for_status()?
+            .json()
+            .await?)
+    }
+
+    async fn query<T: DeserializeOwned>(
+        &self,
+        query: &str,
+        variables: serde_json::Value,
+    ) -> Result<T> {
+        let res: GraphQlResponse<T> = self.query_partial(query, variables).await?;
+        if let Some(errors) = res.errors.filter(|errors| !errors.is_empty()) {
+            bail!(
+                "GraphQL query failed: {}",
+                GraphQlError::join_messages(&errors)
+            );
+        }
+        res.data
+            .ok_or_else(|| anyhow!("GraphQL response doesn't contain data"))
+    }
+
+    fn decode_package(id: &ObjectID, pkg: Option<GraphQlPackage>) -> Result<SuiRawMovePackage> {
+        let bcs_b64 = pkg
+            .and_then(|pkg| pkg.package_bcs)
+            .ok_or_else(|| anyhow!("package {} does not exist", id))?;
+        let bytes = base64::engine::general_purpose::STANDARD.decode(bcs_b64)?;
+        let pkg: MovePackage =
+            bcs::from_bytes(&bytes).with_context(|| format!("unable to decode package {}", id))?;
+        Ok(SuiRawMovePackage {
+            id: pkg.id(),
+            version: pkg.version(),
+            module_map: pkg.serialized_module_map().clone(),
+            type_origin_table: pkg.type_origin_table().clone(),
+            linkage_table: pkg.linkage_table().clone(),
+        })
+    }
+}
+
+#[async_trait]
+impl PackageSource for GraphQlSource {
+    async fn get_packages(&self, ids: Vec<ObjectID>) -> Result<Vec<Result<SuiRawMovePackage>>> {
+        let mut res = Vec::with_capacity(ids.len());
+        for chunk in ids.chunks(GRAPHQL_PACKAGES_PER_QUERY) {
+            // fetch the chunk in a single query with an alias per package
+            let fields = chunk
+                .iter()
+                .enumerate()
+                .map(|(idx, id)| format!("p{}: package(address: \"{}\") {{ packageBcs }}", idx, id))
+                .collect::<Vec<_>>()
+                .join("\n");
+            let query_res: Result<GraphQlResponse<BTreeMap<String, Option<GraphQlPackage>>>> = self
+                .query_partial(&format!("query {{\n{}\n}}", fields), json!({}))
+                .await;
+            let (data, errors) = match query_res {
+                Ok(query_res) => (query_res.data, query_res.errors.unwrap_or_default()),
+                Err(e) => {
+                    // the request itself failed, so none of the packages in the chunk were fetched
+                    for id in chunk {
+                        res.push(Err(anyhow!("unable to fetch package {}: {:#}", id, e)));
+                    }
+                    continue;
+                }
+            };
+            let mut data = data.unwrap_or_default();
+            // errors not tied to a package field (e.g. a malformed query) apply to all of them
+            let query_errors = errors
+                .iter()
+                .filter(|e| e.path.is_empty())
+                .collect::<Vec<_>>();
+
+            for (idx, id) in chunk.iter().enumerate() {
+                let alias = format!("p{}", idx);
+                let pkg_errors = errors
+                    .iter()
+                    .filter(|e| e.path.first().and_then(|p| p.as_str()) == Some(alias.as_str()))
+                    .collect::<Vec<_>>();
+                let pkg = data.remove(&alias).flatten();
+                if pkg.is_none() && !pkg_errors.is_empty() {
+                    res.push(Err(anyhow!(
+                        "unable to fetch package {}: {}",
+                        id,
+                        GraphQlError::join_messages(pkg_errors)
+                    )));
+                } else if pkg.is_none() && !query_errors.is_empty() {
+                    res.push(Err(anyhow!(
+                        "unable to fetch package {}: GraphQL query failed: {}",
+                        id,
+                        GraphQlError::join_messages(query_errors.iter().copied())
+                    )));
+                } else {
+                    res.push(Self::decode_package(id, pkg));
+                }
+            }
+        }
+        Ok(res)
+    }
+
+    async fn get_package_versions(
+        &self,
+        id: AccountAddress,
+    ) -> Result<Option<BTreeMap<AccountAddress, SequenceNumber>>> {
+        let mut versions = BTreeMap::new();
+        let mut after: Option<String> = None;
+        loop {
+            let data: GraphQlPackageVersions = self
+                .query(
+                    PACKAGE_VERSIONS_QUERY,
+                    json!({ "address": id.to_hex_literal(), "after": after }),
+                )
+                .await
+                .with_context(|| format!("unable to fetch versions of package {}", id))?;
+            for node in data.package_versions.nodes {
+                versions.insert(
+                    AccountAddress::from_hex_literal(&node.address)?,
+                    node.version.into(),
+                );
+            }
+            if !data.package_versions.page_info.has_next_page {
+                break;
+            }
+            after = data.package_versions.page_info.end_cursor;
+        }
+        Ok(Some(versions))
+    }
+}
+
+/// Reads packages from a directory of JSON files named by package ID (e.g. `0x2.json`), each
+/// holding a package in the format returned by the JSON-RPC API. Package version listings are read
+/// from `versions/<package ID>.json` if present. Used to run without a network, e.g. with fixtures
+/// recorded by `RecordingSource`.
+pub struct FixtureSource {
+    dir: PathBuf,
+}
+
+impl FixtureSource {
+    pub fn new(dir: PathBuf) -> Self {
+        Self { dir }
+    }
+
+    /// Path of the fixture file for the package at `id`.
+    pub fn package_path(&self, id: &ObjectID) -> PathBuf {
+        self.dir.join(format!(
+            "{}.json",
+            AccountAddress::from(*id).to_hex_literal()
+        ))
+    }
+
+    /// Path of the fixture file listing the versions of the package at `id`.
+    pub fn versions_path(&self, id: &AccountAddress) -> PathBuf {
+        self.dir
+            .join("versions")
+            .join(format!("{}.json", id.to_hex_literal()))
+    }
+
+    fn read_package(&self, id: &ObjectID) -> Result<SuiRawMovePackage> {
+        let path = self.package_path(id);
+        let contents = std::fs::read_to_string(&path).with_context(|| {
+            format!("package {} not found in fixtures at {}", id, path.display())
+        })?;
+        serde_json::from_str(&contents)
+            .with_context(|| format!("unable to parse package fixture {}", path.display()))
+    }
+
+    pub fn write_package(&self, pkg: &SuiRawMovePackage) -> Result<()> {
+        let path = self.package_path(&pkg.id);
+        std::fs::create_dir_all(&self.dir)?;
+        std::fs::write(&path, serde_json::to_string_pretty(pkg)?)
+            .with_context(|| format!("unable to write package fixture {}", path.display()))
+    }
+
+    pub fn write_package_versions(
+        &self,
+        id: &AccountAddress,
+        versions: &BTreeMap<AccountAddress, SequenceNumber>,
+    ) -> Result<()> {
+        let path = self.versions_path(id);
+        let versions = versions
+            .iter()
+            .map(|(id, version)| (id.to_hex_literal(), version.value()))
+            .collect::<BTreeMap<_, _>>();
+        std::fs::create_dir_all(self.dir.join("versions"))?;
+        std::fs::write(&path, serde_json::to_string_pretty(&versions)?).with_context(|| {
+            format!(
+                "unable to write package versions fixture {}",
+                path.display()
+            )
+        })
+    }
+}
+
+#[async_trait]
+impl PackageSource for FixtureSource {
+    async fn get_packages(&self, ids: Vec<ObjectID>) -> Result<Vec<Result<SuiRawMovePackage>>> {
+        Ok(ids.iter().map(|id| self.read_package(id)).collect())
+    }
+
+    async fn get_package_versions(
+        &self,
+        id: AccountAddress,
+    ) -> Result<Option<BTreeMap<AccountAddress, SequenceNumber>>> {
+        let path = self.versions_path(&id);
+        if !path.is_file() {
+            return Ok(None);
+        }
+        let contents = std::fs::read_to_string(&path)?;
+        let versions: BTreeMap<String, u64> =
+            serde_json::from_str(&contents).with_context(|| {
+                format!(
+                    "unable to parse package versions fixture {}",
+                    path.display()
+                )
+            })?;
+        versions
+            .into_iter()
+            .map(|(id, version)| Ok((AccountAddress::from_hex_literal(&id)?, version.into())))
+            .collect::<Result<_>>()
+            .map(Some)
+    }
+}
+
+/// Wraps another source and writes the packages and package version listings fetched through it
+/// to a fixtures directory (`--record`), so that they can be served by `FixtureSource` later.
+pub struct RecordingSource {
+    inner: Box<dyn PackageSource>,
+    fixtures: FixtureSource,
+}
+
+impl RecordingSource {
+    pub fn new(inner: Box<dyn PackageSource>, dir: PathBuf) -> Self {
+        Self {
+            inner,
+            fixtures: FixtureSource::new(dir),
+        }
+    }
+}
+
+#[async_trait]
+impl PackageSource for RecordingSource {
+    async fn get_packages(&self, ids: Vec<ObjectID>) -> Result<Vec<Result<SuiRawMovePackage>>> {
+        let res = self.inner.get_packages(ids).await?;
+        for pkg in res.iter().flatten() {
+            self.fixtures.write_package(pkg)?;
+        }
+        Ok(res)
+    }
+
+    async fn get_package_versions(
+        &self,
+        id: AccountAddress,
+    ) -> Result<Option<BTreeMap<AccountAddress, SequenceNumber>>> {
+        let versions = self.inner.get_package_versions(id).await?;
+        if let Some(versions) = &versions {
+            self.fixtures.write_package_versions(&id, versions)?;
+        }
+        Ok(versions)
+    }
+}
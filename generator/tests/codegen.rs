@@ -0,0 +1,324 @@
+//! Tests of the code generated for the fixture packages in `tests/fixtures`, run with different
+//! configs. Each test checks the parts of the generated code that the config affects.
+
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+use anyhow::Result;
+use sui_client_gen::diagnostics::{Diagnostics, MessageFormat};
+use sui_client_gen::manifest::{parse_gen_manifest_from_file, Config};
+use sui_client_gen::naming::OnCollision;
+use sui_client_gen::package_source::FixtureSource;
+use sui_client_gen::{GeneratedFiles, Generator};
+
+fn manifest_path() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("fixtures")
+        .join("codegen")
+        .join("gen.toml")
+}
+
+/// A writer whose output can be read after it's been moved into `Diagnostics`.
+#[derive(Clone, Default)]
+struct Buffer(Arc<Mutex<Vec<u8>>>);
+
+impl Write for Buffer {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.lock().unwrap().write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Runs the generator over the fixture `gen.toml` with the config adjusted by `configure`.
+/// Returns the generated files and the diagnostics, one JSON record each.
+async fn generate(
+    configure: impl FnOnce(&mut Config),
+) -> (Result<GeneratedFiles>, Vec<serde_json::Value>) {
+    let mut manifest = parse_gen_manifest_from_file(&manifest_path()).unwrap();
+    configure(manifest.config.get_or_insert_with(Config::default));
+
+    let empty = tempfile::tempdir().unwrap();
+    let source = FixtureSource::new(empty.path().to_path_buf());
+    let buf = Buffer::default();
+    let mut diags = Diagnostics::with_writer(MessageFormat::Json, false, Box::new(buf.clone()));
+
+    let files = Generator::new(manifest, &source)
+        .manifest_path(manifest_path())
+        .diagnostics(&mut diags)
+        .generate()
+        .await
+        .map(|(files, _)| files);
+
+    let output = String::from_utf8(buf.0.lock().unwrap().clone()).unwrap();
+    let diags = output
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+    (files, diags)
+}
+
+/// Returns a generated file of the `features` package.
+fn features_file<'a>(files: &'a GeneratedFiles, path: &str) -> &'a str {
+    files
+        .get(Path::new("features").join(path))
+        .unwrap_or_else(|| panic!("features/{} wasn't generated", path))
+}
+
+/// Strips the whitespace from generated code, which is left to the formatter, and normalizes
+/// string quotes so that the tests can match on code fragments.
+fn compact(code: &str) -> String {
+    code.split_whitespace()
+        .collect::<String>()
+        .replace('\'', "\"")
+}
+
+#[tokio::test]
+async fn test_name_collisions_error() {
+    let (files, diags) = generate(|config| config.naming.on_collision = OnCollision::Error).await;
+
+    let err = files.err().unwrap();
+    assert!(err.to_string().contains("naming issue(s)"), "{}", err);
+
+    let collisions = diags
+        .iter()
+        .filter(|diag| diag["code"] == "naming::collision")
+        .filter_map(|diag| diag["message"].as_str())
+        .collect::<Vec<_>>();
+    for name in ["`swapAToB`", "`PoolFields`", "`Reified`", "`myValue`"] {
+        assert!(
+            collisions.iter().any(|msg| msg.contains(name)),
+            "no collision reported for {}: {:?}",
+            name,
+            collisions
+        );
+    }
+}
+
+#[tokio::test]
+async fn test_name_collisions_suffix() {
+    let (files, diags) = generate(|config| config.naming.on_collision = OnCollision::Suffix).await;
+    let files = files.unwrap();
+    assert!(diags.iter().all(|diag| diag["severity"] != "error"));
+
+    // the declaration that comes first by Move name keeps its name
+    let functions = features_file(&files, "collisions/functions.ts");
+    assert!(functions.contains("export function swapAToB("));
+    assert!(functions.contains("export function swapAToB1("));
+
+    let structs = features_file(&files, "collisions/structs.ts");
+    assert!(structs.contains("export class Pool "));
+    assert!(structs.contains("export class PoolFields1 "));
+    assert!(structs.contains("export class Reified1 "));
+    assert!(structs.contains("readonly myValue:"));
+    assert!(structs.contains("readonly myValue1:"));
+}
+
+#[tokio::test]
+async fn test_positional_struct_fields() {
+    let (files, _) = generate(|config| config.naming.on_collision = OnCollision::Suffix).await;
+    let files = files.unwrap();
+    let structs = compact(features_file(&files, "positional/structs.ts"));
+
+    // the TS fields are named by their index..
+    assert!(structs.contains("readonly_0:ToField<"), "{}", structs);
+    assert!(structs.contains("readonly_1:ToField<ID>"), "{}", structs);
+    // ..while BCS and the RPC JSON representation key them `pos<i>`, so decoding goes through
+    // those keys
+    assert!(
+        structs.contains("pos0:bcs.u64(),pos1:ID.bcs"),
+        "{}",
+        structs
+    );
+    assert!(structs.contains("fields.pos0)"), "{}", structs);
+    assert!(structs.contains("fields.pos1)"), "{}", structs);
+    assert!(structs.contains("item.fields.pos0)"), "{}", structs);
+    assert!(!structs.contains("fields._0"), "{}", structs);
+    // ..and encoding reads the TS fields
+    assert!(structs.contains("this._0)"), "{}", structs);
+    assert!(structs.contains("this._1)"), "{}", structs);
+
+    // the generic struct keys its type parameter field the same way
+    assert!(structs.contains("exportclassTagged<"), "{}", structs);
+    assert!(
+        structs.contains("pos0:T,pos1:bcs.vector(bcs.u8())"),
+        "{}",
+        structs
+    );
+}
+
+#[tokio::test]
+async fn test_return_value_types() {
+    let (files, _) = generate(|config| config.naming.on_collision = OnCollision::Suffix).await;
+    let files = files.unwrap();
+    let functions = compact(features_file(&files, "cross-module/functions.ts"));
+
+    // a single return value is tagged with its type, function type parameters being `any`
+    assert!(
+        functions.contains("})asMoveResult<Wrapper<any>>"),
+        "{}",
+        functions
+    );
+    assert!(
+        functions.contains("})asMoveResult<Pair<\"u64\",\"address\">>"),
+        "{}",
+        functions
+    );
+    // multiple return values are split into a tuple
+    assert!(
+        functions.contains(
+            "}),3)as[MoveResult<Vault<any>>,MoveResult<Coin<ToPhantom<SUI>>>,\
+            MoveResult<Pair<\"u64\",\"address\">>]"
+        ),
+        "{}",
+        functions
+    );
+
+    let functions = compact(features_file(&files, "vectors/functions.ts"));
+    assert!(
+        functions.contains("})asMoveResult<\"u64\">"),
+        "{}",
+        functions
+    );
+}
+
+#[tokio::test]
+async fn test_strict_object_args() {
+    let (files, _) = generate(|config| {
+        config.naming.on_collision = OnCollision::Suffix;
+        config.strict_object_args = true;
+    })
+    .await;
+    let files = files.unwrap();
+    let functions = compact(features_file(&files, "cross-module/functions.ts"));
+
+    // objects are typed by their struct class, with function type parameters as `any`
+    for arg in [
+        "vault:ObjectArg<Vault<any>>",
+        "coin:ObjectArg<Coin<ToPhantom<SUI>>>",
+        "wrapper:ObjectArg<Wrapper<any>>",
+    ] {
+        assert!(functions.contains(arg), "{} not in {}", arg, functions);
+    }
+    assert!(
+        !functions.contains("TransactionObjectInput"),
+        "{}",
+        functions
+    );
+
+    // structs without `key` can only be passed as results of previous calls
+    let functions = compact(features_file(&files, "generics/functions.ts"));
+    assert!(
+        functions.contains("pair:MoveResult<Pair<any,any>>"),
+        "{}",
+        functions
+    );
+
+    // key structs can be referenced by a typed ID
+    let structs = compact(features_file(&files, "cross-module/structs.ts"));
+    assert!(
+        structs.contains("get$objectId():string{returnthis.id"),
+        "{}",
+        structs
+    );
+    assert!(
+        structs
+            .contains("staticid<TextendsPhantomTypeArgument>(id:string):TypedObjectId<Wrapper<T>>"),
+        "{}",
+        structs
+    );
+}
+
+#[tokio::test]
+async fn test_loose_object_args() {
+    let (files, _) = generate(|config| config.naming.on_collision = OnCollision::Suffix).await;
+    let files = files.unwrap();
+    let functions = compact(features_file(&files, "cross-module/functions.ts"));
+
+    for arg in [
+        "vault:TransactionObjectInput",
+        "coin:TransactionObjectInput",
+        "wrapper:TransactionObjectInput",
+    ] {
+        assert!(functions.contains(arg), "{} not in {}", arg, functions);
+    }
+    assert!(!functions.contains("ObjectArg<"), "{}", functions);
+}
+
+#[tokio::test]
+async fn test_system_object_params() {
+    let (files, _) = generate(|config| config.naming.on_collision = OnCollision::Suffix).await;
+    let files = files.unwrap();
+    let functions = compact(features_file(&files, "system-objects/functions.ts"));
+
+    // the params are optional and default to the object
+    for code in [
+        "exportfunctionnow(tx:Transaction,clock?:TransactionObjectInput)",
+        "arguments:[obj(tx,clock??tx.object.clock())]",
+        "clock?:TransactionObjectInput",
+        "arguments:[pure(tx,args.deadline,`u64`),obj(tx,args.clock??tx.object.clock())]",
+    ] {
+        assert!(functions.contains(code), "{} not in {}", code, functions);
+    }
+}
+
+#[tokio::test]
+async fn test_system_object_params_configured() {
+    let (files, _) = generate(|config| {
+        config.naming.on_collision = OnCollision::Suffix;
+        config
+            .system_objects
+            .insert("0x2::clock::Clock".to_string(), Some("0xc10c".to_string()));
+    })
+    .await;
+    let files = files.unwrap();
+    let functions = compact(features_file(&files, "system-objects/functions.ts"));
+    // objects at other IDs are passed in by ID
+    assert!(
+        functions.contains("obj(tx,clock??tx.object(\"0xc10c\"))"),
+        "{}",
+        functions
+    );
+
+    let (files, _) = generate(|config| {
+        config.naming.on_collision = OnCollision::Suffix;
+        config
+            .system_objects
+            .insert("0x2::clock::Clock".to_string(), None);
+    })
+    .await;
+    let files = files.unwrap();
+    let functions = compact(features_file(&files, "system-objects/functions.ts"));
+    // turned off, the caller has to pass the object in
+    assert!(
+        functions.contains("exportfunctionnow(tx:Transaction,clock:TransactionObjectInput)"),
+        "{}",
+        functions
+    );
+    assert!(functions.contains("obj(tx,clock)"), "{}", functions);
+}
+
+#[tokio::test]
+async fn test_kiosk_helpers_use_sui_framework_bindings() {
+    let (files, _) = generate(|config| config.naming.on_collision = OnCollision::Suffix).await;
+    let files = files.unwrap();
+    let kiosk = files.get("_framework/kiosk.ts").unwrap();
+
+    // the sui framework is a dependency of the fixture package, so its bindings are generated
+    // under `_dependencies`
+    for module in ["kiosk", "transfer-policy"] {
+        let path = format!("_dependencies/source/0x2/{}/functions", module);
+        assert!(files.get(format!("{}.ts", path)).is_some(), "{}", path);
+        assert!(kiosk.contains(&format!("from '../{}'", path)), "{}", kiosk);
+    }
+    // the rest of the dependency modules only get struct bindings
+    assert!(files
+        .get("_dependencies/source/0x2/coin/functions.ts")
+        .is_none());
+    assert!(!kiosk.contains("{{sui}}"));
+    assert!(!kiosk.contains("target: '0x2::"), "{}", kiosk);
+}
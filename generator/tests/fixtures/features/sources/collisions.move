@@ -0,0 +1,23 @@
+/// Names that collide after case conversion or with names derived by the generator.
+module features::collisions {
+    public struct Pool has copy, drop {
+        my_value: u64,
+        myValue: u64,
+    }
+
+    /// Collides with the `PoolFields` interface generated for `Pool`.
+    public struct PoolFields has copy, drop {
+        value: u64,
+    }
+
+    /// Collides with the imported `Reified` type.
+    public struct Reified has drop {}
+
+    public fun swap_a_to_b(pool: &mut Pool) {
+        pool.my_value = pool.myValue;
+    }
+
+    public fun swapAToB(pool: &Pool): u64 {
+        pool.my_value
+    }
+}
@@ -0,0 +1,33 @@
+/// Structs and functions referencing types from other modules of the package and from the
+/// Sui framework.
+module features::cross_module {
+    use features::generics::{Self, Pair};
+    use features::phantom::Vault;
+    use sui::coin::Coin;
+    use sui::sui::SUI;
+
+    public struct Wrapper<phantom T> has key, store {
+        id: sui::object::UID,
+        pair: Pair<u64, address>,
+        vault: Vault<T>,
+        coin: Coin<SUI>,
+    }
+
+    public fun wrap<T>(
+        vault: Vault<T>,
+        coin: Coin<SUI>,
+        ctx: &mut sui::tx_context::TxContext,
+    ): Wrapper<T> {
+        Wrapper { id: sui::object::new(ctx), pair: generics::new_pair(0, @0x1), vault, coin }
+    }
+
+    public fun pair<T>(wrapper: &Wrapper<T>): Pair<u64, address> {
+        wrapper.pair
+    }
+
+    public fun unwrap<T>(wrapper: Wrapper<T>): (Vault<T>, Coin<SUI>, Pair<u64, address>) {
+        let Wrapper { id, pair, vault, coin } = wrapper;
+        sui::object::delete(id);
+        (vault, coin, pair)
+    }
+}
@@ -0,0 +1,25 @@
+/// Generic structs and functions, including nested generic instantiations.
+module features::generics {
+    public struct Pair<T, U> has copy, drop, store {
+        first: T,
+        second: U,
+    }
+
+    public struct Nested<T: store> has store {
+        pair: Pair<T, u64>,
+        pairs: vector<Pair<T, T>>,
+    }
+
+    public fun new_pair<T, U>(first: T, second: U): Pair<T, U> {
+        Pair { first, second }
+    }
+
+    public fun swap<T, U>(pair: Pair<T, U>): Pair<U, T> {
+        let Pair { first, second } = pair;
+        Pair { first: second, second: first }
+    }
+
+    public fun nest<T: store>(pair: Pair<T, u64>, pairs: vector<Pair<T, T>>): Nested<T> {
+        Nested { pair, pairs }
+    }
+}
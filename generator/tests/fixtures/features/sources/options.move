@@ -0,0 +1,20 @@
+/// `Option` fields and params, including nested options and options in vectors.
+module features::options {
+    use std::option::Option;
+    use std::string::String;
+
+    public struct Config has copy, drop, store {
+        name: Option<String>,
+        limit: Option<u64>,
+        nested: Option<Option<address>>,
+        items: vector<Option<u8>>,
+    }
+
+    public fun new(name: Option<String>, limit: Option<u64>): Config {
+        Config { name, limit, nested: std::option::none(), items: vector[] }
+    }
+
+    public fun limit_or(config: &Config, default: u64): u64 {
+        *std::option::borrow_with_default(&config.limit, &default)
+    }
+}
@@ -0,0 +1,27 @@
+/// Phantom type parameters, alone and mixed with regular ones.
+module features::phantom {
+    use sui::balance::{Self, Balance};
+
+    public struct Marker has drop {}
+
+    public struct Vault<phantom T> has key, store {
+        id: sui::object::UID,
+        balance: Balance<T>,
+    }
+
+    public struct Tagged<phantom A, B: store> has store {
+        value: B,
+    }
+
+    public fun new_vault<T>(ctx: &mut sui::tx_context::TxContext): Vault<T> {
+        Vault { id: sui::object::new(ctx), balance: balance::zero() }
+    }
+
+    public fun tag<A, B: store>(value: B): Tagged<A, B> {
+        Tagged { value }
+    }
+
+    public fun value<A, B: store + copy>(tagged: &Tagged<A, B>): B {
+        tagged.value
+    }
+}
@@ -0,0 +1,18 @@
+/// Move 2024 positional structs, including a generic one.
+module features::positional {
+    public struct Wrapper(u64, sui::object::ID) has copy, drop, store;
+
+    public struct Tagged<T: store>(T, vector<u8>) has store;
+
+    public fun new(value: u64, id: sui::object::ID): Wrapper {
+        Wrapper(value, id)
+    }
+
+    public fun value(wrapper: &Wrapper): u64 {
+        wrapper.0
+    }
+
+    public fun tag<T: store>(value: T, tag: vector<u8>): Tagged<T> {
+        Tagged(value, tag)
+    }
+}
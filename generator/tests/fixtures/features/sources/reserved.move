@@ -0,0 +1,26 @@
+/// Structs, fields, functions and params named after JS reserved words.
+module features::reserved {
+    public struct Delete has copy, drop, store {
+        new: u64,
+        class: bool,
+        function: address,
+        default: vector<u8>,
+    }
+
+    public struct Object has drop {
+        this: u8,
+        await: u8,
+    }
+
+    public fun import(new: u64, class: bool): u64 {
+        if (class) new else 0
+    }
+
+    public fun typeof(delete: Delete): u64 {
+        delete.new
+    }
+
+    public fun null(this: u8, await: u8): Object {
+        Object { this, await }
+    }
+}
@@ -0,0 +1,12 @@
+/// Functions taking shared system objects, alone and among other params.
+module features::system_objects {
+    use sui::clock::Clock;
+
+    public fun now(clock: &Clock): u64 {
+        clock.timestamp_ms()
+    }
+
+    public fun expired(deadline: u64, clock: &Clock): bool {
+        clock.timestamp_ms() > deadline
+    }
+}
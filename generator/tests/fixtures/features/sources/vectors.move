@@ -0,0 +1,32 @@
+/// Nested vectors of primitives, bytes, addresses and IDs.
+module features::vectors {
+    public struct Matrix has copy, drop, store {
+        rows: vector<vector<u64>>,
+        labels: vector<vector<u8>>,
+        cube: vector<vector<vector<bool>>>,
+    }
+
+    public struct Addresses has copy, drop, store {
+        addrs: vector<address>,
+        ids: vector<sui::object::ID>,
+    }
+
+    public fun new_matrix(rows: vector<vector<u64>>, labels: vector<vector<u8>>): Matrix {
+        Matrix { rows, labels, cube: vector[] }
+    }
+
+    public fun sum(rows: &vector<vector<u64>>): u64 {
+        let mut total = 0;
+        let mut i = 0;
+        while (i < std::vector::length(rows)) {
+            let row = std::vector::borrow(rows, i);
+            let mut j = 0;
+            while (j < std::vector::length(row)) {
+                total = total + *std::vector::borrow(row, j);
+                j = j + 1;
+            };
+            i = i + 1;
+        };
+        total
+    }
+}
@@ -0,0 +1,13 @@
+module std::ascii {
+    public struct String has copy, drop, store {
+        bytes: vector<u8>,
+    }
+
+    public fun string(bytes: vector<u8>): String {
+        String { bytes }
+    }
+
+    public fun as_bytes(string: &String): &vector<u8> {
+        &string.bytes
+    }
+}
@@ -0,0 +1,31 @@
+module std::option {
+    public struct Option<Element> has copy, drop, store {
+        vec: vector<Element>,
+    }
+
+    public fun none<Element>(): Option<Element> {
+        Option { vec: vector[] }
+    }
+
+    public fun some<Element>(e: Element): Option<Element> {
+        Option { vec: vector[e] }
+    }
+
+    public fun is_none<Element>(t: &Option<Element>): bool {
+        t.vec.is_empty()
+    }
+
+    public fun is_some<Element>(t: &Option<Element>): bool {
+        !t.vec.is_empty()
+    }
+
+    public fun borrow<Element>(t: &Option<Element>): &Element {
+        &t.vec[0]
+    }
+
+    public fun borrow_with_default<Element>(t: &Option<Element>, default_ref: &Element): &Element {
+        let vec_ref = &t.vec;
+        if (vec_ref.is_empty()) default_ref
+        else &vec_ref[0]
+    }
+}
@@ -0,0 +1,17 @@
+module std::string {
+    public struct String has copy, drop, store {
+        bytes: vector<u8>,
+    }
+
+    public fun utf8(bytes: vector<u8>): String {
+        String { bytes }
+    }
+
+    public fun bytes(s: &String): &vector<u8> {
+        &s.bytes
+    }
+
+    public fun length(s: &String): u64 {
+        s.bytes.length()
+    }
+}
@@ -0,0 +1,13 @@
+module std::type_name {
+    use std::ascii::String;
+
+    public struct TypeName has copy, drop, store {
+        name: String,
+    }
+
+    public native fun get<T>(): TypeName;
+
+    public fun into_string(self: TypeName): String {
+        self.name
+    }
+}
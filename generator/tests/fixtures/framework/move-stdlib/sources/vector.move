@@ -0,0 +1,26 @@
+#[defines_primitive(vector)]
+module std::vector {
+    #[bytecode_instruction]
+    native public fun empty<Element>(): vector<Element>;
+
+    #[bytecode_instruction]
+    native public fun length<Element>(v: &vector<Element>): u64;
+
+    #[syntax(index)]
+    #[bytecode_instruction]
+    native public fun borrow<Element>(v: &vector<Element>, i: u64): &Element;
+
+    #[bytecode_instruction]
+    native public fun push_back<Element>(v: &mut vector<Element>, e: Element);
+
+    #[syntax(index)]
+    #[bytecode_instruction]
+    native public fun borrow_mut<Element>(v: &mut vector<Element>, i: u64): &mut Element;
+
+    #[bytecode_instruction]
+    native public fun pop_back<Element>(v: &mut vector<Element>): Element;
+
+    public fun is_empty<Element>(v: &vector<Element>): bool {
+        v.length() == 0
+    }
+}
@@ -0,0 +1,22 @@
+module sui::coin {
+    use sui::balance::Balance;
+
+    public struct Coin<phantom T> has key, store {
+        id: UID,
+        balance: Balance<T>,
+    }
+
+    public fun value<T>(self: &Coin<T>): u64 {
+        self.balance.value()
+    }
+
+    public fun into_balance<T>(coin: Coin<T>): Balance<T> {
+        let Coin { id, balance } = coin;
+        id.delete();
+        balance
+    }
+
+    public fun from_balance<T>(balance: Balance<T>, ctx: &mut TxContext): Coin<T> {
+        Coin { id: object::new(ctx), balance }
+    }
+}
@@ -0,0 +1,98 @@
+#[allow(unused_variable)]
+module sui::kiosk {
+    use sui::balance::Balance;
+    use sui::coin::Coin;
+    use sui::sui::SUI;
+    use sui::transfer_policy::{TransferPolicy, TransferRequest};
+
+    public struct Kiosk has key, store {
+        id: UID,
+        profits: Balance<SUI>,
+        owner: address,
+        item_count: u32,
+        allow_extensions: bool,
+    }
+
+    public struct KioskOwnerCap has key, store {
+        id: UID,
+        `for`: ID,
+    }
+
+    public struct PurchaseCap<phantom T: key + store> has key, store {
+        id: UID,
+        kiosk_id: ID,
+        item_id: ID,
+        min_price: u64,
+    }
+
+    public struct Item has copy, drop, store {
+        id: ID,
+    }
+
+    public struct Listing has copy, drop, store {
+        id: ID,
+        is_exclusive: bool,
+    }
+
+    public struct Lock has copy, drop, store {
+        id: ID,
+    }
+
+    public fun new(ctx: &mut TxContext): (Kiosk, KioskOwnerCap) {
+        abort 0
+    }
+
+    public fun place<T: key + store>(self: &mut Kiosk, cap: &KioskOwnerCap, item: T) {
+        abort 0
+    }
+
+    public fun lock<T: key + store>(
+        self: &mut Kiosk,
+        cap: &KioskOwnerCap,
+        _policy: &TransferPolicy<T>,
+        item: T,
+    ) {
+        abort 0
+    }
+
+    public fun take<T: key + store>(self: &mut Kiosk, cap: &KioskOwnerCap, id: ID): T {
+        abort 0
+    }
+
+    public fun list<T: key + store>(self: &mut Kiosk, cap: &KioskOwnerCap, id: ID, price: u64) {
+        abort 0
+    }
+
+    public fun place_and_list<T: key + store>(
+        self: &mut Kiosk,
+        cap: &KioskOwnerCap,
+        item: T,
+        price: u64,
+    ) {
+        abort 0
+    }
+
+    public fun delist<T: key + store>(self: &mut Kiosk, cap: &KioskOwnerCap, id: ID) {
+        abort 0
+    }
+
+    public fun purchase<T: key + store>(
+        self: &mut Kiosk,
+        id: ID,
+        payment: Coin<SUI>,
+    ): (T, TransferRequest<T>) {
+        abort 0
+    }
+
+    public fun has_item(self: &Kiosk, id: ID): bool {
+        abort 0
+    }
+
+    public fun owner(self: &Kiosk): address {
+        self.owner
+    }
+
+    public fun item_count(self: &Kiosk): u32 {
+        self.item_count
+    }
+}
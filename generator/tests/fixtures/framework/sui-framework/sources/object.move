@@ -0,0 +1,25 @@
+module sui::object {
+    public struct ID has copy, drop, store {
+        bytes: address,
+    }
+
+    public struct UID has store {
+        id: ID,
+    }
+
+    public native fun new(ctx: &mut TxContext): UID;
+
+    public fun delete(id: UID) {
+        let UID { id: ID { bytes: _ } } = id;
+    }
+
+    public fun uid_to_inner(uid: &UID): ID {
+        uid.id
+    }
+
+    public fun id<T: key>(obj: &T): ID {
+        borrow_uid(obj).id
+    }
+
+    native fun borrow_uid<T: key>(obj: &T): &UID;
+}
@@ -0,0 +1,14 @@
+module sui::table {
+    public struct Table<phantom K: copy + drop + store, phantom V: store> has key, store {
+        id: UID,
+        size: u64,
+    }
+
+    public fun new<K: copy + drop + store, V: store>(ctx: &mut TxContext): Table<K, V> {
+        Table { id: object::new(ctx), size: 0 }
+    }
+
+    public fun length<K: copy + drop + store, V: store>(table: &Table<K, V>): u64 {
+        table.size
+    }
+}
@@ -0,0 +1,63 @@
+#[allow(unused_variable)]
+module sui::transfer_policy {
+    use std::type_name::TypeName;
+    use sui::balance::Balance;
+    use sui::coin::Coin;
+    use sui::sui::SUI;
+    use sui::vec_set::VecSet;
+
+    public struct TransferRequest<phantom T> {
+        item: ID,
+        paid: u64,
+        from: ID,
+        receipts: VecSet<TypeName>,
+    }
+
+    public struct TransferPolicy<phantom T> has key, store {
+        id: UID,
+        balance: Balance<SUI>,
+        rules: VecSet<TypeName>,
+    }
+
+    public struct TransferPolicyCap<phantom T> has key, store {
+        id: UID,
+        policy_id: ID,
+    }
+
+    public struct TransferPolicyCreated<phantom T> has copy, drop {
+        id: ID,
+    }
+
+    public fun new_request<T>(item: ID, paid: u64, from: ID): TransferRequest<T> {
+        TransferRequest { item, paid, from, receipts: sui::vec_set::empty() }
+    }
+
+    public fun withdraw<T>(
+        self: &mut TransferPolicy<T>,
+        cap: &TransferPolicyCap<T>,
+        amount: Option<u64>,
+        ctx: &mut TxContext,
+    ): Coin<SUI> {
+        abort 0
+    }
+
+    public fun confirm_request<T>(
+        self: &TransferPolicy<T>,
+        request: TransferRequest<T>,
+    ): (ID, u64, ID) {
+        let TransferRequest { item, paid, from, receipts: _ } = request;
+        (item, paid, from)
+    }
+
+    public fun item<T>(self: &TransferRequest<T>): ID {
+        self.item
+    }
+
+    public fun paid<T>(self: &TransferRequest<T>): u64 {
+        self.paid
+    }
+
+    public fun from<T>(self: &TransferRequest<T>): ID {
+        self.from
+    }
+}
@@ -0,0 +1,17 @@
+module sui::tx_context {
+    public struct TxContext has drop {
+        sender: address,
+        tx_hash: vector<u8>,
+        epoch: u64,
+        epoch_timestamp_ms: u64,
+        ids_created: u64,
+    }
+
+    public fun sender(self: &TxContext): address {
+        self.sender
+    }
+
+    public fun epoch(self: &TxContext): u64 {
+        self.epoch
+    }
+}
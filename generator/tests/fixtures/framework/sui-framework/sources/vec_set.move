@@ -0,0 +1,13 @@
+module sui::vec_set {
+    public struct VecSet<K: copy + drop> has copy, drop, store {
+        contents: vector<K>,
+    }
+
+    public fun empty<K: copy + drop>(): VecSet<K> {
+        VecSet { contents: vector[] }
+    }
+
+    public fun size<K: copy + drop>(self: &VecSet<K>): u64 {
+        self.contents.length()
+    }
+}
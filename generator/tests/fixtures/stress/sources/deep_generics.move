@@ -0,0 +1,56 @@
+/// Deeply nested generic instantiations, phantom params and generic params used at different
+/// depths.
+module stress::deep_generics {
+    use std::string::String;
+    use sui::balance::Balance;
+    use sui::table::Table;
+
+    public struct Box<T> has copy, drop, store {
+        value: T,
+    }
+
+    public struct Pair<T, U> has copy, drop, store {
+        first: T,
+        second: U,
+    }
+
+    public struct Tagged<phantom Tag, T> has copy, drop, store {
+        value: T,
+    }
+
+    public struct Deep<T: copy + drop + store, U: copy + drop + store> has copy, drop, store {
+        nested: Box<Box<Box<Pair<T, vector<Option<U>>>>>>,
+        matrix: vector<vector<vector<Box<T>>>>,
+        tagged: Tagged<Pair<U, T>, Option<vector<Tagged<T, String>>>>,
+    }
+
+    public struct Vault<phantom C, phantom Tag> has key, store {
+        id: UID,
+        balance: Balance<C>,
+        entries: Table<Pair<address, u64>, Tagged<Tag, Box<vector<u8>>>>,
+    }
+
+    public fun wrap<T: copy + drop + store, U: copy + drop + store>(
+        value: Pair<T, vector<Option<U>>>,
+    ): Box<Box<Box<Pair<T, vector<Option<U>>>>>> {
+        Box { value: Box { value: Box { value } } }
+    }
+
+    public fun unwrap<T: copy + drop + store, U: copy + drop + store>(
+        deep: Deep<T, U>,
+    ): (vector<vector<vector<Box<T>>>>, Option<vector<Tagged<T, String>>>) {
+        let Deep { nested: _, matrix, tagged } = deep;
+        let Tagged { value } = tagged;
+        (matrix, value)
+    }
+
+    public fun tag<Tag, T: copy + drop + store>(
+        boxes: vector<Box<Tagged<Tag, vector<Option<T>>>>>,
+    ): u64 {
+        boxes.length()
+    }
+
+    public fun balance<C, Tag>(vault: &Vault<C, Tag>): &Balance<C> {
+        &vault.balance
+    }
+}
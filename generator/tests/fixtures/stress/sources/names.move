@@ -0,0 +1,86 @@
+/// Names that clash with identifiers used by the generated code: the helpers imported into
+/// `functions.ts` and `structs.ts`, the params of function bindings and JS reserved words.
+module stress::names {
+    use sui::coin::Coin;
+    use sui::sui::SUI;
+
+    public struct Vector has copy, drop, store {
+        vector: vector<u64>,
+    }
+
+    public struct Reified has copy, drop, store {
+        reified: bool,
+        phantom: u8,
+    }
+
+    public struct Promise has copy, drop, store {
+        then: u64,
+        constructor: u8,
+        prototype: u8,
+        to_json: u64,
+        to_bcs: vector<u8>,
+    }
+
+    public struct Transaction has key, store {
+        id: UID,
+        tx: u64,
+        args: vector<u64>,
+    }
+
+    public fun obj(obj: &Transaction): u64 {
+        obj.tx
+    }
+
+    public fun pure(pure: vector<u8>): vector<u8> {
+        pure
+    }
+
+    public fun generic<T: drop>(generic: T): T {
+        generic
+    }
+
+    public fun vector(vector: vector<Vector>): u64 {
+        vector.length()
+    }
+
+    public fun option(option: Option<Reified>): bool {
+        option.is_some()
+    }
+
+    public fun coin_arg(coin_arg: Coin<SUI>): Coin<SUI> {
+        coin_arg
+    }
+
+    public fun split_result(split_result: u64): (u64, u64) {
+        (split_result, split_result)
+    }
+
+    public fun tx(tx: u64): u64 {
+        tx
+    }
+
+    public fun type_arg<T: drop>(type_arg: T): T {
+        type_arg
+    }
+
+    public fun type_args<T: drop, U: drop>(type_args: Option<U>): Option<T> {
+        let _ = type_args;
+        option::none()
+    }
+
+    public fun args(args: vector<u64>, tx: u64): u64 {
+        args.length() + tx
+    }
+
+    public fun published_at(published_at: address): address {
+        published_at
+    }
+
+    public fun default(new: u64, class: bool): u64 {
+        if (class) new else 0
+    }
+
+    public fun yield(yield: Promise): Promise {
+        yield
+    }
+}
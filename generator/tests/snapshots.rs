@@ -0,0 +1,156 @@
+//! Snapshot tests of the code generated for the fixture packages in `tests/fixtures`.
+//!
+//! The generated files of each top-level package are compared against the ones committed in
+//! `tests/snapshots/<package>`. After an intended change to the generated code, run
+//! `UPDATE_SNAPSHOTS=1 cargo test --test snapshots` to rewrite the snapshots and review the diff.
+
+use std::collections::BTreeMap;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use sui_client_gen::diagnostics::{Diagnostics, MessageFormat};
+use sui_client_gen::manifest::parse_gen_manifest_from_file;
+use sui_client_gen::package_source::FixtureSource;
+use sui_client_gen::{GeneratedFiles, Generator};
+
+const UPDATE_ENV: &str = "UPDATE_SNAPSHOTS";
+
+fn tests_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("tests")
+}
+
+/// Runs the generator over the fixture `gen.toml`. Nothing is fetched from chain, so type origins
+/// fall back to the original package IDs.
+async fn generate() -> GeneratedFiles {
+    let manifest_path = tests_dir().join("fixtures").join("gen.toml");
+    let manifest = parse_gen_manifest_from_file(&manifest_path).unwrap();
+    let empty = tempfile::tempdir().unwrap();
+    let source = FixtureSource::new(empty.path().to_path_buf());
+    let mut diags = Diagnostics::with_writer(MessageFormat::Human, false, Box::new(io::sink()));
+
+    let (files, _) = Generator::new(manifest, &source)
+        .manifest_path(&manifest_path)
+        .diagnostics(&mut diags)
+        .generate()
+        .await
+        .unwrap();
+    files
+}
+
+/// Groups the generated files of top-level packages by package directory. The framework,
+/// dependencies and root files are left out since they don't depend on the fixtures.
+fn package_files(files: &GeneratedFiles) -> BTreeMap<String, BTreeMap<PathBuf, String>> {
+    let mut packages: BTreeMap<String, BTreeMap<PathBuf, String>> = BTreeMap::new();
+    for (path, contents) in files.iter() {
+        let mut components = path.components();
+        let (Some(pkg), Some(_)) = (components.next(), components.clone().next()) else {
+            continue;
+        };
+        let pkg = pkg.as_os_str().to_string_lossy().to_string();
+        if pkg.starts_with('_') {
+            continue;
+        }
+        packages
+            .entry(pkg)
+            .or_default()
+            .insert(components.as_path().to_path_buf(), contents.to_string());
+    }
+    packages
+}
+
+fn read_snapshots(dir: &Path) -> BTreeMap<PathBuf, String> {
+    fn visit(root: &Path, dir: &Path, out: &mut BTreeMap<PathBuf, String>) {
+        let Ok(entries) = std::fs::read_dir(dir) else {
+            return;
+        };
+        for entry in entries {
+            let path = entry.unwrap().path();
+            if path.is_dir() {
+                visit(root, &path, out);
+            } else {
+                let contents = std::fs::read_to_string(&path).unwrap();
+                out.insert(path.strip_prefix(root).unwrap().to_path_buf(), contents);
+            }
+        }
+    }
+
+    let mut out = BTreeMap::new();
+    visit(dir, dir, &mut out);
+    out
+}
+
+/// Describes the first line where `actual` differs from `expected`.
+fn first_difference(expected: &str, actual: &str) -> String {
+    let mut expected_lines = expected.lines();
+    let mut actual_lines = actual.lines();
+    for line in 1.. {
+        match (expected_lines.next(), actual_lines.next()) {
+            (Some(e), Some(a)) if e == a => continue,
+            (None, None) => break,
+            (e, a) => {
+                return format!(
+                    "line {}:\n    expected: {}\n    actual:   {}",
+                    line,
+                    e.unwrap_or("<end of file>"),
+                    a.unwrap_or("<end of file>")
+                )
+            }
+        }
+    }
+    "line endings differ".to_string()
+}
+
+#[tokio::test]
+async fn test_generated_code_snapshots() {
+    let files = generate().await;
+    let packages = package_files(&files);
+    assert!(!packages.is_empty(), "no packages were generated");
+
+    let snapshots_dir = tests_dir().join("snapshots");
+    let update = std::env::var_os(UPDATE_ENV).is_some();
+
+    let mut failures = vec![];
+    for (pkg, generated) in packages.iter() {
+        let dir = snapshots_dir.join(pkg);
+        if update {
+            if dir.exists() {
+                std::fs::remove_dir_all(&dir).unwrap();
+            }
+            for (path, contents) in generated {
+                let path = dir.join(path);
+                std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+                std::fs::write(path, contents).unwrap();
+            }
+            continue;
+        }
+
+        let snapshots = read_snapshots(&dir);
+        for (path, contents) in generated {
+            match snapshots.get(path) {
+                None => failures.push(format!("{}/{}: missing snapshot", pkg, path.display())),
+                Some(expected) if expected != contents => failures.push(format!(
+                    "{}/{}: differs from snapshot at {}",
+                    pkg,
+                    path.display(),
+                    first_difference(expected, contents)
+                )),
+                Some(_) => (),
+            }
+        }
+        for path in snapshots
+            .keys()
+            .filter(|path| !generated.contains_key(*path))
+        {
+            failures.push(format!("{}/{}: no longer generated", pkg, path.display()));
+        }
+    }
+
+    assert!(
+        failures.is_empty(),
+        "Generated code doesn't match the snapshots in {}:\n{}\n\
+        If the changes are intended, run with {}=1 to update the snapshots.",
+        snapshots_dir.display(),
+        failures.join("\n"),
+        UPDATE_ENV
+    );
+}
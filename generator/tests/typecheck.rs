@@ -0,0 +1,288 @@
+//! Type checks the code generated for the fixture corpus in `tests/fixtures/typecheck` (the
+//! framework stand-ins, the feature fixtures and a stress package) with `tsc --noEmit`.
+//!
+//! The generated files are written to a temporary directory in `ts/` so that `@mysten/sui` is
+//! resolved from `ts/node_modules`. The test is skipped when the Node toolchain isn't installed
+//! there (`pnpm install` in `ts/`). Compiler errors are traced back to the Move declarations that
+//! produced the code through the generated source maps.
+
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use regex::Regex;
+use sui_client_gen::diagnostics::{Diagnostics, MessageFormat};
+use sui_client_gen::manifest::parse_gen_manifest_from_file;
+use sui_client_gen::package_source::FixtureSource;
+use sui_client_gen::{GeneratedFiles, Generator};
+
+fn tests_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("tests")
+}
+
+fn ts_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("..").join("ts")
+}
+
+/// Returns the `tsc` to run, or `None` if the Node toolchain isn't available. `tsc` from
+/// `ts/node_modules` is preferred over the one on the `PATH`.
+fn find_tsc() -> Option<PathBuf> {
+    if !ts_dir().join("node_modules/@mysten/sui").is_dir() {
+        return None;
+    }
+    let local = ts_dir().join("node_modules/.bin/tsc");
+    let tsc = match local.is_file() {
+        true => local,
+        false => PathBuf::from("tsc"),
+    };
+    let version = Command::new(&tsc).arg("--version").output().ok()?;
+    version.status.success().then_some(tsc)
+}
+
+async fn generate() -> GeneratedFiles {
+    let manifest_path = tests_dir()
+        .join("fixtures")
+        .join("typecheck")
+        .join("gen.toml");
+    let manifest = parse_gen_manifest_from_file(&manifest_path).unwrap();
+    let empty = tempfile::tempdir().unwrap();
+    let source = FixtureSource::new(empty.path().to_path_buf());
+    let mut diags = Diagnostics::with_writer(MessageFormat::Human, false, Box::new(io::sink()));
+
+    let (files, _) = Generator::new(manifest, &source)
+        .manifest_path(&manifest_path)
+        .diagnostics(&mut diags)
+        .generate()
+        .await
+        .unwrap();
+    files
+}
+
+/// Compiler options of `ts/tsconfig.json`, which the generated code is expected to compile with.
+fn tsconfig() -> serde_json::Value {
+    serde_json::json!({
+        "compilerOptions": {
+            "target": "ESNext",
+            "module": "ESNext",
+            "lib": ["ESNext"],
+            "moduleResolution": "Node",
+            "forceConsistentCasingInFileNames": true,
+            "skipLibCheck": true,
+            "strict": true,
+            "useDefineForClassFields": true,
+            "esModuleInterop": false,
+            "allowSyntheticDefaultImports": true,
+            "resolveJsonModule": true,
+            "isolatedModules": true,
+            "noEmit": true,
+        },
+        "include": ["**/*.ts"],
+    })
+}
+
+/// A compiler error, with the file relative to the output root.
+struct TsError {
+    file: PathBuf,
+    line: usize,
+    column: usize,
+    code: String,
+    message: String,
+}
+
+/// Parses the output of `tsc --pretty false`. Continuation lines of multi-line messages are
+/// indented.
+fn parse_tsc_output(output: &str) -> Vec<TsError> {
+    let re = Regex::new(r"^(.+)\((\d+),(\d+)\): error (TS\d+): (.*)$").unwrap();
+    let mut errors: Vec<TsError> = vec![];
+    for line in output.lines() {
+        if let Some(caps) = re.captures(line) {
+            errors.push(TsError {
+                file: PathBuf::from(&caps[1]),
+                line: caps[2].parse().unwrap(),
+                column: caps[3].parse().unwrap(),
+                code: caps[4].to_string(),
+                message: caps[5].to_string(),
+            });
+        } else if let Some(last) = errors.last_mut() {
+            if line.starts_with(char::is_whitespace) {
+                last.message.push('\n');
+                last.message.push_str(line);
+            }
+        }
+    }
+    errors
+}
+
+/// Returns the generated name of the declaration enclosing the (1-based) `line` of a module's
+/// `structs.ts` or `functions.ts`.
+fn enclosing_ts_name(contents: &str, ts_file: &str, line: usize) -> Option<String> {
+    let lines = contents.lines().collect::<Vec<_>>();
+    let preceding = lines[..line.min(lines.len())]
+        .iter()
+        .rev()
+        .map(|text| text.trim_start());
+    let name_of = |decl: &str| {
+        decl.split(|c: char| !(c.is_alphanumeric() || c == '_' || c == '$'))
+            .next()
+            .map(str::to_string)
+    };
+
+    // the code of each struct is preceded by a separator comment with its class name
+    if ts_file == "structs.ts" {
+        return preceding
+            .filter_map(|text| text.strip_prefix("/* ============================== "))
+            .find_map(name_of);
+    }
+
+    for (idx, text) in preceding.enumerate() {
+        if let Some(rest) = text.strip_prefix("export function ") {
+            return name_of(rest);
+        }
+        // the args interface of a function binding precedes the function
+        if text.starts_with("export interface ") {
+            return lines[line.min(lines.len()) - idx - 1..]
+                .iter()
+                .find_map(|text| text.trim_start().strip_prefix("export function "))
+                .and_then(name_of);
+        }
+    }
+    None
+}
+
+/// Describes the Move declaration that produced the code at the location of `err`, using the
+/// `.move-map.json` of the module.
+fn move_decl(out_dir: &Path, err: &TsError) -> Option<String> {
+    let module_dir = out_dir.join(err.file.parent()?);
+    let ts_file = err.file.file_name()?.to_str()?;
+    let source_map = std::fs::read_to_string(module_dir.join(".move-map.json")).ok()?;
+    let source_map: serde_json::Value = serde_json::from_str(&source_map).ok()?;
+    let module = format!(
+        "{}::{}",
+        source_map["packageId"].as_str()?,
+        source_map["module"].as_str()?
+    );
+
+    let contents = std::fs::read_to_string(out_dir.join(&err.file)).ok()?;
+    let entry = enclosing_ts_name(&contents, ts_file, err.line).and_then(|ts_name| {
+        source_map["entries"]
+            .as_array()?
+            .iter()
+            .find(|entry| entry["ts"]["file"] == ts_file && entry["ts"]["name"] == ts_name.as_str())
+    });
+    let Some(entry) = entry else {
+        return Some(format!("module {}", module));
+    };
+
+    let decl = &entry["move"];
+    let mut desc = format!(
+        "{} {}::{}",
+        decl["kind"].as_str()?,
+        module,
+        decl["name"].as_str()?
+    );
+    if let (Some(file), Some(line)) = (decl["file"].as_str(), decl["line"].as_u64()) {
+        desc.push_str(&format!(" ({}:{})", file, line));
+    }
+    Some(desc)
+}
+
+#[tokio::test]
+async fn test_generated_code_type_checks() {
+    let Some(tsc) = find_tsc() else {
+        eprintln!(
+            "skipping type check: no Node toolchain found (run `pnpm install` in {})",
+            ts_dir().display()
+        );
+        return;
+    };
+
+    let files = generate().await;
+    let out = tempfile::Builder::new()
+        .prefix(".typecheck-")
+        .tempdir_in(ts_dir())
+        .unwrap();
+    files.write_to(out.path()).unwrap();
+    std::fs::write(
+        out.path().join("tsconfig.json"),
+        serde_json::to_string_pretty(&tsconfig()).unwrap(),
+    )
+    .unwrap();
+
+    let output = Command::new(&tsc)
+        .args(["--noEmit", "--pretty", "false", "-p", "."])
+        .current_dir(out.path())
+        .output()
+        .unwrap();
+    if output.status.success() {
+        return;
+    }
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let errors = parse_tsc_output(&stdout);
+    assert!(
+        !errors.is_empty(),
+        "tsc failed:\n{}{}",
+        stdout,
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let report = errors
+        .iter()
+        .map(|err| {
+            let decl = move_decl(out.path(), err).unwrap_or_else(|| "no Move declaration".into());
+            format!(
+                "{}:{}:{}: error {}: {}\n  = generated for {}",
+                err.file.display(),
+                err.line,
+                err.column,
+                err.code,
+                err.message,
+                decl
+            )
+        })
+        .collect::<Vec<_>>();
+    panic!(
+        "tsc found {} errors in the generated code:\n{}",
+        errors.len(),
+        report.join("\n")
+    );
+}
+
+#[test]
+fn test_enclosing_ts_name() {
+    let structs = "\
+/* ============================== Pool =============================== */
+
+export function isPool(type: string): boolean {
+  return true
+}
+";
+    assert_eq!(
+        enclosing_ts_name(structs, "structs.ts", 4),
+        Some("Pool".to_string())
+    );
+
+    let functions = "\
+export function deposit(tx: Transaction, amount: bigint) {
+}
+
+export interface SwapArgs {
+  pool: string
+}
+
+export function swap(tx: Transaction, args: SwapArgs) {
+}
+";
+    assert_eq!(
+        enclosing_ts_name(functions, "functions.ts", 2),
+        Some("deposit".to_string())
+    );
+    assert_eq!(
+        enclosing_ts_name(functions, "functions.ts", 5),
+        Some("swap".to_string())
+    );
+    assert_eq!(
+        enclosing_ts_name(functions, "functions.ts", 9),
+        Some("swap".to_string())
+    );
+}
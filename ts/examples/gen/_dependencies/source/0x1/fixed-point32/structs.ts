@@ -4,12 +4,20 @@ import {
   StructClass,
   ToField,
   ToTypeStr,
+  concatBytes,
   decodeFromFields,
   decodeFromFieldsWithTypes,
   decodeFromJSONField,
+  fieldToBcs,
   phantom,
 } from '../../../../_framework/reified'
-import { FieldsWithTypes, composeSuiType, compressSuiType } from '../../../../_framework/util'
+import {
+  FetchObjectResult,
+  FieldsWithTypes,
+  composeSuiType,
+  compressSuiType,
+  fetchObjects,
+} from '../../../../_framework/util'
 import { PKG_V14 } from '../index'
 import { bcs } from '@mysten/sui/bcs'
 import { SuiClient, SuiObjectData, SuiParsedData } from '@mysten/sui/client'
@@ -34,11 +42,15 @@ export class FixedPoint32 implements StructClass {
   static readonly $typeName = `${PKG_V14}::fixed_point32::FixedPoint32`
   static readonly $numTypeParams = 0
   static readonly $isPhantom = [] as const
+  static readonly $moveFieldNames = {
+    value: 'value',
+  } as const
 
   readonly $typeName = FixedPoint32.$typeName
   readonly $fullTypeName: `${typeof PKG_V14}::fixed_point32::FixedPoint32`
   readonly $typeArgs: []
   readonly $isPhantom = FixedPoint32.$isPhantom
+  readonly $moveFieldNames = FixedPoint32.$moveFieldNames
 
   readonly value: ToField<'u64'>
 
@@ -123,6 +135,10 @@ export class FixedPoint32 implements StructClass {
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([fieldToBcs<'u64'>(`u64`, this.value)])
+  }
+
   static fromJSONField(field: any): FixedPoint32 {
     return FixedPoint32.reified().new({ value: decodeFromJSONField('u64', field.value) })
   }
@@ -172,4 +188,11 @@ export class FixedPoint32 implements StructClass {
 
     return FixedPoint32.fromSuiObjectData(res.data)
   }
+
+  static async fetchMany(
+    client: SuiClient,
+    ids: string[]
+  ): Promise<Array<FetchObjectResult<FixedPoint32>>> {
+    return fetchObjects(client, ids, (data: SuiObjectData) => FixedPoint32.fromSuiObjectData(data))
+  }
 }
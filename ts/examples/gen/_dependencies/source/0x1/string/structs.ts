@@ -5,13 +5,21 @@ import {
   StructClass,
   ToField,
   ToTypeStr,
+  concatBytes,
   decodeFromFields,
   decodeFromFieldsWithTypes,
   decodeFromJSONField,
+  fieldToBcs,
   fieldToJSON,
   phantom,
 } from '../../../../_framework/reified'
-import { FieldsWithTypes, composeSuiType, compressSuiType } from '../../../../_framework/util'
+import {
+  FetchObjectResult,
+  FieldsWithTypes,
+  composeSuiType,
+  compressSuiType,
+  fetchObjects,
+} from '../../../../_framework/util'
 import { Vector } from '../../../../_framework/vector'
 import { PKG_V14 } from '../index'
 import { bcs } from '@mysten/sui/bcs'
@@ -37,11 +45,15 @@ export class String implements StructClass {
   static readonly $typeName = `${PKG_V14}::string::String`
   static readonly $numTypeParams = 0
   static readonly $isPhantom = [] as const
+  static readonly $moveFieldNames = {
+    bytes: 'bytes',
+  } as const
 
   readonly $typeName = String.$typeName
   readonly $fullTypeName: `${typeof PKG_V14}::string::String`
   readonly $typeArgs: []
   readonly $isPhantom = String.$isPhantom
+  readonly $moveFieldNames = String.$moveFieldNames
 
   readonly bytes: ToField<Vector<'u8'>>
 
@@ -123,6 +135,10 @@ export class String implements StructClass {
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([fieldToBcs<Vector<'u8'>>(`vector<u8>`, this.bytes)])
+  }
+
   static fromJSONField(field: any): String {
     return String.reified().new({ bytes: decodeFromJSONField(reified.vector('u8'), field.bytes) })
   }
@@ -172,4 +188,11 @@ export class String implements StructClass {
 
     return String.fromSuiObjectData(res.data)
   }
+
+  static async fetchMany(
+    client: SuiClient,
+    ids: string[]
+  ): Promise<Array<FetchObjectResult<String>>> {
+    return fetchObjects(client, ids, (data: SuiObjectData) => String.fromSuiObjectData(data))
+  }
 }
@@ -4,12 +4,20 @@ import {
   StructClass,
   ToField,
   ToTypeStr,
+  concatBytes,
   decodeFromFields,
   decodeFromFieldsWithTypes,
   decodeFromJSONField,
+  fieldToBcs,
   phantom,
 } from '../../../../_framework/reified'
-import { FieldsWithTypes, composeSuiType, compressSuiType } from '../../../../_framework/util'
+import {
+  FetchObjectResult,
+  FieldsWithTypes,
+  composeSuiType,
+  compressSuiType,
+  fetchObjects,
+} from '../../../../_framework/util'
 import { String } from '../ascii/structs'
 import { PKG_V14 } from '../index'
 import { bcs } from '@mysten/sui/bcs'
@@ -35,11 +43,15 @@ export class TypeName implements StructClass {
   static readonly $typeName = `${PKG_V14}::type_name::TypeName`
   static readonly $numTypeParams = 0
   static readonly $isPhantom = [] as const
+  static readonly $moveFieldNames = {
+    name: 'name',
+  } as const
 
   readonly $typeName = TypeName.$typeName
   readonly $fullTypeName: `${typeof PKG_V14}::type_name::TypeName`
   readonly $typeArgs: []
   readonly $isPhantom = TypeName.$isPhantom
+  readonly $moveFieldNames = TypeName.$moveFieldNames
 
   readonly name: ToField<String>
 
@@ -124,6 +136,10 @@ export class TypeName implements StructClass {
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([fieldToBcs<String>(`${String.$typeName}`, this.name)])
+  }
+
   static fromJSONField(field: any): TypeName {
     return TypeName.reified().new({ name: decodeFromJSONField(String.reified(), field.name) })
   }
@@ -173,4 +189,11 @@ export class TypeName implements StructClass {
 
     return TypeName.fromSuiObjectData(res.data)
   }
+
+  static async fetchMany(
+    client: SuiClient,
+    ids: string[]
+  ): Promise<Array<FetchObjectResult<TypeName>>> {
+    return fetchObjects(client, ids, (data: SuiObjectData) => TypeName.fromSuiObjectData(data))
+  }
 }
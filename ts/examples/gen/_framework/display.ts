@@ -0,0 +1,108 @@
+import { bcs } from '@mysten/sui/bcs'
+import { SuiClient } from '@mysten/sui/client'
+import { fromB64 } from '@mysten/sui/utils'
+import { StructClass } from './reified'
+
+export interface DisplayTemplate<T extends StructClass> {
+  /** ID of the `Display<T>` object. */
+  id: string
+  version: number
+  /** The `{field.path}` templates of the display, keyed by display field name. */
+  fields: Record<string, string>
+  /** Renders the templates of the display for a decoded instance of `T`. */
+  renderDisplay: (instance: T) => Record<string, string>
+}
+
+const DisplayBcs = bcs.struct('Display', {
+  id: bcs.Address,
+  fields: bcs.struct('VecMap', {
+    contents: bcs.vector(bcs.struct('Entry', { key: bcs.string(), value: bcs.string() })),
+  }),
+  version: bcs.u16(),
+})
+
+/**
+ * Fetches the `Display<T>` object for the exact type `fullTypeName` (including type arguments).
+ * Like the RPC's `showDisplay`, the display whose version was published last is used. Returns
+ * `null` if no display has been published for the type.
+ */
+export async function fetchDisplay<T extends StructClass>(
+  client: SuiClient,
+  fullTypeName: string
+): Promise<DisplayTemplate<T> | null> {
+  const events = await client.queryEvents({
+    query: { MoveEventType: `0x2::display::VersionUpdated<${fullTypeName}>` },
+    order: 'descending',
+    limit: 1,
+  })
+  if (events.data.length === 0) {
+    return null
+  }
+
+  const id = (events.data[0].parsedJson as { id: string }).id
+  const res = await client.getObject({ id, options: { showBcs: true } })
+  if (res.error) {
+    throw new Error(`error fetching display object at id ${id}: ${res.error.code}`)
+  }
+  if (res.data?.bcs?.dataType !== 'moveObject') {
+    throw new Error(`object at id ${id} is not a display object`)
+  }
+
+  const display = DisplayBcs.parse(fromB64(res.data.bcs.bcsBytes))
+  const fields: Record<string, string> = {}
+  for (const { key, value } of display.fields.contents) {
+    fields[key] = value
+  }
+
+  return {
+    id,
+    version: display.version,
+    fields,
+    renderDisplay: (instance: T) => renderDisplay(fields, instance),
+  }
+}
+
+function resolveDisplayPath(instance: StructClass, path: string): string {
+  let value: any = instance
+  for (const name of path.split('.')) {
+    const fieldNames: Record<string, string> | undefined = value?.$moveFieldNames
+    if (fieldNames === undefined || !(name in fieldNames)) {
+      throw new Error(`cannot resolve display field path "${path}" for ${instance.$fullTypeName}`)
+    }
+    value = value[fieldNames[name]]
+  }
+
+  switch (typeof value) {
+    case 'string':
+      return value
+    case 'number':
+    case 'bigint':
+    case 'boolean':
+      return String(value)
+  }
+  if (value === null) {
+    return ''
+  }
+  throw new Error(
+    `display field path "${path}" of ${instance.$fullTypeName} doesn't resolve to a primitive value`
+  )
+}
+
+/**
+ * Renders display templates for a decoded instance. Each `{field.path}` in a template is replaced
+ * with the value found by following the Move field names of the path through the instance and its
+ * nested structs, so objects can be rendered from their BCS without the RPC's `showDisplay`.
+ * Paths must lead to a primitive, string, ID, address or `Option` (rendered empty when `none`).
+ */
+export function renderDisplay(
+  fields: Record<string, string>,
+  instance: StructClass
+): Record<string, string> {
+  const rendered: Record<string, string> = {}
+  for (const [key, template] of Object.entries(fields)) {
+    rendered[key] = template.replace(/\{([^{}]*)\}/g, (_, path: string) =>
+      resolveDisplayPath(instance, path.trim())
+    )
+  }
+  return rendered
+}
@@ -0,0 +1,383 @@
+import { bcs } from '@mysten/sui/bcs'
+import { SuiClient } from '@mysten/sui/client'
+import {
+  Transaction,
+  TransactionArgument,
+  TransactionObjectArgument,
+} from '@mysten/sui/transactions'
+import { fromB64, normalizeStructTag, normalizeSuiAddress } from '@mysten/sui/utils'
+import * as kiosk from '../sui/kiosk/functions'
+import { confirmRequest } from '../sui/transfer-policy/functions'
+import { StructClass, StructClassReified } from './reified'
+import { ObjectInput, fetchObjects, isObjectInstance } from './util'
+
+/**
+ * Address of the package with the standard transfer policy rules (`royalty_rule`,
+ * `kiosk_lock_rule`, `personal_kiosk_rule` and `floor_price_rule`) on mainnet.
+ */
+export const KIOSK_RULES_PACKAGE =
+  '0x434b5bd8f6a7b05fede0ff46c6e511d71ea326ed38056e3bcd681d2d7c2a7879'
+
+export interface KioskItem<T> {
+  id: string
+  data: T
+  /** The listing price, if the item is listed for sale (exclusive listings are not included). */
+  price?: bigint
+}
+
+export interface KioskPlaceArgs {
+  kiosk: ObjectInput
+  kioskCap: ObjectInput
+  item: ObjectInput
+}
+
+export interface KioskListArgs {
+  kiosk: ObjectInput
+  kioskCap: ObjectInput
+  itemId: string
+  price: bigint
+}
+
+export interface KioskPlaceAndListArgs {
+  kiosk: ObjectInput
+  kioskCap: ObjectInput
+  item: ObjectInput
+  price: bigint
+}
+
+export interface KioskTakeArgs {
+  kiosk: ObjectInput
+  kioskCap: ObjectInput
+  itemId: string
+}
+
+export interface KioskPurchaseArgs {
+  kiosk: ObjectInput
+  itemId: string
+  payment: ObjectInput
+}
+
+export interface KioskPurchaseAndResolveArgs {
+  kiosk: ObjectInput
+  itemId: string
+  /** The listing price, which is split from the gas coin to pay for the item. */
+  price: bigint
+  /** The `TransferPolicy<T>` to resolve the rules of. Looked up from events if not provided. */
+  policy?: string
+  /** The buyer's kiosk. Required by the kiosk lock and personal kiosk rules. */
+  buyerKiosk?: ObjectInput
+  /** The buyer's `KioskOwnerCap`. Required by the kiosk lock rule. */
+  buyerKioskCap?: ObjectInput
+  /** Resolvers for rules not covered by the built-in ones, keyed by the rule's full type name. */
+  resolvers?: Record<string, RuleResolver>
+  /** Address of the standard rules package. Defaults to `KIOSK_RULES_PACKAGE`. */
+  rulesPackage?: string
+}
+
+export interface RuleResolverContext {
+  tx: Transaction
+  itemType: string
+  item: TransactionObjectArgument
+  transferRequest: TransactionObjectArgument
+  policy: TransactionObjectArgument
+  price: bigint
+  buyerKiosk?: ObjectInput
+  buyerKioskCap?: ObjectInput
+}
+
+/**
+ * Adds the calls needed to satisfy a transfer policy rule to the transaction. Returns `true` if the
+ * item was moved (e.g. locked into the buyer's kiosk) and is no longer available to the caller.
+ */
+export type RuleResolver = (ctx: RuleResolverContext) => boolean | void
+
+export interface KioskHelpers<T extends StructClass> {
+  /** Fetches the items of this type placed in the kiosk, along with their listing prices. */
+  fetchItems: (client: SuiClient, kioskId: string) => Promise<Array<KioskItem<T>>>
+  place: (tx: Transaction, args: KioskPlaceArgs) => TransactionArgument
+  list: (tx: Transaction, args: KioskListArgs) => TransactionArgument
+  placeAndList: (tx: Transaction, args: KioskPlaceAndListArgs) => TransactionArgument
+  take: (tx: Transaction, args: KioskTakeArgs) => TransactionArgument
+  /** Returns the purchased item and the `TransferRequest<T>` that needs to be confirmed. */
+  purchase: (
+    tx: Transaction,
+    args: KioskPurchaseArgs
+  ) => [TransactionObjectArgument, TransactionObjectArgument]
+  /**
+   * Purchases the item, resolves the rules of its transfer policy and confirms the request.
+   * Returns the item, or `undefined` if a rule moved it (e.g. locked it into the buyer's kiosk).
+   */
+  purchaseAndResolve: (
+    tx: Transaction,
+    client: SuiClient,
+    args: KioskPurchaseAndResolveArgs
+  ) => Promise<TransactionObjectArgument | undefined>
+}
+
+export function kioskHelpers<T extends StructClass>(
+  reified: StructClassReified<T, any>
+): KioskHelpers<T> {
+  const itemType = reified.fullTypeName as string
+  return {
+    fetchItems: (client, kioskId) => fetchKioskItems(client, reified, kioskId),
+    place: (tx, args) => kioskPlace(tx, itemType, args),
+    list: (tx, args) => kioskList(tx, itemType, args),
+    placeAndList: (tx, args) => kioskPlaceAndList(tx, itemType, args),
+    take: (tx, args) => kioskTake(tx, itemType, args),
+    purchase: (tx, args) => kioskPurchase(tx, itemType, args),
+    purchaseAndResolve: (tx, client, args) => kioskPurchaseAndResolve(tx, client, itemType, args),
+  }
+}
+
+const ListingField = bcs.struct('Field', {
+  id: bcs.Address,
+  name: bcs.struct('Listing', { id: bcs.Address, is_exclusive: bcs.bool() }),
+  value: bcs.u64(),
+})
+
+export async function fetchKioskItems<T extends StructClass>(
+  client: SuiClient,
+  reified: StructClassReified<T, any>,
+  kioskId: string
+): Promise<Array<KioskItem<T>>> {
+  const itemType = normalizeStructTag(reified.fullTypeName as string)
+  const itemIds: string[] = []
+  const listingFieldIds: string[] = []
+
+  let cursor: string | null | undefined = null
+  do {
+    const page = await client.getDynamicFields({ parentId: kioskId, cursor })
+    for (const field of page.data) {
+      const nameType = normalizeStructTag(field.name.type)
+      if (
+        nameType === normalizeStructTag('0x2::kiosk::Item') &&
+        normalizeStructTag(field.objectType) === itemType
+      ) {
+        itemIds.push(field.objectId)
+      } else if (nameType === normalizeStructTag('0x2::kiosk::Listing')) {
+        listingFieldIds.push(field.objectId)
+      }
+    }
+    cursor = page.hasNextPage ? page.nextCursor : null
+  } while (cursor)
+
+  const [items, listings] = await Promise.all([
+    fetchObjects(client, itemIds, data => reified.fromSuiObjectData(data)),
+    fetchObjects(client, listingFieldIds, data => {
+      if (data.bcs?.dataType !== 'moveObject') {
+        throw new Error(`object ${data.objectId} is not a move object`)
+      }
+      return ListingField.parse(fromB64(data.bcs.bcsBytes))
+    }),
+  ])
+
+  const prices = new Map<string, bigint>()
+  for (const listing of listings) {
+    if (listing.error) {
+      throw listing.error
+    }
+    if (!listing.data.name.is_exclusive) {
+      prices.set(normalizeSuiAddress(listing.data.name.id), BigInt(listing.data.value))
+    }
+  }
+
+  return items.map(item => {
+    if (item.error) {
+      throw item.error
+    }
+    return { id: item.id, data: item.data, price: prices.get(normalizeSuiAddress(item.id)) }
+  })
+}
+
+/**
+ * Turns a decoded object instance into its ID. The argument is passed on untyped, so that it's
+ * accepted by bindings generated with `strict_object_args` too.
+ */
+function objectInput(arg: ObjectInput): any {
+  return isObjectInstance(arg) ? arg.$objectId : arg
+}
+
+export function kioskPlace(tx: Transaction, itemType: string, args: KioskPlaceArgs) {
+  return kiosk.place(tx, itemType, {
+    self: objectInput(args.kiosk),
+    cap: objectInput(args.kioskCap),
+    item: args.item,
+  })
+}
+
+export function kioskList(tx: Transaction, itemType: string, args: KioskListArgs) {
+  return kiosk.list(tx, itemType, {
+    self: objectInput(args.kiosk),
+    cap: objectInput(args.kioskCap),
+    id: args.itemId,
+    price: args.price,
+  })
+}
+
+export function kioskPlaceAndList(tx: Transaction, itemType: string, args: KioskPlaceAndListArgs) {
+  return kiosk.placeAndList(tx, itemType, {
+    self: objectInput(args.kiosk),
+    cap: objectInput(args.kioskCap),
+    item: args.item,
+    price: args.price,
+  })
+}
+
+export function kioskTake(tx: Transaction, itemType: string, args: KioskTakeArgs) {
+  return kiosk.take(tx, itemType, {
+    self: objectInput(args.kiosk),
+    cap: objectInput(args.kioskCap),
+    id: args.itemId,
+  })
+}
+
+export function kioskPurchase(
+  tx: Transaction,
+  itemType: string,
+  args: KioskPurchaseArgs
+): [TransactionObjectArgument, TransactionObjectArgument] {
+  const [item, transferRequest] = kiosk.purchase(tx, itemType, {
+    self: objectInput(args.kiosk),
+    id: args.itemId,
+    payment: objectInput(args.payment),
+  })
+  return [item, transferRequest]
+}
+
+/** Finds the ID of the first `TransferPolicy<T>` created for `itemType`. */
+export async function findTransferPolicy(client: SuiClient, itemType: string): Promise<string> {
+  const res = await client.queryEvents({
+    query: { MoveEventType: `0x2::transfer_policy::TransferPolicyCreated<${itemType}>` },
+    limit: 1,
+  })
+  if (res.data.length === 0) {
+    throw new Error(`no transfer policy found for ${itemType}`)
+  }
+  return (res.data[0].parsedJson as { id: string }).id
+}
+
+const TransferPolicyBcs = bcs.struct('TransferPolicy', {
+  id: bcs.Address,
+  balance: bcs.u64(),
+  rules: bcs.vector(bcs.string()),
+})
+
+/** Fetches the full type names of the rules set on a `TransferPolicy`. */
+export async function fetchPolicyRules(client: SuiClient, policyId: string): Promise<string[]> {
+  const res = await client.getObject({ id: policyId, options: { showBcs: true } })
+  if (res.data?.bcs?.dataType !== 'moveObject') {
+    throw new Error(`transfer policy at id ${policyId} not found`)
+  }
+  return TransferPolicyBcs.parse(fromB64(res.data.bcs.bcsBytes)).rules.map(rule =>
+    normalizeStructTag(rule)
+  )
+}
+
+function standardRuleResolvers(rulesPackage: string): Record<string, RuleResolver> {
+  const pkg = normalizeSuiAddress(rulesPackage)
+  const requireBuyerKiosk = (ctx: RuleResolverContext) => {
+    if (!ctx.buyerKiosk) {
+      throw new Error('the transfer policy requires the item to be placed in the buyer kiosk')
+    }
+    return ctx.buyerKiosk
+  }
+
+  return {
+    // `pay` splits the royalty off the coin it's given, so the gas coin can be passed directly
+    [`${pkg}::royalty_rule::Rule`]: ({ tx, itemType, policy, transferRequest }) => {
+      tx.moveCall({
+        target: `${pkg}::royalty_rule::pay`,
+        typeArguments: [itemType],
+        arguments: [policy, transferRequest, tx.gas],
+      })
+    },
+    [`${pkg}::kiosk_lock_rule::Rule`]: ctx => {
+      const { tx, itemType, policy, item, transferRequest } = ctx
+      const buyerKiosk = requireBuyerKiosk(ctx)
+      if (!ctx.buyerKioskCap) {
+        throw new Error('the transfer policy requires the buyer kiosk cap to lock the item')
+      }
+      const buyerKioskArg = tx.object(objectInput(buyerKiosk))
+      kiosk.lock(tx, itemType, {
+        self: buyerKioskArg,
+        cap: objectInput(ctx.buyerKioskCap),
+        policy,
+        item,
+      })
+      tx.moveCall({
+        target: `${pkg}::kiosk_lock_rule::prove`,
+        typeArguments: [itemType],
+        arguments: [transferRequest, buyerKioskArg],
+      })
+      return true
+    },
+    [`${pkg}::personal_kiosk_rule::Rule`]: ctx => {
+      const { tx, itemType, transferRequest } = ctx
+      tx.moveCall({
+        target: `${pkg}::personal_kiosk_rule::prove`,
+        typeArguments: [itemType],
+        arguments: [tx.object(objectInput(requireBuyerKiosk(ctx))), transferRequest],
+      })
+    },
+    [`${pkg}::floor_price_rule::Rule`]: ({ tx, itemType, policy, transferRequest }) => {
+      tx.moveCall({
+        target: `${pkg}::floor_price_rule::prove`,
+        typeArguments: [itemType],
+        arguments: [policy, transferRequest],
+      })
+    },
+  }
+}
+
+/**
+ * Purchases an item from a kiosk, paying the price from the gas coin, and resolves all the rules of
+ * the item type's transfer policy before confirming the `TransferRequest`. The standard rules are
+ * resolved automatically; other rules need a resolver in `args.resolvers`.
+ */
+export async function kioskPurchaseAndResolve(
+  tx: Transaction,
+  client: SuiClient,
+  itemType: string,
+  args: KioskPurchaseAndResolveArgs
+): Promise<TransactionObjectArgument | undefined> {
+  const policyId = args.policy ?? (await findTransferPolicy(client, itemType))
+  const rules = await fetchPolicyRules(client, policyId)
+
+  const resolvers: Record<string, RuleResolver> = {
+    ...standardRuleResolvers(args.rulesPackage ?? KIOSK_RULES_PACKAGE),
+  }
+  for (const [rule, resolver] of Object.entries(args.resolvers ?? {})) {
+    resolvers[normalizeStructTag(rule)] = resolver
+  }
+  const missing = rules.filter(rule => !resolvers[rule])
+  if (missing.length > 0) {
+    throw new Error(`no resolver for transfer policy rules: ${missing.join(', ')}`)
+  }
+
+  const [payment] = tx.splitCoins(tx.gas, [args.price])
+  const [item, transferRequest] = kioskPurchase(tx, itemType, {
+    kiosk: args.kiosk,
+    itemId: args.itemId,
+    payment,
+  })
+  const policy = tx.object(policyId)
+
+  let moved = false
+  for (const rule of rules) {
+    const ctx: RuleResolverContext = {
+      tx,
+      itemType,
+      item,
+      transferRequest,
+      policy,
+      price: args.price,
+      buyerKiosk: args.buyerKiosk,
+      buyerKioskCap: args.buyerKioskCap,
+    }
+    moved = resolvers[rule](ctx) === true || moved
+  }
+
+  confirmRequest(tx, itemType, { self: policy, request: transferRequest })
+
+  return moved ? undefined : item
+}
@@ -402,6 +402,62 @@ export function fieldToJSON<T extends TypeArgument>(type: string, field: ToField
   }
 }
 
+export function fieldToBcs<T extends TypeArgument>(type: string, field: ToField<T>): Uint8Array {
+  const { typeName, typeArgs } = parseTypeName(type)
+  switch (typeName) {
+    case 'bool':
+      return bcs.bool().serialize(field as any).toBytes()
+    case 'u8':
+      return bcs.u8().serialize(field as any).toBytes()
+    case 'u16':
+      return bcs.u16().serialize(field as any).toBytes()
+    case 'u32':
+      return bcs.u32().serialize(field as any).toBytes()
+    case 'u64':
+      return bcs.u64().serialize(field as any).toBytes()
+    case 'u128':
+      return bcs.u128().serialize(field as any).toBytes()
+    case 'u256':
+      return bcs.u256().serialize(field as any).toBytes()
+    case 'address':
+    case 'signer':
+      return bcs.Address.serialize(field as any).toBytes()
+    case 'vector': {
+      const elements = field as any[]
+      return concatBytes([
+        bcs.uleb128().serialize(elements.length).toBytes(),
+        ...elements.map((item: any) => fieldToBcs(typeArgs[0], item)),
+      ])
+    }
+    // handle special types
+    case '0x1::string::String':
+    case '0x1::ascii::String':
+    case '0x2::url::Url':
+      return bcs.string().serialize(field as any).toBytes()
+    case '0x2::object::ID':
+    case '0x2::object::UID':
+      return bcs.Address.serialize(field as any).toBytes()
+    case '0x1::option::Option': {
+      if (field === null) {
+        return new Uint8Array([0])
+      }
+      return concatBytes([new Uint8Array([1]), fieldToBcs(typeArgs[0], field)])
+    }
+    default:
+      return (field as any).toBcs()
+  }
+}
+
+export function concatBytes(chunks: Uint8Array[]): Uint8Array {
+  const result = new Uint8Array(chunks.reduce((len, chunk) => len + chunk.length, 0))
+  let offset = 0
+  for (const chunk of chunks) {
+    result.set(chunk, offset)
+    offset += chunk.length
+  }
+  return result
+}
+
 export function decodeFromJSONField(typeArg: Reified<TypeArgument, any>, field: any) {
   switch (typeArg) {
     case 'bool':
@@ -1,10 +1,14 @@
 import { bcs, BcsType } from '@mysten/sui/bcs'
+import { SuiClient, SuiObjectData } from '@mysten/sui/client'
 import {
   Transaction,
   TransactionArgument,
   TransactionObjectArgument,
   TransactionObjectInput,
+  TransactionResult,
+  coinWithBalance,
 } from '@mysten/sui/transactions'
+import type { StructClass } from './reified'
 
 export interface FieldsWithTypes {
   fields: Record<string, any>
@@ -21,13 +25,47 @@ export type PureArg =
   | null
   | TransactionArgument
   | Array<PureArg>
+/** A decoded instance of a struct with the `key` ability, which can be passed as an object argument. */
+export interface ObjectInstance {
+  readonly $objectId: string
+}
+
+export type ObjectInput = TransactionObjectInput | ObjectInstance
+
 export type GenericArg =
-  | TransactionObjectInput
+  | ObjectInput
   | PureArg
-  | Array<TransactionObjectInput>
+  | Array<ObjectInput>
   | Array<PureArg>
   | Array<GenericArg>
 
+/**
+ * A value returned by a Move call, tagged with its Move type (e.g. `MoveResult<Coin<ToPhantom<SUI>>>`).
+ * It can be passed as an argument to later calls in the same transaction.
+ */
+export type MoveResult<T> = TransactionArgument & { readonly __moveType?: T }
+
+/** Splits the result of a Move call returning multiple values into a tuple of its values. */
+export function splitResult(res: TransactionResult, count: number): TransactionArgument[] {
+  return Array.from({ length: count }, (_, i) => res[i])
+}
+
+declare const objectType: unique symbol
+
+/** An object ID tagged with the struct class of the object, as returned by e.g. `Pool.id(...)`. */
+export type TypedObjectId<T> = string & { readonly [objectType]: T }
+
+/**
+ * An object argument of struct class `T` in bindings generated with `strict_object_args`: a decoded
+ * instance, a `MoveResult` of a previous call or a typed object ID.
+ */
+export type ObjectArg<T> = T | MoveResult<T> | TypedObjectId<T>
+
+/** Passes an untyped object ID or argument where a typed object argument is expected. */
+export function untypedObject<T>(arg: TransactionObjectInput): ObjectArg<T> {
+  return arg as any
+}
+
 export function splitGenericParameters(
   str: string,
   genericSeparators: [string, string] = ['<', '>']
@@ -95,10 +133,29 @@ export function isTransactionArgument(arg: GenericArg): arg is TransactionArgume
   return 'GasCoin' in arg || 'Input' in arg || 'Result' in arg || 'NestedResult' in arg
 }
 
-export function obj(tx: Transaction, arg: TransactionObjectInput) {
+export function isObjectInstance(arg: GenericArg): arg is ObjectInstance {
+  return !!arg && typeof arg === 'object' && '$objectId' in arg
+}
+
+export function obj(tx: Transaction, arg: ObjectInput) {
+  if (isObjectInstance(arg)) {
+    return tx.object(arg.$objectId)
+  }
   return isTransactionArgument(arg) ? arg : tx.object(arg)
 }
 
+/**
+ * Resolves a `Coin<T>` argument. When an amount is passed in, a coin with that balance is created
+ * from the sender's coins of type `T` (merging and splitting them as needed, or splitting the gas
+ * coin for SUI) when the transaction is built.
+ */
+export function coinArg(tx: Transaction, type: string, arg: ObjectInput | bigint) {
+  if (typeof arg === 'bigint') {
+    return tx.add(coinWithBalance({ type, balance: arg }))
+  }
+  return obj(tx, arg)
+}
+
 export function pure(tx: Transaction, arg: PureArg, type: string): TransactionArgument {
   if (isTransactionArgument(arg)) {
     return obj(tx, arg)
@@ -276,12 +333,10 @@ export function generic(tx: Transaction, type: string, arg: GenericArg) {
 
       return tx.makeMoveVec({
         type: itemType,
-        elements: arg.map(item =>
-          obj(tx, item as TransactionObjectInput)
-        ) as Array<TransactionObjectArgument>,
+        elements: arg.map(item => obj(tx, item as ObjectInput)) as Array<TransactionObjectArgument>,
       })
     } else {
-      return obj(tx, arg as TransactionObjectInput)
+      return obj(tx, arg as ObjectInput)
     }
   }
 }
@@ -313,7 +368,9 @@ export function vector(
 
     return tx.makeMoveVec({
       type: itemType,
-      elements: items as Array<TransactionObjectArgument>,
+      elements: (items as Array<ObjectInput>).map(item =>
+        obj(tx, item)
+      ) as Array<TransactionObjectArgument>,
     })
   }
 }
@@ -392,3 +449,126 @@ export function composeSuiType(typeName: string, ...typeArgs: string[]): string
     return typeName
   }
 }
+
+/** Maximum number of object IDs that can be fetched in a single `multiGetObjects` call. */
+export const MULTI_GET_OBJECTS_LIMIT = 50
+
+export type FetchObjectResult<T> =
+  | { id: string; data: T; error?: undefined }
+  | { id: string; data?: undefined; error: Error }
+
+/**
+ * Fetches the objects with the given IDs in batches of `MULTI_GET_OBJECTS_LIMIT` and decodes each
+ * of them with `decode`. If `decode` isn't provided, each object is decoded into the class of its
+ * on-chain type using the `loader`. The results are in the same order as `ids` and an error for
+ * one object (e.g. it doesn't exist or has an unexpected type) doesn't fail the whole batch.
+ */
+export async function fetchObjects<T = StructClass>(
+  client: SuiClient,
+  ids: string[],
+  decode?: (data: SuiObjectData) => T
+): Promise<Array<FetchObjectResult<T>>> {
+  const decodeData = decode ?? (await decodeWithLoader<T>())
+
+  const batches: string[][] = []
+  for (let i = 0; i < ids.length; i += MULTI_GET_OBJECTS_LIMIT) {
+    batches.push(ids.slice(i, i + MULTI_GET_OBJECTS_LIMIT))
+  }
+  const responses = await Promise.allSettled(
+    batches.map(batch => client.multiGetObjects({ ids: batch, options: { showBcs: true } }))
+  )
+
+  return responses.flatMap((batchRes, i): Array<FetchObjectResult<T>> => {
+    const batch = batches[i]
+    if (batchRes.status === 'rejected') {
+      // a failed request fails the objects in its batch only
+      const error = toError(batchRes.reason)
+      return batch.map(id => ({ id, error }))
+    }
+    return batch.map((id, j): FetchObjectResult<T> => {
+      const res = batchRes.value[j]
+      try {
+        if (!res) {
+          throw new Error(`object at id ${id} missing from response`)
+        }
+        if (res.error) {
+          throw new Error(`error fetching object at id ${id}: ${res.error.code}`)
+        }
+        if (!res.data) {
+          throw new Error(`object at id ${id} not found`)
+        }
+        return { id, data: decodeData(res.data) }
+      } catch (e) {
+        return { id, error: toError(e) }
+      }
+    })
+  })
+}
+
+/**
+ * Same as `fetchObjects` without `decode`, except that an object whose on-chain type isn't an
+ * instantiation of `typeName` (a struct type without type arguments) results in an error.
+ */
+export async function fetchObjectsOfType<T = StructClass>(
+  client: SuiClient,
+  ids: string[],
+  typeName: string
+): Promise<Array<FetchObjectResult<T>>> {
+  return fetchObjects(client, ids, await decodeWithLoader<T>(typeName))
+}
+
+function toError(e: unknown): Error {
+  return e instanceof Error ? e : new Error(String(e))
+}
+
+/**
+ * Iterates over the objects of type `structType` owned by `owner`, paging through
+ * `getOwnedObjects` results, and decodes each of them with `decode`. If `structType` doesn't
+ * include type arguments, objects of all its instantiations are returned. If `decode` isn't
+ * provided, each object is decoded into the class of its on-chain type using the `loader`.
+ */
+export async function* fetchOwnedObjects<T = StructClass>(
+  client: SuiClient,
+  owner: string,
+  structType: string,
+  decode?: (data: SuiObjectData) => T
+): AsyncGenerator<T> {
+  const decodeData = decode ?? (await decodeWithLoader<T>())
+
+  let cursor: string | null | undefined = null
+  do {
+    const page = await client.getOwnedObjects({
+      owner,
+      filter: { StructType: structType },
+      options: { showBcs: true },
+      cursor,
+    })
+    for (const res of page.data) {
+      if (res.error) {
+        throw new Error(`error fetching objects owned by ${owner}: ${res.error.code}`)
+      }
+      if (res.data) {
+        yield decodeData(res.data)
+      }
+    }
+    cursor = page.hasNextPage ? page.nextCursor : null
+  } while (cursor)
+}
+
+async function decodeWithLoader<T>(typeName?: string): Promise<(data: SuiObjectData) => T> {
+  // imported lazily because the loader imports all generated classes, which import this module
+  const { loader } = await import('./loader')
+  const expected = typeName === undefined ? undefined : compressSuiType(typeName)
+  return (data: SuiObjectData) => {
+    if (data.bcs?.dataType !== 'moveObject') {
+      throw new Error(`object at id ${data.objectId} is not a Move object`)
+    }
+    if (
+      expected !== undefined &&
+      compressSuiType(parseTypeName(data.bcs.type).typeName) !== expected
+    ) {
+      throw new Error(`object at id ${data.objectId} is not a ${typeName} object`)
+    }
+    return loader.reified(data.bcs.type).fromSuiObjectData(data) as T
+  }
+}
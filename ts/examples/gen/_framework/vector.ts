@@ -13,6 +13,7 @@ import {
   VectorClass,
   VectorClassReified,
   fieldToJSON,
+  fieldToBcs,
 } from './reified'
 import { composeSuiType, FieldsWithTypes } from './util'
 
@@ -99,6 +100,10 @@ export class Vector<T extends TypeArgument> implements VectorClass {
     return this.elements.map(element => fieldToJSON(this.$typeArgs[0], element))
   }
 
+  toBcs(): Uint8Array {
+    return fieldToBcs(this.$fullTypeName, this.elements as any)
+  }
+
   toJSON() {
     return {
       $typeName: this.$typeName,
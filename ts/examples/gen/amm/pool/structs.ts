@@ -1,3 +1,4 @@
+import { DisplayTemplate, fetchDisplay } from '../../_framework/display'
 import * as reified from '../../_framework/reified'
 import { TypeName } from '../../_dependencies/source/0x1/type-name/structs'
 import {
@@ -11,17 +12,24 @@ import {
   ToTypeStr,
   assertFieldsWithTypesArgsMatch,
   assertReifiedTypeArgsMatch,
+  concatBytes,
   decodeFromFields,
   decodeFromFieldsWithTypes,
   decodeFromJSONField,
   extractType,
+  fieldToBcs,
   phantom,
   ToTypeStr as ToPhantom,
 } from '../../_framework/reified'
 import {
+  FetchObjectResult,
   FieldsWithTypes,
+  TypedObjectId,
   composeSuiType,
   compressSuiType,
+  fetchObjects,
+  fetchObjectsOfType,
+  fetchOwnedObjects,
   parseTypeName,
 } from '../../_framework/util'
 import { Balance, Supply } from '../../sui/balance/structs'
@@ -51,11 +59,15 @@ export class AdminCap implements StructClass {
   static readonly $typeName = `${PKG_V1}::pool::AdminCap`
   static readonly $numTypeParams = 0
   static readonly $isPhantom = [] as const
+  static readonly $moveFieldNames = {
+    id: 'id',
+  } as const
 
   readonly $typeName = AdminCap.$typeName
   readonly $fullTypeName: `${typeof PKG_V1}::pool::AdminCap`
   readonly $typeArgs: []
   readonly $isPhantom = AdminCap.$isPhantom
+  readonly $moveFieldNames = AdminCap.$moveFieldNames
 
   readonly id: ToField<UID>
 
@@ -69,6 +81,14 @@ export class AdminCap implements StructClass {
     this.id = fields.id
   }
 
+  get $objectId(): string {
+    return this.id
+  }
+
+  static id(id: string): TypedObjectId<AdminCap> {
+    return id as TypedObjectId<AdminCap>
+  }
+
   static reified(): AdminCapReified {
     return {
       typeName: AdminCap.$typeName,
@@ -135,6 +155,10 @@ export class AdminCap implements StructClass {
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([fieldToBcs<UID>(`${UID.$typeName}`, this.id)])
+  }
+
   static fromJSONField(field: any): AdminCap {
     return AdminCap.reified().new({ id: decodeFromJSONField(UID.reified(), field.id) })
   }
@@ -184,6 +208,23 @@ export class AdminCap implements StructClass {
 
     return AdminCap.fromSuiObjectData(res.data)
   }
+
+  static async fetchMany(
+    client: SuiClient,
+    ids: string[]
+  ): Promise<Array<FetchObjectResult<AdminCap>>> {
+    return fetchObjects(client, ids, (data: SuiObjectData) => AdminCap.fromSuiObjectData(data))
+  }
+
+  static async *fetchOwned(client: SuiClient, owner: string): AsyncGenerator<AdminCap> {
+    yield* fetchOwnedObjects(client, owner, AdminCap.$typeName, (data: SuiObjectData) =>
+      AdminCap.fromSuiObjectData(data)
+    )
+  }
+
+  static async fetchDisplay(client: SuiClient): Promise<DisplayTemplate<AdminCap> | null> {
+    return fetchDisplay(client, AdminCap.reified().fullTypeName)
+  }
 }
 
 /* ============================== LP =============================== */
@@ -210,11 +251,15 @@ export class LP<A extends PhantomTypeArgument, B extends PhantomTypeArgument>
   static readonly $typeName = `${PKG_V1}::pool::LP`
   static readonly $numTypeParams = 2
   static readonly $isPhantom = [true, true] as const
+  static readonly $moveFieldNames = {
+    dummy_field: 'dummyField',
+  } as const
 
   readonly $typeName = LP.$typeName
   readonly $fullTypeName: `${typeof PKG_V1}::pool::LP<${PhantomToTypeStr<A>}, ${PhantomToTypeStr<B>}>`
   readonly $typeArgs: [PhantomToTypeStr<A>, PhantomToTypeStr<B>]
   readonly $isPhantom = LP.$isPhantom
+  readonly $moveFieldNames = LP.$moveFieldNames
 
   readonly dummyField: ToField<'bool'>
 
@@ -329,6 +374,10 @@ export class LP<A extends PhantomTypeArgument, B extends PhantomTypeArgument>
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([fieldToBcs<'bool'>(`bool`, this.dummyField)])
+  }
+
   static fromJSONField<
     A extends PhantomReified<PhantomTypeArgument>,
     B extends PhantomReified<PhantomTypeArgument>,
@@ -426,6 +475,25 @@ export class LP<A extends PhantomTypeArgument, B extends PhantomTypeArgument>
 
     return LP.fromSuiObjectData(typeArgs, res.data)
   }
+
+  static async fetchMany<
+    A extends PhantomReified<PhantomTypeArgument>,
+    B extends PhantomReified<PhantomTypeArgument>,
+  >(
+    client: SuiClient,
+    ids: string[],
+    typeArgs?: [A, B]
+  ): Promise<Array<FetchObjectResult<LP<ToPhantomTypeArgument<A>, ToPhantomTypeArgument<B>>>>> {
+    if (typeArgs === undefined) {
+      return fetchObjectsOfType<LP<ToPhantomTypeArgument<A>, ToPhantomTypeArgument<B>>>(
+        client,
+        ids,
+        LP.$typeName
+      )
+    }
+
+    return fetchObjects(client, ids, (data: SuiObjectData) => LP.fromSuiObjectData(typeArgs, data))
+  }
 }
 
 /* ============================== Pool =============================== */
@@ -458,11 +526,21 @@ export class Pool<A extends PhantomTypeArgument, B extends PhantomTypeArgument>
   static readonly $typeName = `${PKG_V1}::pool::Pool`
   static readonly $numTypeParams = 2
   static readonly $isPhantom = [true, true] as const
+  static readonly $moveFieldNames = {
+    id: 'id',
+    balance_a: 'balanceA',
+    balance_b: 'balanceB',
+    lp_supply: 'lpSupply',
+    lp_fee_bps: 'lpFeeBps',
+    admin_fee_pct: 'adminFeePct',
+    admin_fee_balance: 'adminFeeBalance',
+  } as const
 
   readonly $typeName = Pool.$typeName
   readonly $fullTypeName: `${typeof PKG_V1}::pool::Pool<${PhantomToTypeStr<A>}, ${PhantomToTypeStr<B>}>`
   readonly $typeArgs: [PhantomToTypeStr<A>, PhantomToTypeStr<B>]
   readonly $isPhantom = Pool.$isPhantom
+  readonly $moveFieldNames = Pool.$moveFieldNames
 
   readonly id: ToField<UID>
   readonly balanceA: ToField<Balance<A>>
@@ -491,6 +569,16 @@ export class Pool<A extends PhantomTypeArgument, B extends PhantomTypeArgument>
     this.adminFeeBalance = fields.adminFeeBalance
   }
 
+  get $objectId(): string {
+    return this.id
+  }
+
+  static id<A extends PhantomTypeArgument, B extends PhantomTypeArgument>(
+    id: string
+  ): TypedObjectId<Pool<A, B>> {
+    return id as TypedObjectId<Pool<A, B>>
+  }
+
   static reified<
     A extends PhantomReified<PhantomTypeArgument>,
     B extends PhantomReified<PhantomTypeArgument>,
@@ -628,6 +716,24 @@ export class Pool<A extends PhantomTypeArgument, B extends PhantomTypeArgument>
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([
+      fieldToBcs<UID>(`${UID.$typeName}`, this.id),
+      fieldToBcs<Balance<A>>(`${Balance.$typeName}<${this.$typeArgs[0]}>`, this.balanceA),
+      fieldToBcs<Balance<B>>(`${Balance.$typeName}<${this.$typeArgs[1]}>`, this.balanceB),
+      fieldToBcs<Supply<ToPhantom<LP<A, B>>>>(
+        `${Supply.$typeName}<${LP.$typeName}<${this.$typeArgs[0]}, ${this.$typeArgs[1]}>>`,
+        this.lpSupply
+      ),
+      fieldToBcs<'u64'>(`u64`, this.lpFeeBps),
+      fieldToBcs<'u64'>(`u64`, this.adminFeePct),
+      fieldToBcs<Balance<ToPhantom<LP<A, B>>>>(
+        `${Balance.$typeName}<${LP.$typeName}<${this.$typeArgs[0]}, ${this.$typeArgs[1]}>>`,
+        this.adminFeeBalance
+      ),
+    ])
+  }
+
   static fromJSONField<
     A extends PhantomReified<PhantomTypeArgument>,
     B extends PhantomReified<PhantomTypeArgument>,
@@ -740,6 +846,62 @@ export class Pool<A extends PhantomTypeArgument, B extends PhantomTypeArgument>
 
     return Pool.fromSuiObjectData(typeArgs, res.data)
   }
+
+  static async fetchMany<
+    A extends PhantomReified<PhantomTypeArgument>,
+    B extends PhantomReified<PhantomTypeArgument>,
+  >(
+    client: SuiClient,
+    ids: string[],
+    typeArgs?: [A, B]
+  ): Promise<Array<FetchObjectResult<Pool<ToPhantomTypeArgument<A>, ToPhantomTypeArgument<B>>>>> {
+    if (typeArgs === undefined) {
+      return fetchObjectsOfType<Pool<ToPhantomTypeArgument<A>, ToPhantomTypeArgument<B>>>(
+        client,
+        ids,
+        Pool.$typeName
+      )
+    }
+
+    return fetchObjects(client, ids, (data: SuiObjectData) =>
+      Pool.fromSuiObjectData(typeArgs, data)
+    )
+  }
+
+  static async *fetchOwned<
+    A extends PhantomReified<PhantomTypeArgument>,
+    B extends PhantomReified<PhantomTypeArgument>,
+  >(
+    client: SuiClient,
+    owner: string,
+    typeArgs?: [A, B]
+  ): AsyncGenerator<Pool<ToPhantomTypeArgument<A>, ToPhantomTypeArgument<B>>> {
+    if (typeArgs === undefined) {
+      yield* fetchOwnedObjects<Pool<ToPhantomTypeArgument<A>, ToPhantomTypeArgument<B>>>(
+        client,
+        owner,
+        Pool.$typeName
+      )
+      return
+    }
+
+    yield* fetchOwnedObjects(
+      client,
+      owner,
+      composeSuiType(Pool.$typeName, ...typeArgs.map(typeArg => extractType(typeArg))),
+      (data: SuiObjectData) => Pool.fromSuiObjectData(typeArgs, data)
+    )
+  }
+
+  static async fetchDisplay<
+    A extends PhantomReified<PhantomTypeArgument>,
+    B extends PhantomReified<PhantomTypeArgument>,
+  >(
+    client: SuiClient,
+    typeArgs: [A, B]
+  ): Promise<DisplayTemplate<Pool<ToPhantomTypeArgument<A>, ToPhantomTypeArgument<B>>> | null> {
+    return fetchDisplay(client, Pool.reified(...typeArgs).fullTypeName)
+  }
 }
 
 /* ============================== PoolCreationEvent =============================== */
@@ -761,11 +923,15 @@ export class PoolCreationEvent implements StructClass {
   static readonly $typeName = `${PKG_V1}::pool::PoolCreationEvent`
   static readonly $numTypeParams = 0
   static readonly $isPhantom = [] as const
+  static readonly $moveFieldNames = {
+    pool_id: 'poolId',
+  } as const
 
   readonly $typeName = PoolCreationEvent.$typeName
   readonly $fullTypeName: `${typeof PKG_V1}::pool::PoolCreationEvent`
   readonly $typeArgs: []
   readonly $isPhantom = PoolCreationEvent.$isPhantom
+  readonly $moveFieldNames = PoolCreationEvent.$moveFieldNames
 
   readonly poolId: ToField<ID>
 
@@ -852,6 +1018,10 @@ export class PoolCreationEvent implements StructClass {
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([fieldToBcs<ID>(`${ID.$typeName}`, this.poolId)])
+  }
+
   static fromJSONField(field: any): PoolCreationEvent {
     return PoolCreationEvent.reified().new({
       poolId: decodeFromJSONField(ID.reified(), field.poolId),
@@ -903,6 +1073,15 @@ export class PoolCreationEvent implements StructClass {
 
     return PoolCreationEvent.fromSuiObjectData(res.data)
   }
+
+  static async fetchMany(
+    client: SuiClient,
+    ids: string[]
+  ): Promise<Array<FetchObjectResult<PoolCreationEvent>>> {
+    return fetchObjects(client, ids, (data: SuiObjectData) =>
+      PoolCreationEvent.fromSuiObjectData(data)
+    )
+  }
 }
 
 /* ============================== PoolRegistry =============================== */
@@ -925,11 +1104,16 @@ export class PoolRegistry implements StructClass {
   static readonly $typeName = `${PKG_V1}::pool::PoolRegistry`
   static readonly $numTypeParams = 0
   static readonly $isPhantom = [] as const
+  static readonly $moveFieldNames = {
+    id: 'id',
+    table: 'table',
+  } as const
 
   readonly $typeName = PoolRegistry.$typeName
   readonly $fullTypeName: `${typeof PKG_V1}::pool::PoolRegistry`
   readonly $typeArgs: []
   readonly $isPhantom = PoolRegistry.$isPhantom
+  readonly $moveFieldNames = PoolRegistry.$moveFieldNames
 
   readonly id: ToField<UID>
   readonly table: ToField<Table<ToPhantom<PoolRegistryItem>, 'bool'>>
@@ -945,6 +1129,14 @@ export class PoolRegistry implements StructClass {
     this.table = fields.table
   }
 
+  get $objectId(): string {
+    return this.id
+  }
+
+  static id(id: string): TypedObjectId<PoolRegistry> {
+    return id as TypedObjectId<PoolRegistry>
+  }
+
   static reified(): PoolRegistryReified {
     return {
       typeName: PoolRegistry.$typeName,
@@ -1028,6 +1220,16 @@ export class PoolRegistry implements StructClass {
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([
+      fieldToBcs<UID>(`${UID.$typeName}`, this.id),
+      fieldToBcs<Table<ToPhantom<PoolRegistryItem>, 'bool'>>(
+        `${Table.$typeName}<${PoolRegistryItem.$typeName}, bool>`,
+        this.table
+      ),
+    ])
+  }
+
   static fromJSONField(field: any): PoolRegistry {
     return PoolRegistry.reified().new({
       id: decodeFromJSONField(UID.reified(), field.id),
@@ -1083,6 +1285,23 @@ export class PoolRegistry implements StructClass {
 
     return PoolRegistry.fromSuiObjectData(res.data)
   }
+
+  static async fetchMany(
+    client: SuiClient,
+    ids: string[]
+  ): Promise<Array<FetchObjectResult<PoolRegistry>>> {
+    return fetchObjects(client, ids, (data: SuiObjectData) => PoolRegistry.fromSuiObjectData(data))
+  }
+
+  static async *fetchOwned(client: SuiClient, owner: string): AsyncGenerator<PoolRegistry> {
+    yield* fetchOwnedObjects(client, owner, PoolRegistry.$typeName, (data: SuiObjectData) =>
+      PoolRegistry.fromSuiObjectData(data)
+    )
+  }
+
+  static async fetchDisplay(client: SuiClient): Promise<DisplayTemplate<PoolRegistry> | null> {
+    return fetchDisplay(client, PoolRegistry.reified().fullTypeName)
+  }
 }
 
 /* ============================== PoolRegistryItem =============================== */
@@ -1105,11 +1324,16 @@ export class PoolRegistryItem implements StructClass {
   static readonly $typeName = `${PKG_V1}::pool::PoolRegistryItem`
   static readonly $numTypeParams = 0
   static readonly $isPhantom = [] as const
+  static readonly $moveFieldNames = {
+    a: 'a',
+    b: 'b',
+  } as const
 
   readonly $typeName = PoolRegistryItem.$typeName
   readonly $fullTypeName: `${typeof PKG_V1}::pool::PoolRegistryItem`
   readonly $typeArgs: []
   readonly $isPhantom = PoolRegistryItem.$isPhantom
+  readonly $moveFieldNames = PoolRegistryItem.$moveFieldNames
 
   readonly a: ToField<TypeName>
   readonly b: ToField<TypeName>
@@ -1202,6 +1426,13 @@ export class PoolRegistryItem implements StructClass {
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([
+      fieldToBcs<TypeName>(`${TypeName.$typeName}`, this.a),
+      fieldToBcs<TypeName>(`${TypeName.$typeName}`, this.b),
+    ])
+  }
+
   static fromJSONField(field: any): PoolRegistryItem {
     return PoolRegistryItem.reified().new({
       a: decodeFromJSONField(TypeName.reified(), field.a),
@@ -1254,4 +1485,13 @@ export class PoolRegistryItem implements StructClass {
 
     return PoolRegistryItem.fromSuiObjectData(res.data)
   }
+
+  static async fetchMany(
+    client: SuiClient,
+    ids: string[]
+  ): Promise<Array<FetchObjectResult<PoolRegistryItem>>> {
+    return fetchObjects(client, ids, (data: SuiObjectData) =>
+      PoolRegistryItem.fromSuiObjectData(data)
+    )
+  }
 }
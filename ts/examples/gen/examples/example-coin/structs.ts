@@ -1,3 +1,4 @@
+import { DisplayTemplate, fetchDisplay } from '../../_framework/display'
 import * as reified from '../../_framework/reified'
 import {
   PhantomReified,
@@ -5,13 +6,23 @@ import {
   StructClass,
   ToField,
   ToTypeStr,
+  concatBytes,
   decodeFromFields,
   decodeFromFieldsWithTypes,
   decodeFromJSONField,
+  fieldToBcs,
   phantom,
   ToTypeStr as ToPhantom,
 } from '../../_framework/reified'
-import { FieldsWithTypes, composeSuiType, compressSuiType } from '../../_framework/util'
+import {
+  FetchObjectResult,
+  FieldsWithTypes,
+  TypedObjectId,
+  composeSuiType,
+  compressSuiType,
+  fetchObjects,
+  fetchOwnedObjects,
+} from '../../_framework/util'
 import { TreasuryCap } from '../../sui/coin/structs'
 import { UID } from '../../sui/object/structs'
 import { PKG_V1 } from '../index'
@@ -38,11 +49,15 @@ export class EXAMPLE_COIN implements StructClass {
   static readonly $typeName = `${PKG_V1}::example_coin::EXAMPLE_COIN`
   static readonly $numTypeParams = 0
   static readonly $isPhantom = [] as const
+  static readonly $moveFieldNames = {
+    dummy_field: 'dummyField',
+  } as const
 
   readonly $typeName = EXAMPLE_COIN.$typeName
   readonly $fullTypeName: `${typeof PKG_V1}::example_coin::EXAMPLE_COIN`
   readonly $typeArgs: []
   readonly $isPhantom = EXAMPLE_COIN.$isPhantom
+  readonly $moveFieldNames = EXAMPLE_COIN.$moveFieldNames
 
   readonly dummyField: ToField<'bool'>
 
@@ -127,6 +142,10 @@ export class EXAMPLE_COIN implements StructClass {
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([fieldToBcs<'bool'>(`bool`, this.dummyField)])
+  }
+
   static fromJSONField(field: any): EXAMPLE_COIN {
     return EXAMPLE_COIN.reified().new({ dummyField: decodeFromJSONField('bool', field.dummyField) })
   }
@@ -176,6 +195,13 @@ export class EXAMPLE_COIN implements StructClass {
 
     return EXAMPLE_COIN.fromSuiObjectData(res.data)
   }
+
+  static async fetchMany(
+    client: SuiClient,
+    ids: string[]
+  ): Promise<Array<FetchObjectResult<EXAMPLE_COIN>>> {
+    return fetchObjects(client, ids, (data: SuiObjectData) => EXAMPLE_COIN.fromSuiObjectData(data))
+  }
 }
 
 /* ============================== Faucet =============================== */
@@ -198,11 +224,16 @@ export class Faucet implements StructClass {
   static readonly $typeName = `${PKG_V1}::example_coin::Faucet`
   static readonly $numTypeParams = 0
   static readonly $isPhantom = [] as const
+  static readonly $moveFieldNames = {
+    id: 'id',
+    cap: 'cap',
+  } as const
 
   readonly $typeName = Faucet.$typeName
   readonly $fullTypeName: `${typeof PKG_V1}::example_coin::Faucet`
   readonly $typeArgs: []
   readonly $isPhantom = Faucet.$isPhantom
+  readonly $moveFieldNames = Faucet.$moveFieldNames
 
   readonly id: ToField<UID>
   readonly cap: ToField<TreasuryCap<ToPhantom<EXAMPLE_COIN>>>
@@ -218,6 +249,14 @@ export class Faucet implements StructClass {
     this.cap = fields.cap
   }
 
+  get $objectId(): string {
+    return this.id
+  }
+
+  static id(id: string): TypedObjectId<Faucet> {
+    return id as TypedObjectId<Faucet>
+  }
+
   static reified(): FaucetReified {
     return {
       typeName: Faucet.$typeName,
@@ -301,6 +340,16 @@ export class Faucet implements StructClass {
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([
+      fieldToBcs<UID>(`${UID.$typeName}`, this.id),
+      fieldToBcs<TreasuryCap<ToPhantom<EXAMPLE_COIN>>>(
+        `${TreasuryCap.$typeName}<${EXAMPLE_COIN.$typeName}>`,
+        this.cap
+      ),
+    ])
+  }
+
   static fromJSONField(field: any): Faucet {
     return Faucet.reified().new({
       id: decodeFromJSONField(UID.reified(), field.id),
@@ -356,4 +405,21 @@ export class Faucet implements StructClass {
 
     return Faucet.fromSuiObjectData(res.data)
   }
+
+  static async fetchMany(
+    client: SuiClient,
+    ids: string[]
+  ): Promise<Array<FetchObjectResult<Faucet>>> {
+    return fetchObjects(client, ids, (data: SuiObjectData) => Faucet.fromSuiObjectData(data))
+  }
+
+  static async *fetchOwned(client: SuiClient, owner: string): AsyncGenerator<Faucet> {
+    yield* fetchOwnedObjects(client, owner, Faucet.$typeName, (data: SuiObjectData) =>
+      Faucet.fromSuiObjectData(data)
+    )
+  }
+
+  static async fetchDisplay(client: SuiClient): Promise<DisplayTemplate<Faucet> | null> {
+    return fetchDisplay(client, Faucet.reified().fullTypeName)
+  }
 }
@@ -1,3 +1,4 @@
+import { DisplayTemplate, fetchDisplay } from '../../_framework/display'
 import * as reified from '../../_framework/reified'
 import { String } from '../../_dependencies/source/0x1/ascii/structs'
 import { Option } from '../../_dependencies/source/0x1/option/structs'
@@ -8,13 +9,23 @@ import {
   StructClass,
   ToField,
   ToTypeStr,
+  concatBytes,
   decodeFromFields,
   decodeFromFieldsWithTypes,
   decodeFromJSONField,
+  fieldToBcs,
   fieldToJSON,
   phantom,
 } from '../../_framework/reified'
-import { FieldsWithTypes, composeSuiType, compressSuiType } from '../../_framework/util'
+import {
+  FetchObjectResult,
+  FieldsWithTypes,
+  TypedObjectId,
+  composeSuiType,
+  compressSuiType,
+  fetchObjects,
+  fetchOwnedObjects,
+} from '../../_framework/util'
 import { Vector } from '../../_framework/vector'
 import { ID, UID } from '../../sui/object/structs'
 import { PKG_V1 } from '../index'
@@ -41,11 +52,15 @@ export class ExampleStruct implements StructClass {
   static readonly $typeName = `${PKG_V1}::examples::ExampleStruct`
   static readonly $numTypeParams = 0
   static readonly $isPhantom = [] as const
+  static readonly $moveFieldNames = {
+    dummy_field: 'dummyField',
+  } as const
 
   readonly $typeName = ExampleStruct.$typeName
   readonly $fullTypeName: `${typeof PKG_V1}::examples::ExampleStruct`
   readonly $typeArgs: []
   readonly $isPhantom = ExampleStruct.$isPhantom
+  readonly $moveFieldNames = ExampleStruct.$moveFieldNames
 
   readonly dummyField: ToField<'bool'>
 
@@ -130,6 +145,10 @@ export class ExampleStruct implements StructClass {
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([fieldToBcs<'bool'>(`bool`, this.dummyField)])
+  }
+
   static fromJSONField(field: any): ExampleStruct {
     return ExampleStruct.reified().new({
       dummyField: decodeFromJSONField('bool', field.dummyField),
@@ -181,6 +200,13 @@ export class ExampleStruct implements StructClass {
 
     return ExampleStruct.fromSuiObjectData(res.data)
   }
+
+  static async fetchMany(
+    client: SuiClient,
+    ids: string[]
+  ): Promise<Array<FetchObjectResult<ExampleStruct>>> {
+    return fetchObjects(client, ids, (data: SuiObjectData) => ExampleStruct.fromSuiObjectData(data))
+  }
 }
 
 /* ============================== SpecialTypesStruct =============================== */
@@ -210,11 +236,23 @@ export class SpecialTypesStruct implements StructClass {
   static readonly $typeName = `${PKG_V1}::examples::SpecialTypesStruct`
   static readonly $numTypeParams = 0
   static readonly $isPhantom = [] as const
+  static readonly $moveFieldNames = {
+    id: 'id',
+    ascii_string: 'asciiString',
+    utf8_string: 'utf8String',
+    vector_of_u64: 'vectorOfU64',
+    vector_of_objects: 'vectorOfObjects',
+    id_field: 'idField',
+    address: 'address',
+    option_some: 'optionSome',
+    option_none: 'optionNone',
+  } as const
 
   readonly $typeName = SpecialTypesStruct.$typeName
   readonly $fullTypeName: `${typeof PKG_V1}::examples::SpecialTypesStruct`
   readonly $typeArgs: []
   readonly $isPhantom = SpecialTypesStruct.$isPhantom
+  readonly $moveFieldNames = SpecialTypesStruct.$moveFieldNames
 
   readonly id: ToField<UID>
   readonly asciiString: ToField<String>
@@ -244,6 +282,14 @@ export class SpecialTypesStruct implements StructClass {
     this.optionNone = fields.optionNone
   }
 
+  get $objectId(): string {
+    return this.id
+  }
+
+  static id(id: string): TypedObjectId<SpecialTypesStruct> {
+    return id as TypedObjectId<SpecialTypesStruct>
+  }
+
   static reified(): SpecialTypesStructReified {
     return {
       typeName: SpecialTypesStruct.$typeName,
@@ -361,6 +407,20 @@ export class SpecialTypesStruct implements StructClass {
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([
+      fieldToBcs<UID>(`${UID.$typeName}`, this.id),
+      fieldToBcs<String>(`${String.$typeName}`, this.asciiString),
+      fieldToBcs<String1>(`${String1.$typeName}`, this.utf8String),
+      fieldToBcs<Vector<'u64'>>(`vector<u64>`, this.vectorOfU64),
+      fieldToBcs<Vector<ExampleStruct>>(`vector<${ExampleStruct.$typeName}>`, this.vectorOfObjects),
+      fieldToBcs<ID>(`${ID.$typeName}`, this.idField),
+      fieldToBcs<'address'>(`address`, this.address),
+      fieldToBcs<Option<'u64'>>(`${Option.$typeName}<u64>`, this.optionSome),
+      fieldToBcs<Option<'u64'>>(`${Option.$typeName}<u64>`, this.optionNone),
+    ])
+  }
+
   static fromJSONField(field: any): SpecialTypesStruct {
     return SpecialTypesStruct.reified().new({
       id: decodeFromJSONField(UID.reified(), field.id),
@@ -423,4 +483,25 @@ export class SpecialTypesStruct implements StructClass {
 
     return SpecialTypesStruct.fromSuiObjectData(res.data)
   }
+
+  static async fetchMany(
+    client: SuiClient,
+    ids: string[]
+  ): Promise<Array<FetchObjectResult<SpecialTypesStruct>>> {
+    return fetchObjects(client, ids, (data: SuiObjectData) =>
+      SpecialTypesStruct.fromSuiObjectData(data)
+    )
+  }
+
+  static async *fetchOwned(client: SuiClient, owner: string): AsyncGenerator<SpecialTypesStruct> {
+    yield* fetchOwnedObjects(client, owner, SpecialTypesStruct.$typeName, (data: SuiObjectData) =>
+      SpecialTypesStruct.fromSuiObjectData(data)
+    )
+  }
+
+  static async fetchDisplay(
+    client: SuiClient
+  ): Promise<DisplayTemplate<SpecialTypesStruct> | null> {
+    return fetchDisplay(client, SpecialTypesStruct.reified().fullTypeName)
+  }
 }
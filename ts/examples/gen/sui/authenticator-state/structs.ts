@@ -1,3 +1,4 @@
+import { DisplayTemplate, fetchDisplay } from '../../_framework/display'
 import * as reified from '../../_framework/reified'
 import { String } from '../../_dependencies/source/0x1/string/structs'
 import {
@@ -6,13 +7,23 @@ import {
   StructClass,
   ToField,
   ToTypeStr,
+  concatBytes,
   decodeFromFields,
   decodeFromFieldsWithTypes,
   decodeFromJSONField,
+  fieldToBcs,
   fieldToJSON,
   phantom,
 } from '../../_framework/reified'
-import { FieldsWithTypes, composeSuiType, compressSuiType } from '../../_framework/util'
+import {
+  FetchObjectResult,
+  FieldsWithTypes,
+  TypedObjectId,
+  composeSuiType,
+  compressSuiType,
+  fetchObjects,
+  fetchOwnedObjects,
+} from '../../_framework/util'
 import { Vector } from '../../_framework/vector'
 import { PKG_V27 } from '../index'
 import { UID } from '../object/structs'
@@ -41,11 +52,17 @@ export class ActiveJwk implements StructClass {
   static readonly $typeName = `${PKG_V27}::authenticator_state::ActiveJwk`
   static readonly $numTypeParams = 0
   static readonly $isPhantom = [] as const
+  static readonly $moveFieldNames = {
+    jwk_id: 'jwkId',
+    jwk: 'jwk',
+    epoch: 'epoch',
+  } as const
 
   readonly $typeName = ActiveJwk.$typeName
   readonly $fullTypeName: `${typeof PKG_V27}::authenticator_state::ActiveJwk`
   readonly $typeArgs: []
   readonly $isPhantom = ActiveJwk.$isPhantom
+  readonly $moveFieldNames = ActiveJwk.$moveFieldNames
 
   readonly jwkId: ToField<JwkId>
   readonly jwk: ToField<JWK>
@@ -144,6 +161,14 @@ export class ActiveJwk implements StructClass {
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([
+      fieldToBcs<JwkId>(`${JwkId.$typeName}`, this.jwkId),
+      fieldToBcs<JWK>(`${JWK.$typeName}`, this.jwk),
+      fieldToBcs<'u64'>(`u64`, this.epoch),
+    ])
+  }
+
   static fromJSONField(field: any): ActiveJwk {
     return ActiveJwk.reified().new({
       jwkId: decodeFromJSONField(JwkId.reified(), field.jwkId),
@@ -197,6 +222,13 @@ export class ActiveJwk implements StructClass {
 
     return ActiveJwk.fromSuiObjectData(res.data)
   }
+
+  static async fetchMany(
+    client: SuiClient,
+    ids: string[]
+  ): Promise<Array<FetchObjectResult<ActiveJwk>>> {
+    return fetchObjects(client, ids, (data: SuiObjectData) => ActiveJwk.fromSuiObjectData(data))
+  }
 }
 
 /* ============================== AuthenticatorState =============================== */
@@ -219,11 +251,16 @@ export class AuthenticatorState implements StructClass {
   static readonly $typeName = `${PKG_V27}::authenticator_state::AuthenticatorState`
   static readonly $numTypeParams = 0
   static readonly $isPhantom = [] as const
+  static readonly $moveFieldNames = {
+    id: 'id',
+    version: 'version',
+  } as const
 
   readonly $typeName = AuthenticatorState.$typeName
   readonly $fullTypeName: `${typeof PKG_V27}::authenticator_state::AuthenticatorState`
   readonly $typeArgs: []
   readonly $isPhantom = AuthenticatorState.$isPhantom
+  readonly $moveFieldNames = AuthenticatorState.$moveFieldNames
 
   readonly id: ToField<UID>
   readonly version: ToField<'u64'>
@@ -239,6 +276,14 @@ export class AuthenticatorState implements StructClass {
     this.version = fields.version
   }
 
+  get $objectId(): string {
+    return this.id
+  }
+
+  static id(id: string): TypedObjectId<AuthenticatorState> {
+    return id as TypedObjectId<AuthenticatorState>
+  }
+
   static reified(): AuthenticatorStateReified {
     return {
       typeName: AuthenticatorState.$typeName,
@@ -316,6 +361,13 @@ export class AuthenticatorState implements StructClass {
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([
+      fieldToBcs<UID>(`${UID.$typeName}`, this.id),
+      fieldToBcs<'u64'>(`u64`, this.version),
+    ])
+  }
+
   static fromJSONField(field: any): AuthenticatorState {
     return AuthenticatorState.reified().new({
       id: decodeFromJSONField(UID.reified(), field.id),
@@ -368,6 +420,27 @@ export class AuthenticatorState implements StructClass {
 
     return AuthenticatorState.fromSuiObjectData(res.data)
   }
+
+  static async fetchMany(
+    client: SuiClient,
+    ids: string[]
+  ): Promise<Array<FetchObjectResult<AuthenticatorState>>> {
+    return fetchObjects(client, ids, (data: SuiObjectData) =>
+      AuthenticatorState.fromSuiObjectData(data)
+    )
+  }
+
+  static async *fetchOwned(client: SuiClient, owner: string): AsyncGenerator<AuthenticatorState> {
+    yield* fetchOwnedObjects(client, owner, AuthenticatorState.$typeName, (data: SuiObjectData) =>
+      AuthenticatorState.fromSuiObjectData(data)
+    )
+  }
+
+  static async fetchDisplay(
+    client: SuiClient
+  ): Promise<DisplayTemplate<AuthenticatorState> | null> {
+    return fetchDisplay(client, AuthenticatorState.reified().fullTypeName)
+  }
 }
 
 /* ============================== AuthenticatorStateInner =============================== */
@@ -393,11 +466,16 @@ export class AuthenticatorStateInner implements StructClass {
   static readonly $typeName = `${PKG_V27}::authenticator_state::AuthenticatorStateInner`
   static readonly $numTypeParams = 0
   static readonly $isPhantom = [] as const
+  static readonly $moveFieldNames = {
+    version: 'version',
+    active_jwks: 'activeJwks',
+  } as const
 
   readonly $typeName = AuthenticatorStateInner.$typeName
   readonly $fullTypeName: `${typeof PKG_V27}::authenticator_state::AuthenticatorStateInner`
   readonly $typeArgs: []
   readonly $isPhantom = AuthenticatorStateInner.$isPhantom
+  readonly $moveFieldNames = AuthenticatorStateInner.$moveFieldNames
 
   readonly version: ToField<'u64'>
   readonly activeJwks: ToField<Vector<ActiveJwk>>
@@ -496,6 +574,13 @@ export class AuthenticatorStateInner implements StructClass {
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([
+      fieldToBcs<'u64'>(`u64`, this.version),
+      fieldToBcs<Vector<ActiveJwk>>(`vector<${ActiveJwk.$typeName}>`, this.activeJwks),
+    ])
+  }
+
   static fromJSONField(field: any): AuthenticatorStateInner {
     return AuthenticatorStateInner.reified().new({
       version: decodeFromJSONField('u64', field.version),
@@ -552,6 +637,15 @@ export class AuthenticatorStateInner implements StructClass {
 
     return AuthenticatorStateInner.fromSuiObjectData(res.data)
   }
+
+  static async fetchMany(
+    client: SuiClient,
+    ids: string[]
+  ): Promise<Array<FetchObjectResult<AuthenticatorStateInner>>> {
+    return fetchObjects(client, ids, (data: SuiObjectData) =>
+      AuthenticatorStateInner.fromSuiObjectData(data)
+    )
+  }
 }
 
 /* ============================== JWK =============================== */
@@ -576,11 +670,18 @@ export class JWK implements StructClass {
   static readonly $typeName = `${PKG_V27}::authenticator_state::JWK`
   static readonly $numTypeParams = 0
   static readonly $isPhantom = [] as const
+  static readonly $moveFieldNames = {
+    kty: 'kty',
+    e: 'e',
+    n: 'n',
+    alg: 'alg',
+  } as const
 
   readonly $typeName = JWK.$typeName
   readonly $fullTypeName: `${typeof PKG_V27}::authenticator_state::JWK`
   readonly $typeArgs: []
   readonly $isPhantom = JWK.$isPhantom
+  readonly $moveFieldNames = JWK.$moveFieldNames
 
   readonly kty: ToField<String>
   readonly e: ToField<String>
@@ -685,6 +786,15 @@ export class JWK implements StructClass {
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([
+      fieldToBcs<String>(`${String.$typeName}`, this.kty),
+      fieldToBcs<String>(`${String.$typeName}`, this.e),
+      fieldToBcs<String>(`${String.$typeName}`, this.n),
+      fieldToBcs<String>(`${String.$typeName}`, this.alg),
+    ])
+  }
+
   static fromJSONField(field: any): JWK {
     return JWK.reified().new({
       kty: decodeFromJSONField(String.reified(), field.kty),
@@ -739,6 +849,10 @@ export class JWK implements StructClass {
 
     return JWK.fromSuiObjectData(res.data)
   }
+
+  static async fetchMany(client: SuiClient, ids: string[]): Promise<Array<FetchObjectResult<JWK>>> {
+    return fetchObjects(client, ids, (data: SuiObjectData) => JWK.fromSuiObjectData(data))
+  }
 }
 
 /* ============================== JwkId =============================== */
@@ -761,11 +875,16 @@ export class JwkId implements StructClass {
   static readonly $typeName = `${PKG_V27}::authenticator_state::JwkId`
   static readonly $numTypeParams = 0
   static readonly $isPhantom = [] as const
+  static readonly $moveFieldNames = {
+    iss: 'iss',
+    kid: 'kid',
+  } as const
 
   readonly $typeName = JwkId.$typeName
   readonly $fullTypeName: `${typeof PKG_V27}::authenticator_state::JwkId`
   readonly $typeArgs: []
   readonly $isPhantom = JwkId.$isPhantom
+  readonly $moveFieldNames = JwkId.$moveFieldNames
 
   readonly iss: ToField<String>
   readonly kid: ToField<String>
@@ -858,6 +977,13 @@ export class JwkId implements StructClass {
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([
+      fieldToBcs<String>(`${String.$typeName}`, this.iss),
+      fieldToBcs<String>(`${String.$typeName}`, this.kid),
+    ])
+  }
+
   static fromJSONField(field: any): JwkId {
     return JwkId.reified().new({
       iss: decodeFromJSONField(String.reified(), field.iss),
@@ -910,4 +1036,11 @@ export class JwkId implements StructClass {
 
     return JwkId.fromSuiObjectData(res.data)
   }
+
+  static async fetchMany(
+    client: SuiClient,
+    ids: string[]
+  ): Promise<Array<FetchObjectResult<JwkId>>> {
+    return fetchObjects(client, ids, (data: SuiObjectData) => JwkId.fromSuiObjectData(data))
+  }
 }
@@ -5,13 +5,21 @@ import {
   StructClass,
   ToField,
   ToTypeStr,
+  concatBytes,
   decodeFromFields,
   decodeFromFieldsWithTypes,
   decodeFromJSONField,
+  fieldToBcs,
   fieldToJSON,
   phantom,
 } from '../../_framework/reified'
-import { FieldsWithTypes, composeSuiType, compressSuiType } from '../../_framework/util'
+import {
+  FetchObjectResult,
+  FieldsWithTypes,
+  composeSuiType,
+  compressSuiType,
+  fetchObjects,
+} from '../../_framework/util'
 import { Vector } from '../../_framework/vector'
 import { PKG_V27 } from '../index'
 import { bcs } from '@mysten/sui/bcs'
@@ -37,11 +45,15 @@ export class BCS implements StructClass {
   static readonly $typeName = `${PKG_V27}::bcs::BCS`
   static readonly $numTypeParams = 0
   static readonly $isPhantom = [] as const
+  static readonly $moveFieldNames = {
+    bytes: 'bytes',
+  } as const
 
   readonly $typeName = BCS.$typeName
   readonly $fullTypeName: `${typeof PKG_V27}::bcs::BCS`
   readonly $typeArgs: []
   readonly $isPhantom = BCS.$isPhantom
+  readonly $moveFieldNames = BCS.$moveFieldNames
 
   readonly bytes: ToField<Vector<'u8'>>
 
@@ -120,6 +132,10 @@ export class BCS implements StructClass {
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([fieldToBcs<Vector<'u8'>>(`vector<u8>`, this.bytes)])
+  }
+
   static fromJSONField(field: any): BCS {
     return BCS.reified().new({ bytes: decodeFromJSONField(reified.vector('u8'), field.bytes) })
   }
@@ -169,4 +185,8 @@ export class BCS implements StructClass {
 
     return BCS.fromSuiObjectData(res.data)
   }
+
+  static async fetchMany(client: SuiClient, ids: string[]): Promise<Array<FetchObjectResult<BCS>>> {
+    return fetchObjects(client, ids, (data: SuiObjectData) => BCS.fromSuiObjectData(data))
+  }
 }
@@ -1,3 +1,4 @@
+import { DisplayTemplate, fetchDisplay } from '../../_framework/display'
 import * as reified from '../../_framework/reified'
 import {
   PhantomReified,
@@ -5,13 +6,23 @@ import {
   StructClass,
   ToField,
   ToTypeStr,
+  concatBytes,
   decodeFromFields,
   decodeFromFieldsWithTypes,
   decodeFromJSONField,
+  fieldToBcs,
   phantom,
   ToTypeStr as ToPhantom,
 } from '../../_framework/reified'
-import { FieldsWithTypes, composeSuiType, compressSuiType } from '../../_framework/util'
+import {
+  FetchObjectResult,
+  FieldsWithTypes,
+  TypedObjectId,
+  composeSuiType,
+  compressSuiType,
+  fetchObjects,
+  fetchOwnedObjects,
+} from '../../_framework/util'
 import { Vector } from '../../_framework/vector'
 import { Bag } from '../bag/structs'
 import { PKG_V27 } from '../index'
@@ -42,11 +53,16 @@ export class DenyList implements StructClass {
   static readonly $typeName = `${PKG_V27}::deny_list::DenyList`
   static readonly $numTypeParams = 0
   static readonly $isPhantom = [] as const
+  static readonly $moveFieldNames = {
+    id: 'id',
+    lists: 'lists',
+  } as const
 
   readonly $typeName = DenyList.$typeName
   readonly $fullTypeName: `${typeof PKG_V27}::deny_list::DenyList`
   readonly $typeArgs: []
   readonly $isPhantom = DenyList.$isPhantom
+  readonly $moveFieldNames = DenyList.$moveFieldNames
 
   readonly id: ToField<UID>
   readonly lists: ToField<Bag>
@@ -62,6 +78,14 @@ export class DenyList implements StructClass {
     this.lists = fields.lists
   }
 
+  get $objectId(): string {
+    return this.id
+  }
+
+  static id(id: string): TypedObjectId<DenyList> {
+    return id as TypedObjectId<DenyList>
+  }
+
   static reified(): DenyListReified {
     return {
       typeName: DenyList.$typeName,
@@ -139,6 +163,13 @@ export class DenyList implements StructClass {
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([
+      fieldToBcs<UID>(`${UID.$typeName}`, this.id),
+      fieldToBcs<Bag>(`${Bag.$typeName}`, this.lists),
+    ])
+  }
+
   static fromJSONField(field: any): DenyList {
     return DenyList.reified().new({
       id: decodeFromJSONField(UID.reified(), field.id),
@@ -191,6 +222,23 @@ export class DenyList implements StructClass {
 
     return DenyList.fromSuiObjectData(res.data)
   }
+
+  static async fetchMany(
+    client: SuiClient,
+    ids: string[]
+  ): Promise<Array<FetchObjectResult<DenyList>>> {
+    return fetchObjects(client, ids, (data: SuiObjectData) => DenyList.fromSuiObjectData(data))
+  }
+
+  static async *fetchOwned(client: SuiClient, owner: string): AsyncGenerator<DenyList> {
+    yield* fetchOwnedObjects(client, owner, DenyList.$typeName, (data: SuiObjectData) =>
+      DenyList.fromSuiObjectData(data)
+    )
+  }
+
+  static async fetchDisplay(client: SuiClient): Promise<DisplayTemplate<DenyList> | null> {
+    return fetchDisplay(client, DenyList.reified().fullTypeName)
+  }
 }
 
 /* ============================== PerTypeList =============================== */
@@ -214,11 +262,17 @@ export class PerTypeList implements StructClass {
   static readonly $typeName = `${PKG_V27}::deny_list::PerTypeList`
   static readonly $numTypeParams = 0
   static readonly $isPhantom = [] as const
+  static readonly $moveFieldNames = {
+    id: 'id',
+    denied_count: 'deniedCount',
+    denied_addresses: 'deniedAddresses',
+  } as const
 
   readonly $typeName = PerTypeList.$typeName
   readonly $fullTypeName: `${typeof PKG_V27}::deny_list::PerTypeList`
   readonly $typeArgs: []
   readonly $isPhantom = PerTypeList.$isPhantom
+  readonly $moveFieldNames = PerTypeList.$moveFieldNames
 
   readonly id: ToField<UID>
   readonly deniedCount: ToField<Table<'address', 'u64'>>
@@ -236,6 +290,14 @@ export class PerTypeList implements StructClass {
     this.deniedAddresses = fields.deniedAddresses
   }
 
+  get $objectId(): string {
+    return this.id
+  }
+
+  static id(id: string): TypedObjectId<PerTypeList> {
+    return id as TypedObjectId<PerTypeList>
+  }
+
   static reified(): PerTypeListReified {
     return {
       typeName: PerTypeList.$typeName,
@@ -335,6 +397,17 @@ export class PerTypeList implements StructClass {
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([
+      fieldToBcs<UID>(`${UID.$typeName}`, this.id),
+      fieldToBcs<Table<'address', 'u64'>>(`${Table.$typeName}<address, u64>`, this.deniedCount),
+      fieldToBcs<Table<ToPhantom<Vector<'u8'>>, ToPhantom<VecSet<'address'>>>>(
+        `${Table.$typeName}<vector<u8>, ${VecSet.$typeName}<address>>`,
+        this.deniedAddresses
+      ),
+    ])
+  }
+
   static fromJSONField(field: any): PerTypeList {
     return PerTypeList.reified().new({
       id: decodeFromJSONField(UID.reified(), field.id),
@@ -397,4 +470,21 @@ export class PerTypeList implements StructClass {
 
     return PerTypeList.fromSuiObjectData(res.data)
   }
+
+  static async fetchMany(
+    client: SuiClient,
+    ids: string[]
+  ): Promise<Array<FetchObjectResult<PerTypeList>>> {
+    return fetchObjects(client, ids, (data: SuiObjectData) => PerTypeList.fromSuiObjectData(data))
+  }
+
+  static async *fetchOwned(client: SuiClient, owner: string): AsyncGenerator<PerTypeList> {
+    yield* fetchOwnedObjects(client, owner, PerTypeList.$typeName, (data: SuiObjectData) =>
+      PerTypeList.fromSuiObjectData(data)
+    )
+  }
+
+  static async fetchDisplay(client: SuiClient): Promise<DisplayTemplate<PerTypeList> | null> {
+    return fetchDisplay(client, PerTypeList.reified().fullTypeName)
+  }
 }
@@ -1,3 +1,4 @@
+import { DisplayTemplate, fetchDisplay } from '../../_framework/display'
 import {
   PhantomReified,
   Reified,
@@ -8,18 +9,25 @@ import {
   TypeArgument,
   assertFieldsWithTypesArgsMatch,
   assertReifiedTypeArgsMatch,
+  concatBytes,
   decodeFromFields,
   decodeFromFieldsWithTypes,
   decodeFromJSONField,
   extractType,
+  fieldToBcs,
   fieldToJSON,
   phantom,
   toBcs,
 } from '../../_framework/reified'
 import {
+  FetchObjectResult,
   FieldsWithTypes,
+  TypedObjectId,
   composeSuiType,
   compressSuiType,
+  fetchObjects,
+  fetchObjectsOfType,
+  fetchOwnedObjects,
   parseTypeName,
 } from '../../_framework/util'
 import { PKG_V27 } from '../index'
@@ -52,11 +60,17 @@ export class Field<Name extends TypeArgument, Value extends TypeArgument> implem
   static readonly $typeName = `${PKG_V27}::dynamic_field::Field`
   static readonly $numTypeParams = 2
   static readonly $isPhantom = [false, false] as const
+  static readonly $moveFieldNames = {
+    id: 'id',
+    name: 'name',
+    value: 'value',
+  } as const
 
   readonly $typeName = Field.$typeName
   readonly $fullTypeName: `${typeof PKG_V27}::dynamic_field::Field<${ToTypeStr<Name>}, ${ToTypeStr<Value>}>`
   readonly $typeArgs: [ToTypeStr<Name>, ToTypeStr<Value>]
   readonly $isPhantom = Field.$isPhantom
+  readonly $moveFieldNames = Field.$moveFieldNames
 
   readonly id: ToField<UID>
   readonly name: ToField<Name>
@@ -77,6 +91,16 @@ export class Field<Name extends TypeArgument, Value extends TypeArgument> implem
     this.value = fields.value
   }
 
+  get $objectId(): string {
+    return this.id
+  }
+
+  static id<Name extends TypeArgument, Value extends TypeArgument>(
+    id: string
+  ): TypedObjectId<Field<Name, Value>> {
+    return id as TypedObjectId<Field<Name, Value>>
+  }
+
   static reified<Name extends Reified<TypeArgument, any>, Value extends Reified<TypeArgument, any>>(
     Name: Name,
     Value: Value
@@ -187,6 +211,14 @@ export class Field<Name extends TypeArgument, Value extends TypeArgument> implem
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([
+      fieldToBcs<UID>(`${UID.$typeName}`, this.id),
+      fieldToBcs<Name>(`${this.$typeArgs[0]}`, this.name),
+      fieldToBcs<Value>(`${this.$typeArgs[1]}`, this.value),
+    ])
+  }
+
   static fromJSONField<
     Name extends Reified<TypeArgument, any>,
     Value extends Reified<TypeArgument, any>,
@@ -289,4 +321,60 @@ export class Field<Name extends TypeArgument, Value extends TypeArgument> implem
 
     return Field.fromSuiObjectData(typeArgs, res.data)
   }
+
+  static async fetchMany<
+    Name extends Reified<TypeArgument, any>,
+    Value extends Reified<TypeArgument, any>,
+  >(
+    client: SuiClient,
+    ids: string[],
+    typeArgs?: [Name, Value]
+  ): Promise<Array<FetchObjectResult<Field<ToTypeArgument<Name>, ToTypeArgument<Value>>>>> {
+    if (typeArgs === undefined) {
+      return fetchObjectsOfType<Field<ToTypeArgument<Name>, ToTypeArgument<Value>>>(
+        client,
+        ids,
+        Field.$typeName
+      )
+    }
+
+    return fetchObjects(client, ids, (data: SuiObjectData) =>
+      Field.fromSuiObjectData(typeArgs, data)
+    )
+  }
+
+  static async *fetchOwned<
+    Name extends Reified<TypeArgument, any>,
+    Value extends Reified<TypeArgument, any>,
+  >(
+    client: SuiClient,
+    owner: string,
+    typeArgs?: [Name, Value]
+  ): AsyncGenerator<Field<ToTypeArgument<Name>, ToTypeArgument<Value>>> {
+    if (typeArgs === undefined) {
+      yield* fetchOwnedObjects<Field<ToTypeArgument<Name>, ToTypeArgument<Value>>>(
+        client,
+        owner,
+        Field.$typeName
+      )
+      return
+    }
+
+    yield* fetchOwnedObjects(
+      client,
+      owner,
+      composeSuiType(Field.$typeName, ...typeArgs.map(typeArg => extractType(typeArg))),
+      (data: SuiObjectData) => Field.fromSuiObjectData(typeArgs, data)
+    )
+  }
+
+  static async fetchDisplay<
+    Name extends Reified<TypeArgument, any>,
+    Value extends Reified<TypeArgument, any>,
+  >(
+    client: SuiClient,
+    typeArgs: [Name, Value]
+  ): Promise<DisplayTemplate<Field<ToTypeArgument<Name>, ToTypeArgument<Value>>> | null> {
+    return fetchDisplay(client, Field.reified(...typeArgs).fullTypeName)
+  }
 }
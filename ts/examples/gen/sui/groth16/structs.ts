@@ -5,13 +5,21 @@ import {
   StructClass,
   ToField,
   ToTypeStr,
+  concatBytes,
   decodeFromFields,
   decodeFromFieldsWithTypes,
   decodeFromJSONField,
+  fieldToBcs,
   fieldToJSON,
   phantom,
 } from '../../_framework/reified'
-import { FieldsWithTypes, composeSuiType, compressSuiType } from '../../_framework/util'
+import {
+  FetchObjectResult,
+  FieldsWithTypes,
+  composeSuiType,
+  compressSuiType,
+  fetchObjects,
+} from '../../_framework/util'
 import { Vector } from '../../_framework/vector'
 import { PKG_V27 } from '../index'
 import { bcs } from '@mysten/sui/bcs'
@@ -37,11 +45,15 @@ export class Curve implements StructClass {
   static readonly $typeName = `${PKG_V27}::groth16::Curve`
   static readonly $numTypeParams = 0
   static readonly $isPhantom = [] as const
+  static readonly $moveFieldNames = {
+    id: 'id',
+  } as const
 
   readonly $typeName = Curve.$typeName
   readonly $fullTypeName: `${typeof PKG_V27}::groth16::Curve`
   readonly $typeArgs: []
   readonly $isPhantom = Curve.$isPhantom
+  readonly $moveFieldNames = Curve.$moveFieldNames
 
   readonly id: ToField<'u8'>
 
@@ -121,6 +133,10 @@ export class Curve implements StructClass {
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([fieldToBcs<'u8'>(`u8`, this.id)])
+  }
+
   static fromJSONField(field: any): Curve {
     return Curve.reified().new({ id: decodeFromJSONField('u8', field.id) })
   }
@@ -170,6 +186,13 @@ export class Curve implements StructClass {
 
     return Curve.fromSuiObjectData(res.data)
   }
+
+  static async fetchMany(
+    client: SuiClient,
+    ids: string[]
+  ): Promise<Array<FetchObjectResult<Curve>>> {
+    return fetchObjects(client, ids, (data: SuiObjectData) => Curve.fromSuiObjectData(data))
+  }
 }
 
 /* ============================== PreparedVerifyingKey =============================== */
@@ -194,11 +217,18 @@ export class PreparedVerifyingKey implements StructClass {
   static readonly $typeName = `${PKG_V27}::groth16::PreparedVerifyingKey`
   static readonly $numTypeParams = 0
   static readonly $isPhantom = [] as const
+  static readonly $moveFieldNames = {
+    vk_gamma_abc_g1_bytes: 'vkGammaAbcG1Bytes',
+    alpha_g1_beta_g2_bytes: 'alphaG1BetaG2Bytes',
+    gamma_g2_neg_pc_bytes: 'gammaG2NegPcBytes',
+    delta_g2_neg_pc_bytes: 'deltaG2NegPcBytes',
+  } as const
 
   readonly $typeName = PreparedVerifyingKey.$typeName
   readonly $fullTypeName: `${typeof PKG_V27}::groth16::PreparedVerifyingKey`
   readonly $typeArgs: []
   readonly $isPhantom = PreparedVerifyingKey.$isPhantom
+  readonly $moveFieldNames = PreparedVerifyingKey.$moveFieldNames
 
   readonly vkGammaAbcG1Bytes: ToField<Vector<'u8'>>
   readonly alphaG1BetaG2Bytes: ToField<Vector<'u8'>>
@@ -318,6 +348,15 @@ export class PreparedVerifyingKey implements StructClass {
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([
+      fieldToBcs<Vector<'u8'>>(`vector<u8>`, this.vkGammaAbcG1Bytes),
+      fieldToBcs<Vector<'u8'>>(`vector<u8>`, this.alphaG1BetaG2Bytes),
+      fieldToBcs<Vector<'u8'>>(`vector<u8>`, this.gammaG2NegPcBytes),
+      fieldToBcs<Vector<'u8'>>(`vector<u8>`, this.deltaG2NegPcBytes),
+    ])
+  }
+
   static fromJSONField(field: any): PreparedVerifyingKey {
     return PreparedVerifyingKey.reified().new({
       vkGammaAbcG1Bytes: decodeFromJSONField(reified.vector('u8'), field.vkGammaAbcG1Bytes),
@@ -374,6 +413,15 @@ export class PreparedVerifyingKey implements StructClass {
 
     return PreparedVerifyingKey.fromSuiObjectData(res.data)
   }
+
+  static async fetchMany(
+    client: SuiClient,
+    ids: string[]
+  ): Promise<Array<FetchObjectResult<PreparedVerifyingKey>>> {
+    return fetchObjects(client, ids, (data: SuiObjectData) =>
+      PreparedVerifyingKey.fromSuiObjectData(data)
+    )
+  }
 }
 
 /* ============================== ProofPoints =============================== */
@@ -395,11 +443,15 @@ export class ProofPoints implements StructClass {
   static readonly $typeName = `${PKG_V27}::groth16::ProofPoints`
   static readonly $numTypeParams = 0
   static readonly $isPhantom = [] as const
+  static readonly $moveFieldNames = {
+    bytes: 'bytes',
+  } as const
 
   readonly $typeName = ProofPoints.$typeName
   readonly $fullTypeName: `${typeof PKG_V27}::groth16::ProofPoints`
   readonly $typeArgs: []
   readonly $isPhantom = ProofPoints.$isPhantom
+  readonly $moveFieldNames = ProofPoints.$moveFieldNames
 
   readonly bytes: ToField<Vector<'u8'>>
 
@@ -486,6 +538,10 @@ export class ProofPoints implements StructClass {
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([fieldToBcs<Vector<'u8'>>(`vector<u8>`, this.bytes)])
+  }
+
   static fromJSONField(field: any): ProofPoints {
     return ProofPoints.reified().new({
       bytes: decodeFromJSONField(reified.vector('u8'), field.bytes),
@@ -537,6 +593,13 @@ export class ProofPoints implements StructClass {
 
     return ProofPoints.fromSuiObjectData(res.data)
   }
+
+  static async fetchMany(
+    client: SuiClient,
+    ids: string[]
+  ): Promise<Array<FetchObjectResult<ProofPoints>>> {
+    return fetchObjects(client, ids, (data: SuiObjectData) => ProofPoints.fromSuiObjectData(data))
+  }
 }
 
 /* ============================== PublicProofInputs =============================== */
@@ -558,11 +621,15 @@ export class PublicProofInputs implements StructClass {
   static readonly $typeName = `${PKG_V27}::groth16::PublicProofInputs`
   static readonly $numTypeParams = 0
   static readonly $isPhantom = [] as const
+  static readonly $moveFieldNames = {
+    bytes: 'bytes',
+  } as const
 
   readonly $typeName = PublicProofInputs.$typeName
   readonly $fullTypeName: `${typeof PKG_V27}::groth16::PublicProofInputs`
   readonly $typeArgs: []
   readonly $isPhantom = PublicProofInputs.$isPhantom
+  readonly $moveFieldNames = PublicProofInputs.$moveFieldNames
 
   readonly bytes: ToField<Vector<'u8'>>
 
@@ -649,6 +716,10 @@ export class PublicProofInputs implements StructClass {
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([fieldToBcs<Vector<'u8'>>(`vector<u8>`, this.bytes)])
+  }
+
   static fromJSONField(field: any): PublicProofInputs {
     return PublicProofInputs.reified().new({
       bytes: decodeFromJSONField(reified.vector('u8'), field.bytes),
@@ -700,4 +771,13 @@ export class PublicProofInputs implements StructClass {
 
     return PublicProofInputs.fromSuiObjectData(res.data)
   }
+
+  static async fetchMany(
+    client: SuiClient,
+    ids: string[]
+  ): Promise<Array<FetchObjectResult<PublicProofInputs>>> {
+    return fetchObjects(client, ids, (data: SuiObjectData) =>
+      PublicProofInputs.fromSuiObjectData(data)
+    )
+  }
 }
@@ -1,3 +1,4 @@
+import { DisplayTemplate, fetchDisplay } from '../../_framework/display'
 import * as reified from '../../_framework/reified'
 import {
   PhantomReified,
@@ -10,17 +11,24 @@ import {
   ToTypeStr,
   assertFieldsWithTypesArgsMatch,
   assertReifiedTypeArgsMatch,
+  concatBytes,
   decodeFromFields,
   decodeFromFieldsWithTypes,
   decodeFromJSONField,
   extractType,
+  fieldToBcs,
   phantom,
   ToTypeStr as ToPhantom,
 } from '../../_framework/reified'
 import {
+  FetchObjectResult,
   FieldsWithTypes,
+  TypedObjectId,
   composeSuiType,
   compressSuiType,
+  fetchObjects,
+  fetchObjectsOfType,
+  fetchOwnedObjects,
   parseTypeName,
 } from '../../_framework/util'
 import { Balance } from '../balance/structs'
@@ -51,11 +59,16 @@ export class Borrow implements StructClass {
   static readonly $typeName = `${PKG_V27}::kiosk::Borrow`
   static readonly $numTypeParams = 0
   static readonly $isPhantom = [] as const
+  static readonly $moveFieldNames = {
+    kiosk_id: 'kioskId',
+    item_id: 'itemId',
+  } as const
 
   readonly $typeName = Borrow.$typeName
   readonly $fullTypeName: `${typeof PKG_V27}::kiosk::Borrow`
   readonly $typeArgs: []
   readonly $isPhantom = Borrow.$isPhantom
+  readonly $moveFieldNames = Borrow.$moveFieldNames
 
   readonly kioskId: ToField<ID>
   readonly itemId: ToField<ID>
@@ -145,6 +158,13 @@ export class Borrow implements StructClass {
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([
+      fieldToBcs<ID>(`${ID.$typeName}`, this.kioskId),
+      fieldToBcs<ID>(`${ID.$typeName}`, this.itemId),
+    ])
+  }
+
   static fromJSONField(field: any): Borrow {
     return Borrow.reified().new({
       kioskId: decodeFromJSONField(ID.reified(), field.kioskId),
@@ -197,6 +217,13 @@ export class Borrow implements StructClass {
 
     return Borrow.fromSuiObjectData(res.data)
   }
+
+  static async fetchMany(
+    client: SuiClient,
+    ids: string[]
+  ): Promise<Array<FetchObjectResult<Borrow>>> {
+    return fetchObjects(client, ids, (data: SuiObjectData) => Borrow.fromSuiObjectData(data))
+  }
 }
 
 /* ============================== Item =============================== */
@@ -218,11 +245,15 @@ export class Item implements StructClass {
   static readonly $typeName = `${PKG_V27}::kiosk::Item`
   static readonly $numTypeParams = 0
   static readonly $isPhantom = [] as const
+  static readonly $moveFieldNames = {
+    id: 'id',
+  } as const
 
   readonly $typeName = Item.$typeName
   readonly $fullTypeName: `${typeof PKG_V27}::kiosk::Item`
   readonly $typeArgs: []
   readonly $isPhantom = Item.$isPhantom
+  readonly $moveFieldNames = Item.$moveFieldNames
 
   readonly id: ToField<ID>
 
@@ -302,6 +333,10 @@ export class Item implements StructClass {
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([fieldToBcs<ID>(`${ID.$typeName}`, this.id)])
+  }
+
   static fromJSONField(field: any): Item {
     return Item.reified().new({ id: decodeFromJSONField(ID.reified(), field.id) })
   }
@@ -351,6 +386,13 @@ export class Item implements StructClass {
 
     return Item.fromSuiObjectData(res.data)
   }
+
+  static async fetchMany(
+    client: SuiClient,
+    ids: string[]
+  ): Promise<Array<FetchObjectResult<Item>>> {
+    return fetchObjects(client, ids, (data: SuiObjectData) => Item.fromSuiObjectData(data))
+  }
 }
 
 /* ============================== ItemDelisted =============================== */
@@ -376,11 +418,16 @@ export class ItemDelisted<T extends PhantomTypeArgument> implements StructClass
   static readonly $typeName = `${PKG_V27}::kiosk::ItemDelisted`
   static readonly $numTypeParams = 1
   static readonly $isPhantom = [true] as const
+  static readonly $moveFieldNames = {
+    kiosk: 'kiosk',
+    id: 'id',
+  } as const
 
   readonly $typeName = ItemDelisted.$typeName
   readonly $fullTypeName: `${typeof PKG_V27}::kiosk::ItemDelisted<${PhantomToTypeStr<T>}>`
   readonly $typeArgs: [PhantomToTypeStr<T>]
   readonly $isPhantom = ItemDelisted.$isPhantom
+  readonly $moveFieldNames = ItemDelisted.$moveFieldNames
 
   readonly kiosk: ToField<ID>
   readonly id: ToField<ID>
@@ -487,6 +534,13 @@ export class ItemDelisted<T extends PhantomTypeArgument> implements StructClass
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([
+      fieldToBcs<ID>(`${ID.$typeName}`, this.kiosk),
+      fieldToBcs<ID>(`${ID.$typeName}`, this.id),
+    ])
+  }
+
   static fromJSONField<T extends PhantomReified<PhantomTypeArgument>>(
     typeArg: T,
     field: any
@@ -574,6 +628,24 @@ export class ItemDelisted<T extends PhantomTypeArgument> implements StructClass
 
     return ItemDelisted.fromSuiObjectData(typeArg, res.data)
   }
+
+  static async fetchMany<T extends PhantomReified<PhantomTypeArgument>>(
+    client: SuiClient,
+    ids: string[],
+    typeArg?: T
+  ): Promise<Array<FetchObjectResult<ItemDelisted<ToPhantomTypeArgument<T>>>>> {
+    if (typeArg === undefined) {
+      return fetchObjectsOfType<ItemDelisted<ToPhantomTypeArgument<T>>>(
+        client,
+        ids,
+        ItemDelisted.$typeName
+      )
+    }
+
+    return fetchObjects(client, ids, (data: SuiObjectData) =>
+      ItemDelisted.fromSuiObjectData(typeArg, data)
+    )
+  }
 }
 
 /* ============================== ItemListed =============================== */
@@ -600,11 +672,17 @@ export class ItemListed<T extends PhantomTypeArgument> implements StructClass {
   static readonly $typeName = `${PKG_V27}::kiosk::ItemListed`
   static readonly $numTypeParams = 1
   static readonly $isPhantom = [true] as const
+  static readonly $moveFieldNames = {
+    kiosk: 'kiosk',
+    id: 'id',
+    price: 'price',
+  } as const
 
   readonly $typeName = ItemListed.$typeName
   readonly $fullTypeName: `${typeof PKG_V27}::kiosk::ItemListed<${PhantomToTypeStr<T>}>`
   readonly $typeArgs: [PhantomToTypeStr<T>]
   readonly $isPhantom = ItemListed.$isPhantom
+  readonly $moveFieldNames = ItemListed.$moveFieldNames
 
   readonly kiosk: ToField<ID>
   readonly id: ToField<ID>
@@ -717,6 +795,14 @@ export class ItemListed<T extends PhantomTypeArgument> implements StructClass {
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([
+      fieldToBcs<ID>(`${ID.$typeName}`, this.kiosk),
+      fieldToBcs<ID>(`${ID.$typeName}`, this.id),
+      fieldToBcs<'u64'>(`u64`, this.price),
+    ])
+  }
+
   static fromJSONField<T extends PhantomReified<PhantomTypeArgument>>(
     typeArg: T,
     field: any
@@ -805,6 +891,24 @@ export class ItemListed<T extends PhantomTypeArgument> implements StructClass {
 
     return ItemListed.fromSuiObjectData(typeArg, res.data)
   }
+
+  static async fetchMany<T extends PhantomReified<PhantomTypeArgument>>(
+    client: SuiClient,
+    ids: string[],
+    typeArg?: T
+  ): Promise<Array<FetchObjectResult<ItemListed<ToPhantomTypeArgument<T>>>>> {
+    if (typeArg === undefined) {
+      return fetchObjectsOfType<ItemListed<ToPhantomTypeArgument<T>>>(
+        client,
+        ids,
+        ItemListed.$typeName
+      )
+    }
+
+    return fetchObjects(client, ids, (data: SuiObjectData) =>
+      ItemListed.fromSuiObjectData(typeArg, data)
+    )
+  }
 }
 
 /* ============================== ItemPurchased =============================== */
@@ -831,11 +935,17 @@ export class ItemPurchased<T extends PhantomTypeArgument> implements StructClass
   static readonly $typeName = `${PKG_V27}::kiosk::ItemPurchased`
   static readonly $numTypeParams = 1
   static readonly $isPhantom = [true] as const
+  static readonly $moveFieldNames = {
+    kiosk: 'kiosk',
+    id: 'id',
+    price: 'price',
+  } as const
 
   readonly $typeName = ItemPurchased.$typeName
   readonly $fullTypeName: `${typeof PKG_V27}::kiosk::ItemPurchased<${PhantomToTypeStr<T>}>`
   readonly $typeArgs: [PhantomToTypeStr<T>]
   readonly $isPhantom = ItemPurchased.$isPhantom
+  readonly $moveFieldNames = ItemPurchased.$moveFieldNames
 
   readonly kiosk: ToField<ID>
   readonly id: ToField<ID>
@@ -948,6 +1058,14 @@ export class ItemPurchased<T extends PhantomTypeArgument> implements StructClass
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([
+      fieldToBcs<ID>(`${ID.$typeName}`, this.kiosk),
+      fieldToBcs<ID>(`${ID.$typeName}`, this.id),
+      fieldToBcs<'u64'>(`u64`, this.price),
+    ])
+  }
+
   static fromJSONField<T extends PhantomReified<PhantomTypeArgument>>(
     typeArg: T,
     field: any
@@ -1036,6 +1154,24 @@ export class ItemPurchased<T extends PhantomTypeArgument> implements StructClass
 
     return ItemPurchased.fromSuiObjectData(typeArg, res.data)
   }
+
+  static async fetchMany<T extends PhantomReified<PhantomTypeArgument>>(
+    client: SuiClient,
+    ids: string[],
+    typeArg?: T
+  ): Promise<Array<FetchObjectResult<ItemPurchased<ToPhantomTypeArgument<T>>>>> {
+    if (typeArg === undefined) {
+      return fetchObjectsOfType<ItemPurchased<ToPhantomTypeArgument<T>>>(
+        client,
+        ids,
+        ItemPurchased.$typeName
+      )
+    }
+
+    return fetchObjects(client, ids, (data: SuiObjectData) =>
+      ItemPurchased.fromSuiObjectData(typeArg, data)
+    )
+  }
 }
 
 /* ============================== Kiosk =============================== */
@@ -1061,11 +1197,19 @@ export class Kiosk implements StructClass {
   static readonly $typeName = `${PKG_V27}::kiosk::Kiosk`
   static readonly $numTypeParams = 0
   static readonly $isPhantom = [] as const
+  static readonly $moveFieldNames = {
+    id: 'id',
+    profits: 'profits',
+    owner: 'owner',
+    item_count: 'itemCount',
+    allow_extensions: 'allowExtensions',
+  } as const
 
   readonly $typeName = Kiosk.$typeName
   readonly $fullTypeName: `${typeof PKG_V27}::kiosk::Kiosk`
   readonly $typeArgs: []
   readonly $isPhantom = Kiosk.$isPhantom
+  readonly $moveFieldNames = Kiosk.$moveFieldNames
 
   readonly id: ToField<UID>
   readonly profits: ToField<Balance<ToPhantom<SUI>>>
@@ -1087,6 +1231,14 @@ export class Kiosk implements StructClass {
     this.allowExtensions = fields.allowExtensions
   }
 
+  get $objectId(): string {
+    return this.id
+  }
+
+  static id(id: string): TypedObjectId<Kiosk> {
+    return id as TypedObjectId<Kiosk>
+  }
+
   static reified(): KioskReified {
     return {
       typeName: Kiosk.$typeName,
@@ -1179,6 +1331,16 @@ export class Kiosk implements StructClass {
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([
+      fieldToBcs<UID>(`${UID.$typeName}`, this.id),
+      fieldToBcs<Balance<ToPhantom<SUI>>>(`${Balance.$typeName}<${SUI.$typeName}>`, this.profits),
+      fieldToBcs<'address'>(`address`, this.owner),
+      fieldToBcs<'u32'>(`u32`, this.itemCount),
+      fieldToBcs<'bool'>(`bool`, this.allowExtensions),
+    ])
+  }
+
   static fromJSONField(field: any): Kiosk {
     return Kiosk.reified().new({
       id: decodeFromJSONField(UID.reified(), field.id),
@@ -1234,6 +1396,23 @@ export class Kiosk implements StructClass {
 
     return Kiosk.fromSuiObjectData(res.data)
   }
+
+  static async fetchMany(
+    client: SuiClient,
+    ids: string[]
+  ): Promise<Array<FetchObjectResult<Kiosk>>> {
+    return fetchObjects(client, ids, (data: SuiObjectData) => Kiosk.fromSuiObjectData(data))
+  }
+
+  static async *fetchOwned(client: SuiClient, owner: string): AsyncGenerator<Kiosk> {
+    yield* fetchOwnedObjects(client, owner, Kiosk.$typeName, (data: SuiObjectData) =>
+      Kiosk.fromSuiObjectData(data)
+    )
+  }
+
+  static async fetchDisplay(client: SuiClient): Promise<DisplayTemplate<Kiosk> | null> {
+    return fetchDisplay(client, Kiosk.reified().fullTypeName)
+  }
 }
 
 /* ============================== KioskOwnerCap =============================== */
@@ -1256,11 +1435,16 @@ export class KioskOwnerCap implements StructClass {
   static readonly $typeName = `${PKG_V27}::kiosk::KioskOwnerCap`
   static readonly $numTypeParams = 0
   static readonly $isPhantom = [] as const
+  static readonly $moveFieldNames = {
+    id: 'id',
+    for: 'for',
+  } as const
 
   readonly $typeName = KioskOwnerCap.$typeName
   readonly $fullTypeName: `${typeof PKG_V27}::kiosk::KioskOwnerCap`
   readonly $typeArgs: []
   readonly $isPhantom = KioskOwnerCap.$isPhantom
+  readonly $moveFieldNames = KioskOwnerCap.$moveFieldNames
 
   readonly id: ToField<UID>
   readonly for: ToField<ID>
@@ -1276,6 +1460,14 @@ export class KioskOwnerCap implements StructClass {
     this.for = fields.for
   }
 
+  get $objectId(): string {
+    return this.id
+  }
+
+  static id(id: string): TypedObjectId<KioskOwnerCap> {
+    return id as TypedObjectId<KioskOwnerCap>
+  }
+
   static reified(): KioskOwnerCapReified {
     return {
       typeName: KioskOwnerCap.$typeName,
@@ -1353,6 +1545,13 @@ export class KioskOwnerCap implements StructClass {
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([
+      fieldToBcs<UID>(`${UID.$typeName}`, this.id),
+      fieldToBcs<ID>(`${ID.$typeName}`, this.for),
+    ])
+  }
+
   static fromJSONField(field: any): KioskOwnerCap {
     return KioskOwnerCap.reified().new({
       id: decodeFromJSONField(UID.reified(), field.id),
@@ -1405,6 +1604,23 @@ export class KioskOwnerCap implements StructClass {
 
     return KioskOwnerCap.fromSuiObjectData(res.data)
   }
+
+  static async fetchMany(
+    client: SuiClient,
+    ids: string[]
+  ): Promise<Array<FetchObjectResult<KioskOwnerCap>>> {
+    return fetchObjects(client, ids, (data: SuiObjectData) => KioskOwnerCap.fromSuiObjectData(data))
+  }
+
+  static async *fetchOwned(client: SuiClient, owner: string): AsyncGenerator<KioskOwnerCap> {
+    yield* fetchOwnedObjects(client, owner, KioskOwnerCap.$typeName, (data: SuiObjectData) =>
+      KioskOwnerCap.fromSuiObjectData(data)
+    )
+  }
+
+  static async fetchDisplay(client: SuiClient): Promise<DisplayTemplate<KioskOwnerCap> | null> {
+    return fetchDisplay(client, KioskOwnerCap.reified().fullTypeName)
+  }
 }
 
 /* ============================== Listing =============================== */
@@ -1427,11 +1643,16 @@ export class Listing implements StructClass {
   static readonly $typeName = `${PKG_V27}::kiosk::Listing`
   static readonly $numTypeParams = 0
   static readonly $isPhantom = [] as const
+  static readonly $moveFieldNames = {
+    id: 'id',
+    is_exclusive: 'isExclusive',
+  } as const
 
   readonly $typeName = Listing.$typeName
   readonly $fullTypeName: `${typeof PKG_V27}::kiosk::Listing`
   readonly $typeArgs: []
   readonly $isPhantom = Listing.$isPhantom
+  readonly $moveFieldNames = Listing.$moveFieldNames
 
   readonly id: ToField<ID>
   readonly isExclusive: ToField<'bool'>
@@ -1521,6 +1742,13 @@ export class Listing implements StructClass {
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([
+      fieldToBcs<ID>(`${ID.$typeName}`, this.id),
+      fieldToBcs<'bool'>(`bool`, this.isExclusive),
+    ])
+  }
+
   static fromJSONField(field: any): Listing {
     return Listing.reified().new({
       id: decodeFromJSONField(ID.reified(), field.id),
@@ -1573,6 +1801,13 @@ export class Listing implements StructClass {
 
     return Listing.fromSuiObjectData(res.data)
   }
+
+  static async fetchMany(
+    client: SuiClient,
+    ids: string[]
+  ): Promise<Array<FetchObjectResult<Listing>>> {
+    return fetchObjects(client, ids, (data: SuiObjectData) => Listing.fromSuiObjectData(data))
+  }
 }
 
 /* ============================== Lock =============================== */
@@ -1594,11 +1829,15 @@ export class Lock implements StructClass {
   static readonly $typeName = `${PKG_V27}::kiosk::Lock`
   static readonly $numTypeParams = 0
   static readonly $isPhantom = [] as const
+  static readonly $moveFieldNames = {
+    id: 'id',
+  } as const
 
   readonly $typeName = Lock.$typeName
   readonly $fullTypeName: `${typeof PKG_V27}::kiosk::Lock`
   readonly $typeArgs: []
   readonly $isPhantom = Lock.$isPhantom
+  readonly $moveFieldNames = Lock.$moveFieldNames
 
   readonly id: ToField<ID>
 
@@ -1678,6 +1917,10 @@ export class Lock implements StructClass {
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([fieldToBcs<ID>(`${ID.$typeName}`, this.id)])
+  }
+
   static fromJSONField(field: any): Lock {
     return Lock.reified().new({ id: decodeFromJSONField(ID.reified(), field.id) })
   }
@@ -1727,6 +1970,13 @@ export class Lock implements StructClass {
 
     return Lock.fromSuiObjectData(res.data)
   }
+
+  static async fetchMany(
+    client: SuiClient,
+    ids: string[]
+  ): Promise<Array<FetchObjectResult<Lock>>> {
+    return fetchObjects(client, ids, (data: SuiObjectData) => Lock.fromSuiObjectData(data))
+  }
 }
 
 /* ============================== PurchaseCap =============================== */
@@ -1754,11 +2004,18 @@ export class PurchaseCap<T extends PhantomTypeArgument> implements StructClass {
   static readonly $typeName = `${PKG_V27}::kiosk::PurchaseCap`
   static readonly $numTypeParams = 1
   static readonly $isPhantom = [true] as const
+  static readonly $moveFieldNames = {
+    id: 'id',
+    kiosk_id: 'kioskId',
+    item_id: 'itemId',
+    min_price: 'minPrice',
+  } as const
 
   readonly $typeName = PurchaseCap.$typeName
   readonly $fullTypeName: `${typeof PKG_V27}::kiosk::PurchaseCap<${PhantomToTypeStr<T>}>`
   readonly $typeArgs: [PhantomToTypeStr<T>]
   readonly $isPhantom = PurchaseCap.$isPhantom
+  readonly $moveFieldNames = PurchaseCap.$moveFieldNames
 
   readonly id: ToField<UID>
   readonly kioskId: ToField<ID>
@@ -1778,6 +2035,14 @@ export class PurchaseCap<T extends PhantomTypeArgument> implements StructClass {
     this.minPrice = fields.minPrice
   }
 
+  get $objectId(): string {
+    return this.id
+  }
+
+  static id<T extends PhantomTypeArgument>(id: string): TypedObjectId<PurchaseCap<T>> {
+    return id as TypedObjectId<PurchaseCap<T>>
+  }
+
   static reified<T extends PhantomReified<PhantomTypeArgument>>(
     T: T
   ): PurchaseCapReified<ToPhantomTypeArgument<T>> {
@@ -1877,6 +2142,15 @@ export class PurchaseCap<T extends PhantomTypeArgument> implements StructClass {
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([
+      fieldToBcs<UID>(`${UID.$typeName}`, this.id),
+      fieldToBcs<ID>(`${ID.$typeName}`, this.kioskId),
+      fieldToBcs<ID>(`${ID.$typeName}`, this.itemId),
+      fieldToBcs<'u64'>(`u64`, this.minPrice),
+    ])
+  }
+
   static fromJSONField<T extends PhantomReified<PhantomTypeArgument>>(
     typeArg: T,
     field: any
@@ -1966,4 +2240,51 @@ export class PurchaseCap<T extends PhantomTypeArgument> implements StructClass {
 
     return PurchaseCap.fromSuiObjectData(typeArg, res.data)
   }
+
+  static async fetchMany<T extends PhantomReified<PhantomTypeArgument>>(
+    client: SuiClient,
+    ids: string[],
+    typeArg?: T
+  ): Promise<Array<FetchObjectResult<PurchaseCap<ToPhantomTypeArgument<T>>>>> {
+    if (typeArg === undefined) {
+      return fetchObjectsOfType<PurchaseCap<ToPhantomTypeArgument<T>>>(
+        client,
+        ids,
+        PurchaseCap.$typeName
+      )
+    }
+
+    return fetchObjects(client, ids, (data: SuiObjectData) =>
+      PurchaseCap.fromSuiObjectData(typeArg, data)
+    )
+  }
+
+  static async *fetchOwned<T extends PhantomReified<PhantomTypeArgument>>(
+    client: SuiClient,
+    owner: string,
+    typeArg?: T
+  ): AsyncGenerator<PurchaseCap<ToPhantomTypeArgument<T>>> {
+    if (typeArg === undefined) {
+      yield* fetchOwnedObjects<PurchaseCap<ToPhantomTypeArgument<T>>>(
+        client,
+        owner,
+        PurchaseCap.$typeName
+      )
+      return
+    }
+
+    yield* fetchOwnedObjects(
+      client,
+      owner,
+      composeSuiType(PurchaseCap.$typeName, extractType(typeArg)),
+      (data: SuiObjectData) => PurchaseCap.fromSuiObjectData(typeArg, data)
+    )
+  }
+
+  static async fetchDisplay<T extends PhantomReified<PhantomTypeArgument>>(
+    client: SuiClient,
+    typeArg: T
+  ): Promise<DisplayTemplate<PurchaseCap<ToPhantomTypeArgument<T>>> | null> {
+    return fetchDisplay(client, PurchaseCap.reified(typeArg).fullTypeName)
+  }
 }
@@ -9,18 +9,23 @@ import {
   TypeArgument,
   assertFieldsWithTypesArgsMatch,
   assertReifiedTypeArgsMatch,
+  concatBytes,
   decodeFromFields,
   decodeFromFieldsWithTypes,
   decodeFromJSONField,
   extractType,
+  fieldToBcs,
   fieldToJSON,
   phantom,
   toBcs,
 } from '../../_framework/reified'
 import {
+  FetchObjectResult,
   FieldsWithTypes,
   composeSuiType,
   compressSuiType,
+  fetchObjects,
+  fetchObjectsOfType,
   parseTypeName,
 } from '../../_framework/util'
 import { Vector } from '../../_framework/vector'
@@ -49,11 +54,16 @@ export class Entry<T extends TypeArgument> implements StructClass {
   static readonly $typeName = `${PKG_V27}::priority_queue::Entry`
   static readonly $numTypeParams = 1
   static readonly $isPhantom = [false] as const
+  static readonly $moveFieldNames = {
+    priority: 'priority',
+    value: 'value',
+  } as const
 
   readonly $typeName = Entry.$typeName
   readonly $fullTypeName: `${typeof PKG_V27}::priority_queue::Entry<${ToTypeStr<T>}>`
   readonly $typeArgs: [ToTypeStr<T>]
   readonly $isPhantom = Entry.$isPhantom
+  readonly $moveFieldNames = Entry.$moveFieldNames
 
   readonly priority: ToField<'u64'>
   readonly value: ToField<T>
@@ -161,6 +171,13 @@ export class Entry<T extends TypeArgument> implements StructClass {
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([
+      fieldToBcs<'u64'>(`u64`, this.priority),
+      fieldToBcs<T>(`${this.$typeArgs[0]}`, this.value),
+    ])
+  }
+
   static fromJSONField<T extends Reified<TypeArgument, any>>(
     typeArg: T,
     field: any
@@ -248,6 +265,20 @@ export class Entry<T extends TypeArgument> implements StructClass {
 
     return Entry.fromSuiObjectData(typeArg, res.data)
   }
+
+  static async fetchMany<T extends Reified<TypeArgument, any>>(
+    client: SuiClient,
+    ids: string[],
+    typeArg?: T
+  ): Promise<Array<FetchObjectResult<Entry<ToTypeArgument<T>>>>> {
+    if (typeArg === undefined) {
+      return fetchObjectsOfType<Entry<ToTypeArgument<T>>>(client, ids, Entry.$typeName)
+    }
+
+    return fetchObjects(client, ids, (data: SuiObjectData) =>
+      Entry.fromSuiObjectData(typeArg, data)
+    )
+  }
 }
 
 /* ============================== PriorityQueue =============================== */
@@ -272,11 +303,15 @@ export class PriorityQueue<T extends TypeArgument> implements StructClass {
   static readonly $typeName = `${PKG_V27}::priority_queue::PriorityQueue`
   static readonly $numTypeParams = 1
   static readonly $isPhantom = [false] as const
+  static readonly $moveFieldNames = {
+    entries: 'entries',
+  } as const
 
   readonly $typeName = PriorityQueue.$typeName
   readonly $fullTypeName: `${typeof PKG_V27}::priority_queue::PriorityQueue<${ToTypeStr<T>}>`
   readonly $typeArgs: [ToTypeStr<T>]
   readonly $isPhantom = PriorityQueue.$isPhantom
+  readonly $moveFieldNames = PriorityQueue.$moveFieldNames
 
   readonly entries: ToField<Vector<Entry<T>>>
 
@@ -386,6 +421,15 @@ export class PriorityQueue<T extends TypeArgument> implements StructClass {
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([
+      fieldToBcs<Vector<Entry<T>>>(
+        `vector<${Entry.$typeName}<${this.$typeArgs[0]}>>`,
+        this.entries
+      ),
+    ])
+  }
+
   static fromJSONField<T extends Reified<TypeArgument, any>>(
     typeArg: T,
     field: any
@@ -472,4 +516,22 @@ export class PriorityQueue<T extends TypeArgument> implements StructClass {
 
     return PriorityQueue.fromSuiObjectData(typeArg, res.data)
   }
+
+  static async fetchMany<T extends Reified<TypeArgument, any>>(
+    client: SuiClient,
+    ids: string[],
+    typeArg?: T
+  ): Promise<Array<FetchObjectResult<PriorityQueue<ToTypeArgument<T>>>>> {
+    if (typeArg === undefined) {
+      return fetchObjectsOfType<PriorityQueue<ToTypeArgument<T>>>(
+        client,
+        ids,
+        PriorityQueue.$typeName
+      )
+    }
+
+    return fetchObjects(client, ids, (data: SuiObjectData) =>
+      PriorityQueue.fromSuiObjectData(typeArg, data)
+    )
+  }
 }
@@ -10,16 +10,21 @@ import {
   ToTypeStr,
   assertFieldsWithTypesArgsMatch,
   assertReifiedTypeArgsMatch,
+  concatBytes,
   decodeFromFields,
   decodeFromFieldsWithTypes,
   decodeFromJSONField,
   extractType,
+  fieldToBcs,
   phantom,
 } from '../../_framework/reified'
 import {
+  FetchObjectResult,
   FieldsWithTypes,
   composeSuiType,
   compressSuiType,
+  fetchObjects,
+  fetchObjectsOfType,
   parseTypeName,
 } from '../../_framework/util'
 import { PKG_V27 } from '../index'
@@ -50,11 +55,15 @@ export class TableVec<Element extends PhantomTypeArgument> implements StructClas
   static readonly $typeName = `${PKG_V27}::table_vec::TableVec`
   static readonly $numTypeParams = 1
   static readonly $isPhantom = [true] as const
+  static readonly $moveFieldNames = {
+    contents: 'contents',
+  } as const
 
   readonly $typeName = TableVec.$typeName
   readonly $fullTypeName: `${typeof PKG_V27}::table_vec::TableVec<${PhantomToTypeStr<Element>}>`
   readonly $typeArgs: [PhantomToTypeStr<Element>]
   readonly $isPhantom = TableVec.$isPhantom
+  readonly $moveFieldNames = TableVec.$moveFieldNames
 
   readonly contents: ToField<Table<'u64', Element>>
 
@@ -158,6 +167,15 @@ export class TableVec<Element extends PhantomTypeArgument> implements StructClas
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([
+      fieldToBcs<Table<'u64', Element>>(
+        `${Table.$typeName}<u64, ${this.$typeArgs[0]}>`,
+        this.contents
+      ),
+    ])
+  }
+
   static fromJSONField<Element extends PhantomReified<PhantomTypeArgument>>(
     typeArg: Element,
     field: any
@@ -244,4 +262,22 @@ export class TableVec<Element extends PhantomTypeArgument> implements StructClas
 
     return TableVec.fromSuiObjectData(typeArg, res.data)
   }
+
+  static async fetchMany<Element extends PhantomReified<PhantomTypeArgument>>(
+    client: SuiClient,
+    ids: string[],
+    typeArg?: Element
+  ): Promise<Array<FetchObjectResult<TableVec<ToPhantomTypeArgument<Element>>>>> {
+    if (typeArg === undefined) {
+      return fetchObjectsOfType<TableVec<ToPhantomTypeArgument<Element>>>(
+        client,
+        ids,
+        TableVec.$typeName
+      )
+    }
+
+    return fetchObjects(client, ids, (data: SuiObjectData) =>
+      TableVec.fromSuiObjectData(typeArg, data)
+    )
+  }
 }
@@ -1,3 +1,4 @@
+import { DisplayTemplate, fetchDisplay } from '../../_framework/display'
 import { Option } from '../../_dependencies/source/0x1/option/structs'
 import { String } from '../../_dependencies/source/0x1/string/structs'
 import { TypeName } from '../../_dependencies/source/0x1/type-name/structs'
@@ -12,17 +13,24 @@ import {
   ToTypeStr,
   assertFieldsWithTypesArgsMatch,
   assertReifiedTypeArgsMatch,
+  concatBytes,
   decodeFromFields,
   decodeFromFieldsWithTypes,
   decodeFromJSONField,
   extractType,
+  fieldToBcs,
   fieldToJSON,
   phantom,
 } from '../../_framework/reified'
 import {
+  FetchObjectResult,
   FieldsWithTypes,
+  TypedObjectId,
   composeSuiType,
   compressSuiType,
+  fetchObjects,
+  fetchObjectsOfType,
+  fetchOwnedObjects,
   parseTypeName,
 } from '../../_framework/util'
 import { Balance } from '../balance/structs'
@@ -53,11 +61,15 @@ export class RuleKey<T extends PhantomTypeArgument> implements StructClass {
   static readonly $typeName = `${PKG_V27}::token::RuleKey`
   static readonly $numTypeParams = 1
   static readonly $isPhantom = [true] as const
+  static readonly $moveFieldNames = {
+    is_protected: 'isProtected',
+  } as const
 
   readonly $typeName = RuleKey.$typeName
   readonly $fullTypeName: `${typeof PKG_V27}::token::RuleKey<${PhantomToTypeStr<T>}>`
   readonly $typeArgs: [PhantomToTypeStr<T>]
   readonly $isPhantom = RuleKey.$isPhantom
+  readonly $moveFieldNames = RuleKey.$moveFieldNames
 
   readonly isProtected: ToField<'bool'>
 
@@ -158,6 +170,10 @@ export class RuleKey<T extends PhantomTypeArgument> implements StructClass {
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([fieldToBcs<'bool'>(`bool`, this.isProtected)])
+  }
+
   static fromJSONField<T extends PhantomReified<PhantomTypeArgument>>(
     typeArg: T,
     field: any
@@ -244,6 +260,20 @@ export class RuleKey<T extends PhantomTypeArgument> implements StructClass {
 
     return RuleKey.fromSuiObjectData(typeArg, res.data)
   }
+
+  static async fetchMany<T extends PhantomReified<PhantomTypeArgument>>(
+    client: SuiClient,
+    ids: string[],
+    typeArg?: T
+  ): Promise<Array<FetchObjectResult<RuleKey<ToPhantomTypeArgument<T>>>>> {
+    if (typeArg === undefined) {
+      return fetchObjectsOfType<RuleKey<ToPhantomTypeArgument<T>>>(client, ids, RuleKey.$typeName)
+    }
+
+    return fetchObjects(client, ids, (data: SuiObjectData) =>
+      RuleKey.fromSuiObjectData(typeArg, data)
+    )
+  }
 }
 
 /* ============================== ActionRequest =============================== */
@@ -273,11 +303,20 @@ export class ActionRequest<T extends PhantomTypeArgument> implements StructClass
   static readonly $typeName = `${PKG_V27}::token::ActionRequest`
   static readonly $numTypeParams = 1
   static readonly $isPhantom = [true] as const
+  static readonly $moveFieldNames = {
+    name: 'name',
+    amount: 'amount',
+    sender: 'sender',
+    recipient: 'recipient',
+    spent_balance: 'spentBalance',
+    approvals: 'approvals',
+  } as const
 
   readonly $typeName = ActionRequest.$typeName
   readonly $fullTypeName: `${typeof PKG_V27}::token::ActionRequest<${PhantomToTypeStr<T>}>`
   readonly $typeArgs: [PhantomToTypeStr<T>]
   readonly $isPhantom = ActionRequest.$isPhantom
+  readonly $moveFieldNames = ActionRequest.$moveFieldNames
 
   readonly name: ToField<String>
   readonly amount: ToField<'u64'>
@@ -428,6 +467,20 @@ export class ActionRequest<T extends PhantomTypeArgument> implements StructClass
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([
+      fieldToBcs<String>(`${String.$typeName}`, this.name),
+      fieldToBcs<'u64'>(`u64`, this.amount),
+      fieldToBcs<'address'>(`address`, this.sender),
+      fieldToBcs<Option<'address'>>(`${Option.$typeName}<address>`, this.recipient),
+      fieldToBcs<Option<Balance<T>>>(
+        `${Option.$typeName}<${Balance.$typeName}<${this.$typeArgs[0]}>>`,
+        this.spentBalance
+      ),
+      fieldToBcs<VecSet<TypeName>>(`${VecSet.$typeName}<${TypeName.$typeName}>`, this.approvals),
+    ])
+  }
+
   static fromJSONField<T extends PhantomReified<PhantomTypeArgument>>(
     typeArg: T,
     field: any
@@ -522,6 +575,24 @@ export class ActionRequest<T extends PhantomTypeArgument> implements StructClass
 
     return ActionRequest.fromSuiObjectData(typeArg, res.data)
   }
+
+  static async fetchMany<T extends PhantomReified<PhantomTypeArgument>>(
+    client: SuiClient,
+    ids: string[],
+    typeArg?: T
+  ): Promise<Array<FetchObjectResult<ActionRequest<ToPhantomTypeArgument<T>>>>> {
+    if (typeArg === undefined) {
+      return fetchObjectsOfType<ActionRequest<ToPhantomTypeArgument<T>>>(
+        client,
+        ids,
+        ActionRequest.$typeName
+      )
+    }
+
+    return fetchObjects(client, ids, (data: SuiObjectData) =>
+      ActionRequest.fromSuiObjectData(typeArg, data)
+    )
+  }
 }
 
 /* ============================== Token =============================== */
@@ -544,11 +615,16 @@ export class Token<T extends PhantomTypeArgument> implements StructClass {
   static readonly $typeName = `${PKG_V27}::token::Token`
   static readonly $numTypeParams = 1
   static readonly $isPhantom = [true] as const
+  static readonly $moveFieldNames = {
+    id: 'id',
+    balance: 'balance',
+  } as const
 
   readonly $typeName = Token.$typeName
   readonly $fullTypeName: `${typeof PKG_V27}::token::Token<${PhantomToTypeStr<T>}>`
   readonly $typeArgs: [PhantomToTypeStr<T>]
   readonly $isPhantom = Token.$isPhantom
+  readonly $moveFieldNames = Token.$moveFieldNames
 
   readonly id: ToField<UID>
   readonly balance: ToField<Balance<T>>
@@ -564,6 +640,14 @@ export class Token<T extends PhantomTypeArgument> implements StructClass {
     this.balance = fields.balance
   }
 
+  get $objectId(): string {
+    return this.id
+  }
+
+  static id<T extends PhantomTypeArgument>(id: string): TypedObjectId<Token<T>> {
+    return id as TypedObjectId<Token<T>>
+  }
+
   static reified<T extends PhantomReified<PhantomTypeArgument>>(
     T: T
   ): TokenReified<ToPhantomTypeArgument<T>> {
@@ -655,6 +739,13 @@ export class Token<T extends PhantomTypeArgument> implements StructClass {
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([
+      fieldToBcs<UID>(`${UID.$typeName}`, this.id),
+      fieldToBcs<Balance<T>>(`${Balance.$typeName}<${this.$typeArgs[0]}>`, this.balance),
+    ])
+  }
+
   static fromJSONField<T extends PhantomReified<PhantomTypeArgument>>(
     typeArg: T,
     field: any
@@ -742,6 +833,45 @@ export class Token<T extends PhantomTypeArgument> implements StructClass {
 
     return Token.fromSuiObjectData(typeArg, res.data)
   }
+
+  static async fetchMany<T extends PhantomReified<PhantomTypeArgument>>(
+    client: SuiClient,
+    ids: string[],
+    typeArg?: T
+  ): Promise<Array<FetchObjectResult<Token<ToPhantomTypeArgument<T>>>>> {
+    if (typeArg === undefined) {
+      return fetchObjectsOfType<Token<ToPhantomTypeArgument<T>>>(client, ids, Token.$typeName)
+    }
+
+    return fetchObjects(client, ids, (data: SuiObjectData) =>
+      Token.fromSuiObjectData(typeArg, data)
+    )
+  }
+
+  static async *fetchOwned<T extends PhantomReified<PhantomTypeArgument>>(
+    client: SuiClient,
+    owner: string,
+    typeArg?: T
+  ): AsyncGenerator<Token<ToPhantomTypeArgument<T>>> {
+    if (typeArg === undefined) {
+      yield* fetchOwnedObjects<Token<ToPhantomTypeArgument<T>>>(client, owner, Token.$typeName)
+      return
+    }
+
+    yield* fetchOwnedObjects(
+      client,
+      owner,
+      composeSuiType(Token.$typeName, extractType(typeArg)),
+      (data: SuiObjectData) => Token.fromSuiObjectData(typeArg, data)
+    )
+  }
+
+  static async fetchDisplay<T extends PhantomReified<PhantomTypeArgument>>(
+    client: SuiClient,
+    typeArg: T
+  ): Promise<DisplayTemplate<Token<ToPhantomTypeArgument<T>>> | null> {
+    return fetchDisplay(client, Token.reified(typeArg).fullTypeName)
+  }
 }
 
 /* ============================== TokenPolicy =============================== */
@@ -768,11 +898,17 @@ export class TokenPolicy<T extends PhantomTypeArgument> implements StructClass {
   static readonly $typeName = `${PKG_V27}::token::TokenPolicy`
   static readonly $numTypeParams = 1
   static readonly $isPhantom = [true] as const
+  static readonly $moveFieldNames = {
+    id: 'id',
+    spent_balance: 'spentBalance',
+    rules: 'rules',
+  } as const
 
   readonly $typeName = TokenPolicy.$typeName
   readonly $fullTypeName: `${typeof PKG_V27}::token::TokenPolicy<${PhantomToTypeStr<T>}>`
   readonly $typeArgs: [PhantomToTypeStr<T>]
   readonly $isPhantom = TokenPolicy.$isPhantom
+  readonly $moveFieldNames = TokenPolicy.$moveFieldNames
 
   readonly id: ToField<UID>
   readonly spentBalance: ToField<Balance<T>>
@@ -790,6 +926,14 @@ export class TokenPolicy<T extends PhantomTypeArgument> implements StructClass {
     this.rules = fields.rules
   }
 
+  get $objectId(): string {
+    return this.id
+  }
+
+  static id<T extends PhantomTypeArgument>(id: string): TypedObjectId<TokenPolicy<T>> {
+    return id as TypedObjectId<TokenPolicy<T>>
+  }
+
   static reified<T extends PhantomReified<PhantomTypeArgument>>(
     T: T
   ): TokenPolicyReified<ToPhantomTypeArgument<T>> {
@@ -891,6 +1035,17 @@ export class TokenPolicy<T extends PhantomTypeArgument> implements StructClass {
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([
+      fieldToBcs<UID>(`${UID.$typeName}`, this.id),
+      fieldToBcs<Balance<T>>(`${Balance.$typeName}<${this.$typeArgs[0]}>`, this.spentBalance),
+      fieldToBcs<VecMap<String, VecSet<TypeName>>>(
+        `${VecMap.$typeName}<${String.$typeName}, ${VecSet.$typeName}<${TypeName.$typeName}>>`,
+        this.rules
+      ),
+    ])
+  }
+
   static fromJSONField<T extends PhantomReified<PhantomTypeArgument>>(
     typeArg: T,
     field: any
@@ -982,6 +1137,53 @@ export class TokenPolicy<T extends PhantomTypeArgument> implements StructClass {
 
     return TokenPolicy.fromSuiObjectData(typeArg, res.data)
   }
+
+  static async fetchMany<T extends PhantomReified<PhantomTypeArgument>>(
+    client: SuiClient,
+    ids: string[],
+    typeArg?: T
+  ): Promise<Array<FetchObjectResult<TokenPolicy<ToPhantomTypeArgument<T>>>>> {
+    if (typeArg === undefined) {
+      return fetchObjectsOfType<TokenPolicy<ToPhantomTypeArgument<T>>>(
+        client,
+        ids,
+        TokenPolicy.$typeName
+      )
+    }
+
+    return fetchObjects(client, ids, (data: SuiObjectData) =>
+      TokenPolicy.fromSuiObjectData(typeArg, data)
+    )
+  }
+
+  static async *fetchOwned<T extends PhantomReified<PhantomTypeArgument>>(
+    client: SuiClient,
+    owner: string,
+    typeArg?: T
+  ): AsyncGenerator<TokenPolicy<ToPhantomTypeArgument<T>>> {
+    if (typeArg === undefined) {
+      yield* fetchOwnedObjects<TokenPolicy<ToPhantomTypeArgument<T>>>(
+        client,
+        owner,
+        TokenPolicy.$typeName
+      )
+      return
+    }
+
+    yield* fetchOwnedObjects(
+      client,
+      owner,
+      composeSuiType(TokenPolicy.$typeName, extractType(typeArg)),
+      (data: SuiObjectData) => TokenPolicy.fromSuiObjectData(typeArg, data)
+    )
+  }
+
+  static async fetchDisplay<T extends PhantomReified<PhantomTypeArgument>>(
+    client: SuiClient,
+    typeArg: T
+  ): Promise<DisplayTemplate<TokenPolicy<ToPhantomTypeArgument<T>>> | null> {
+    return fetchDisplay(client, TokenPolicy.reified(typeArg).fullTypeName)
+  }
 }
 
 /* ============================== TokenPolicyCap =============================== */
@@ -1007,11 +1209,16 @@ export class TokenPolicyCap<T extends PhantomTypeArgument> implements StructClas
   static readonly $typeName = `${PKG_V27}::token::TokenPolicyCap`
   static readonly $numTypeParams = 1
   static readonly $isPhantom = [true] as const
+  static readonly $moveFieldNames = {
+    id: 'id',
+    for: 'for',
+  } as const
 
   readonly $typeName = TokenPolicyCap.$typeName
   readonly $fullTypeName: `${typeof PKG_V27}::token::TokenPolicyCap<${PhantomToTypeStr<T>}>`
   readonly $typeArgs: [PhantomToTypeStr<T>]
   readonly $isPhantom = TokenPolicyCap.$isPhantom
+  readonly $moveFieldNames = TokenPolicyCap.$moveFieldNames
 
   readonly id: ToField<UID>
   readonly for: ToField<ID>
@@ -1027,6 +1234,14 @@ export class TokenPolicyCap<T extends PhantomTypeArgument> implements StructClas
     this.for = fields.for
   }
 
+  get $objectId(): string {
+    return this.id
+  }
+
+  static id<T extends PhantomTypeArgument>(id: string): TypedObjectId<TokenPolicyCap<T>> {
+    return id as TypedObjectId<TokenPolicyCap<T>>
+  }
+
   static reified<T extends PhantomReified<PhantomTypeArgument>>(
     T: T
   ): TokenPolicyCapReified<ToPhantomTypeArgument<T>> {
@@ -1118,6 +1333,13 @@ export class TokenPolicyCap<T extends PhantomTypeArgument> implements StructClas
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([
+      fieldToBcs<UID>(`${UID.$typeName}`, this.id),
+      fieldToBcs<ID>(`${ID.$typeName}`, this.for),
+    ])
+  }
+
   static fromJSONField<T extends PhantomReified<PhantomTypeArgument>>(
     typeArg: T,
     field: any
@@ -1205,6 +1427,53 @@ export class TokenPolicyCap<T extends PhantomTypeArgument> implements StructClas
 
     return TokenPolicyCap.fromSuiObjectData(typeArg, res.data)
   }
+
+  static async fetchMany<T extends PhantomReified<PhantomTypeArgument>>(
+    client: SuiClient,
+    ids: string[],
+    typeArg?: T
+  ): Promise<Array<FetchObjectResult<TokenPolicyCap<ToPhantomTypeArgument<T>>>>> {
+    if (typeArg === undefined) {
+      return fetchObjectsOfType<TokenPolicyCap<ToPhantomTypeArgument<T>>>(
+        client,
+        ids,
+        TokenPolicyCap.$typeName
+      )
+    }
+
+    return fetchObjects(client, ids, (data: SuiObjectData) =>
+      TokenPolicyCap.fromSuiObjectData(typeArg, data)
+    )
+  }
+
+  static async *fetchOwned<T extends PhantomReified<PhantomTypeArgument>>(
+    client: SuiClient,
+    owner: string,
+    typeArg?: T
+  ): AsyncGenerator<TokenPolicyCap<ToPhantomTypeArgument<T>>> {
+    if (typeArg === undefined) {
+      yield* fetchOwnedObjects<TokenPolicyCap<ToPhantomTypeArgument<T>>>(
+        client,
+        owner,
+        TokenPolicyCap.$typeName
+      )
+      return
+    }
+
+    yield* fetchOwnedObjects(
+      client,
+      owner,
+      composeSuiType(TokenPolicyCap.$typeName, extractType(typeArg)),
+      (data: SuiObjectData) => TokenPolicyCap.fromSuiObjectData(typeArg, data)
+    )
+  }
+
+  static async fetchDisplay<T extends PhantomReified<PhantomTypeArgument>>(
+    client: SuiClient,
+    typeArg: T
+  ): Promise<DisplayTemplate<TokenPolicyCap<ToPhantomTypeArgument<T>>> | null> {
+    return fetchDisplay(client, TokenPolicyCap.reified(typeArg).fullTypeName)
+  }
 }
 
 /* ============================== TokenPolicyCreated =============================== */
@@ -1230,11 +1499,16 @@ export class TokenPolicyCreated<T extends PhantomTypeArgument> implements Struct
   static readonly $typeName = `${PKG_V27}::token::TokenPolicyCreated`
   static readonly $numTypeParams = 1
   static readonly $isPhantom = [true] as const
+  static readonly $moveFieldNames = {
+    id: 'id',
+    is_mutable: 'isMutable',
+  } as const
 
   readonly $typeName = TokenPolicyCreated.$typeName
   readonly $fullTypeName: `${typeof PKG_V27}::token::TokenPolicyCreated<${PhantomToTypeStr<T>}>`
   readonly $typeArgs: [PhantomToTypeStr<T>]
   readonly $isPhantom = TokenPolicyCreated.$isPhantom
+  readonly $moveFieldNames = TokenPolicyCreated.$moveFieldNames
 
   readonly id: ToField<ID>
   readonly isMutable: ToField<'bool'>
@@ -1344,6 +1618,13 @@ export class TokenPolicyCreated<T extends PhantomTypeArgument> implements Struct
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([
+      fieldToBcs<ID>(`${ID.$typeName}`, this.id),
+      fieldToBcs<'bool'>(`bool`, this.isMutable),
+    ])
+  }
+
   static fromJSONField<T extends PhantomReified<PhantomTypeArgument>>(
     typeArg: T,
     field: any
@@ -1431,4 +1712,22 @@ export class TokenPolicyCreated<T extends PhantomTypeArgument> implements Struct
 
     return TokenPolicyCreated.fromSuiObjectData(typeArg, res.data)
   }
+
+  static async fetchMany<T extends PhantomReified<PhantomTypeArgument>>(
+    client: SuiClient,
+    ids: string[],
+    typeArg?: T
+  ): Promise<Array<FetchObjectResult<TokenPolicyCreated<ToPhantomTypeArgument<T>>>>> {
+    if (typeArg === undefined) {
+      return fetchObjectsOfType<TokenPolicyCreated<ToPhantomTypeArgument<T>>>(
+        client,
+        ids,
+        TokenPolicyCreated.$typeName
+      )
+    }
+
+    return fetchObjects(client, ids, (data: SuiObjectData) =>
+      TokenPolicyCreated.fromSuiObjectData(typeArg, data)
+    )
+  }
 }
@@ -1,3 +1,4 @@
+import { DisplayTemplate, fetchDisplay } from '../../_framework/display'
 import * as reified from '../../_framework/reified'
 import { TypeName } from '../../_dependencies/source/0x1/type-name/structs'
 import {
@@ -11,17 +12,24 @@ import {
   ToTypeStr,
   assertFieldsWithTypesArgsMatch,
   assertReifiedTypeArgsMatch,
+  concatBytes,
   decodeFromFields,
   decodeFromFieldsWithTypes,
   decodeFromJSONField,
   extractType,
+  fieldToBcs,
   phantom,
   ToTypeStr as ToPhantom,
 } from '../../_framework/reified'
 import {
+  FetchObjectResult,
   FieldsWithTypes,
+  TypedObjectId,
   composeSuiType,
   compressSuiType,
+  fetchObjects,
+  fetchObjectsOfType,
+  fetchOwnedObjects,
   parseTypeName,
 } from '../../_framework/util'
 import { Balance } from '../balance/structs'
@@ -52,11 +60,15 @@ export class RuleKey<T extends PhantomTypeArgument> implements StructClass {
   static readonly $typeName = `${PKG_V27}::transfer_policy::RuleKey`
   static readonly $numTypeParams = 1
   static readonly $isPhantom = [true] as const
+  static readonly $moveFieldNames = {
+    dummy_field: 'dummyField',
+  } as const
 
   readonly $typeName = RuleKey.$typeName
   readonly $fullTypeName: `${typeof PKG_V27}::transfer_policy::RuleKey<${PhantomToTypeStr<T>}>`
   readonly $typeArgs: [PhantomToTypeStr<T>]
   readonly $isPhantom = RuleKey.$isPhantom
+  readonly $moveFieldNames = RuleKey.$moveFieldNames
 
   readonly dummyField: ToField<'bool'>
 
@@ -157,6 +169,10 @@ export class RuleKey<T extends PhantomTypeArgument> implements StructClass {
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([fieldToBcs<'bool'>(`bool`, this.dummyField)])
+  }
+
   static fromJSONField<T extends PhantomReified<PhantomTypeArgument>>(
     typeArg: T,
     field: any
@@ -243,6 +259,20 @@ export class RuleKey<T extends PhantomTypeArgument> implements StructClass {
 
     return RuleKey.fromSuiObjectData(typeArg, res.data)
   }
+
+  static async fetchMany<T extends PhantomReified<PhantomTypeArgument>>(
+    client: SuiClient,
+    ids: string[],
+    typeArg?: T
+  ): Promise<Array<FetchObjectResult<RuleKey<ToPhantomTypeArgument<T>>>>> {
+    if (typeArg === undefined) {
+      return fetchObjectsOfType<RuleKey<ToPhantomTypeArgument<T>>>(client, ids, RuleKey.$typeName)
+    }
+
+    return fetchObjects(client, ids, (data: SuiObjectData) =>
+      RuleKey.fromSuiObjectData(typeArg, data)
+    )
+  }
 }
 
 /* ============================== TransferPolicy =============================== */
@@ -269,11 +299,17 @@ export class TransferPolicy<T extends PhantomTypeArgument> implements StructClas
   static readonly $typeName = `${PKG_V27}::transfer_policy::TransferPolicy`
   static readonly $numTypeParams = 1
   static readonly $isPhantom = [true] as const
+  static readonly $moveFieldNames = {
+    id: 'id',
+    balance: 'balance',
+    rules: 'rules',
+  } as const
 
   readonly $typeName = TransferPolicy.$typeName
   readonly $fullTypeName: `${typeof PKG_V27}::transfer_policy::TransferPolicy<${PhantomToTypeStr<T>}>`
   readonly $typeArgs: [PhantomToTypeStr<T>]
   readonly $isPhantom = TransferPolicy.$isPhantom
+  readonly $moveFieldNames = TransferPolicy.$moveFieldNames
 
   readonly id: ToField<UID>
   readonly balance: ToField<Balance<ToPhantom<SUI>>>
@@ -291,6 +327,14 @@ export class TransferPolicy<T extends PhantomTypeArgument> implements StructClas
     this.rules = fields.rules
   }
 
+  get $objectId(): string {
+    return this.id
+  }
+
+  static id<T extends PhantomTypeArgument>(id: string): TypedObjectId<TransferPolicy<T>> {
+    return id as TypedObjectId<TransferPolicy<T>>
+  }
+
   static reified<T extends PhantomReified<PhantomTypeArgument>>(
     T: T
   ): TransferPolicyReified<ToPhantomTypeArgument<T>> {
@@ -389,6 +433,14 @@ export class TransferPolicy<T extends PhantomTypeArgument> implements StructClas
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([
+      fieldToBcs<UID>(`${UID.$typeName}`, this.id),
+      fieldToBcs<Balance<ToPhantom<SUI>>>(`${Balance.$typeName}<${SUI.$typeName}>`, this.balance),
+      fieldToBcs<VecSet<TypeName>>(`${VecSet.$typeName}<${TypeName.$typeName}>`, this.rules),
+    ])
+  }
+
   static fromJSONField<T extends PhantomReified<PhantomTypeArgument>>(
     typeArg: T,
     field: any
@@ -477,6 +529,53 @@ export class TransferPolicy<T extends PhantomTypeArgument> implements StructClas
 
     return TransferPolicy.fromSuiObjectData(typeArg, res.data)
   }
+
+  static async fetchMany<T extends PhantomReified<PhantomTypeArgument>>(
+    client: SuiClient,
+    ids: string[],
+    typeArg?: T
+  ): Promise<Array<FetchObjectResult<TransferPolicy<ToPhantomTypeArgument<T>>>>> {
+    if (typeArg === undefined) {
+      return fetchObjectsOfType<TransferPolicy<ToPhantomTypeArgument<T>>>(
+        client,
+        ids,
+        TransferPolicy.$typeName
+      )
+    }
+
+    return fetchObjects(client, ids, (data: SuiObjectData) =>
+      TransferPolicy.fromSuiObjectData(typeArg, data)
+    )
+  }
+
+  static async *fetchOwned<T extends PhantomReified<PhantomTypeArgument>>(
+    client: SuiClient,
+    owner: string,
+    typeArg?: T
+  ): AsyncGenerator<TransferPolicy<ToPhantomTypeArgument<T>>> {
+    if (typeArg === undefined) {
+      yield* fetchOwnedObjects<TransferPolicy<ToPhantomTypeArgument<T>>>(
+        client,
+        owner,
+        TransferPolicy.$typeName
+      )
+      return
+    }
+
+    yield* fetchOwnedObjects(
+      client,
+      owner,
+      composeSuiType(TransferPolicy.$typeName, extractType(typeArg)),
+      (data: SuiObjectData) => TransferPolicy.fromSuiObjectData(typeArg, data)
+    )
+  }
+
+  static async fetchDisplay<T extends PhantomReified<PhantomTypeArgument>>(
+    client: SuiClient,
+    typeArg: T
+  ): Promise<DisplayTemplate<TransferPolicy<ToPhantomTypeArgument<T>>> | null> {
+    return fetchDisplay(client, TransferPolicy.reified(typeArg).fullTypeName)
+  }
 }
 
 /* ============================== TransferPolicyCap =============================== */
@@ -502,11 +601,16 @@ export class TransferPolicyCap<T extends PhantomTypeArgument> implements StructC
   static readonly $typeName = `${PKG_V27}::transfer_policy::TransferPolicyCap`
   static readonly $numTypeParams = 1
   static readonly $isPhantom = [true] as const
+  static readonly $moveFieldNames = {
+    id: 'id',
+    policy_id: 'policyId',
+  } as const
 
   readonly $typeName = TransferPolicyCap.$typeName
   readonly $fullTypeName: `${typeof PKG_V27}::transfer_policy::TransferPolicyCap<${PhantomToTypeStr<T>}>`
   readonly $typeArgs: [PhantomToTypeStr<T>]
   readonly $isPhantom = TransferPolicyCap.$isPhantom
+  readonly $moveFieldNames = TransferPolicyCap.$moveFieldNames
 
   readonly id: ToField<UID>
   readonly policyId: ToField<ID>
@@ -522,6 +626,14 @@ export class TransferPolicyCap<T extends PhantomTypeArgument> implements StructC
     this.policyId = fields.policyId
   }
 
+  get $objectId(): string {
+    return this.id
+  }
+
+  static id<T extends PhantomTypeArgument>(id: string): TypedObjectId<TransferPolicyCap<T>> {
+    return id as TypedObjectId<TransferPolicyCap<T>>
+  }
+
   static reified<T extends PhantomReified<PhantomTypeArgument>>(
     T: T
   ): TransferPolicyCapReified<ToPhantomTypeArgument<T>> {
@@ -616,6 +728,13 @@ export class TransferPolicyCap<T extends PhantomTypeArgument> implements StructC
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([
+      fieldToBcs<UID>(`${UID.$typeName}`, this.id),
+      fieldToBcs<ID>(`${ID.$typeName}`, this.policyId),
+    ])
+  }
+
   static fromJSONField<T extends PhantomReified<PhantomTypeArgument>>(
     typeArg: T,
     field: any
@@ -703,6 +822,53 @@ export class TransferPolicyCap<T extends PhantomTypeArgument> implements StructC
 
     return TransferPolicyCap.fromSuiObjectData(typeArg, res.data)
   }
+
+  static async fetchMany<T extends PhantomReified<PhantomTypeArgument>>(
+    client: SuiClient,
+    ids: string[],
+    typeArg?: T
+  ): Promise<Array<FetchObjectResult<TransferPolicyCap<ToPhantomTypeArgument<T>>>>> {
+    if (typeArg === undefined) {
+      return fetchObjectsOfType<TransferPolicyCap<ToPhantomTypeArgument<T>>>(
+        client,
+        ids,
+        TransferPolicyCap.$typeName
+      )
+    }
+
+    return fetchObjects(client, ids, (data: SuiObjectData) =>
+      TransferPolicyCap.fromSuiObjectData(typeArg, data)
+    )
+  }
+
+  static async *fetchOwned<T extends PhantomReified<PhantomTypeArgument>>(
+    client: SuiClient,
+    owner: string,
+    typeArg?: T
+  ): AsyncGenerator<TransferPolicyCap<ToPhantomTypeArgument<T>>> {
+    if (typeArg === undefined) {
+      yield* fetchOwnedObjects<TransferPolicyCap<ToPhantomTypeArgument<T>>>(
+        client,
+        owner,
+        TransferPolicyCap.$typeName
+      )
+      return
+    }
+
+    yield* fetchOwnedObjects(
+      client,
+      owner,
+      composeSuiType(TransferPolicyCap.$typeName, extractType(typeArg)),
+      (data: SuiObjectData) => TransferPolicyCap.fromSuiObjectData(typeArg, data)
+    )
+  }
+
+  static async fetchDisplay<T extends PhantomReified<PhantomTypeArgument>>(
+    client: SuiClient,
+    typeArg: T
+  ): Promise<DisplayTemplate<TransferPolicyCap<ToPhantomTypeArgument<T>>> | null> {
+    return fetchDisplay(client, TransferPolicyCap.reified(typeArg).fullTypeName)
+  }
 }
 
 /* ============================== TransferPolicyCreated =============================== */
@@ -727,11 +893,15 @@ export class TransferPolicyCreated<T extends PhantomTypeArgument> implements Str
   static readonly $typeName = `${PKG_V27}::transfer_policy::TransferPolicyCreated`
   static readonly $numTypeParams = 1
   static readonly $isPhantom = [true] as const
+  static readonly $moveFieldNames = {
+    id: 'id',
+  } as const
 
   readonly $typeName = TransferPolicyCreated.$typeName
   readonly $fullTypeName: `${typeof PKG_V27}::transfer_policy::TransferPolicyCreated<${PhantomToTypeStr<T>}>`
   readonly $typeArgs: [PhantomToTypeStr<T>]
   readonly $isPhantom = TransferPolicyCreated.$isPhantom
+  readonly $moveFieldNames = TransferPolicyCreated.$moveFieldNames
 
   readonly id: ToField<ID>
 
@@ -835,6 +1005,10 @@ export class TransferPolicyCreated<T extends PhantomTypeArgument> implements Str
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([fieldToBcs<ID>(`${ID.$typeName}`, this.id)])
+  }
+
   static fromJSONField<T extends PhantomReified<PhantomTypeArgument>>(
     typeArg: T,
     field: any
@@ -923,6 +1097,24 @@ export class TransferPolicyCreated<T extends PhantomTypeArgument> implements Str
 
     return TransferPolicyCreated.fromSuiObjectData(typeArg, res.data)
   }
+
+  static async fetchMany<T extends PhantomReified<PhantomTypeArgument>>(
+    client: SuiClient,
+    ids: string[],
+    typeArg?: T
+  ): Promise<Array<FetchObjectResult<TransferPolicyCreated<ToPhantomTypeArgument<T>>>>> {
+    if (typeArg === undefined) {
+      return fetchObjectsOfType<TransferPolicyCreated<ToPhantomTypeArgument<T>>>(
+        client,
+        ids,
+        TransferPolicyCreated.$typeName
+      )
+    }
+
+    return fetchObjects(client, ids, (data: SuiObjectData) =>
+      TransferPolicyCreated.fromSuiObjectData(typeArg, data)
+    )
+  }
 }
 
 /* ============================== TransferPolicyDestroyed =============================== */
@@ -947,11 +1139,15 @@ export class TransferPolicyDestroyed<T extends PhantomTypeArgument> implements S
   static readonly $typeName = `${PKG_V27}::transfer_policy::TransferPolicyDestroyed`
   static readonly $numTypeParams = 1
   static readonly $isPhantom = [true] as const
+  static readonly $moveFieldNames = {
+    id: 'id',
+  } as const
 
   readonly $typeName = TransferPolicyDestroyed.$typeName
   readonly $fullTypeName: `${typeof PKG_V27}::transfer_policy::TransferPolicyDestroyed<${PhantomToTypeStr<T>}>`
   readonly $typeArgs: [PhantomToTypeStr<T>]
   readonly $isPhantom = TransferPolicyDestroyed.$isPhantom
+  readonly $moveFieldNames = TransferPolicyDestroyed.$moveFieldNames
 
   readonly id: ToField<ID>
 
@@ -1055,6 +1251,10 @@ export class TransferPolicyDestroyed<T extends PhantomTypeArgument> implements S
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([fieldToBcs<ID>(`${ID.$typeName}`, this.id)])
+  }
+
   static fromJSONField<T extends PhantomReified<PhantomTypeArgument>>(
     typeArg: T,
     field: any
@@ -1145,6 +1345,24 @@ export class TransferPolicyDestroyed<T extends PhantomTypeArgument> implements S
 
     return TransferPolicyDestroyed.fromSuiObjectData(typeArg, res.data)
   }
+
+  static async fetchMany<T extends PhantomReified<PhantomTypeArgument>>(
+    client: SuiClient,
+    ids: string[],
+    typeArg?: T
+  ): Promise<Array<FetchObjectResult<TransferPolicyDestroyed<ToPhantomTypeArgument<T>>>>> {
+    if (typeArg === undefined) {
+      return fetchObjectsOfType<TransferPolicyDestroyed<ToPhantomTypeArgument<T>>>(
+        client,
+        ids,
+        TransferPolicyDestroyed.$typeName
+      )
+    }
+
+    return fetchObjects(client, ids, (data: SuiObjectData) =>
+      TransferPolicyDestroyed.fromSuiObjectData(typeArg, data)
+    )
+  }
 }
 
 /* ============================== TransferRequest =============================== */
@@ -1172,11 +1390,18 @@ export class TransferRequest<T extends PhantomTypeArgument> implements StructCla
   static readonly $typeName = `${PKG_V27}::transfer_policy::TransferRequest`
   static readonly $numTypeParams = 1
   static readonly $isPhantom = [true] as const
+  static readonly $moveFieldNames = {
+    item: 'item',
+    paid: 'paid',
+    from: 'from',
+    receipts: 'receipts',
+  } as const
 
   readonly $typeName = TransferRequest.$typeName
   readonly $fullTypeName: `${typeof PKG_V27}::transfer_policy::TransferRequest<${PhantomToTypeStr<T>}>`
   readonly $typeArgs: [PhantomToTypeStr<T>]
   readonly $isPhantom = TransferRequest.$isPhantom
+  readonly $moveFieldNames = TransferRequest.$moveFieldNames
 
   readonly item: ToField<ID>
   readonly paid: ToField<'u64'>
@@ -1295,6 +1520,15 @@ export class TransferRequest<T extends PhantomTypeArgument> implements StructCla
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([
+      fieldToBcs<ID>(`${ID.$typeName}`, this.item),
+      fieldToBcs<'u64'>(`u64`, this.paid),
+      fieldToBcs<ID>(`${ID.$typeName}`, this.from),
+      fieldToBcs<VecSet<TypeName>>(`${VecSet.$typeName}<${TypeName.$typeName}>`, this.receipts),
+    ])
+  }
+
   static fromJSONField<T extends PhantomReified<PhantomTypeArgument>>(
     typeArg: T,
     field: any
@@ -1384,4 +1618,22 @@ export class TransferRequest<T extends PhantomTypeArgument> implements StructCla
 
     return TransferRequest.fromSuiObjectData(typeArg, res.data)
   }
+
+  static async fetchMany<T extends PhantomReified<PhantomTypeArgument>>(
+    client: SuiClient,
+    ids: string[],
+    typeArg?: T
+  ): Promise<Array<FetchObjectResult<TransferRequest<ToPhantomTypeArgument<T>>>>> {
+    if (typeArg === undefined) {
+      return fetchObjectsOfType<TransferRequest<ToPhantomTypeArgument<T>>>(
+        client,
+        ids,
+        TransferRequest.$typeName
+      )
+    }
+
+    return fetchObjects(client, ids, (data: SuiObjectData) =>
+      TransferRequest.fromSuiObjectData(typeArg, data)
+    )
+  }
 }
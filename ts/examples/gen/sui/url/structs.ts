@@ -5,12 +5,20 @@ import {
   StructClass,
   ToField,
   ToTypeStr,
+  concatBytes,
   decodeFromFields,
   decodeFromFieldsWithTypes,
   decodeFromJSONField,
+  fieldToBcs,
   phantom,
 } from '../../_framework/reified'
-import { FieldsWithTypes, composeSuiType, compressSuiType } from '../../_framework/util'
+import {
+  FetchObjectResult,
+  FieldsWithTypes,
+  composeSuiType,
+  compressSuiType,
+  fetchObjects,
+} from '../../_framework/util'
 import { PKG_V27 } from '../index'
 import { bcs } from '@mysten/sui/bcs'
 import { SuiClient, SuiObjectData, SuiParsedData } from '@mysten/sui/client'
@@ -35,11 +43,15 @@ export class Url implements StructClass {
   static readonly $typeName = `${PKG_V27}::url::Url`
   static readonly $numTypeParams = 0
   static readonly $isPhantom = [] as const
+  static readonly $moveFieldNames = {
+    url: 'url',
+  } as const
 
   readonly $typeName = Url.$typeName
   readonly $fullTypeName: `${typeof PKG_V27}::url::Url`
   readonly $typeArgs: []
   readonly $isPhantom = Url.$isPhantom
+  readonly $moveFieldNames = Url.$moveFieldNames
 
   readonly url: ToField<String>
 
@@ -116,6 +128,10 @@ export class Url implements StructClass {
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([fieldToBcs<String>(`${String.$typeName}`, this.url)])
+  }
+
   static fromJSONField(field: any): Url {
     return Url.reified().new({ url: decodeFromJSONField(String.reified(), field.url) })
   }
@@ -165,4 +181,8 @@ export class Url implements StructClass {
 
     return Url.fromSuiObjectData(res.data)
   }
+
+  static async fetchMany(client: SuiClient, ids: string[]): Promise<Array<FetchObjectResult<Url>>> {
+    return fetchObjects(client, ids, (data: SuiObjectData) => Url.fromSuiObjectData(data))
+  }
 }
@@ -4,12 +4,20 @@ import {
   StructClass,
   ToField,
   ToTypeStr,
+  concatBytes,
   decodeFromFields,
   decodeFromFieldsWithTypes,
   decodeFromJSONField,
+  fieldToBcs,
   phantom,
 } from '../../_framework/reified'
-import { FieldsWithTypes, composeSuiType, compressSuiType } from '../../_framework/util'
+import {
+  FetchObjectResult,
+  FieldsWithTypes,
+  composeSuiType,
+  compressSuiType,
+  fetchObjects,
+} from '../../_framework/util'
 import { PKG_V1, PKG_V2 } from '../index'
 import { bcs } from '@mysten/sui/bcs'
 import { SuiClient, SuiObjectData, SuiParsedData } from '@mysten/sui/client'
@@ -37,11 +45,15 @@ export class StructFromOtherModule implements StructClass {
   static readonly $typeName = `${PKG_V1}::other_module::StructFromOtherModule`
   static readonly $numTypeParams = 0
   static readonly $isPhantom = [] as const
+  static readonly $moveFieldNames = {
+    dummy_field: 'dummyField',
+  } as const
 
   readonly $typeName = StructFromOtherModule.$typeName
   readonly $fullTypeName: `${typeof PKG_V1}::other_module::StructFromOtherModule`
   readonly $typeArgs: []
   readonly $isPhantom = StructFromOtherModule.$isPhantom
+  readonly $moveFieldNames = StructFromOtherModule.$moveFieldNames
 
   readonly dummyField: ToField<'bool'>
 
@@ -131,6 +143,10 @@ export class StructFromOtherModule implements StructClass {
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([fieldToBcs<'bool'>(`bool`, this.dummyField)])
+  }
+
   static fromJSONField(field: any): StructFromOtherModule {
     return StructFromOtherModule.reified().new({
       dummyField: decodeFromJSONField('bool', field.dummyField),
@@ -184,6 +200,15 @@ export class StructFromOtherModule implements StructClass {
 
     return StructFromOtherModule.fromSuiObjectData(res.data)
   }
+
+  static async fetchMany(
+    client: SuiClient,
+    ids: string[]
+  ): Promise<Array<FetchObjectResult<StructFromOtherModule>>> {
+    return fetchObjects(client, ids, (data: SuiObjectData) =>
+      StructFromOtherModule.fromSuiObjectData(data)
+    )
+  }
 }
 
 /* ============================== AddedInAnUpgrade =============================== */
@@ -205,11 +230,15 @@ export class AddedInAnUpgrade implements StructClass {
   static readonly $typeName = `${PKG_V2}::other_module::AddedInAnUpgrade`
   static readonly $numTypeParams = 0
   static readonly $isPhantom = [] as const
+  static readonly $moveFieldNames = {
+    dummy_field: 'dummyField',
+  } as const
 
   readonly $typeName = AddedInAnUpgrade.$typeName
   readonly $fullTypeName: `${typeof PKG_V2}::other_module::AddedInAnUpgrade`
   readonly $typeArgs: []
   readonly $isPhantom = AddedInAnUpgrade.$isPhantom
+  readonly $moveFieldNames = AddedInAnUpgrade.$moveFieldNames
 
   readonly dummyField: ToField<'bool'>
 
@@ -296,6 +325,10 @@ export class AddedInAnUpgrade implements StructClass {
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([fieldToBcs<'bool'>(`bool`, this.dummyField)])
+  }
+
   static fromJSONField(field: any): AddedInAnUpgrade {
     return AddedInAnUpgrade.reified().new({
       dummyField: decodeFromJSONField('bool', field.dummyField),
@@ -347,4 +380,13 @@ export class AddedInAnUpgrade implements StructClass {
 
     return AddedInAnUpgrade.fromSuiObjectData(res.data)
   }
+
+  static async fetchMany(
+    client: SuiClient,
+    ids: string[]
+  ): Promise<Array<FetchObjectResult<AddedInAnUpgrade>>> {
+    return fetchObjects(client, ids, (data: SuiObjectData) =>
+      AddedInAnUpgrade.fromSuiObjectData(data)
+    )
+  }
 }
@@ -1,3 +1,4 @@
+import { DisplayTemplate, fetchDisplay } from '../../_framework/display'
 import * as reified from '../../_framework/reified'
 import {
   PhantomReified,
@@ -12,19 +13,26 @@ import {
   TypeArgument,
   assertFieldsWithTypesArgsMatch,
   assertReifiedTypeArgsMatch,
+  concatBytes,
   decodeFromFields,
   decodeFromFieldsWithTypes,
   decodeFromJSONField,
   extractType,
+  fieldToBcs,
   fieldToJSON,
   phantom,
   toBcs,
   ToTypeStr as ToPhantom,
 } from '../../_framework/reified'
 import {
+  FetchObjectResult,
   FieldsWithTypes,
+  TypedObjectId,
   composeSuiType,
   compressSuiType,
+  fetchObjects,
+  fetchObjectsOfType,
+  fetchOwnedObjects,
   parseTypeName,
 } from '../../_framework/util'
 import { Vector } from '../../_framework/vector'
@@ -60,11 +68,15 @@ export class Bar implements StructClass {
   static readonly $typeName = `${PKG_V1}::fixture::Bar`
   static readonly $numTypeParams = 0
   static readonly $isPhantom = [] as const
+  static readonly $moveFieldNames = {
+    value: 'value',
+  } as const
 
   readonly $typeName = Bar.$typeName
   readonly $fullTypeName: `${typeof PKG_V1}::fixture::Bar`
   readonly $typeArgs: []
   readonly $isPhantom = Bar.$isPhantom
+  readonly $moveFieldNames = Bar.$moveFieldNames
 
   readonly value: ToField<'u64'>
 
@@ -144,6 +156,10 @@ export class Bar implements StructClass {
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([fieldToBcs<'u64'>(`u64`, this.value)])
+  }
+
   static fromJSONField(field: any): Bar {
     return Bar.reified().new({ value: decodeFromJSONField('u64', field.value) })
   }
@@ -193,6 +209,10 @@ export class Bar implements StructClass {
 
     return Bar.fromSuiObjectData(res.data)
   }
+
+  static async fetchMany(client: SuiClient, ids: string[]): Promise<Array<FetchObjectResult<Bar>>> {
+    return fetchObjects(client, ids, (data: SuiObjectData) => Bar.fromSuiObjectData(data))
+  }
 }
 
 /* ============================== Dummy =============================== */
@@ -214,11 +234,15 @@ export class Dummy implements StructClass {
   static readonly $typeName = `${PKG_V1}::fixture::Dummy`
   static readonly $numTypeParams = 0
   static readonly $isPhantom = [] as const
+  static readonly $moveFieldNames = {
+    dummy_field: 'dummyField',
+  } as const
 
   readonly $typeName = Dummy.$typeName
   readonly $fullTypeName: `${typeof PKG_V1}::fixture::Dummy`
   readonly $typeArgs: []
   readonly $isPhantom = Dummy.$isPhantom
+  readonly $moveFieldNames = Dummy.$moveFieldNames
 
   readonly dummyField: ToField<'bool'>
 
@@ -300,6 +324,10 @@ export class Dummy implements StructClass {
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([fieldToBcs<'bool'>(`bool`, this.dummyField)])
+  }
+
   static fromJSONField(field: any): Dummy {
     return Dummy.reified().new({ dummyField: decodeFromJSONField('bool', field.dummyField) })
   }
@@ -349,6 +377,13 @@ export class Dummy implements StructClass {
 
     return Dummy.fromSuiObjectData(res.data)
   }
+
+  static async fetchMany(
+    client: SuiClient,
+    ids: string[]
+  ): Promise<Array<FetchObjectResult<Dummy>>> {
+    return fetchObjects(client, ids, (data: SuiObjectData) => Dummy.fromSuiObjectData(data))
+  }
 }
 
 /* ============================== Foo =============================== */
@@ -383,11 +418,28 @@ export class Foo<T extends TypeArgument> implements StructClass {
   static readonly $typeName = `${PKG_V1}::fixture::Foo`
   static readonly $numTypeParams = 1
   static readonly $isPhantom = [false] as const
+  static readonly $moveFieldNames = {
+    id: 'id',
+    generic: 'generic',
+    reified_primitive_vec: 'reifiedPrimitiveVec',
+    reified_object_vec: 'reifiedObjectVec',
+    generic_vec: 'genericVec',
+    generic_vec_nested: 'genericVecNested',
+    two_generics: 'twoGenerics',
+    two_generics_reified_primitive: 'twoGenericsReifiedPrimitive',
+    two_generics_reified_object: 'twoGenericsReifiedObject',
+    two_generics_nested: 'twoGenericsNested',
+    two_generics_reified_nested: 'twoGenericsReifiedNested',
+    two_generics_nested_vec: 'twoGenericsNestedVec',
+    dummy: 'dummy',
+    other: 'other',
+  } as const
 
   readonly $typeName = Foo.$typeName
   readonly $fullTypeName: `${typeof PKG_V1}::fixture::Foo<${ToTypeStr<T>}>`
   readonly $typeArgs: [ToTypeStr<T>]
   readonly $isPhantom = Foo.$isPhantom
+  readonly $moveFieldNames = Foo.$moveFieldNames
 
   readonly id: ToField<UID>
   readonly generic: ToField<T>
@@ -429,6 +481,14 @@ export class Foo<T extends TypeArgument> implements StructClass {
     this.other = fields.other
   }
 
+  get $objectId(): string {
+    return this.id
+  }
+
+  static id<T extends TypeArgument>(id: string): TypedObjectId<Foo<T>> {
+    return id as TypedObjectId<Foo<T>>
+  }
+
   static reified<T extends Reified<TypeArgument, any>>(T: T): FooReified<ToTypeArgument<T>> {
     return {
       typeName: Foo.$typeName,
@@ -640,6 +700,46 @@ export class Foo<T extends TypeArgument> implements StructClass {
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([
+      fieldToBcs<UID>(`${UID.$typeName}`, this.id),
+      fieldToBcs<T>(`${this.$typeArgs[0]}`, this.generic),
+      fieldToBcs<Vector<'u64'>>(`vector<u64>`, this.reifiedPrimitiveVec),
+      fieldToBcs<Vector<Bar>>(`vector<${Bar.$typeName}>`, this.reifiedObjectVec),
+      fieldToBcs<Vector<T>>(`vector<${this.$typeArgs[0]}>`, this.genericVec),
+      fieldToBcs<Vector<WithTwoGenerics<T, 'u8'>>>(
+        `vector<${WithTwoGenerics.$typeName}<${this.$typeArgs[0]}, u8>>`,
+        this.genericVecNested
+      ),
+      fieldToBcs<WithTwoGenerics<T, Bar>>(
+        `${WithTwoGenerics.$typeName}<${this.$typeArgs[0]}, ${Bar.$typeName}>`,
+        this.twoGenerics
+      ),
+      fieldToBcs<WithTwoGenerics<'u16', 'u64'>>(
+        `${WithTwoGenerics.$typeName}<u16, u64>`,
+        this.twoGenericsReifiedPrimitive
+      ),
+      fieldToBcs<WithTwoGenerics<Bar, Bar>>(
+        `${WithTwoGenerics.$typeName}<${Bar.$typeName}, ${Bar.$typeName}>`,
+        this.twoGenericsReifiedObject
+      ),
+      fieldToBcs<WithTwoGenerics<T, WithTwoGenerics<'u8', 'u8'>>>(
+        `${WithTwoGenerics.$typeName}<${this.$typeArgs[0]}, ${WithTwoGenerics.$typeName}<u8, u8>>`,
+        this.twoGenericsNested
+      ),
+      fieldToBcs<WithTwoGenerics<Bar, WithTwoGenerics<'u8', 'u8'>>>(
+        `${WithTwoGenerics.$typeName}<${Bar.$typeName}, ${WithTwoGenerics.$typeName}<u8, u8>>`,
+        this.twoGenericsReifiedNested
+      ),
+      fieldToBcs<Vector<WithTwoGenerics<Bar, Vector<WithTwoGenerics<T, 'u8'>>>>>(
+        `vector<${WithTwoGenerics.$typeName}<${Bar.$typeName}, vector<${WithTwoGenerics.$typeName}<${this.$typeArgs[0]}, u8>>>>`,
+        this.twoGenericsNestedVec
+      ),
+      fieldToBcs<Dummy>(`${Dummy.$typeName}`, this.dummy),
+      fieldToBcs<StructFromOtherModule>(`${StructFromOtherModule.$typeName}`, this.other),
+    ])
+  }
+
   static fromJSONField<T extends Reified<TypeArgument, any>>(
     typeArg: T,
     field: any
@@ -765,6 +865,43 @@ export class Foo<T extends TypeArgument> implements StructClass {
 
     return Foo.fromSuiObjectData(typeArg, res.data)
   }
+
+  static async fetchMany<T extends Reified<TypeArgument, any>>(
+    client: SuiClient,
+    ids: string[],
+    typeArg?: T
+  ): Promise<Array<FetchObjectResult<Foo<ToTypeArgument<T>>>>> {
+    if (typeArg === undefined) {
+      return fetchObjectsOfType<Foo<ToTypeArgument<T>>>(client, ids, Foo.$typeName)
+    }
+
+    return fetchObjects(client, ids, (data: SuiObjectData) => Foo.fromSuiObjectData(typeArg, data))
+  }
+
+  static async *fetchOwned<T extends Reified<TypeArgument, any>>(
+    client: SuiClient,
+    owner: string,
+    typeArg?: T
+  ): AsyncGenerator<Foo<ToTypeArgument<T>>> {
+    if (typeArg === undefined) {
+      yield* fetchOwnedObjects<Foo<ToTypeArgument<T>>>(client, owner, Foo.$typeName)
+      return
+    }
+
+    yield* fetchOwnedObjects(
+      client,
+      owner,
+      composeSuiType(Foo.$typeName, extractType(typeArg)),
+      (data: SuiObjectData) => Foo.fromSuiObjectData(typeArg, data)
+    )
+  }
+
+  static async fetchDisplay<T extends Reified<TypeArgument, any>>(
+    client: SuiClient,
+    typeArg: T
+  ): Promise<DisplayTemplate<Foo<ToTypeArgument<T>>> | null> {
+    return fetchDisplay(client, Foo.reified(typeArg).fullTypeName)
+  }
 }
 
 /* ============================== WithGenericField =============================== */
@@ -790,11 +927,16 @@ export class WithGenericField<T extends TypeArgument> implements StructClass {
   static readonly $typeName = `${PKG_V1}::fixture::WithGenericField`
   static readonly $numTypeParams = 1
   static readonly $isPhantom = [false] as const
+  static readonly $moveFieldNames = {
+    id: 'id',
+    generic_field: 'genericField',
+  } as const
 
   readonly $typeName = WithGenericField.$typeName
   readonly $fullTypeName: `${typeof PKG_V1}::fixture::WithGenericField<${ToTypeStr<T>}>`
   readonly $typeArgs: [ToTypeStr<T>]
   readonly $isPhantom = WithGenericField.$isPhantom
+  readonly $moveFieldNames = WithGenericField.$moveFieldNames
 
   readonly id: ToField<UID>
   readonly genericField: ToField<T>
@@ -810,6 +952,14 @@ export class WithGenericField<T extends TypeArgument> implements StructClass {
     this.genericField = fields.genericField
   }
 
+  get $objectId(): string {
+    return this.id
+  }
+
+  static id<T extends TypeArgument>(id: string): TypedObjectId<WithGenericField<T>> {
+    return id as TypedObjectId<WithGenericField<T>>
+  }
+
   static reified<T extends Reified<TypeArgument, any>>(
     T: T
   ): WithGenericFieldReified<ToTypeArgument<T>> {
@@ -907,6 +1057,13 @@ export class WithGenericField<T extends TypeArgument> implements StructClass {
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([
+      fieldToBcs<UID>(`${UID.$typeName}`, this.id),
+      fieldToBcs<T>(`${this.$typeArgs[0]}`, this.genericField),
+    ])
+  }
+
   static fromJSONField<T extends Reified<TypeArgument, any>>(
     typeArg: T,
     field: any
@@ -994,6 +1151,53 @@ export class WithGenericField<T extends TypeArgument> implements StructClass {
 
     return WithGenericField.fromSuiObjectData(typeArg, res.data)
   }
+
+  static async fetchMany<T extends Reified<TypeArgument, any>>(
+    client: SuiClient,
+    ids: string[],
+    typeArg?: T
+  ): Promise<Array<FetchObjectResult<WithGenericField<ToTypeArgument<T>>>>> {
+    if (typeArg === undefined) {
+      return fetchObjectsOfType<WithGenericField<ToTypeArgument<T>>>(
+        client,
+        ids,
+        WithGenericField.$typeName
+      )
+    }
+
+    return fetchObjects(client, ids, (data: SuiObjectData) =>
+      WithGenericField.fromSuiObjectData(typeArg, data)
+    )
+  }
+
+  static async *fetchOwned<T extends Reified<TypeArgument, any>>(
+    client: SuiClient,
+    owner: string,
+    typeArg?: T
+  ): AsyncGenerator<WithGenericField<ToTypeArgument<T>>> {
+    if (typeArg === undefined) {
+      yield* fetchOwnedObjects<WithGenericField<ToTypeArgument<T>>>(
+        client,
+        owner,
+        WithGenericField.$typeName
+      )
+      return
+    }
+
+    yield* fetchOwnedObjects(
+      client,
+      owner,
+      composeSuiType(WithGenericField.$typeName, extractType(typeArg)),
+      (data: SuiObjectData) => WithGenericField.fromSuiObjectData(typeArg, data)
+    )
+  }
+
+  static async fetchDisplay<T extends Reified<TypeArgument, any>>(
+    client: SuiClient,
+    typeArg: T
+  ): Promise<DisplayTemplate<WithGenericField<ToTypeArgument<T>>> | null> {
+    return fetchDisplay(client, WithGenericField.reified(typeArg).fullTypeName)
+  }
 }
 
 /* ============================== WithSpecialTypes =============================== */
@@ -1032,11 +1236,27 @@ export class WithSpecialTypes<T extends PhantomTypeArgument, U extends TypeArgum
   static readonly $typeName = `${PKG_V1}::fixture::WithSpecialTypes`
   static readonly $numTypeParams = 2
   static readonly $isPhantom = [true, false] as const
+  static readonly $moveFieldNames = {
+    id: 'id',
+    string: 'string',
+    ascii_string: 'asciiString',
+    url: 'url',
+    id_field: 'idField',
+    uid: 'uid',
+    balance: 'balance',
+    option: 'option',
+    option_obj: 'optionObj',
+    option_none: 'optionNone',
+    balance_generic: 'balanceGeneric',
+    option_generic: 'optionGeneric',
+    option_generic_none: 'optionGenericNone',
+  } as const
 
   readonly $typeName = WithSpecialTypes.$typeName
   readonly $fullTypeName: `${typeof PKG_V1}::fixture::WithSpecialTypes<${PhantomToTypeStr<T>}, ${ToTypeStr<U>}>`
   readonly $typeArgs: [PhantomToTypeStr<T>, ToTypeStr<U>]
   readonly $isPhantom = WithSpecialTypes.$isPhantom
+  readonly $moveFieldNames = WithSpecialTypes.$moveFieldNames
 
   readonly id: ToField<UID>
   readonly string: ToField<String>
@@ -1077,6 +1297,16 @@ export class WithSpecialTypes<T extends PhantomTypeArgument, U extends TypeArgum
     this.optionGenericNone = fields.optionGenericNone
   }
 
+  get $objectId(): string {
+    return this.id
+  }
+
+  static id<T extends PhantomTypeArgument, U extends TypeArgument>(
+    id: string
+  ): TypedObjectId<WithSpecialTypes<T, U>> {
+    return id as TypedObjectId<WithSpecialTypes<T, U>>
+  }
+
   static reified<
     T extends PhantomReified<PhantomTypeArgument>,
     U extends Reified<TypeArgument, any>,
@@ -1254,6 +1484,24 @@ export class WithSpecialTypes<T extends PhantomTypeArgument, U extends TypeArgum
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([
+      fieldToBcs<UID>(`${UID.$typeName}`, this.id),
+      fieldToBcs<String>(`${String.$typeName}`, this.string),
+      fieldToBcs<String1>(`${String1.$typeName}`, this.asciiString),
+      fieldToBcs<Url>(`${Url.$typeName}`, this.url),
+      fieldToBcs<ID>(`${ID.$typeName}`, this.idField),
+      fieldToBcs<UID>(`${UID.$typeName}`, this.uid),
+      fieldToBcs<Balance<ToPhantom<SUI>>>(`${Balance.$typeName}<${SUI.$typeName}>`, this.balance),
+      fieldToBcs<Option<'u64'>>(`${Option.$typeName}<u64>`, this.option),
+      fieldToBcs<Option<Bar>>(`${Option.$typeName}<${Bar.$typeName}>`, this.optionObj),
+      fieldToBcs<Option<'u64'>>(`${Option.$typeName}<u64>`, this.optionNone),
+      fieldToBcs<Balance<T>>(`${Balance.$typeName}<${this.$typeArgs[0]}>`, this.balanceGeneric),
+      fieldToBcs<Option<U>>(`${Option.$typeName}<${this.$typeArgs[1]}>`, this.optionGeneric),
+      fieldToBcs<Option<U>>(`${Option.$typeName}<${this.$typeArgs[1]}>`, this.optionGenericNone),
+    ])
+  }
+
   static fromJSONField<
     T extends PhantomReified<PhantomTypeArgument>,
     U extends Reified<TypeArgument, any>,
@@ -1366,6 +1614,66 @@ export class WithSpecialTypes<T extends PhantomTypeArgument, U extends TypeArgum
 
     return WithSpecialTypes.fromSuiObjectData(typeArgs, res.data)
   }
+
+  static async fetchMany<
+    T extends PhantomReified<PhantomTypeArgument>,
+    U extends Reified<TypeArgument, any>,
+  >(
+    client: SuiClient,
+    ids: string[],
+    typeArgs?: [T, U]
+  ): Promise<
+    Array<FetchObjectResult<WithSpecialTypes<ToPhantomTypeArgument<T>, ToTypeArgument<U>>>>
+  > {
+    if (typeArgs === undefined) {
+      return fetchObjectsOfType<WithSpecialTypes<ToPhantomTypeArgument<T>, ToTypeArgument<U>>>(
+        client,
+        ids,
+        WithSpecialTypes.$typeName
+      )
+    }
+
+    return fetchObjects(client, ids, (data: SuiObjectData) =>
+      WithSpecialTypes.fromSuiObjectData(typeArgs, data)
+    )
+  }
+
+  static async *fetchOwned<
+    T extends PhantomReified<PhantomTypeArgument>,
+    U extends Reified<TypeArgument, any>,
+  >(
+    client: SuiClient,
+    owner: string,
+    typeArgs?: [T, U]
+  ): AsyncGenerator<WithSpecialTypes<ToPhantomTypeArgument<T>, ToTypeArgument<U>>> {
+    if (typeArgs === undefined) {
+      yield* fetchOwnedObjects<WithSpecialTypes<ToPhantomTypeArgument<T>, ToTypeArgument<U>>>(
+        client,
+        owner,
+        WithSpecialTypes.$typeName
+      )
+      return
+    }
+
+    yield* fetchOwnedObjects(
+      client,
+      owner,
+      composeSuiType(WithSpecialTypes.$typeName, ...typeArgs.map(typeArg => extractType(typeArg))),
+      (data: SuiObjectData) => WithSpecialTypes.fromSuiObjectData(typeArgs, data)
+    )
+  }
+
+  static async fetchDisplay<
+    T extends PhantomReified<PhantomTypeArgument>,
+    U extends Reified<TypeArgument, any>,
+  >(
+    client: SuiClient,
+    typeArgs: [T, U]
+  ): Promise<
+    DisplayTemplate<WithSpecialTypes<ToPhantomTypeArgument<T>, ToTypeArgument<U>>> | null
+  > {
+    return fetchDisplay(client, WithSpecialTypes.reified(...typeArgs).fullTypeName)
+  }
 }
 
 /* ============================== WithSpecialTypesAsGenerics =============================== */
@@ -1426,6 +1734,17 @@ export class WithSpecialTypesAsGenerics<
   static readonly $typeName = `${PKG_V1}::fixture::WithSpecialTypesAsGenerics`
   static readonly $numTypeParams = 8
   static readonly $isPhantom = [false, false, false, false, false, false, false, false] as const
+  static readonly $moveFieldNames = {
+    id: 'id',
+    string: 'string',
+    ascii_string: 'asciiString',
+    url: 'url',
+    id_field: 'idField',
+    uid: 'uid',
+    balance: 'balance',
+    option: 'option',
+    option_none: 'optionNone',
+  } as const
 
   readonly $typeName = WithSpecialTypesAsGenerics.$typeName
   readonly $fullTypeName: `${typeof PKG_V1}::fixture::WithSpecialTypesAsGenerics<${ToTypeStr<T0>}, ${ToTypeStr<T1>}, ${ToTypeStr<T2>}, ${ToTypeStr<T3>}, ${ToTypeStr<T4>}, ${ToTypeStr<T5>}, ${ToTypeStr<T6>}, ${ToTypeStr<T7>}>`
@@ -1440,6 +1759,7 @@ export class WithSpecialTypesAsGenerics<
     ToTypeStr<T7>,
   ]
   readonly $isPhantom = WithSpecialTypesAsGenerics.$isPhantom
+  readonly $moveFieldNames = WithSpecialTypesAsGenerics.$moveFieldNames
 
   readonly id: ToField<UID>
   readonly string: ToField<T0>
@@ -1481,6 +1801,25 @@ export class WithSpecialTypesAsGenerics<
     this.optionNone = fields.optionNone
   }
 
+  get $objectId(): string {
+    return this.id
+  }
+
+  static id<
+    T0 extends TypeArgument,
+    T1 extends TypeArgument,
+    T2 extends TypeArgument,
+    T3 extends TypeArgument,
+    T4 extends TypeArgument,
+    T5 extends TypeArgument,
+    T6 extends TypeArgument,
+    T7 extends TypeArgument,
+  >(
+    id: string
+  ): TypedObjectId<WithSpecialTypesAsGenerics<T0, T1, T2, T3, T4, T5, T6, T7>> {
+    return id as TypedObjectId<WithSpecialTypesAsGenerics<T0, T1, T2, T3, T4, T5, T6, T7>>
+  }
+
   static reified<
     T0 extends Reified<TypeArgument, any>,
     T1 extends Reified<TypeArgument, any>,
@@ -1827,6 +2166,20 @@ export class WithSpecialTypesAsGenerics<
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([
+      fieldToBcs<UID>(`${UID.$typeName}`, this.id),
+      fieldToBcs<T0>(`${this.$typeArgs[0]}`, this.string),
+      fieldToBcs<T1>(`${this.$typeArgs[1]}`, this.asciiString),
+      fieldToBcs<T2>(`${this.$typeArgs[2]}`, this.url),
+      fieldToBcs<T3>(`${this.$typeArgs[3]}`, this.idField),
+      fieldToBcs<T4>(`${this.$typeArgs[4]}`, this.uid),
+      fieldToBcs<T5>(`${this.$typeArgs[5]}`, this.balance),
+      fieldToBcs<T6>(`${this.$typeArgs[6]}`, this.option),
+      fieldToBcs<T7>(`${this.$typeArgs[7]}`, this.optionNone),
+    ])
+  }
+
   static fromJSONField<
     T0 extends Reified<TypeArgument, any>,
     T1 extends Reified<TypeArgument, any>,
@@ -2031,6 +2384,145 @@ export class WithSpecialTypesAsGenerics<
 
     return WithSpecialTypesAsGenerics.fromSuiObjectData(typeArgs, res.data)
   }
+
+  static async fetchMany<
+    T0 extends Reified<TypeArgument, any>,
+    T1 extends Reified<TypeArgument, any>,
+    T2 extends Reified<TypeArgument, any>,
+    T3 extends Reified<TypeArgument, any>,
+    T4 extends Reified<TypeArgument, any>,
+    T5 extends Reified<TypeArgument, any>,
+    T6 extends Reified<TypeArgument, any>,
+    T7 extends Reified<TypeArgument, any>,
+  >(
+    client: SuiClient,
+    ids: string[],
+    typeArgs?: [T0, T1, T2, T3, T4, T5, T6, T7]
+  ): Promise<
+    Array<
+      FetchObjectResult<
+        WithSpecialTypesAsGenerics<
+          ToTypeArgument<T0>,
+          ToTypeArgument<T1>,
+          ToTypeArgument<T2>,
+          ToTypeArgument<T3>,
+          ToTypeArgument<T4>,
+          ToTypeArgument<T5>,
+          ToTypeArgument<T6>,
+          ToTypeArgument<T7>
+        >
+      >
+    >
+  > {
+    if (typeArgs === undefined) {
+      return fetchObjectsOfType<
+        WithSpecialTypesAsGenerics<
+          ToTypeArgument<T0>,
+          ToTypeArgument<T1>,
+          ToTypeArgument<T2>,
+          ToTypeArgument<T3>,
+          ToTypeArgument<T4>,
+          ToTypeArgument<T5>,
+          ToTypeArgument<T6>,
+          ToTypeArgument<T7>
+        >
+      >(
+        client,
+        ids,
+        WithSpecialTypesAsGenerics.$typeName
+      )
+    }
+
+    return fetchObjects(client, ids, (data: SuiObjectData) =>
+      WithSpecialTypesAsGenerics.fromSuiObjectData(typeArgs, data)
+    )
+  }
+
+  static async *fetchOwned<
+    T0 extends Reified<TypeArgument, any>,
+    T1 extends Reified<TypeArgument, any>,
+    T2 extends Reified<TypeArgument, any>,
+    T3 extends Reified<TypeArgument, any>,
+    T4 extends Reified<TypeArgument, any>,
+    T5 extends Reified<TypeArgument, any>,
+    T6 extends Reified<TypeArgument, any>,
+    T7 extends Reified<TypeArgument, any>,
+  >(
+    client: SuiClient,
+    owner: string,
+    typeArgs?: [T0, T1, T2, T3, T4, T5, T6, T7]
+  ): AsyncGenerator<
+    WithSpecialTypesAsGenerics<
+      ToTypeArgument<T0>,
+      ToTypeArgument<T1>,
+      ToTypeArgument<T2>,
+      ToTypeArgument<T3>,
+      ToTypeArgument<T4>,
+      ToTypeArgument<T5>,
+      ToTypeArgument<T6>,
+      ToTypeArgument<T7>
+    >
+  > {
+    if (typeArgs === undefined) {
+      yield* fetchOwnedObjects<
+        WithSpecialTypesAsGenerics<
+          ToTypeArgument<T0>,
+          ToTypeArgument<T1>,
+          ToTypeArgument<T2>,
+          ToTypeArgument<T3>,
+          ToTypeArgument<T4>,
+          ToTypeArgument<T5>,
+          ToTypeArgument<T6>,
+          ToTypeArgument<T7>
+        >
+      >(
+        client,
+        owner,
+        WithSpecialTypesAsGenerics.$typeName
+      )
+      return
+    }
+
+    yield* fetchOwnedObjects(
+      client,
+      owner,
+      composeSuiType(
+        WithSpecialTypesAsGenerics.$typeName,
+        ...typeArgs.map(typeArg => extractType(typeArg))
+      ),
+      (data: SuiObjectData) => WithSpecialTypesAsGenerics.fromSuiObjectData(typeArgs, data)
+    )
+  }
+
+  static async fetchDisplay<
+    T0 extends Reified<TypeArgument, any>,
+    T1 extends Reified<TypeArgument, any>,
+    T2 extends Reified<TypeArgument, any>,
+    T3 extends Reified<TypeArgument, any>,
+    T4 extends Reified<TypeArgument, any>,
+    T5 extends Reified<TypeArgument, any>,
+    T6 extends Reified<TypeArgument, any>,
+    T7 extends Reified<TypeArgument, any>,
+  >(
+    client: SuiClient,
+    typeArgs: [T0, T1, T2, T3, T4, T5, T6, T7]
+  ): Promise<
+    | DisplayTemplate<
+        WithSpecialTypesAsGenerics<
+          ToTypeArgument<T0>,
+          ToTypeArgument<T1>,
+          ToTypeArgument<T2>,
+          ToTypeArgument<T3>,
+          ToTypeArgument<T4>,
+          ToTypeArgument<T5>,
+          ToTypeArgument<T6>,
+          ToTypeArgument<T7>
+        >
+      >
+    | null
+  > {
+    return fetchDisplay(client, WithSpecialTypesAsGenerics.reified(...typeArgs).fullTypeName)
+  }
 }
 
 /* ============================== WithSpecialTypesInVectors =============================== */
@@ -2061,11 +2553,21 @@ export class WithSpecialTypesInVectors<T extends TypeArgument> implements Struct
   static readonly $typeName = `${PKG_V1}::fixture::WithSpecialTypesInVectors`
   static readonly $numTypeParams = 1
   static readonly $isPhantom = [false] as const
+  static readonly $moveFieldNames = {
+    id: 'id',
+    string: 'string',
+    ascii_string: 'asciiString',
+    id_field: 'idField',
+    bar: 'bar',
+    option: 'option',
+    option_generic: 'optionGeneric',
+  } as const
 
   readonly $typeName = WithSpecialTypesInVectors.$typeName
   readonly $fullTypeName: `${typeof PKG_V1}::fixture::WithSpecialTypesInVectors<${ToTypeStr<T>}>`
   readonly $typeArgs: [ToTypeStr<T>]
   readonly $isPhantom = WithSpecialTypesInVectors.$isPhantom
+  readonly $moveFieldNames = WithSpecialTypesInVectors.$moveFieldNames
 
   readonly id: ToField<UID>
   readonly string: ToField<Vector<String>>
@@ -2091,6 +2593,14 @@ export class WithSpecialTypesInVectors<T extends TypeArgument> implements Struct
     this.optionGeneric = fields.optionGeneric
   }
 
+  get $objectId(): string {
+    return this.id
+  }
+
+  static id<T extends TypeArgument>(id: string): TypedObjectId<WithSpecialTypesInVectors<T>> {
+    return id as TypedObjectId<WithSpecialTypesInVectors<T>>
+  }
+
   static reified<T extends Reified<TypeArgument, any>>(
     T: T
   ): WithSpecialTypesInVectorsReified<ToTypeArgument<T>> {
@@ -2224,6 +2734,21 @@ export class WithSpecialTypesInVectors<T extends TypeArgument> implements Struct
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([
+      fieldToBcs<UID>(`${UID.$typeName}`, this.id),
+      fieldToBcs<Vector<String>>(`vector<${String.$typeName}>`, this.string),
+      fieldToBcs<Vector<String1>>(`vector<${String1.$typeName}>`, this.asciiString),
+      fieldToBcs<Vector<ID>>(`vector<${ID.$typeName}>`, this.idField),
+      fieldToBcs<Vector<Bar>>(`vector<${Bar.$typeName}>`, this.bar),
+      fieldToBcs<Vector<Option<'u64'>>>(`vector<${Option.$typeName}<u64>>`, this.option),
+      fieldToBcs<Vector<Option<T>>>(
+        `vector<${Option.$typeName}<${this.$typeArgs[0]}>>`,
+        this.optionGeneric
+      ),
+    ])
+  }
+
   static fromJSONField<T extends Reified<TypeArgument, any>>(
     typeArg: T,
     field: any
@@ -2326,6 +2851,53 @@ export class WithSpecialTypesInVectors<T extends TypeArgument> implements Struct
 
     return WithSpecialTypesInVectors.fromSuiObjectData(typeArg, res.data)
   }
+
+  static async fetchMany<T extends Reified<TypeArgument, any>>(
+    client: SuiClient,
+    ids: string[],
+    typeArg?: T
+  ): Promise<Array<FetchObjectResult<WithSpecialTypesInVectors<ToTypeArgument<T>>>>> {
+    if (typeArg === undefined) {
+      return fetchObjectsOfType<WithSpecialTypesInVectors<ToTypeArgument<T>>>(
+        client,
+        ids,
+        WithSpecialTypesInVectors.$typeName
+      )
+    }
+
+    return fetchObjects(client, ids, (data: SuiObjectData) =>
+      WithSpecialTypesInVectors.fromSuiObjectData(typeArg, data)
+    )
+  }
+
+  static async *fetchOwned<T extends Reified<TypeArgument, any>>(
+    client: SuiClient,
+    owner: string,
+    typeArg?: T
+  ): AsyncGenerator<WithSpecialTypesInVectors<ToTypeArgument<T>>> {
+    if (typeArg === undefined) {
+      yield* fetchOwnedObjects<WithSpecialTypesInVectors<ToTypeArgument<T>>>(
+        client,
+        owner,
+        WithSpecialTypesInVectors.$typeName
+      )
+      return
+    }
+
+    yield* fetchOwnedObjects(
+      client,
+      owner,
+      composeSuiType(WithSpecialTypesInVectors.$typeName, extractType(typeArg)),
+      (data: SuiObjectData) => WithSpecialTypesInVectors.fromSuiObjectData(typeArg, data)
+    )
+  }
+
+  static async fetchDisplay<T extends Reified<TypeArgument, any>>(
+    client: SuiClient,
+    typeArg: T
+  ): Promise<DisplayTemplate<WithSpecialTypesInVectors<ToTypeArgument<T>>> | null> {
+    return fetchDisplay(client, WithSpecialTypesInVectors.reified(typeArg).fullTypeName)
+  }
 }
 
 /* ============================== WithTwoGenerics =============================== */
@@ -2353,11 +2925,16 @@ export class WithTwoGenerics<T extends TypeArgument, U extends TypeArgument>
   static readonly $typeName = `${PKG_V1}::fixture::WithTwoGenerics`
   static readonly $numTypeParams = 2
   static readonly $isPhantom = [false, false] as const
+  static readonly $moveFieldNames = {
+    generic_field_1: 'genericField1',
+    generic_field_2: 'genericField2',
+  } as const
 
   readonly $typeName = WithTwoGenerics.$typeName
   readonly $fullTypeName: `${typeof PKG_V1}::fixture::WithTwoGenerics<${ToTypeStr<T>}, ${ToTypeStr<U>}>`
   readonly $typeArgs: [ToTypeStr<T>, ToTypeStr<U>]
   readonly $isPhantom = WithTwoGenerics.$isPhantom
+  readonly $moveFieldNames = WithTwoGenerics.$moveFieldNames
 
   readonly genericField1: ToField<T>
   readonly genericField2: ToField<U>
@@ -2479,6 +3056,13 @@ export class WithTwoGenerics<T extends TypeArgument, U extends TypeArgument>
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([
+      fieldToBcs<T>(`${this.$typeArgs[0]}`, this.genericField1),
+      fieldToBcs<U>(`${this.$typeArgs[1]}`, this.genericField2),
+    ])
+  }
+
   static fromJSONField<T extends Reified<TypeArgument, any>, U extends Reified<TypeArgument, any>>(
     typeArgs: [T, U],
     field: any
@@ -2571,4 +3155,25 @@ export class WithTwoGenerics<T extends TypeArgument, U extends TypeArgument>
 
     return WithTwoGenerics.fromSuiObjectData(typeArgs, res.data)
   }
+
+  static async fetchMany<
+    T extends Reified<TypeArgument, any>,
+    U extends Reified<TypeArgument, any>,
+  >(
+    client: SuiClient,
+    ids: string[],
+    typeArgs?: [T, U]
+  ): Promise<Array<FetchObjectResult<WithTwoGenerics<ToTypeArgument<T>, ToTypeArgument<U>>>>> {
+    if (typeArgs === undefined) {
+      return fetchObjectsOfType<WithTwoGenerics<ToTypeArgument<T>, ToTypeArgument<U>>>(
+        client,
+        ids,
+        WithTwoGenerics.$typeName
+      )
+    }
+
+    return fetchObjects(client, ids, (data: SuiObjectData) =>
+      WithTwoGenerics.fromSuiObjectData(typeArgs, data)
+    )
+  }
 }
@@ -5,13 +5,21 @@ import {
   StructClass,
   ToField,
   ToTypeStr,
+  concatBytes,
   decodeFromFields,
   decodeFromFieldsWithTypes,
   decodeFromJSONField,
+  fieldToBcs,
   fieldToJSON,
   phantom,
 } from '../../_framework/reified'
-import { FieldsWithTypes, composeSuiType, compressSuiType } from '../../_framework/util'
+import {
+  FetchObjectResult,
+  FieldsWithTypes,
+  composeSuiType,
+  compressSuiType,
+  fetchObjects,
+} from '../../_framework/util'
 import { Vector } from '../../_framework/vector'
 import { PKG_V14 } from '../index'
 import { bcs } from '@mysten/sui/bcs'
@@ -38,11 +46,16 @@ export class BitVector implements StructClass {
   static readonly $typeName = `${PKG_V14}::bit_vector::BitVector`
   static readonly $numTypeParams = 0
   static readonly $isPhantom = [] as const
+  static readonly $moveFieldNames = {
+    length: 'length',
+    bit_field: 'bitField',
+  } as const
 
   readonly $typeName = BitVector.$typeName
   readonly $fullTypeName: `${typeof PKG_V14}::bit_vector::BitVector`
   readonly $typeArgs: []
   readonly $isPhantom = BitVector.$isPhantom
+  readonly $moveFieldNames = BitVector.$moveFieldNames
 
   readonly length: ToField<'u64'>
   readonly bitField: ToField<Vector<'bool'>>
@@ -135,6 +148,13 @@ export class BitVector implements StructClass {
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([
+      fieldToBcs<'u64'>(`u64`, this.length),
+      fieldToBcs<Vector<'bool'>>(`vector<bool>`, this.bitField),
+    ])
+  }
+
   static fromJSONField(field: any): BitVector {
     return BitVector.reified().new({
       length: decodeFromJSONField('u64', field.length),
@@ -187,4 +207,11 @@ export class BitVector implements StructClass {
 
     return BitVector.fromSuiObjectData(res.data)
   }
+
+  static async fetchMany(
+    client: SuiClient,
+    ids: string[]
+  ): Promise<Array<FetchObjectResult<BitVector>>> {
+    return fetchObjects(client, ids, (data: SuiObjectData) => BitVector.fromSuiObjectData(data))
+  }
 }
@@ -9,18 +9,23 @@ import {
   TypeArgument,
   assertFieldsWithTypesArgsMatch,
   assertReifiedTypeArgsMatch,
+  concatBytes,
   decodeFromFields,
   decodeFromFieldsWithTypes,
   decodeFromJSONField,
   extractType,
+  fieldToBcs,
   fieldToJSON,
   phantom,
   toBcs,
 } from '../../_framework/reified'
 import {
+  FetchObjectResult,
   FieldsWithTypes,
   composeSuiType,
   compressSuiType,
+  fetchObjects,
+  fetchObjectsOfType,
   parseTypeName,
 } from '../../_framework/util'
 import { Vector } from '../../_framework/vector'
@@ -48,6 +53,9 @@ export class Option<T0 extends TypeArgument> implements StructClass {
   static readonly $typeName = `${PKG_V14}::option::Option`
   static readonly $numTypeParams = 1
   static readonly $isPhantom = [false] as const
+  static readonly $moveFieldNames = {
+    vec: 'vec',
+  } as const
 
   __inner: T0 = null as unknown as T0 // for type checking in reified.ts
 
@@ -55,6 +63,7 @@ export class Option<T0 extends TypeArgument> implements StructClass {
   readonly $fullTypeName: `${typeof PKG_V14}::option::Option<${ToTypeStr<T0>}>`
   readonly $typeArgs: [ToTypeStr<T0>]
   readonly $isPhantom = Option.$isPhantom
+  readonly $moveFieldNames = Option.$moveFieldNames
 
   readonly vec: ToField<Vector<T0>>
 
@@ -156,6 +165,10 @@ export class Option<T0 extends TypeArgument> implements StructClass {
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([fieldToBcs<Vector<T0>>(`vector<${this.$typeArgs[0]}>`, this.vec)])
+  }
+
   static fromJSONField<T0 extends Reified<TypeArgument, any>>(
     typeArg: T0,
     field: any
@@ -242,4 +255,18 @@ export class Option<T0 extends TypeArgument> implements StructClass {
 
     return Option.fromSuiObjectData(typeArg, res.data)
   }
+
+  static async fetchMany<T0 extends Reified<TypeArgument, any>>(
+    client: SuiClient,
+    ids: string[],
+    typeArg?: T0
+  ): Promise<Array<FetchObjectResult<Option<ToTypeArgument<T0>>>>> {
+    if (typeArg === undefined) {
+      return fetchObjectsOfType<Option<ToTypeArgument<T0>>>(client, ids, Option.$typeName)
+    }
+
+    return fetchObjects(client, ids, (data: SuiObjectData) =>
+      Option.fromSuiObjectData(typeArg, data)
+    )
+  }
 }
@@ -4,12 +4,20 @@ import {
   StructClass,
   ToField,
   ToTypeStr,
+  concatBytes,
   decodeFromFields,
   decodeFromFieldsWithTypes,
   decodeFromJSONField,
+  fieldToBcs,
   phantom,
 } from '../../_framework/reified'
-import { FieldsWithTypes, composeSuiType, compressSuiType } from '../../_framework/util'
+import {
+  FetchObjectResult,
+  FieldsWithTypes,
+  composeSuiType,
+  compressSuiType,
+  fetchObjects,
+} from '../../_framework/util'
 import { PKG_V14 } from '../index'
 import { bcs } from '@mysten/sui/bcs'
 import { SuiClient, SuiObjectData, SuiParsedData } from '@mysten/sui/client'
@@ -34,11 +42,15 @@ export class UQ32_32 implements StructClass {
   static readonly $typeName = `${PKG_V14}::uq32_32::UQ32_32`
   static readonly $numTypeParams = 0
   static readonly $isPhantom = [] as const
+  static readonly $moveFieldNames = {
+    pos0: 'pos0',
+  } as const
 
   readonly $typeName = UQ32_32.$typeName
   readonly $fullTypeName: `${typeof PKG_V14}::uq32_32::UQ32_32`
   readonly $typeArgs: []
   readonly $isPhantom = UQ32_32.$isPhantom
+  readonly $moveFieldNames = UQ32_32.$moveFieldNames
 
   readonly pos0: ToField<'u64'>
 
@@ -121,6 +133,10 @@ export class UQ32_32 implements StructClass {
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([fieldToBcs<'u64'>(`u64`, this.pos0)])
+  }
+
   static fromJSONField(field: any): UQ32_32 {
     return UQ32_32.reified().new({ pos0: decodeFromJSONField('u64', field.pos0) })
   }
@@ -170,4 +186,11 @@ export class UQ32_32 implements StructClass {
 
     return UQ32_32.fromSuiObjectData(res.data)
   }
+
+  static async fetchMany(
+    client: SuiClient,
+    ids: string[]
+  ): Promise<Array<FetchObjectResult<UQ32_32>>> {
+    return fetchObjects(client, ids, (data: SuiObjectData) => UQ32_32.fromSuiObjectData(data))
+  }
 }
@@ -4,12 +4,20 @@ import {
   StructClass,
   ToField,
   ToTypeStr,
+  concatBytes,
   decodeFromFields,
   decodeFromFieldsWithTypes,
   decodeFromJSONField,
+  fieldToBcs,
   phantom,
 } from '../../_framework/reified'
-import { FieldsWithTypes, composeSuiType, compressSuiType } from '../../_framework/util'
+import {
+  FetchObjectResult,
+  FieldsWithTypes,
+  composeSuiType,
+  compressSuiType,
+  fetchObjects,
+} from '../../_framework/util'
 import { PKG_V14 } from '../index'
 import { bcs } from '@mysten/sui/bcs'
 import { SuiClient, SuiObjectData, SuiParsedData } from '@mysten/sui/client'
@@ -34,11 +42,15 @@ export class UQ64_64 implements StructClass {
   static readonly $typeName = `${PKG_V14}::uq64_64::UQ64_64`
   static readonly $numTypeParams = 0
   static readonly $isPhantom = [] as const
+  static readonly $moveFieldNames = {
+    pos0: 'pos0',
+  } as const
 
   readonly $typeName = UQ64_64.$typeName
   readonly $fullTypeName: `${typeof PKG_V14}::uq64_64::UQ64_64`
   readonly $typeArgs: []
   readonly $isPhantom = UQ64_64.$isPhantom
+  readonly $moveFieldNames = UQ64_64.$moveFieldNames
 
   readonly pos0: ToField<'u128'>
 
@@ -121,6 +133,10 @@ export class UQ64_64 implements StructClass {
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([fieldToBcs<'u128'>(`u128`, this.pos0)])
+  }
+
   static fromJSONField(field: any): UQ64_64 {
     return UQ64_64.reified().new({ pos0: decodeFromJSONField('u128', field.pos0) })
   }
@@ -170,4 +186,11 @@ export class UQ64_64 implements StructClass {
 
     return UQ64_64.fromSuiObjectData(res.data)
   }
+
+  static async fetchMany(
+    client: SuiClient,
+    ids: string[]
+  ): Promise<Array<FetchObjectResult<UQ64_64>>> {
+    return fetchObjects(client, ids, (data: SuiObjectData) => UQ64_64.fromSuiObjectData(data))
+  }
 }
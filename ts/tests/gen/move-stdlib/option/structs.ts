@@ -9,18 +9,23 @@ import {
   TypeArgument,
   assertFieldsWithTypesArgsMatch,
   assertReifiedTypeArgsMatch,
+  concatBytes,
   decodeFromFields,
   decodeFromFieldsWithTypes,
   decodeFromJSONField,
   extractType,
+  fieldToBcs,
   fieldToJSON,
   phantom,
   toBcs,
 } from '../../_framework/reified'
 import {
+  FetchObjectResult,
   FieldsWithTypes,
   composeSuiType,
   compressSuiType,
+  fetchObjects,
+  fetchObjectsOfType,
   parseTypeName,
 } from '../../_framework/util'
 import { Vector } from '../../_framework/vector'
@@ -51,6 +56,9 @@ export class Option<Element extends TypeArgument> implements StructClass {
   static readonly $typeName = `${PKG_V14}::option::Option`
   static readonly $numTypeParams = 1
   static readonly $isPhantom = [false] as const
+  static readonly $moveFieldNames = {
+    vec: 'vec',
+  } as const
 
   __inner: Element = null as unknown as Element // for type checking in reified.ts
 
@@ -58,6 +66,7 @@ export class Option<Element extends TypeArgument> implements StructClass {
   readonly $fullTypeName: `${typeof PKG_V14}::option::Option<${ToTypeStr<Element>}>`
   readonly $typeArgs: [ToTypeStr<Element>]
   readonly $isPhantom = Option.$isPhantom
+  readonly $moveFieldNames = Option.$moveFieldNames
 
   readonly vec: ToField<Vector<Element>>
 
@@ -161,6 +170,10 @@ export class Option<Element extends TypeArgument> implements StructClass {
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([fieldToBcs<Vector<Element>>(`vector<${this.$typeArgs[0]}>`, this.vec)])
+  }
+
   static fromJSONField<Element extends Reified<TypeArgument, any>>(
     typeArg: Element,
     field: any
@@ -247,4 +260,18 @@ export class Option<Element extends TypeArgument> implements StructClass {
 
     return Option.fromSuiObjectData(typeArg, res.data)
   }
+
+  static async fetchMany<Element extends Reified<TypeArgument, any>>(
+    client: SuiClient,
+    ids: string[],
+    typeArg?: Element
+  ): Promise<Array<FetchObjectResult<Option<ToTypeArgument<Element>>>>> {
+    if (typeArg === undefined) {
+      return fetchObjectsOfType<Option<ToTypeArgument<Element>>>(client, ids, Option.$typeName)
+    }
+
+    return fetchObjects(client, ids, (data: SuiObjectData) =>
+      Option.fromSuiObjectData(typeArg, data)
+    )
+  }
 }
@@ -1,15 +1,26 @@
+import { DisplayTemplate, fetchDisplay } from '../../_framework/display'
 import {
   PhantomReified,
   Reified,
   StructClass,
   ToField,
   ToTypeStr,
+  concatBytes,
   decodeFromFields,
   decodeFromFieldsWithTypes,
   decodeFromJSONField,
+  fieldToBcs,
   phantom,
 } from '../../_framework/reified'
-import { FieldsWithTypes, composeSuiType, compressSuiType } from '../../_framework/util'
+import {
+  FetchObjectResult,
+  FieldsWithTypes,
+  TypedObjectId,
+  composeSuiType,
+  compressSuiType,
+  fetchObjects,
+  fetchOwnedObjects,
+} from '../../_framework/util'
 import { PKG_V27 } from '../index'
 import { UID } from '../object/structs'
 import { bcs } from '@mysten/sui/bcs'
@@ -36,11 +47,16 @@ export class Bag implements StructClass {
   static readonly $typeName = `${PKG_V27}::bag::Bag`
   static readonly $numTypeParams = 0
   static readonly $isPhantom = [] as const
+  static readonly $moveFieldNames = {
+    id: 'id',
+    size: 'size',
+  } as const
 
   readonly $typeName = Bag.$typeName
   readonly $fullTypeName: `${typeof PKG_V27}::bag::Bag`
   readonly $typeArgs: []
   readonly $isPhantom = Bag.$isPhantom
+  readonly $moveFieldNames = Bag.$moveFieldNames
 
   readonly id: ToField<UID>
   readonly size: ToField<'u64'>
@@ -53,6 +69,14 @@ export class Bag implements StructClass {
     this.size = fields.size
   }
 
+  get $objectId(): string {
+    return this.id
+  }
+
+  static id(id: string): TypedObjectId<Bag> {
+    return id as TypedObjectId<Bag>
+  }
+
   static reified(): BagReified {
     return {
       typeName: Bag.$typeName,
@@ -127,6 +151,13 @@ export class Bag implements StructClass {
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([
+      fieldToBcs<UID>(`${UID.$typeName}`, this.id),
+      fieldToBcs<'u64'>(`u64`, this.size),
+    ])
+  }
+
   static fromJSONField(field: any): Bag {
     return Bag.reified().new({
       id: decodeFromJSONField(UID.reified(), field.id),
@@ -179,4 +210,18 @@ export class Bag implements StructClass {
 
     return Bag.fromSuiObjectData(res.data)
   }
+
+  static async fetchMany(client: SuiClient, ids: string[]): Promise<Array<FetchObjectResult<Bag>>> {
+    return fetchObjects(client, ids, (data: SuiObjectData) => Bag.fromSuiObjectData(data))
+  }
+
+  static async *fetchOwned(client: SuiClient, owner: string): AsyncGenerator<Bag> {
+    yield* fetchOwnedObjects(client, owner, Bag.$typeName, (data: SuiObjectData) =>
+      Bag.fromSuiObjectData(data)
+    )
+  }
+
+  static async fetchDisplay(client: SuiClient): Promise<DisplayTemplate<Bag> | null> {
+    return fetchDisplay(client, Bag.reified().fullTypeName)
+  }
 }
@@ -9,16 +9,21 @@ import {
   ToTypeStr,
   assertFieldsWithTypesArgsMatch,
   assertReifiedTypeArgsMatch,
+  concatBytes,
   decodeFromFields,
   decodeFromFieldsWithTypes,
   decodeFromJSONField,
   extractType,
+  fieldToBcs,
   phantom,
 } from '../../_framework/reified'
 import {
+  FetchObjectResult,
   FieldsWithTypes,
   composeSuiType,
   compressSuiType,
+  fetchObjects,
+  fetchObjectsOfType,
   parseTypeName,
 } from '../../_framework/util'
 import { PKG_V27 } from '../index'
@@ -45,11 +50,15 @@ export class Supply<T0 extends PhantomTypeArgument> implements StructClass {
   static readonly $typeName = `${PKG_V27}::balance::Supply`
   static readonly $numTypeParams = 1
   static readonly $isPhantom = [true] as const
+  static readonly $moveFieldNames = {
+    value: 'value',
+  } as const
 
   readonly $typeName = Supply.$typeName
   readonly $fullTypeName: `${typeof PKG_V27}::balance::Supply<${PhantomToTypeStr<T0>}>`
   readonly $typeArgs: [PhantomToTypeStr<T0>]
   readonly $isPhantom = Supply.$isPhantom
+  readonly $moveFieldNames = Supply.$moveFieldNames
 
   readonly value: ToField<'u64'>
 
@@ -148,6 +157,10 @@ export class Supply<T0 extends PhantomTypeArgument> implements StructClass {
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([fieldToBcs<'u64'>(`u64`, this.value)])
+  }
+
   static fromJSONField<T0 extends PhantomReified<PhantomTypeArgument>>(
     typeArg: T0,
     field: any
@@ -232,6 +245,20 @@ export class Supply<T0 extends PhantomTypeArgument> implements StructClass {
 
     return Supply.fromSuiObjectData(typeArg, res.data)
   }
+
+  static async fetchMany<T0 extends PhantomReified<PhantomTypeArgument>>(
+    client: SuiClient,
+    ids: string[],
+    typeArg?: T0
+  ): Promise<Array<FetchObjectResult<Supply<ToPhantomTypeArgument<T0>>>>> {
+    if (typeArg === undefined) {
+      return fetchObjectsOfType<Supply<ToPhantomTypeArgument<T0>>>(client, ids, Supply.$typeName)
+    }
+
+    return fetchObjects(client, ids, (data: SuiObjectData) =>
+      Supply.fromSuiObjectData(typeArg, data)
+    )
+  }
 }
 
 /* ============================== Balance =============================== */
@@ -253,11 +280,15 @@ export class Balance<T0 extends PhantomTypeArgument> implements StructClass {
   static readonly $typeName = `${PKG_V27}::balance::Balance`
   static readonly $numTypeParams = 1
   static readonly $isPhantom = [true] as const
+  static readonly $moveFieldNames = {
+    value: 'value',
+  } as const
 
   readonly $typeName = Balance.$typeName
   readonly $fullTypeName: `${typeof PKG_V27}::balance::Balance<${PhantomToTypeStr<T0>}>`
   readonly $typeArgs: [PhantomToTypeStr<T0>]
   readonly $isPhantom = Balance.$isPhantom
+  readonly $moveFieldNames = Balance.$moveFieldNames
 
   readonly value: ToField<'u64'>
 
@@ -356,6 +387,10 @@ export class Balance<T0 extends PhantomTypeArgument> implements StructClass {
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([fieldToBcs<'u64'>(`u64`, this.value)])
+  }
+
   static fromJSONField<T0 extends PhantomReified<PhantomTypeArgument>>(
     typeArg: T0,
     field: any
@@ -440,4 +475,18 @@ export class Balance<T0 extends PhantomTypeArgument> implements StructClass {
 
     return Balance.fromSuiObjectData(typeArg, res.data)
   }
+
+  static async fetchMany<T0 extends PhantomReified<PhantomTypeArgument>>(
+    client: SuiClient,
+    ids: string[],
+    typeArg?: T0
+  ): Promise<Array<FetchObjectResult<Balance<ToPhantomTypeArgument<T0>>>>> {
+    if (typeArg === undefined) {
+      return fetchObjectsOfType<Balance<ToPhantomTypeArgument<T0>>>(client, ids, Balance.$typeName)
+    }
+
+    return fetchObjects(client, ids, (data: SuiObjectData) =>
+      Balance.fromSuiObjectData(typeArg, data)
+    )
+  }
 }
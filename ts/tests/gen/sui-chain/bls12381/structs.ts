@@ -4,12 +4,20 @@ import {
   StructClass,
   ToField,
   ToTypeStr,
+  concatBytes,
   decodeFromFields,
   decodeFromFieldsWithTypes,
   decodeFromJSONField,
+  fieldToBcs,
   phantom,
 } from '../../_framework/reified'
-import { FieldsWithTypes, composeSuiType, compressSuiType } from '../../_framework/util'
+import {
+  FetchObjectResult,
+  FieldsWithTypes,
+  composeSuiType,
+  compressSuiType,
+  fetchObjects,
+} from '../../_framework/util'
 import { PKG_V27 } from '../index'
 import { bcs } from '@mysten/sui/bcs'
 import { SuiClient, SuiObjectData, SuiParsedData } from '@mysten/sui/client'
@@ -34,11 +42,15 @@ export class Scalar implements StructClass {
   static readonly $typeName = `${PKG_V27}::bls12381::Scalar`
   static readonly $numTypeParams = 0
   static readonly $isPhantom = [] as const
+  static readonly $moveFieldNames = {
+    dummy_field: 'dummyField',
+  } as const
 
   readonly $typeName = Scalar.$typeName
   readonly $fullTypeName: `${typeof PKG_V27}::bls12381::Scalar`
   readonly $typeArgs: []
   readonly $isPhantom = Scalar.$isPhantom
+  readonly $moveFieldNames = Scalar.$moveFieldNames
 
   readonly dummyField: ToField<'bool'>
 
@@ -123,6 +135,10 @@ export class Scalar implements StructClass {
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([fieldToBcs<'bool'>(`bool`, this.dummyField)])
+  }
+
   static fromJSONField(field: any): Scalar {
     return Scalar.reified().new({ dummyField: decodeFromJSONField('bool', field.dummyField) })
   }
@@ -172,6 +188,13 @@ export class Scalar implements StructClass {
 
     return Scalar.fromSuiObjectData(res.data)
   }
+
+  static async fetchMany(
+    client: SuiClient,
+    ids: string[]
+  ): Promise<Array<FetchObjectResult<Scalar>>> {
+    return fetchObjects(client, ids, (data: SuiObjectData) => Scalar.fromSuiObjectData(data))
+  }
 }
 
 /* ============================== G1 =============================== */
@@ -193,11 +216,15 @@ export class G1 implements StructClass {
   static readonly $typeName = `${PKG_V27}::bls12381::G1`
   static readonly $numTypeParams = 0
   static readonly $isPhantom = [] as const
+  static readonly $moveFieldNames = {
+    dummy_field: 'dummyField',
+  } as const
 
   readonly $typeName = G1.$typeName
   readonly $fullTypeName: `${typeof PKG_V27}::bls12381::G1`
   readonly $typeArgs: []
   readonly $isPhantom = G1.$isPhantom
+  readonly $moveFieldNames = G1.$moveFieldNames
 
   readonly dummyField: ToField<'bool'>
 
@@ -279,6 +306,10 @@ export class G1 implements StructClass {
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([fieldToBcs<'bool'>(`bool`, this.dummyField)])
+  }
+
   static fromJSONField(field: any): G1 {
     return G1.reified().new({ dummyField: decodeFromJSONField('bool', field.dummyField) })
   }
@@ -328,6 +359,10 @@ export class G1 implements StructClass {
 
     return G1.fromSuiObjectData(res.data)
   }
+
+  static async fetchMany(client: SuiClient, ids: string[]): Promise<Array<FetchObjectResult<G1>>> {
+    return fetchObjects(client, ids, (data: SuiObjectData) => G1.fromSuiObjectData(data))
+  }
 }
 
 /* ============================== G2 =============================== */
@@ -349,11 +384,15 @@ export class G2 implements StructClass {
   static readonly $typeName = `${PKG_V27}::bls12381::G2`
   static readonly $numTypeParams = 0
   static readonly $isPhantom = [] as const
+  static readonly $moveFieldNames = {
+    dummy_field: 'dummyField',
+  } as const
 
   readonly $typeName = G2.$typeName
   readonly $fullTypeName: `${typeof PKG_V27}::bls12381::G2`
   readonly $typeArgs: []
   readonly $isPhantom = G2.$isPhantom
+  readonly $moveFieldNames = G2.$moveFieldNames
 
   readonly dummyField: ToField<'bool'>
 
@@ -435,6 +474,10 @@ export class G2 implements StructClass {
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([fieldToBcs<'bool'>(`bool`, this.dummyField)])
+  }
+
   static fromJSONField(field: any): G2 {
     return G2.reified().new({ dummyField: decodeFromJSONField('bool', field.dummyField) })
   }
@@ -484,6 +527,10 @@ export class G2 implements StructClass {
 
     return G2.fromSuiObjectData(res.data)
   }
+
+  static async fetchMany(client: SuiClient, ids: string[]): Promise<Array<FetchObjectResult<G2>>> {
+    return fetchObjects(client, ids, (data: SuiObjectData) => G2.fromSuiObjectData(data))
+  }
 }
 
 /* ============================== GT =============================== */
@@ -505,11 +552,15 @@ export class GT implements StructClass {
   static readonly $typeName = `${PKG_V27}::bls12381::GT`
   static readonly $numTypeParams = 0
   static readonly $isPhantom = [] as const
+  static readonly $moveFieldNames = {
+    dummy_field: 'dummyField',
+  } as const
 
   readonly $typeName = GT.$typeName
   readonly $fullTypeName: `${typeof PKG_V27}::bls12381::GT`
   readonly $typeArgs: []
   readonly $isPhantom = GT.$isPhantom
+  readonly $moveFieldNames = GT.$moveFieldNames
 
   readonly dummyField: ToField<'bool'>
 
@@ -591,6 +642,10 @@ export class GT implements StructClass {
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([fieldToBcs<'bool'>(`bool`, this.dummyField)])
+  }
+
   static fromJSONField(field: any): GT {
     return GT.reified().new({ dummyField: decodeFromJSONField('bool', field.dummyField) })
   }
@@ -640,6 +695,10 @@ export class GT implements StructClass {
 
     return GT.fromSuiObjectData(res.data)
   }
+
+  static async fetchMany(client: SuiClient, ids: string[]): Promise<Array<FetchObjectResult<GT>>> {
+    return fetchObjects(client, ids, (data: SuiObjectData) => GT.fromSuiObjectData(data))
+  }
 }
 
 /* ============================== UncompressedG1 =============================== */
@@ -661,11 +720,15 @@ export class UncompressedG1 implements StructClass {
   static readonly $typeName = `${PKG_V27}::bls12381::UncompressedG1`
   static readonly $numTypeParams = 0
   static readonly $isPhantom = [] as const
+  static readonly $moveFieldNames = {
+    dummy_field: 'dummyField',
+  } as const
 
   readonly $typeName = UncompressedG1.$typeName
   readonly $fullTypeName: `${typeof PKG_V27}::bls12381::UncompressedG1`
   readonly $typeArgs: []
   readonly $isPhantom = UncompressedG1.$isPhantom
+  readonly $moveFieldNames = UncompressedG1.$moveFieldNames
 
   readonly dummyField: ToField<'bool'>
 
@@ -752,6 +815,10 @@ export class UncompressedG1 implements StructClass {
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([fieldToBcs<'bool'>(`bool`, this.dummyField)])
+  }
+
   static fromJSONField(field: any): UncompressedG1 {
     return UncompressedG1.reified().new({
       dummyField: decodeFromJSONField('bool', field.dummyField),
@@ -803,4 +870,13 @@ export class UncompressedG1 implements StructClass {
 
     return UncompressedG1.fromSuiObjectData(res.data)
   }
+
+  static async fetchMany(
+    client: SuiClient,
+    ids: string[]
+  ): Promise<Array<FetchObjectResult<UncompressedG1>>> {
+    return fetchObjects(client, ids, (data: SuiObjectData) =>
+      UncompressedG1.fromSuiObjectData(data)
+    )
+  }
 }
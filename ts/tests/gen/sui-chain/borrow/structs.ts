@@ -8,18 +8,23 @@ import {
   TypeArgument,
   assertFieldsWithTypesArgsMatch,
   assertReifiedTypeArgsMatch,
+  concatBytes,
   decodeFromFields,
   decodeFromFieldsWithTypes,
   decodeFromJSONField,
   extractType,
+  fieldToBcs,
   fieldToJSON,
   phantom,
   toBcs,
 } from '../../_framework/reified'
 import {
+  FetchObjectResult,
   FieldsWithTypes,
   composeSuiType,
   compressSuiType,
+  fetchObjects,
+  fetchObjectsOfType,
   parseTypeName,
 } from '../../_framework/util'
 import { Option } from '../../move-stdlib-chain/option/structs'
@@ -49,11 +54,16 @@ export class Referent<T0 extends TypeArgument> implements StructClass {
   static readonly $typeName = `${PKG_V27}::borrow::Referent`
   static readonly $numTypeParams = 1
   static readonly $isPhantom = [false] as const
+  static readonly $moveFieldNames = {
+    id: 'id',
+    value: 'value',
+  } as const
 
   readonly $typeName = Referent.$typeName
   readonly $fullTypeName: `${typeof PKG_V27}::borrow::Referent<${ToTypeStr<T0>}>`
   readonly $typeArgs: [ToTypeStr<T0>]
   readonly $isPhantom = Referent.$isPhantom
+  readonly $moveFieldNames = Referent.$moveFieldNames
 
   readonly id: ToField<'address'>
   readonly value: ToField<Option<T0>>
@@ -166,6 +176,13 @@ export class Referent<T0 extends TypeArgument> implements StructClass {
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([
+      fieldToBcs<'address'>(`address`, this.id),
+      fieldToBcs<Option<T0>>(`${Option.$typeName}<${this.$typeArgs[0]}>`, this.value),
+    ])
+  }
+
   static fromJSONField<T0 extends Reified<TypeArgument, any>>(
     typeArg: T0,
     field: any
@@ -253,6 +270,20 @@ export class Referent<T0 extends TypeArgument> implements StructClass {
 
     return Referent.fromSuiObjectData(typeArg, res.data)
   }
+
+  static async fetchMany<T0 extends Reified<TypeArgument, any>>(
+    client: SuiClient,
+    ids: string[],
+    typeArg?: T0
+  ): Promise<Array<FetchObjectResult<Referent<ToTypeArgument<T0>>>>> {
+    if (typeArg === undefined) {
+      return fetchObjectsOfType<Referent<ToTypeArgument<T0>>>(client, ids, Referent.$typeName)
+    }
+
+    return fetchObjects(client, ids, (data: SuiObjectData) =>
+      Referent.fromSuiObjectData(typeArg, data)
+    )
+  }
 }
 
 /* ============================== Borrow =============================== */
@@ -275,11 +306,16 @@ export class Borrow implements StructClass {
   static readonly $typeName = `${PKG_V27}::borrow::Borrow`
   static readonly $numTypeParams = 0
   static readonly $isPhantom = [] as const
+  static readonly $moveFieldNames = {
+    ref: 'ref',
+    obj: 'obj',
+  } as const
 
   readonly $typeName = Borrow.$typeName
   readonly $fullTypeName: `${typeof PKG_V27}::borrow::Borrow`
   readonly $typeArgs: []
   readonly $isPhantom = Borrow.$isPhantom
+  readonly $moveFieldNames = Borrow.$moveFieldNames
 
   readonly ref: ToField<'address'>
   readonly obj: ToField<ID>
@@ -372,6 +408,13 @@ export class Borrow implements StructClass {
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([
+      fieldToBcs<'address'>(`address`, this.ref),
+      fieldToBcs<ID>(`${ID.$typeName}`, this.obj),
+    ])
+  }
+
   static fromJSONField(field: any): Borrow {
     return Borrow.reified().new({
       ref: decodeFromJSONField('address', field.ref),
@@ -424,4 +467,11 @@ export class Borrow implements StructClass {
 
     return Borrow.fromSuiObjectData(res.data)
   }
+
+  static async fetchMany(
+    client: SuiClient,
+    ids: string[]
+  ): Promise<Array<FetchObjectResult<Borrow>>> {
+    return fetchObjects(client, ids, (data: SuiObjectData) => Borrow.fromSuiObjectData(data))
+  }
 }
@@ -1,3 +1,4 @@
+import { DisplayTemplate, fetchDisplay } from '../../_framework/display'
 import {
   PhantomReified,
   PhantomToTypeStr,
@@ -9,17 +10,24 @@ import {
   ToTypeStr,
   assertFieldsWithTypesArgsMatch,
   assertReifiedTypeArgsMatch,
+  concatBytes,
   decodeFromFields,
   decodeFromFieldsWithTypes,
   decodeFromJSONField,
   extractType,
+  fieldToBcs,
   fieldToJSON,
   phantom,
 } from '../../_framework/reified'
 import {
+  FetchObjectResult,
   FieldsWithTypes,
+  TypedObjectId,
   composeSuiType,
   compressSuiType,
+  fetchObjects,
+  fetchObjectsOfType,
+  fetchOwnedObjects,
   parseTypeName,
 } from '../../_framework/util'
 import { String as String1 } from '../../move-stdlib-chain/ascii/structs'
@@ -53,11 +61,16 @@ export class Coin<T0 extends PhantomTypeArgument> implements StructClass {
   static readonly $typeName = `${PKG_V27}::coin::Coin`
   static readonly $numTypeParams = 1
   static readonly $isPhantom = [true] as const
+  static readonly $moveFieldNames = {
+    id: 'id',
+    balance: 'balance',
+  } as const
 
   readonly $typeName = Coin.$typeName
   readonly $fullTypeName: `${typeof PKG_V27}::coin::Coin<${PhantomToTypeStr<T0>}>`
   readonly $typeArgs: [PhantomToTypeStr<T0>]
   readonly $isPhantom = Coin.$isPhantom
+  readonly $moveFieldNames = Coin.$moveFieldNames
 
   readonly id: ToField<UID>
   readonly balance: ToField<Balance<T0>>
@@ -73,6 +86,14 @@ export class Coin<T0 extends PhantomTypeArgument> implements StructClass {
     this.balance = fields.balance
   }
 
+  get $objectId(): string {
+    return this.id
+  }
+
+  static id<T0 extends PhantomTypeArgument>(id: string): TypedObjectId<Coin<T0>> {
+    return id as TypedObjectId<Coin<T0>>
+  }
+
   static reified<T0 extends PhantomReified<PhantomTypeArgument>>(
     T0: T0
   ): CoinReified<ToPhantomTypeArgument<T0>> {
@@ -164,6 +185,13 @@ export class Coin<T0 extends PhantomTypeArgument> implements StructClass {
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([
+      fieldToBcs<UID>(`${UID.$typeName}`, this.id),
+      fieldToBcs<Balance<T0>>(`${Balance.$typeName}<${this.$typeArgs[0]}>`, this.balance),
+    ])
+  }
+
   static fromJSONField<T0 extends PhantomReified<PhantomTypeArgument>>(
     typeArg: T0,
     field: any
@@ -251,6 +279,43 @@ export class Coin<T0 extends PhantomTypeArgument> implements StructClass {
 
     return Coin.fromSuiObjectData(typeArg, res.data)
   }
+
+  static async fetchMany<T0 extends PhantomReified<PhantomTypeArgument>>(
+    client: SuiClient,
+    ids: string[],
+    typeArg?: T0
+  ): Promise<Array<FetchObjectResult<Coin<ToPhantomTypeArgument<T0>>>>> {
+    if (typeArg === undefined) {
+      return fetchObjectsOfType<Coin<ToPhantomTypeArgument<T0>>>(client, ids, Coin.$typeName)
+    }
+
+    return fetchObjects(client, ids, (data: SuiObjectData) => Coin.fromSuiObjectData(typeArg, data))
+  }
+
+  static async *fetchOwned<T0 extends PhantomReified<PhantomTypeArgument>>(
+    client: SuiClient,
+    owner: string,
+    typeArg?: T0
+  ): AsyncGenerator<Coin<ToPhantomTypeArgument<T0>>> {
+    if (typeArg === undefined) {
+      yield* fetchOwnedObjects<Coin<ToPhantomTypeArgument<T0>>>(client, owner, Coin.$typeName)
+      return
+    }
+
+    yield* fetchOwnedObjects(
+      client,
+      owner,
+      composeSuiType(Coin.$typeName, extractType(typeArg)),
+      (data: SuiObjectData) => Coin.fromSuiObjectData(typeArg, data)
+    )
+  }
+
+  static async fetchDisplay<T0 extends PhantomReified<PhantomTypeArgument>>(
+    client: SuiClient,
+    typeArg: T0
+  ): Promise<DisplayTemplate<Coin<ToPhantomTypeArgument<T0>>> | null> {
+    return fetchDisplay(client, Coin.reified(typeArg).fullTypeName)
+  }
 }
 
 /* ============================== CoinMetadata =============================== */
@@ -280,11 +345,20 @@ export class CoinMetadata<T0 extends PhantomTypeArgument> implements StructClass
   static readonly $typeName = `${PKG_V27}::coin::CoinMetadata`
   static readonly $numTypeParams = 1
   static readonly $isPhantom = [true] as const
+  static readonly $moveFieldNames = {
+    id: 'id',
+    decimals: 'decimals',
+    name: 'name',
+    symbol: 'symbol',
+    description: 'description',
+    icon_url: 'iconUrl',
+  } as const
 
   readonly $typeName = CoinMetadata.$typeName
   readonly $fullTypeName: `${typeof PKG_V27}::coin::CoinMetadata<${PhantomToTypeStr<T0>}>`
   readonly $typeArgs: [PhantomToTypeStr<T0>]
   readonly $isPhantom = CoinMetadata.$isPhantom
+  readonly $moveFieldNames = CoinMetadata.$moveFieldNames
 
   readonly id: ToField<UID>
   readonly decimals: ToField<'u8'>
@@ -308,6 +382,14 @@ export class CoinMetadata<T0 extends PhantomTypeArgument> implements StructClass
     this.iconUrl = fields.iconUrl
   }
 
+  get $objectId(): string {
+    return this.id
+  }
+
+  static id<T0 extends PhantomTypeArgument>(id: string): TypedObjectId<CoinMetadata<T0>> {
+    return id as TypedObjectId<CoinMetadata<T0>>
+  }
+
   static reified<T0 extends PhantomReified<PhantomTypeArgument>>(
     T0: T0
   ): CoinMetadataReified<ToPhantomTypeArgument<T0>> {
@@ -415,6 +497,17 @@ export class CoinMetadata<T0 extends PhantomTypeArgument> implements StructClass
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([
+      fieldToBcs<UID>(`${UID.$typeName}`, this.id),
+      fieldToBcs<'u8'>(`u8`, this.decimals),
+      fieldToBcs<String>(`${String.$typeName}`, this.name),
+      fieldToBcs<String1>(`${String1.$typeName}`, this.symbol),
+      fieldToBcs<String>(`${String.$typeName}`, this.description),
+      fieldToBcs<Option<Url>>(`${Option.$typeName}<${Url.$typeName}>`, this.iconUrl),
+    ])
+  }
+
   static fromJSONField<T0 extends PhantomReified<PhantomTypeArgument>>(
     typeArg: T0,
     field: any
@@ -506,6 +599,53 @@ export class CoinMetadata<T0 extends PhantomTypeArgument> implements StructClass
 
     return CoinMetadata.fromSuiObjectData(typeArg, res.data)
   }
+
+  static async fetchMany<T0 extends PhantomReified<PhantomTypeArgument>>(
+    client: SuiClient,
+    ids: string[],
+    typeArg?: T0
+  ): Promise<Array<FetchObjectResult<CoinMetadata<ToPhantomTypeArgument<T0>>>>> {
+    if (typeArg === undefined) {
+      return fetchObjectsOfType<CoinMetadata<ToPhantomTypeArgument<T0>>>(
+        client,
+        ids,
+        CoinMetadata.$typeName
+      )
+    }
+
+    return fetchObjects(client, ids, (data: SuiObjectData) =>
+      CoinMetadata.fromSuiObjectData(typeArg, data)
+    )
+  }
+
+  static async *fetchOwned<T0 extends PhantomReified<PhantomTypeArgument>>(
+    client: SuiClient,
+    owner: string,
+    typeArg?: T0
+  ): AsyncGenerator<CoinMetadata<ToPhantomTypeArgument<T0>>> {
+    if (typeArg === undefined) {
+      yield* fetchOwnedObjects<CoinMetadata<ToPhantomTypeArgument<T0>>>(
+        client,
+        owner,
+        CoinMetadata.$typeName
+      )
+      return
+    }
+
+    yield* fetchOwnedObjects(
+      client,
+      owner,
+      composeSuiType(CoinMetadata.$typeName, extractType(typeArg)),
+      (data: SuiObjectData) => CoinMetadata.fromSuiObjectData(typeArg, data)
+    )
+  }
+
+  static async fetchDisplay<T0 extends PhantomReified<PhantomTypeArgument>>(
+    client: SuiClient,
+    typeArg: T0
+  ): Promise<DisplayTemplate<CoinMetadata<ToPhantomTypeArgument<T0>>> | null> {
+    return fetchDisplay(client, CoinMetadata.reified(typeArg).fullTypeName)
+  }
 }
 
 /* ============================== RegulatedCoinMetadata =============================== */
@@ -532,11 +672,17 @@ export class RegulatedCoinMetadata<T0 extends PhantomTypeArgument> implements St
   static readonly $typeName = `${PKG_V27}::coin::RegulatedCoinMetadata`
   static readonly $numTypeParams = 1
   static readonly $isPhantom = [true] as const
+  static readonly $moveFieldNames = {
+    id: 'id',
+    coin_metadata_object: 'coinMetadataObject',
+    deny_cap_object: 'denyCapObject',
+  } as const
 
   readonly $typeName = RegulatedCoinMetadata.$typeName
   readonly $fullTypeName: `${typeof PKG_V27}::coin::RegulatedCoinMetadata<${PhantomToTypeStr<T0>}>`
   readonly $typeArgs: [PhantomToTypeStr<T0>]
   readonly $isPhantom = RegulatedCoinMetadata.$isPhantom
+  readonly $moveFieldNames = RegulatedCoinMetadata.$moveFieldNames
 
   readonly id: ToField<UID>
   readonly coinMetadataObject: ToField<ID>
@@ -554,6 +700,14 @@ export class RegulatedCoinMetadata<T0 extends PhantomTypeArgument> implements St
     this.denyCapObject = fields.denyCapObject
   }
 
+  get $objectId(): string {
+    return this.id
+  }
+
+  static id<T0 extends PhantomTypeArgument>(id: string): TypedObjectId<RegulatedCoinMetadata<T0>> {
+    return id as TypedObjectId<RegulatedCoinMetadata<T0>>
+  }
+
   static reified<T0 extends PhantomReified<PhantomTypeArgument>>(
     T0: T0
   ): RegulatedCoinMetadataReified<ToPhantomTypeArgument<T0>> {
@@ -652,6 +806,14 @@ export class RegulatedCoinMetadata<T0 extends PhantomTypeArgument> implements St
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([
+      fieldToBcs<UID>(`${UID.$typeName}`, this.id),
+      fieldToBcs<ID>(`${ID.$typeName}`, this.coinMetadataObject),
+      fieldToBcs<ID>(`${ID.$typeName}`, this.denyCapObject),
+    ])
+  }
+
   static fromJSONField<T0 extends PhantomReified<PhantomTypeArgument>>(
     typeArg: T0,
     field: any
@@ -742,6 +904,53 @@ export class RegulatedCoinMetadata<T0 extends PhantomTypeArgument> implements St
 
     return RegulatedCoinMetadata.fromSuiObjectData(typeArg, res.data)
   }
+
+  static async fetchMany<T0 extends PhantomReified<PhantomTypeArgument>>(
+    client: SuiClient,
+    ids: string[],
+    typeArg?: T0
+  ): Promise<Array<FetchObjectResult<RegulatedCoinMetadata<ToPhantomTypeArgument<T0>>>>> {
+    if (typeArg === undefined) {
+      return fetchObjectsOfType<RegulatedCoinMetadata<ToPhantomTypeArgument<T0>>>(
+        client,
+        ids,
+        RegulatedCoinMetadata.$typeName
+      )
+    }
+
+    return fetchObjects(client, ids, (data: SuiObjectData) =>
+      RegulatedCoinMetadata.fromSuiObjectData(typeArg, data)
+    )
+  }
+
+  static async *fetchOwned<T0 extends PhantomReified<PhantomTypeArgument>>(
+    client: SuiClient,
+    owner: string,
+    typeArg?: T0
+  ): AsyncGenerator<RegulatedCoinMetadata<ToPhantomTypeArgument<T0>>> {
+    if (typeArg === undefined) {
+      yield* fetchOwnedObjects<RegulatedCoinMetadata<ToPhantomTypeArgument<T0>>>(
+        client,
+        owner,
+        RegulatedCoinMetadata.$typeName
+      )
+      return
+    }
+
+    yield* fetchOwnedObjects(
+      client,
+      owner,
+      composeSuiType(RegulatedCoinMetadata.$typeName, extractType(typeArg)),
+      (data: SuiObjectData) => RegulatedCoinMetadata.fromSuiObjectData(typeArg, data)
+    )
+  }
+
+  static async fetchDisplay<T0 extends PhantomReified<PhantomTypeArgument>>(
+    client: SuiClient,
+    typeArg: T0
+  ): Promise<DisplayTemplate<RegulatedCoinMetadata<ToPhantomTypeArgument<T0>>> | null> {
+    return fetchDisplay(client, RegulatedCoinMetadata.reified(typeArg).fullTypeName)
+  }
 }
 
 /* ============================== TreasuryCap =============================== */
@@ -767,11 +976,16 @@ export class TreasuryCap<T0 extends PhantomTypeArgument> implements StructClass
   static readonly $typeName = `${PKG_V27}::coin::TreasuryCap`
   static readonly $numTypeParams = 1
   static readonly $isPhantom = [true] as const
+  static readonly $moveFieldNames = {
+    id: 'id',
+    total_supply: 'totalSupply',
+  } as const
 
   readonly $typeName = TreasuryCap.$typeName
   readonly $fullTypeName: `${typeof PKG_V27}::coin::TreasuryCap<${PhantomToTypeStr<T0>}>`
   readonly $typeArgs: [PhantomToTypeStr<T0>]
   readonly $isPhantom = TreasuryCap.$isPhantom
+  readonly $moveFieldNames = TreasuryCap.$moveFieldNames
 
   readonly id: ToField<UID>
   readonly totalSupply: ToField<Supply<T0>>
@@ -787,6 +1001,14 @@ export class TreasuryCap<T0 extends PhantomTypeArgument> implements StructClass
     this.totalSupply = fields.totalSupply
   }
 
+  get $objectId(): string {
+    return this.id
+  }
+
+  static id<T0 extends PhantomTypeArgument>(id: string): TypedObjectId<TreasuryCap<T0>> {
+    return id as TypedObjectId<TreasuryCap<T0>>
+  }
+
   static reified<T0 extends PhantomReified<PhantomTypeArgument>>(
     T0: T0
   ): TreasuryCapReified<ToPhantomTypeArgument<T0>> {
@@ -878,6 +1100,13 @@ export class TreasuryCap<T0 extends PhantomTypeArgument> implements StructClass
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([
+      fieldToBcs<UID>(`${UID.$typeName}`, this.id),
+      fieldToBcs<Supply<T0>>(`${Supply.$typeName}<${this.$typeArgs[0]}>`, this.totalSupply),
+    ])
+  }
+
   static fromJSONField<T0 extends PhantomReified<PhantomTypeArgument>>(
     typeArg: T0,
     field: any
@@ -965,6 +1194,53 @@ export class TreasuryCap<T0 extends PhantomTypeArgument> implements StructClass
 
     return TreasuryCap.fromSuiObjectData(typeArg, res.data)
   }
+
+  static async fetchMany<T0 extends PhantomReified<PhantomTypeArgument>>(
+    client: SuiClient,
+    ids: string[],
+    typeArg?: T0
+  ): Promise<Array<FetchObjectResult<TreasuryCap<ToPhantomTypeArgument<T0>>>>> {
+    if (typeArg === undefined) {
+      return fetchObjectsOfType<TreasuryCap<ToPhantomTypeArgument<T0>>>(
+        client,
+        ids,
+        TreasuryCap.$typeName
+      )
+    }
+
+    return fetchObjects(client, ids, (data: SuiObjectData) =>
+      TreasuryCap.fromSuiObjectData(typeArg, data)
+    )
+  }
+
+  static async *fetchOwned<T0 extends PhantomReified<PhantomTypeArgument>>(
+    client: SuiClient,
+    owner: string,
+    typeArg?: T0
+  ): AsyncGenerator<TreasuryCap<ToPhantomTypeArgument<T0>>> {
+    if (typeArg === undefined) {
+      yield* fetchOwnedObjects<TreasuryCap<ToPhantomTypeArgument<T0>>>(
+        client,
+        owner,
+        TreasuryCap.$typeName
+      )
+      return
+    }
+
+    yield* fetchOwnedObjects(
+      client,
+      owner,
+      composeSuiType(TreasuryCap.$typeName, extractType(typeArg)),
+      (data: SuiObjectData) => TreasuryCap.fromSuiObjectData(typeArg, data)
+    )
+  }
+
+  static async fetchDisplay<T0 extends PhantomReified<PhantomTypeArgument>>(
+    client: SuiClient,
+    typeArg: T0
+  ): Promise<DisplayTemplate<TreasuryCap<ToPhantomTypeArgument<T0>>> | null> {
+    return fetchDisplay(client, TreasuryCap.reified(typeArg).fullTypeName)
+  }
 }
 
 /* ============================== DenyCapV2 =============================== */
@@ -990,11 +1266,16 @@ export class DenyCapV2<T0 extends PhantomTypeArgument> implements StructClass {
   static readonly $typeName = `${PKG_V27}::coin::DenyCapV2`
   static readonly $numTypeParams = 1
   static readonly $isPhantom = [true] as const
+  static readonly $moveFieldNames = {
+    id: 'id',
+    allow_global_pause: 'allowGlobalPause',
+  } as const
 
   readonly $typeName = DenyCapV2.$typeName
   readonly $fullTypeName: `${typeof PKG_V27}::coin::DenyCapV2<${PhantomToTypeStr<T0>}>`
   readonly $typeArgs: [PhantomToTypeStr<T0>]
   readonly $isPhantom = DenyCapV2.$isPhantom
+  readonly $moveFieldNames = DenyCapV2.$moveFieldNames
 
   readonly id: ToField<UID>
   readonly allowGlobalPause: ToField<'bool'>
@@ -1010,6 +1291,14 @@ export class DenyCapV2<T0 extends PhantomTypeArgument> implements StructClass {
     this.allowGlobalPause = fields.allowGlobalPause
   }
 
+  get $objectId(): string {
+    return this.id
+  }
+
+  static id<T0 extends PhantomTypeArgument>(id: string): TypedObjectId<DenyCapV2<T0>> {
+    return id as TypedObjectId<DenyCapV2<T0>>
+  }
+
   static reified<T0 extends PhantomReified<PhantomTypeArgument>>(
     T0: T0
   ): DenyCapV2Reified<ToPhantomTypeArgument<T0>> {
@@ -1101,6 +1390,13 @@ export class DenyCapV2<T0 extends PhantomTypeArgument> implements StructClass {
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([
+      fieldToBcs<UID>(`${UID.$typeName}`, this.id),
+      fieldToBcs<'bool'>(`bool`, this.allowGlobalPause),
+    ])
+  }
+
   static fromJSONField<T0 extends PhantomReified<PhantomTypeArgument>>(
     typeArg: T0,
     field: any
@@ -1188,6 +1484,53 @@ export class DenyCapV2<T0 extends PhantomTypeArgument> implements StructClass {
 
     return DenyCapV2.fromSuiObjectData(typeArg, res.data)
   }
+
+  static async fetchMany<T0 extends PhantomReified<PhantomTypeArgument>>(
+    client: SuiClient,
+    ids: string[],
+    typeArg?: T0
+  ): Promise<Array<FetchObjectResult<DenyCapV2<ToPhantomTypeArgument<T0>>>>> {
+    if (typeArg === undefined) {
+      return fetchObjectsOfType<DenyCapV2<ToPhantomTypeArgument<T0>>>(
+        client,
+        ids,
+        DenyCapV2.$typeName
+      )
+    }
+
+    return fetchObjects(client, ids, (data: SuiObjectData) =>
+      DenyCapV2.fromSuiObjectData(typeArg, data)
+    )
+  }
+
+  static async *fetchOwned<T0 extends PhantomReified<PhantomTypeArgument>>(
+    client: SuiClient,
+    owner: string,
+    typeArg?: T0
+  ): AsyncGenerator<DenyCapV2<ToPhantomTypeArgument<T0>>> {
+    if (typeArg === undefined) {
+      yield* fetchOwnedObjects<DenyCapV2<ToPhantomTypeArgument<T0>>>(
+        client,
+        owner,
+        DenyCapV2.$typeName
+      )
+      return
+    }
+
+    yield* fetchOwnedObjects(
+      client,
+      owner,
+      composeSuiType(DenyCapV2.$typeName, extractType(typeArg)),
+      (data: SuiObjectData) => DenyCapV2.fromSuiObjectData(typeArg, data)
+    )
+  }
+
+  static async fetchDisplay<T0 extends PhantomReified<PhantomTypeArgument>>(
+    client: SuiClient,
+    typeArg: T0
+  ): Promise<DisplayTemplate<DenyCapV2<ToPhantomTypeArgument<T0>>> | null> {
+    return fetchDisplay(client, DenyCapV2.reified(typeArg).fullTypeName)
+  }
 }
 
 /* ============================== CurrencyCreated =============================== */
@@ -1212,11 +1555,15 @@ export class CurrencyCreated<T0 extends PhantomTypeArgument> implements StructCl
   static readonly $typeName = `${PKG_V27}::coin::CurrencyCreated`
   static readonly $numTypeParams = 1
   static readonly $isPhantom = [true] as const
+  static readonly $moveFieldNames = {
+    decimals: 'decimals',
+  } as const
 
   readonly $typeName = CurrencyCreated.$typeName
   readonly $fullTypeName: `${typeof PKG_V27}::coin::CurrencyCreated<${PhantomToTypeStr<T0>}>`
   readonly $typeArgs: [PhantomToTypeStr<T0>]
   readonly $isPhantom = CurrencyCreated.$isPhantom
+  readonly $moveFieldNames = CurrencyCreated.$moveFieldNames
 
   readonly decimals: ToField<'u8'>
 
@@ -1317,6 +1664,10 @@ export class CurrencyCreated<T0 extends PhantomTypeArgument> implements StructCl
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([fieldToBcs<'u8'>(`u8`, this.decimals)])
+  }
+
   static fromJSONField<T0 extends PhantomReified<PhantomTypeArgument>>(
     typeArg: T0,
     field: any
@@ -1403,6 +1754,24 @@ export class CurrencyCreated<T0 extends PhantomTypeArgument> implements StructCl
 
     return CurrencyCreated.fromSuiObjectData(typeArg, res.data)
   }
+
+  static async fetchMany<T0 extends PhantomReified<PhantomTypeArgument>>(
+    client: SuiClient,
+    ids: string[],
+    typeArg?: T0
+  ): Promise<Array<FetchObjectResult<CurrencyCreated<ToPhantomTypeArgument<T0>>>>> {
+    if (typeArg === undefined) {
+      return fetchObjectsOfType<CurrencyCreated<ToPhantomTypeArgument<T0>>>(
+        client,
+        ids,
+        CurrencyCreated.$typeName
+      )
+    }
+
+    return fetchObjects(client, ids, (data: SuiObjectData) =>
+      CurrencyCreated.fromSuiObjectData(typeArg, data)
+    )
+  }
 }
 
 /* ============================== DenyCap =============================== */
@@ -1424,11 +1793,15 @@ export class DenyCap<T0 extends PhantomTypeArgument> implements StructClass {
   static readonly $typeName = `${PKG_V27}::coin::DenyCap`
   static readonly $numTypeParams = 1
   static readonly $isPhantom = [true] as const
+  static readonly $moveFieldNames = {
+    id: 'id',
+  } as const
 
   readonly $typeName = DenyCap.$typeName
   readonly $fullTypeName: `${typeof PKG_V27}::coin::DenyCap<${PhantomToTypeStr<T0>}>`
   readonly $typeArgs: [PhantomToTypeStr<T0>]
   readonly $isPhantom = DenyCap.$isPhantom
+  readonly $moveFieldNames = DenyCap.$moveFieldNames
 
   readonly id: ToField<UID>
 
@@ -1442,6 +1815,14 @@ export class DenyCap<T0 extends PhantomTypeArgument> implements StructClass {
     this.id = fields.id
   }
 
+  get $objectId(): string {
+    return this.id
+  }
+
+  static id<T0 extends PhantomTypeArgument>(id: string): TypedObjectId<DenyCap<T0>> {
+    return id as TypedObjectId<DenyCap<T0>>
+  }
+
   static reified<T0 extends PhantomReified<PhantomTypeArgument>>(
     T0: T0
   ): DenyCapReified<ToPhantomTypeArgument<T0>> {
@@ -1527,6 +1908,10 @@ export class DenyCap<T0 extends PhantomTypeArgument> implements StructClass {
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([fieldToBcs<UID>(`${UID.$typeName}`, this.id)])
+  }
+
   static fromJSONField<T0 extends PhantomReified<PhantomTypeArgument>>(
     typeArg: T0,
     field: any
@@ -1611,4 +1996,43 @@ export class DenyCap<T0 extends PhantomTypeArgument> implements StructClass {
 
     return DenyCap.fromSuiObjectData(typeArg, res.data)
   }
+
+  static async fetchMany<T0 extends PhantomReified<PhantomTypeArgument>>(
+    client: SuiClient,
+    ids: string[],
+    typeArg?: T0
+  ): Promise<Array<FetchObjectResult<DenyCap<ToPhantomTypeArgument<T0>>>>> {
+    if (typeArg === undefined) {
+      return fetchObjectsOfType<DenyCap<ToPhantomTypeArgument<T0>>>(client, ids, DenyCap.$typeName)
+    }
+
+    return fetchObjects(client, ids, (data: SuiObjectData) =>
+      DenyCap.fromSuiObjectData(typeArg, data)
+    )
+  }
+
+  static async *fetchOwned<T0 extends PhantomReified<PhantomTypeArgument>>(
+    client: SuiClient,
+    owner: string,
+    typeArg?: T0
+  ): AsyncGenerator<DenyCap<ToPhantomTypeArgument<T0>>> {
+    if (typeArg === undefined) {
+      yield* fetchOwnedObjects<DenyCap<ToPhantomTypeArgument<T0>>>(client, owner, DenyCap.$typeName)
+      return
+    }
+
+    yield* fetchOwnedObjects(
+      client,
+      owner,
+      composeSuiType(DenyCap.$typeName, extractType(typeArg)),
+      (data: SuiObjectData) => DenyCap.fromSuiObjectData(typeArg, data)
+    )
+  }
+
+  static async fetchDisplay<T0 extends PhantomReified<PhantomTypeArgument>>(
+    client: SuiClient,
+    typeArg: T0
+  ): Promise<DisplayTemplate<DenyCap<ToPhantomTypeArgument<T0>>> | null> {
+    return fetchDisplay(client, DenyCap.reified(typeArg).fullTypeName)
+  }
 }
@@ -1,3 +1,4 @@
+import { DisplayTemplate, fetchDisplay } from '../../_framework/display'
 import {
   PhantomReified,
   PhantomToTypeStr,
@@ -11,18 +12,25 @@ import {
   TypeArgument,
   assertFieldsWithTypesArgsMatch,
   assertReifiedTypeArgsMatch,
+  concatBytes,
   decodeFromFields,
   decodeFromFieldsWithTypes,
   decodeFromJSONField,
   extractType,
+  fieldToBcs,
   fieldToJSON,
   phantom,
   toBcs,
 } from '../../_framework/reified'
 import {
+  FetchObjectResult,
   FieldsWithTypes,
+  TypedObjectId,
   composeSuiType,
   compressSuiType,
+  fetchObjects,
+  fetchObjectsOfType,
+  fetchOwnedObjects,
   parseTypeName,
 } from '../../_framework/util'
 import { Option } from '../../move-stdlib-chain/option/structs'
@@ -51,11 +59,15 @@ export class Config<T0 extends PhantomTypeArgument> implements StructClass {
   static readonly $typeName = `${PKG_V27}::config::Config`
   static readonly $numTypeParams = 1
   static readonly $isPhantom = [true] as const
+  static readonly $moveFieldNames = {
+    id: 'id',
+  } as const
 
   readonly $typeName = Config.$typeName
   readonly $fullTypeName: `${typeof PKG_V27}::config::Config<${PhantomToTypeStr<T0>}>`
   readonly $typeArgs: [PhantomToTypeStr<T0>]
   readonly $isPhantom = Config.$isPhantom
+  readonly $moveFieldNames = Config.$moveFieldNames
 
   readonly id: ToField<UID>
 
@@ -69,6 +81,14 @@ export class Config<T0 extends PhantomTypeArgument> implements StructClass {
     this.id = fields.id
   }
 
+  get $objectId(): string {
+    return this.id
+  }
+
+  static id<T0 extends PhantomTypeArgument>(id: string): TypedObjectId<Config<T0>> {
+    return id as TypedObjectId<Config<T0>>
+  }
+
   static reified<T0 extends PhantomReified<PhantomTypeArgument>>(
     T0: T0
   ): ConfigReified<ToPhantomTypeArgument<T0>> {
@@ -154,6 +174,10 @@ export class Config<T0 extends PhantomTypeArgument> implements StructClass {
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([fieldToBcs<UID>(`${UID.$typeName}`, this.id)])
+  }
+
   static fromJSONField<T0 extends PhantomReified<PhantomTypeArgument>>(
     typeArg: T0,
     field: any
@@ -238,6 +262,45 @@ export class Config<T0 extends PhantomTypeArgument> implements StructClass {
 
     return Config.fromSuiObjectData(typeArg, res.data)
   }
+
+  static async fetchMany<T0 extends PhantomReified<PhantomTypeArgument>>(
+    client: SuiClient,
+    ids: string[],
+    typeArg?: T0
+  ): Promise<Array<FetchObjectResult<Config<ToPhantomTypeArgument<T0>>>>> {
+    if (typeArg === undefined) {
+      return fetchObjectsOfType<Config<ToPhantomTypeArgument<T0>>>(client, ids, Config.$typeName)
+    }
+
+    return fetchObjects(client, ids, (data: SuiObjectData) =>
+      Config.fromSuiObjectData(typeArg, data)
+    )
+  }
+
+  static async *fetchOwned<T0 extends PhantomReified<PhantomTypeArgument>>(
+    client: SuiClient,
+    owner: string,
+    typeArg?: T0
+  ): AsyncGenerator<Config<ToPhantomTypeArgument<T0>>> {
+    if (typeArg === undefined) {
+      yield* fetchOwnedObjects<Config<ToPhantomTypeArgument<T0>>>(client, owner, Config.$typeName)
+      return
+    }
+
+    yield* fetchOwnedObjects(
+      client,
+      owner,
+      composeSuiType(Config.$typeName, extractType(typeArg)),
+      (data: SuiObjectData) => Config.fromSuiObjectData(typeArg, data)
+    )
+  }
+
+  static async fetchDisplay<T0 extends PhantomReified<PhantomTypeArgument>>(
+    client: SuiClient,
+    typeArg: T0
+  ): Promise<DisplayTemplate<Config<ToPhantomTypeArgument<T0>>> | null> {
+    return fetchDisplay(client, Config.reified(typeArg).fullTypeName)
+  }
 }
 
 /* ============================== Setting =============================== */
@@ -259,11 +322,15 @@ export class Setting<T0 extends TypeArgument> implements StructClass {
   static readonly $typeName = `${PKG_V27}::config::Setting`
   static readonly $numTypeParams = 1
   static readonly $isPhantom = [false] as const
+  static readonly $moveFieldNames = {
+    data: 'data',
+  } as const
 
   readonly $typeName = Setting.$typeName
   readonly $fullTypeName: `${typeof PKG_V27}::config::Setting<${ToTypeStr<T0>}>`
   readonly $typeArgs: [ToTypeStr<T0>]
   readonly $isPhantom = Setting.$isPhantom
+  readonly $moveFieldNames = Setting.$moveFieldNames
 
   readonly data: ToField<Option<SettingData<T0>>>
 
@@ -373,6 +440,15 @@ export class Setting<T0 extends TypeArgument> implements StructClass {
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([
+      fieldToBcs<Option<SettingData<T0>>>(
+        `${Option.$typeName}<${SettingData.$typeName}<${this.$typeArgs[0]}>>`,
+        this.data
+      ),
+    ])
+  }
+
   static fromJSONField<T0 extends Reified<TypeArgument, any>>(
     typeArg: T0,
     field: any
@@ -459,6 +535,20 @@ export class Setting<T0 extends TypeArgument> implements StructClass {
 
     return Setting.fromSuiObjectData(typeArg, res.data)
   }
+
+  static async fetchMany<T0 extends Reified<TypeArgument, any>>(
+    client: SuiClient,
+    ids: string[],
+    typeArg?: T0
+  ): Promise<Array<FetchObjectResult<Setting<ToTypeArgument<T0>>>>> {
+    if (typeArg === undefined) {
+      return fetchObjectsOfType<Setting<ToTypeArgument<T0>>>(client, ids, Setting.$typeName)
+    }
+
+    return fetchObjects(client, ids, (data: SuiObjectData) =>
+      Setting.fromSuiObjectData(typeArg, data)
+    )
+  }
 }
 
 /* ============================== SettingData =============================== */
@@ -485,11 +575,17 @@ export class SettingData<T0 extends TypeArgument> implements StructClass {
   static readonly $typeName = `${PKG_V27}::config::SettingData`
   static readonly $numTypeParams = 1
   static readonly $isPhantom = [false] as const
+  static readonly $moveFieldNames = {
+    newer_value_epoch: 'newerValueEpoch',
+    newer_value: 'newerValue',
+    older_value_opt: 'olderValueOpt',
+  } as const
 
   readonly $typeName = SettingData.$typeName
   readonly $fullTypeName: `${typeof PKG_V27}::config::SettingData<${ToTypeStr<T0>}>`
   readonly $typeArgs: [ToTypeStr<T0>]
   readonly $isPhantom = SettingData.$isPhantom
+  readonly $moveFieldNames = SettingData.$moveFieldNames
 
   readonly newerValueEpoch: ToField<'u64'>
   readonly newerValue: ToField<Option<T0>>
@@ -614,6 +710,14 @@ export class SettingData<T0 extends TypeArgument> implements StructClass {
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([
+      fieldToBcs<'u64'>(`u64`, this.newerValueEpoch),
+      fieldToBcs<Option<T0>>(`${Option.$typeName}<${this.$typeArgs[0]}>`, this.newerValue),
+      fieldToBcs<Option<T0>>(`${Option.$typeName}<${this.$typeArgs[0]}>`, this.olderValueOpt),
+    ])
+  }
+
   static fromJSONField<T0 extends Reified<TypeArgument, any>>(
     typeArg: T0,
     field: any
@@ -702,4 +806,18 @@ export class SettingData<T0 extends TypeArgument> implements StructClass {
 
     return SettingData.fromSuiObjectData(typeArg, res.data)
   }
+
+  static async fetchMany<T0 extends Reified<TypeArgument, any>>(
+    client: SuiClient,
+    ids: string[],
+    typeArg?: T0
+  ): Promise<Array<FetchObjectResult<SettingData<ToTypeArgument<T0>>>>> {
+    if (typeArg === undefined) {
+      return fetchObjectsOfType<SettingData<ToTypeArgument<T0>>>(client, ids, SettingData.$typeName)
+    }
+
+    return fetchObjects(client, ids, (data: SuiObjectData) =>
+      SettingData.fromSuiObjectData(typeArg, data)
+    )
+  }
 }
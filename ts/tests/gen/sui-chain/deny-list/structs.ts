@@ -1,3 +1,4 @@
+import { DisplayTemplate, fetchDisplay } from '../../_framework/display'
 import * as reified from '../../_framework/reified'
 import {
   PhantomReified,
@@ -5,14 +6,24 @@ import {
   StructClass,
   ToField,
   ToTypeStr,
+  concatBytes,
   decodeFromFields,
   decodeFromFieldsWithTypes,
   decodeFromJSONField,
+  fieldToBcs,
   fieldToJSON,
   phantom,
   ToTypeStr as ToPhantom,
 } from '../../_framework/reified'
-import { FieldsWithTypes, composeSuiType, compressSuiType } from '../../_framework/util'
+import {
+  FetchObjectResult,
+  FieldsWithTypes,
+  TypedObjectId,
+  composeSuiType,
+  compressSuiType,
+  fetchObjects,
+  fetchOwnedObjects,
+} from '../../_framework/util'
 import { Vector } from '../../_framework/vector'
 import { Bag } from '../bag/structs'
 import { PKG_V27 } from '../index'
@@ -43,11 +54,16 @@ export class DenyList implements StructClass {
   static readonly $typeName = `${PKG_V27}::deny_list::DenyList`
   static readonly $numTypeParams = 0
   static readonly $isPhantom = [] as const
+  static readonly $moveFieldNames = {
+    id: 'id',
+    lists: 'lists',
+  } as const
 
   readonly $typeName = DenyList.$typeName
   readonly $fullTypeName: `${typeof PKG_V27}::deny_list::DenyList`
   readonly $typeArgs: []
   readonly $isPhantom = DenyList.$isPhantom
+  readonly $moveFieldNames = DenyList.$moveFieldNames
 
   readonly id: ToField<UID>
   readonly lists: ToField<Bag>
@@ -63,6 +79,14 @@ export class DenyList implements StructClass {
     this.lists = fields.lists
   }
 
+  get $objectId(): string {
+    return this.id
+  }
+
+  static id(id: string): TypedObjectId<DenyList> {
+    return id as TypedObjectId<DenyList>
+  }
+
   static reified(): DenyListReified {
     return {
       typeName: DenyList.$typeName,
@@ -140,6 +164,13 @@ export class DenyList implements StructClass {
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([
+      fieldToBcs<UID>(`${UID.$typeName}`, this.id),
+      fieldToBcs<Bag>(`${Bag.$typeName}`, this.lists),
+    ])
+  }
+
   static fromJSONField(field: any): DenyList {
     return DenyList.reified().new({
       id: decodeFromJSONField(UID.reified(), field.id),
@@ -192,6 +223,23 @@ export class DenyList implements StructClass {
 
     return DenyList.fromSuiObjectData(res.data)
   }
+
+  static async fetchMany(
+    client: SuiClient,
+    ids: string[]
+  ): Promise<Array<FetchObjectResult<DenyList>>> {
+    return fetchObjects(client, ids, (data: SuiObjectData) => DenyList.fromSuiObjectData(data))
+  }
+
+  static async *fetchOwned(client: SuiClient, owner: string): AsyncGenerator<DenyList> {
+    yield* fetchOwnedObjects(client, owner, DenyList.$typeName, (data: SuiObjectData) =>
+      DenyList.fromSuiObjectData(data)
+    )
+  }
+
+  static async fetchDisplay(client: SuiClient): Promise<DisplayTemplate<DenyList> | null> {
+    return fetchDisplay(client, DenyList.reified().fullTypeName)
+  }
 }
 
 /* ============================== ConfigWriteCap =============================== */
@@ -213,11 +261,15 @@ export class ConfigWriteCap implements StructClass {
   static readonly $typeName = `${PKG_V27}::deny_list::ConfigWriteCap`
   static readonly $numTypeParams = 0
   static readonly $isPhantom = [] as const
+  static readonly $moveFieldNames = {
+    dummy_field: 'dummyField',
+  } as const
 
   readonly $typeName = ConfigWriteCap.$typeName
   readonly $fullTypeName: `${typeof PKG_V27}::deny_list::ConfigWriteCap`
   readonly $typeArgs: []
   readonly $isPhantom = ConfigWriteCap.$isPhantom
+  readonly $moveFieldNames = ConfigWriteCap.$moveFieldNames
 
   readonly dummyField: ToField<'bool'>
 
@@ -304,6 +356,10 @@ export class ConfigWriteCap implements StructClass {
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([fieldToBcs<'bool'>(`bool`, this.dummyField)])
+  }
+
   static fromJSONField(field: any): ConfigWriteCap {
     return ConfigWriteCap.reified().new({
       dummyField: decodeFromJSONField('bool', field.dummyField),
@@ -355,6 +411,15 @@ export class ConfigWriteCap implements StructClass {
 
     return ConfigWriteCap.fromSuiObjectData(res.data)
   }
+
+  static async fetchMany(
+    client: SuiClient,
+    ids: string[]
+  ): Promise<Array<FetchObjectResult<ConfigWriteCap>>> {
+    return fetchObjects(client, ids, (data: SuiObjectData) =>
+      ConfigWriteCap.fromSuiObjectData(data)
+    )
+  }
 }
 
 /* ============================== ConfigKey =============================== */
@@ -377,11 +442,16 @@ export class ConfigKey implements StructClass {
   static readonly $typeName = `${PKG_V27}::deny_list::ConfigKey`
   static readonly $numTypeParams = 0
   static readonly $isPhantom = [] as const
+  static readonly $moveFieldNames = {
+    per_type_index: 'perTypeIndex',
+    per_type_key: 'perTypeKey',
+  } as const
 
   readonly $typeName = ConfigKey.$typeName
   readonly $fullTypeName: `${typeof PKG_V27}::deny_list::ConfigKey`
   readonly $typeArgs: []
   readonly $isPhantom = ConfigKey.$isPhantom
+  readonly $moveFieldNames = ConfigKey.$moveFieldNames
 
   readonly perTypeIndex: ToField<'u64'>
   readonly perTypeKey: ToField<Vector<'u8'>>
@@ -474,6 +544,13 @@ export class ConfigKey implements StructClass {
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([
+      fieldToBcs<'u64'>(`u64`, this.perTypeIndex),
+      fieldToBcs<Vector<'u8'>>(`vector<u8>`, this.perTypeKey),
+    ])
+  }
+
   static fromJSONField(field: any): ConfigKey {
     return ConfigKey.reified().new({
       perTypeIndex: decodeFromJSONField('u64', field.perTypeIndex),
@@ -526,6 +603,13 @@ export class ConfigKey implements StructClass {
 
     return ConfigKey.fromSuiObjectData(res.data)
   }
+
+  static async fetchMany(
+    client: SuiClient,
+    ids: string[]
+  ): Promise<Array<FetchObjectResult<ConfigKey>>> {
+    return fetchObjects(client, ids, (data: SuiObjectData) => ConfigKey.fromSuiObjectData(data))
+  }
 }
 
 /* ============================== AddressKey =============================== */
@@ -547,11 +631,15 @@ export class AddressKey implements StructClass {
   static readonly $typeName = `${PKG_V27}::deny_list::AddressKey`
   static readonly $numTypeParams = 0
   static readonly $isPhantom = [] as const
+  static readonly $moveFieldNames = {
+    pos0: 'pos0',
+  } as const
 
   readonly $typeName = AddressKey.$typeName
   readonly $fullTypeName: `${typeof PKG_V27}::deny_list::AddressKey`
   readonly $typeArgs: []
   readonly $isPhantom = AddressKey.$isPhantom
+  readonly $moveFieldNames = AddressKey.$moveFieldNames
 
   readonly pos0: ToField<'address'>
 
@@ -639,6 +727,10 @@ export class AddressKey implements StructClass {
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([fieldToBcs<'address'>(`address`, this.pos0)])
+  }
+
   static fromJSONField(field: any): AddressKey {
     return AddressKey.reified().new({ pos0: decodeFromJSONField('address', field.pos0) })
   }
@@ -688,6 +780,13 @@ export class AddressKey implements StructClass {
 
     return AddressKey.fromSuiObjectData(res.data)
   }
+
+  static async fetchMany(
+    client: SuiClient,
+    ids: string[]
+  ): Promise<Array<FetchObjectResult<AddressKey>>> {
+    return fetchObjects(client, ids, (data: SuiObjectData) => AddressKey.fromSuiObjectData(data))
+  }
 }
 
 /* ============================== GlobalPauseKey =============================== */
@@ -709,11 +808,15 @@ export class GlobalPauseKey implements StructClass {
   static readonly $typeName = `${PKG_V27}::deny_list::GlobalPauseKey`
   static readonly $numTypeParams = 0
   static readonly $isPhantom = [] as const
+  static readonly $moveFieldNames = {
+    dummy_field: 'dummyField',
+  } as const
 
   readonly $typeName = GlobalPauseKey.$typeName
   readonly $fullTypeName: `${typeof PKG_V27}::deny_list::GlobalPauseKey`
   readonly $typeArgs: []
   readonly $isPhantom = GlobalPauseKey.$isPhantom
+  readonly $moveFieldNames = GlobalPauseKey.$moveFieldNames
 
   readonly dummyField: ToField<'bool'>
 
@@ -800,6 +903,10 @@ export class GlobalPauseKey implements StructClass {
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([fieldToBcs<'bool'>(`bool`, this.dummyField)])
+  }
+
   static fromJSONField(field: any): GlobalPauseKey {
     return GlobalPauseKey.reified().new({
       dummyField: decodeFromJSONField('bool', field.dummyField),
@@ -851,6 +958,15 @@ export class GlobalPauseKey implements StructClass {
 
     return GlobalPauseKey.fromSuiObjectData(res.data)
   }
+
+  static async fetchMany(
+    client: SuiClient,
+    ids: string[]
+  ): Promise<Array<FetchObjectResult<GlobalPauseKey>>> {
+    return fetchObjects(client, ids, (data: SuiObjectData) =>
+      GlobalPauseKey.fromSuiObjectData(data)
+    )
+  }
 }
 
 /* ============================== PerTypeConfigCreated =============================== */
@@ -873,11 +989,16 @@ export class PerTypeConfigCreated implements StructClass {
   static readonly $typeName = `${PKG_V27}::deny_list::PerTypeConfigCreated`
   static readonly $numTypeParams = 0
   static readonly $isPhantom = [] as const
+  static readonly $moveFieldNames = {
+    key: 'key',
+    config_id: 'configId',
+  } as const
 
   readonly $typeName = PerTypeConfigCreated.$typeName
   readonly $fullTypeName: `${typeof PKG_V27}::deny_list::PerTypeConfigCreated`
   readonly $typeArgs: []
   readonly $isPhantom = PerTypeConfigCreated.$isPhantom
+  readonly $moveFieldNames = PerTypeConfigCreated.$moveFieldNames
 
   readonly key: ToField<ConfigKey>
   readonly configId: ToField<ID>
@@ -973,6 +1094,13 @@ export class PerTypeConfigCreated implements StructClass {
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([
+      fieldToBcs<ConfigKey>(`${ConfigKey.$typeName}`, this.key),
+      fieldToBcs<ID>(`${ID.$typeName}`, this.configId),
+    ])
+  }
+
   static fromJSONField(field: any): PerTypeConfigCreated {
     return PerTypeConfigCreated.reified().new({
       key: decodeFromJSONField(ConfigKey.reified(), field.key),
@@ -1027,6 +1155,15 @@ export class PerTypeConfigCreated implements StructClass {
 
     return PerTypeConfigCreated.fromSuiObjectData(res.data)
   }
+
+  static async fetchMany(
+    client: SuiClient,
+    ids: string[]
+  ): Promise<Array<FetchObjectResult<PerTypeConfigCreated>>> {
+    return fetchObjects(client, ids, (data: SuiObjectData) =>
+      PerTypeConfigCreated.fromSuiObjectData(data)
+    )
+  }
 }
 
 /* ============================== PerTypeList =============================== */
@@ -1050,11 +1187,17 @@ export class PerTypeList implements StructClass {
   static readonly $typeName = `${PKG_V27}::deny_list::PerTypeList`
   static readonly $numTypeParams = 0
   static readonly $isPhantom = [] as const
+  static readonly $moveFieldNames = {
+    id: 'id',
+    denied_count: 'deniedCount',
+    denied_addresses: 'deniedAddresses',
+  } as const
 
   readonly $typeName = PerTypeList.$typeName
   readonly $fullTypeName: `${typeof PKG_V27}::deny_list::PerTypeList`
   readonly $typeArgs: []
   readonly $isPhantom = PerTypeList.$isPhantom
+  readonly $moveFieldNames = PerTypeList.$moveFieldNames
 
   readonly id: ToField<UID>
   readonly deniedCount: ToField<Table<'address', 'u64'>>
@@ -1072,6 +1215,14 @@ export class PerTypeList implements StructClass {
     this.deniedAddresses = fields.deniedAddresses
   }
 
+  get $objectId(): string {
+    return this.id
+  }
+
+  static id(id: string): TypedObjectId<PerTypeList> {
+    return id as TypedObjectId<PerTypeList>
+  }
+
   static reified(): PerTypeListReified {
     return {
       typeName: PerTypeList.$typeName,
@@ -1171,6 +1322,17 @@ export class PerTypeList implements StructClass {
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([
+      fieldToBcs<UID>(`${UID.$typeName}`, this.id),
+      fieldToBcs<Table<'address', 'u64'>>(`${Table.$typeName}<address, u64>`, this.deniedCount),
+      fieldToBcs<Table<ToPhantom<Vector<'u8'>>, ToPhantom<VecSet<'address'>>>>(
+        `${Table.$typeName}<vector<u8>, ${VecSet.$typeName}<address>>`,
+        this.deniedAddresses
+      ),
+    ])
+  }
+
   static fromJSONField(field: any): PerTypeList {
     return PerTypeList.reified().new({
       id: decodeFromJSONField(UID.reified(), field.id),
@@ -1233,4 +1395,21 @@ export class PerTypeList implements StructClass {
 
     return PerTypeList.fromSuiObjectData(res.data)
   }
+
+  static async fetchMany(
+    client: SuiClient,
+    ids: string[]
+  ): Promise<Array<FetchObjectResult<PerTypeList>>> {
+    return fetchObjects(client, ids, (data: SuiObjectData) => PerTypeList.fromSuiObjectData(data))
+  }
+
+  static async *fetchOwned(client: SuiClient, owner: string): AsyncGenerator<PerTypeList> {
+    yield* fetchOwnedObjects(client, owner, PerTypeList.$typeName, (data: SuiObjectData) =>
+      PerTypeList.fromSuiObjectData(data)
+    )
+  }
+
+  static async fetchDisplay(client: SuiClient): Promise<DisplayTemplate<PerTypeList> | null> {
+    return fetchDisplay(client, PerTypeList.reified().fullTypeName)
+  }
 }
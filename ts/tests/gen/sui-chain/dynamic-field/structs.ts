@@ -1,3 +1,4 @@
+import { DisplayTemplate, fetchDisplay } from '../../_framework/display'
 import {
   PhantomReified,
   Reified,
@@ -8,18 +9,25 @@ import {
   TypeArgument,
   assertFieldsWithTypesArgsMatch,
   assertReifiedTypeArgsMatch,
+  concatBytes,
   decodeFromFields,
   decodeFromFieldsWithTypes,
   decodeFromJSONField,
   extractType,
+  fieldToBcs,
   fieldToJSON,
   phantom,
   toBcs,
 } from '../../_framework/reified'
 import {
+  FetchObjectResult,
   FieldsWithTypes,
+  TypedObjectId,
   composeSuiType,
   compressSuiType,
+  fetchObjects,
+  fetchObjectsOfType,
+  fetchOwnedObjects,
   parseTypeName,
 } from '../../_framework/util'
 import { PKG_V27 } from '../index'
@@ -52,11 +60,17 @@ export class Field<T0 extends TypeArgument, T1 extends TypeArgument> implements
   static readonly $typeName = `${PKG_V27}::dynamic_field::Field`
   static readonly $numTypeParams = 2
   static readonly $isPhantom = [false, false] as const
+  static readonly $moveFieldNames = {
+    id: 'id',
+    name: 'name',
+    value: 'value',
+  } as const
 
   readonly $typeName = Field.$typeName
   readonly $fullTypeName: `${typeof PKG_V27}::dynamic_field::Field<${ToTypeStr<T0>}, ${ToTypeStr<T1>}>`
   readonly $typeArgs: [ToTypeStr<T0>, ToTypeStr<T1>]
   readonly $isPhantom = Field.$isPhantom
+  readonly $moveFieldNames = Field.$moveFieldNames
 
   readonly id: ToField<UID>
   readonly name: ToField<T0>
@@ -74,6 +88,16 @@ export class Field<T0 extends TypeArgument, T1 extends TypeArgument> implements
     this.value = fields.value
   }
 
+  get $objectId(): string {
+    return this.id
+  }
+
+  static id<T0 extends TypeArgument, T1 extends TypeArgument>(
+    id: string
+  ): TypedObjectId<Field<T0, T1>> {
+    return id as TypedObjectId<Field<T0, T1>>
+  }
+
   static reified<T0 extends Reified<TypeArgument, any>, T1 extends Reified<TypeArgument, any>>(
     T0: T0,
     T1: T1
@@ -175,6 +199,14 @@ export class Field<T0 extends TypeArgument, T1 extends TypeArgument> implements
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([
+      fieldToBcs<UID>(`${UID.$typeName}`, this.id),
+      fieldToBcs<T0>(`${this.$typeArgs[0]}`, this.name),
+      fieldToBcs<T1>(`${this.$typeArgs[1]}`, this.value),
+    ])
+  }
+
   static fromJSONField<
     T0 extends Reified<TypeArgument, any>,
     T1 extends Reified<TypeArgument, any>,
@@ -265,4 +297,60 @@ export class Field<T0 extends TypeArgument, T1 extends TypeArgument> implements
 
     return Field.fromSuiObjectData(typeArgs, res.data)
   }
+
+  static async fetchMany<
+    T0 extends Reified<TypeArgument, any>,
+    T1 extends Reified<TypeArgument, any>,
+  >(
+    client: SuiClient,
+    ids: string[],
+    typeArgs?: [T0, T1]
+  ): Promise<Array<FetchObjectResult<Field<ToTypeArgument<T0>, ToTypeArgument<T1>>>>> {
+    if (typeArgs === undefined) {
+      return fetchObjectsOfType<Field<ToTypeArgument<T0>, ToTypeArgument<T1>>>(
+        client,
+        ids,
+        Field.$typeName
+      )
+    }
+
+    return fetchObjects(client, ids, (data: SuiObjectData) =>
+      Field.fromSuiObjectData(typeArgs, data)
+    )
+  }
+
+  static async *fetchOwned<
+    T0 extends Reified<TypeArgument, any>,
+    T1 extends Reified<TypeArgument, any>,
+  >(
+    client: SuiClient,
+    owner: string,
+    typeArgs?: [T0, T1]
+  ): AsyncGenerator<Field<ToTypeArgument<T0>, ToTypeArgument<T1>>> {
+    if (typeArgs === undefined) {
+      yield* fetchOwnedObjects<Field<ToTypeArgument<T0>, ToTypeArgument<T1>>>(
+        client,
+        owner,
+        Field.$typeName
+      )
+      return
+    }
+
+    yield* fetchOwnedObjects(
+      client,
+      owner,
+      composeSuiType(Field.$typeName, ...typeArgs.map(typeArg => extractType(typeArg))),
+      (data: SuiObjectData) => Field.fromSuiObjectData(typeArgs, data)
+    )
+  }
+
+  static async fetchDisplay<
+    T0 extends Reified<TypeArgument, any>,
+    T1 extends Reified<TypeArgument, any>,
+  >(
+    client: SuiClient,
+    typeArgs: [T0, T1]
+  ): Promise<DisplayTemplate<Field<ToTypeArgument<T0>, ToTypeArgument<T1>>> | null> {
+    return fetchDisplay(client, Field.reified(...typeArgs).fullTypeName)
+  }
 }
@@ -8,18 +8,23 @@ import {
   TypeArgument,
   assertFieldsWithTypesArgsMatch,
   assertReifiedTypeArgsMatch,
+  concatBytes,
   decodeFromFields,
   decodeFromFieldsWithTypes,
   decodeFromJSONField,
   extractType,
+  fieldToBcs,
   fieldToJSON,
   phantom,
   toBcs,
 } from '../../_framework/reified'
 import {
+  FetchObjectResult,
   FieldsWithTypes,
   composeSuiType,
   compressSuiType,
+  fetchObjects,
+  fetchObjectsOfType,
   parseTypeName,
 } from '../../_framework/util'
 import { PKG_V27 } from '../index'
@@ -46,11 +51,15 @@ export class Wrapper<T0 extends TypeArgument> implements StructClass {
   static readonly $typeName = `${PKG_V27}::dynamic_object_field::Wrapper`
   static readonly $numTypeParams = 1
   static readonly $isPhantom = [false] as const
+  static readonly $moveFieldNames = {
+    name: 'name',
+  } as const
 
   readonly $typeName = Wrapper.$typeName
   readonly $fullTypeName: `${typeof PKG_V27}::dynamic_object_field::Wrapper<${ToTypeStr<T0>}>`
   readonly $typeArgs: [ToTypeStr<T0>]
   readonly $isPhantom = Wrapper.$isPhantom
+  readonly $moveFieldNames = Wrapper.$moveFieldNames
 
   readonly name: ToField<T0>
 
@@ -152,6 +161,10 @@ export class Wrapper<T0 extends TypeArgument> implements StructClass {
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([fieldToBcs<T0>(`${this.$typeArgs[0]}`, this.name)])
+  }
+
   static fromJSONField<T0 extends Reified<TypeArgument, any>>(
     typeArg: T0,
     field: any
@@ -236,4 +249,18 @@ export class Wrapper<T0 extends TypeArgument> implements StructClass {
 
     return Wrapper.fromSuiObjectData(typeArg, res.data)
   }
+
+  static async fetchMany<T0 extends Reified<TypeArgument, any>>(
+    client: SuiClient,
+    ids: string[],
+    typeArg?: T0
+  ): Promise<Array<FetchObjectResult<Wrapper<ToTypeArgument<T0>>>>> {
+    if (typeArg === undefined) {
+      return fetchObjectsOfType<Wrapper<ToTypeArgument<T0>>>(client, ids, Wrapper.$typeName)
+    }
+
+    return fetchObjects(client, ids, (data: SuiObjectData) =>
+      Wrapper.fromSuiObjectData(typeArg, data)
+    )
+  }
 }
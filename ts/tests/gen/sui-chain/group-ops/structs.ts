@@ -10,17 +10,22 @@ import {
   ToTypeStr,
   assertFieldsWithTypesArgsMatch,
   assertReifiedTypeArgsMatch,
+  concatBytes,
   decodeFromFields,
   decodeFromFieldsWithTypes,
   decodeFromJSONField,
   extractType,
+  fieldToBcs,
   fieldToJSON,
   phantom,
 } from '../../_framework/reified'
 import {
+  FetchObjectResult,
   FieldsWithTypes,
   composeSuiType,
   compressSuiType,
+  fetchObjects,
+  fetchObjectsOfType,
   parseTypeName,
 } from '../../_framework/util'
 import { Vector } from '../../_framework/vector'
@@ -48,11 +53,15 @@ export class Element<T0 extends PhantomTypeArgument> implements StructClass {
   static readonly $typeName = `${PKG_V27}::group_ops::Element`
   static readonly $numTypeParams = 1
   static readonly $isPhantom = [true] as const
+  static readonly $moveFieldNames = {
+    bytes: 'bytes',
+  } as const
 
   readonly $typeName = Element.$typeName
   readonly $fullTypeName: `${typeof PKG_V27}::group_ops::Element<${PhantomToTypeStr<T0>}>`
   readonly $typeArgs: [PhantomToTypeStr<T0>]
   readonly $isPhantom = Element.$isPhantom
+  readonly $moveFieldNames = Element.$moveFieldNames
 
   readonly bytes: ToField<Vector<'u8'>>
 
@@ -153,6 +162,10 @@ export class Element<T0 extends PhantomTypeArgument> implements StructClass {
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([fieldToBcs<Vector<'u8'>>(`vector<u8>`, this.bytes)])
+  }
+
   static fromJSONField<T0 extends PhantomReified<PhantomTypeArgument>>(
     typeArg: T0,
     field: any
@@ -239,4 +252,18 @@ export class Element<T0 extends PhantomTypeArgument> implements StructClass {
 
     return Element.fromSuiObjectData(typeArg, res.data)
   }
+
+  static async fetchMany<T0 extends PhantomReified<PhantomTypeArgument>>(
+    client: SuiClient,
+    ids: string[],
+    typeArg?: T0
+  ): Promise<Array<FetchObjectResult<Element<ToPhantomTypeArgument<T0>>>>> {
+    if (typeArg === undefined) {
+      return fetchObjectsOfType<Element<ToPhantomTypeArgument<T0>>>(client, ids, Element.$typeName)
+    }
+
+    return fetchObjects(client, ids, (data: SuiObjectData) =>
+      Element.fromSuiObjectData(typeArg, data)
+    )
+  }
 }
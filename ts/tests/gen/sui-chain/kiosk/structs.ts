@@ -1,3 +1,4 @@
+import { DisplayTemplate, fetchDisplay } from '../../_framework/display'
 import * as reified from '../../_framework/reified'
 import {
   PhantomReified,
@@ -10,17 +11,24 @@ import {
   ToTypeStr,
   assertFieldsWithTypesArgsMatch,
   assertReifiedTypeArgsMatch,
+  concatBytes,
   decodeFromFields,
   decodeFromFieldsWithTypes,
   decodeFromJSONField,
   extractType,
+  fieldToBcs,
   phantom,
   ToTypeStr as ToPhantom,
 } from '../../_framework/reified'
 import {
+  FetchObjectResult,
   FieldsWithTypes,
+  TypedObjectId,
   composeSuiType,
   compressSuiType,
+  fetchObjects,
+  fetchObjectsOfType,
+  fetchOwnedObjects,
   parseTypeName,
 } from '../../_framework/util'
 import { Balance } from '../balance/structs'
@@ -51,11 +59,16 @@ export class Borrow implements StructClass {
   static readonly $typeName = `${PKG_V27}::kiosk::Borrow`
   static readonly $numTypeParams = 0
   static readonly $isPhantom = [] as const
+  static readonly $moveFieldNames = {
+    kiosk_id: 'kioskId',
+    item_id: 'itemId',
+  } as const
 
   readonly $typeName = Borrow.$typeName
   readonly $fullTypeName: `${typeof PKG_V27}::kiosk::Borrow`
   readonly $typeArgs: []
   readonly $isPhantom = Borrow.$isPhantom
+  readonly $moveFieldNames = Borrow.$moveFieldNames
 
   readonly kioskId: ToField<ID>
   readonly itemId: ToField<ID>
@@ -145,6 +158,13 @@ export class Borrow implements StructClass {
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([
+      fieldToBcs<ID>(`${ID.$typeName}`, this.kioskId),
+      fieldToBcs<ID>(`${ID.$typeName}`, this.itemId),
+    ])
+  }
+
   static fromJSONField(field: any): Borrow {
     return Borrow.reified().new({
       kioskId: decodeFromJSONField(ID.reified(), field.kioskId),
@@ -197,6 +217,13 @@ export class Borrow implements StructClass {
 
     return Borrow.fromSuiObjectData(res.data)
   }
+
+  static async fetchMany(
+    client: SuiClient,
+    ids: string[]
+  ): Promise<Array<FetchObjectResult<Borrow>>> {
+    return fetchObjects(client, ids, (data: SuiObjectData) => Borrow.fromSuiObjectData(data))
+  }
 }
 
 /* ============================== Kiosk =============================== */
@@ -222,11 +249,19 @@ export class Kiosk implements StructClass {
   static readonly $typeName = `${PKG_V27}::kiosk::Kiosk`
   static readonly $numTypeParams = 0
   static readonly $isPhantom = [] as const
+  static readonly $moveFieldNames = {
+    id: 'id',
+    profits: 'profits',
+    owner: 'owner',
+    item_count: 'itemCount',
+    allow_extensions: 'allowExtensions',
+  } as const
 
   readonly $typeName = Kiosk.$typeName
   readonly $fullTypeName: `${typeof PKG_V27}::kiosk::Kiosk`
   readonly $typeArgs: []
   readonly $isPhantom = Kiosk.$isPhantom
+  readonly $moveFieldNames = Kiosk.$moveFieldNames
 
   readonly id: ToField<UID>
   readonly profits: ToField<Balance<ToPhantom<SUI>>>
@@ -248,6 +283,14 @@ export class Kiosk implements StructClass {
     this.allowExtensions = fields.allowExtensions
   }
 
+  get $objectId(): string {
+    return this.id
+  }
+
+  static id(id: string): TypedObjectId<Kiosk> {
+    return id as TypedObjectId<Kiosk>
+  }
+
   static reified(): KioskReified {
     return {
       typeName: Kiosk.$typeName,
@@ -340,6 +383,16 @@ export class Kiosk implements StructClass {
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([
+      fieldToBcs<UID>(`${UID.$typeName}`, this.id),
+      fieldToBcs<Balance<ToPhantom<SUI>>>(`${Balance.$typeName}<${SUI.$typeName}>`, this.profits),
+      fieldToBcs<'address'>(`address`, this.owner),
+      fieldToBcs<'u32'>(`u32`, this.itemCount),
+      fieldToBcs<'bool'>(`bool`, this.allowExtensions),
+    ])
+  }
+
   static fromJSONField(field: any): Kiosk {
     return Kiosk.reified().new({
       id: decodeFromJSONField(UID.reified(), field.id),
@@ -395,6 +448,23 @@ export class Kiosk implements StructClass {
 
     return Kiosk.fromSuiObjectData(res.data)
   }
+
+  static async fetchMany(
+    client: SuiClient,
+    ids: string[]
+  ): Promise<Array<FetchObjectResult<Kiosk>>> {
+    return fetchObjects(client, ids, (data: SuiObjectData) => Kiosk.fromSuiObjectData(data))
+  }
+
+  static async *fetchOwned(client: SuiClient, owner: string): AsyncGenerator<Kiosk> {
+    yield* fetchOwnedObjects(client, owner, Kiosk.$typeName, (data: SuiObjectData) =>
+      Kiosk.fromSuiObjectData(data)
+    )
+  }
+
+  static async fetchDisplay(client: SuiClient): Promise<DisplayTemplate<Kiosk> | null> {
+    return fetchDisplay(client, Kiosk.reified().fullTypeName)
+  }
 }
 
 /* ============================== KioskOwnerCap =============================== */
@@ -417,11 +487,16 @@ export class KioskOwnerCap implements StructClass {
   static readonly $typeName = `${PKG_V27}::kiosk::KioskOwnerCap`
   static readonly $numTypeParams = 0
   static readonly $isPhantom = [] as const
+  static readonly $moveFieldNames = {
+    id: 'id',
+    for: 'for',
+  } as const
 
   readonly $typeName = KioskOwnerCap.$typeName
   readonly $fullTypeName: `${typeof PKG_V27}::kiosk::KioskOwnerCap`
   readonly $typeArgs: []
   readonly $isPhantom = KioskOwnerCap.$isPhantom
+  readonly $moveFieldNames = KioskOwnerCap.$moveFieldNames
 
   readonly id: ToField<UID>
   readonly for: ToField<ID>
@@ -437,6 +512,14 @@ export class KioskOwnerCap implements StructClass {
     this.for = fields.for
   }
 
+  get $objectId(): string {
+    return this.id
+  }
+
+  static id(id: string): TypedObjectId<KioskOwnerCap> {
+    return id as TypedObjectId<KioskOwnerCap>
+  }
+
   static reified(): KioskOwnerCapReified {
     return {
       typeName: KioskOwnerCap.$typeName,
@@ -514,6 +597,13 @@ export class KioskOwnerCap implements StructClass {
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([
+      fieldToBcs<UID>(`${UID.$typeName}`, this.id),
+      fieldToBcs<ID>(`${ID.$typeName}`, this.for),
+    ])
+  }
+
   static fromJSONField(field: any): KioskOwnerCap {
     return KioskOwnerCap.reified().new({
       id: decodeFromJSONField(UID.reified(), field.id),
@@ -566,6 +656,23 @@ export class KioskOwnerCap implements StructClass {
 
     return KioskOwnerCap.fromSuiObjectData(res.data)
   }
+
+  static async fetchMany(
+    client: SuiClient,
+    ids: string[]
+  ): Promise<Array<FetchObjectResult<KioskOwnerCap>>> {
+    return fetchObjects(client, ids, (data: SuiObjectData) => KioskOwnerCap.fromSuiObjectData(data))
+  }
+
+  static async *fetchOwned(client: SuiClient, owner: string): AsyncGenerator<KioskOwnerCap> {
+    yield* fetchOwnedObjects(client, owner, KioskOwnerCap.$typeName, (data: SuiObjectData) =>
+      KioskOwnerCap.fromSuiObjectData(data)
+    )
+  }
+
+  static async fetchDisplay(client: SuiClient): Promise<DisplayTemplate<KioskOwnerCap> | null> {
+    return fetchDisplay(client, KioskOwnerCap.reified().fullTypeName)
+  }
 }
 
 /* ============================== PurchaseCap =============================== */
@@ -593,11 +700,18 @@ export class PurchaseCap<T0 extends PhantomTypeArgument> implements StructClass
   static readonly $typeName = `${PKG_V27}::kiosk::PurchaseCap`
   static readonly $numTypeParams = 1
   static readonly $isPhantom = [true] as const
+  static readonly $moveFieldNames = {
+    id: 'id',
+    kiosk_id: 'kioskId',
+    item_id: 'itemId',
+    min_price: 'minPrice',
+  } as const
 
   readonly $typeName = PurchaseCap.$typeName
   readonly $fullTypeName: `${typeof PKG_V27}::kiosk::PurchaseCap<${PhantomToTypeStr<T0>}>`
   readonly $typeArgs: [PhantomToTypeStr<T0>]
   readonly $isPhantom = PurchaseCap.$isPhantom
+  readonly $moveFieldNames = PurchaseCap.$moveFieldNames
 
   readonly id: ToField<UID>
   readonly kioskId: ToField<ID>
@@ -617,6 +731,14 @@ export class PurchaseCap<T0 extends PhantomTypeArgument> implements StructClass
     this.minPrice = fields.minPrice
   }
 
+  get $objectId(): string {
+    return this.id
+  }
+
+  static id<T0 extends PhantomTypeArgument>(id: string): TypedObjectId<PurchaseCap<T0>> {
+    return id as TypedObjectId<PurchaseCap<T0>>
+  }
+
   static reified<T0 extends PhantomReified<PhantomTypeArgument>>(
     T0: T0
   ): PurchaseCapReified<ToPhantomTypeArgument<T0>> {
@@ -716,6 +838,15 @@ export class PurchaseCap<T0 extends PhantomTypeArgument> implements StructClass
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([
+      fieldToBcs<UID>(`${UID.$typeName}`, this.id),
+      fieldToBcs<ID>(`${ID.$typeName}`, this.kioskId),
+      fieldToBcs<ID>(`${ID.$typeName}`, this.itemId),
+      fieldToBcs<'u64'>(`u64`, this.minPrice),
+    ])
+  }
+
   static fromJSONField<T0 extends PhantomReified<PhantomTypeArgument>>(
     typeArg: T0,
     field: any
@@ -805,6 +936,53 @@ export class PurchaseCap<T0 extends PhantomTypeArgument> implements StructClass
 
     return PurchaseCap.fromSuiObjectData(typeArg, res.data)
   }
+
+  static async fetchMany<T0 extends PhantomReified<PhantomTypeArgument>>(
+    client: SuiClient,
+    ids: string[],
+    typeArg?: T0
+  ): Promise<Array<FetchObjectResult<PurchaseCap<ToPhantomTypeArgument<T0>>>>> {
+    if (typeArg === undefined) {
+      return fetchObjectsOfType<PurchaseCap<ToPhantomTypeArgument<T0>>>(
+        client,
+        ids,
+        PurchaseCap.$typeName
+      )
+    }
+
+    return fetchObjects(client, ids, (data: SuiObjectData) =>
+      PurchaseCap.fromSuiObjectData(typeArg, data)
+    )
+  }
+
+  static async *fetchOwned<T0 extends PhantomReified<PhantomTypeArgument>>(
+    client: SuiClient,
+    owner: string,
+    typeArg?: T0
+  ): AsyncGenerator<PurchaseCap<ToPhantomTypeArgument<T0>>> {
+    if (typeArg === undefined) {
+      yield* fetchOwnedObjects<PurchaseCap<ToPhantomTypeArgument<T0>>>(
+        client,
+        owner,
+        PurchaseCap.$typeName
+      )
+      return
+    }
+
+    yield* fetchOwnedObjects(
+      client,
+      owner,
+      composeSuiType(PurchaseCap.$typeName, extractType(typeArg)),
+      (data: SuiObjectData) => PurchaseCap.fromSuiObjectData(typeArg, data)
+    )
+  }
+
+  static async fetchDisplay<T0 extends PhantomReified<PhantomTypeArgument>>(
+    client: SuiClient,
+    typeArg: T0
+  ): Promise<DisplayTemplate<PurchaseCap<ToPhantomTypeArgument<T0>>> | null> {
+    return fetchDisplay(client, PurchaseCap.reified(typeArg).fullTypeName)
+  }
 }
 
 /* ============================== Item =============================== */
@@ -826,11 +1004,15 @@ export class Item implements StructClass {
   static readonly $typeName = `${PKG_V27}::kiosk::Item`
   static readonly $numTypeParams = 0
   static readonly $isPhantom = [] as const
+  static readonly $moveFieldNames = {
+    id: 'id',
+  } as const
 
   readonly $typeName = Item.$typeName
   readonly $fullTypeName: `${typeof PKG_V27}::kiosk::Item`
   readonly $typeArgs: []
   readonly $isPhantom = Item.$isPhantom
+  readonly $moveFieldNames = Item.$moveFieldNames
 
   readonly id: ToField<ID>
 
@@ -910,6 +1092,10 @@ export class Item implements StructClass {
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([fieldToBcs<ID>(`${ID.$typeName}`, this.id)])
+  }
+
   static fromJSONField(field: any): Item {
     return Item.reified().new({ id: decodeFromJSONField(ID.reified(), field.id) })
   }
@@ -959,6 +1145,13 @@ export class Item implements StructClass {
 
     return Item.fromSuiObjectData(res.data)
   }
+
+  static async fetchMany(
+    client: SuiClient,
+    ids: string[]
+  ): Promise<Array<FetchObjectResult<Item>>> {
+    return fetchObjects(client, ids, (data: SuiObjectData) => Item.fromSuiObjectData(data))
+  }
 }
 
 /* ============================== Listing =============================== */
@@ -981,11 +1174,16 @@ export class Listing implements StructClass {
   static readonly $typeName = `${PKG_V27}::kiosk::Listing`
   static readonly $numTypeParams = 0
   static readonly $isPhantom = [] as const
+  static readonly $moveFieldNames = {
+    id: 'id',
+    is_exclusive: 'isExclusive',
+  } as const
 
   readonly $typeName = Listing.$typeName
   readonly $fullTypeName: `${typeof PKG_V27}::kiosk::Listing`
   readonly $typeArgs: []
   readonly $isPhantom = Listing.$isPhantom
+  readonly $moveFieldNames = Listing.$moveFieldNames
 
   readonly id: ToField<ID>
   readonly isExclusive: ToField<'bool'>
@@ -1075,6 +1273,13 @@ export class Listing implements StructClass {
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([
+      fieldToBcs<ID>(`${ID.$typeName}`, this.id),
+      fieldToBcs<'bool'>(`bool`, this.isExclusive),
+    ])
+  }
+
   static fromJSONField(field: any): Listing {
     return Listing.reified().new({
       id: decodeFromJSONField(ID.reified(), field.id),
@@ -1127,6 +1332,13 @@ export class Listing implements StructClass {
 
     return Listing.fromSuiObjectData(res.data)
   }
+
+  static async fetchMany(
+    client: SuiClient,
+    ids: string[]
+  ): Promise<Array<FetchObjectResult<Listing>>> {
+    return fetchObjects(client, ids, (data: SuiObjectData) => Listing.fromSuiObjectData(data))
+  }
 }
 
 /* ============================== Lock =============================== */
@@ -1148,11 +1360,15 @@ export class Lock implements StructClass {
   static readonly $typeName = `${PKG_V27}::kiosk::Lock`
   static readonly $numTypeParams = 0
   static readonly $isPhantom = [] as const
+  static readonly $moveFieldNames = {
+    id: 'id',
+  } as const
 
   readonly $typeName = Lock.$typeName
   readonly $fullTypeName: `${typeof PKG_V27}::kiosk::Lock`
   readonly $typeArgs: []
   readonly $isPhantom = Lock.$isPhantom
+  readonly $moveFieldNames = Lock.$moveFieldNames
 
   readonly id: ToField<ID>
 
@@ -1232,6 +1448,10 @@ export class Lock implements StructClass {
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([fieldToBcs<ID>(`${ID.$typeName}`, this.id)])
+  }
+
   static fromJSONField(field: any): Lock {
     return Lock.reified().new({ id: decodeFromJSONField(ID.reified(), field.id) })
   }
@@ -1281,6 +1501,13 @@ export class Lock implements StructClass {
 
     return Lock.fromSuiObjectData(res.data)
   }
+
+  static async fetchMany(
+    client: SuiClient,
+    ids: string[]
+  ): Promise<Array<FetchObjectResult<Lock>>> {
+    return fetchObjects(client, ids, (data: SuiObjectData) => Lock.fromSuiObjectData(data))
+  }
 }
 
 /* ============================== ItemListed =============================== */
@@ -1307,11 +1534,17 @@ export class ItemListed<T0 extends PhantomTypeArgument> implements StructClass {
   static readonly $typeName = `${PKG_V27}::kiosk::ItemListed`
   static readonly $numTypeParams = 1
   static readonly $isPhantom = [true] as const
+  static readonly $moveFieldNames = {
+    kiosk: 'kiosk',
+    id: 'id',
+    price: 'price',
+  } as const
 
   readonly $typeName = ItemListed.$typeName
   readonly $fullTypeName: `${typeof PKG_V27}::kiosk::ItemListed<${PhantomToTypeStr<T0>}>`
   readonly $typeArgs: [PhantomToTypeStr<T0>]
   readonly $isPhantom = ItemListed.$isPhantom
+  readonly $moveFieldNames = ItemListed.$moveFieldNames
 
   readonly kiosk: ToField<ID>
   readonly id: ToField<ID>
@@ -1424,6 +1657,14 @@ export class ItemListed<T0 extends PhantomTypeArgument> implements StructClass {
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([
+      fieldToBcs<ID>(`${ID.$typeName}`, this.kiosk),
+      fieldToBcs<ID>(`${ID.$typeName}`, this.id),
+      fieldToBcs<'u64'>(`u64`, this.price),
+    ])
+  }
+
   static fromJSONField<T0 extends PhantomReified<PhantomTypeArgument>>(
     typeArg: T0,
     field: any
@@ -1512,6 +1753,24 @@ export class ItemListed<T0 extends PhantomTypeArgument> implements StructClass {
 
     return ItemListed.fromSuiObjectData(typeArg, res.data)
   }
+
+  static async fetchMany<T0 extends PhantomReified<PhantomTypeArgument>>(
+    client: SuiClient,
+    ids: string[],
+    typeArg?: T0
+  ): Promise<Array<FetchObjectResult<ItemListed<ToPhantomTypeArgument<T0>>>>> {
+    if (typeArg === undefined) {
+      return fetchObjectsOfType<ItemListed<ToPhantomTypeArgument<T0>>>(
+        client,
+        ids,
+        ItemListed.$typeName
+      )
+    }
+
+    return fetchObjects(client, ids, (data: SuiObjectData) =>
+      ItemListed.fromSuiObjectData(typeArg, data)
+    )
+  }
 }
 
 /* ============================== ItemPurchased =============================== */
@@ -1538,11 +1797,17 @@ export class ItemPurchased<T0 extends PhantomTypeArgument> implements StructClas
   static readonly $typeName = `${PKG_V27}::kiosk::ItemPurchased`
   static readonly $numTypeParams = 1
   static readonly $isPhantom = [true] as const
+  static readonly $moveFieldNames = {
+    kiosk: 'kiosk',
+    id: 'id',
+    price: 'price',
+  } as const
 
   readonly $typeName = ItemPurchased.$typeName
   readonly $fullTypeName: `${typeof PKG_V27}::kiosk::ItemPurchased<${PhantomToTypeStr<T0>}>`
   readonly $typeArgs: [PhantomToTypeStr<T0>]
   readonly $isPhantom = ItemPurchased.$isPhantom
+  readonly $moveFieldNames = ItemPurchased.$moveFieldNames
 
   readonly kiosk: ToField<ID>
   readonly id: ToField<ID>
@@ -1655,6 +1920,14 @@ export class ItemPurchased<T0 extends PhantomTypeArgument> implements StructClas
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([
+      fieldToBcs<ID>(`${ID.$typeName}`, this.kiosk),
+      fieldToBcs<ID>(`${ID.$typeName}`, this.id),
+      fieldToBcs<'u64'>(`u64`, this.price),
+    ])
+  }
+
   static fromJSONField<T0 extends PhantomReified<PhantomTypeArgument>>(
     typeArg: T0,
     field: any
@@ -1743,6 +2016,24 @@ export class ItemPurchased<T0 extends PhantomTypeArgument> implements StructClas
 
     return ItemPurchased.fromSuiObjectData(typeArg, res.data)
   }
+
+  static async fetchMany<T0 extends PhantomReified<PhantomTypeArgument>>(
+    client: SuiClient,
+    ids: string[],
+    typeArg?: T0
+  ): Promise<Array<FetchObjectResult<ItemPurchased<ToPhantomTypeArgument<T0>>>>> {
+    if (typeArg === undefined) {
+      return fetchObjectsOfType<ItemPurchased<ToPhantomTypeArgument<T0>>>(
+        client,
+        ids,
+        ItemPurchased.$typeName
+      )
+    }
+
+    return fetchObjects(client, ids, (data: SuiObjectData) =>
+      ItemPurchased.fromSuiObjectData(typeArg, data)
+    )
+  }
 }
 
 /* ============================== ItemDelisted =============================== */
@@ -1768,11 +2059,16 @@ export class ItemDelisted<T0 extends PhantomTypeArgument> implements StructClass
   static readonly $typeName = `${PKG_V27}::kiosk::ItemDelisted`
   static readonly $numTypeParams = 1
   static readonly $isPhantom = [true] as const
+  static readonly $moveFieldNames = {
+    kiosk: 'kiosk',
+    id: 'id',
+  } as const
 
   readonly $typeName = ItemDelisted.$typeName
   readonly $fullTypeName: `${typeof PKG_V27}::kiosk::ItemDelisted<${PhantomToTypeStr<T0>}>`
   readonly $typeArgs: [PhantomToTypeStr<T0>]
   readonly $isPhantom = ItemDelisted.$isPhantom
+  readonly $moveFieldNames = ItemDelisted.$moveFieldNames
 
   readonly kiosk: ToField<ID>
   readonly id: ToField<ID>
@@ -1879,6 +2175,13 @@ export class ItemDelisted<T0 extends PhantomTypeArgument> implements StructClass
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([
+      fieldToBcs<ID>(`${ID.$typeName}`, this.kiosk),
+      fieldToBcs<ID>(`${ID.$typeName}`, this.id),
+    ])
+  }
+
   static fromJSONField<T0 extends PhantomReified<PhantomTypeArgument>>(
     typeArg: T0,
     field: any
@@ -1966,4 +2269,22 @@ export class ItemDelisted<T0 extends PhantomTypeArgument> implements StructClass
 
     return ItemDelisted.fromSuiObjectData(typeArg, res.data)
   }
+
+  static async fetchMany<T0 extends PhantomReified<PhantomTypeArgument>>(
+    client: SuiClient,
+    ids: string[],
+    typeArg?: T0
+  ): Promise<Array<FetchObjectResult<ItemDelisted<ToPhantomTypeArgument<T0>>>>> {
+    if (typeArg === undefined) {
+      return fetchObjectsOfType<ItemDelisted<ToPhantomTypeArgument<T0>>>(
+        client,
+        ids,
+        ItemDelisted.$typeName
+      )
+    }
+
+    return fetchObjects(client, ids, (data: SuiObjectData) =>
+      ItemDelisted.fromSuiObjectData(typeArg, data)
+    )
+  }
 }
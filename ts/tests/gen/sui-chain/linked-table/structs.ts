@@ -1,3 +1,4 @@
+import { DisplayTemplate, fetchDisplay } from '../../_framework/display'
 import {
   PhantomReified,
   PhantomToTypeStr,
@@ -11,18 +12,25 @@ import {
   TypeArgument,
   assertFieldsWithTypesArgsMatch,
   assertReifiedTypeArgsMatch,
+  concatBytes,
   decodeFromFields,
   decodeFromFieldsWithTypes,
   decodeFromJSONField,
   extractType,
+  fieldToBcs,
   fieldToJSON,
   phantom,
   toBcs,
 } from '../../_framework/reified'
 import {
+  FetchObjectResult,
   FieldsWithTypes,
+  TypedObjectId,
   composeSuiType,
   compressSuiType,
+  fetchObjects,
+  fetchObjectsOfType,
+  fetchOwnedObjects,
   parseTypeName,
 } from '../../_framework/util'
 import { Option } from '../../move-stdlib-chain/option/structs'
@@ -59,11 +67,18 @@ export class LinkedTable<T0 extends TypeArgument, T1 extends PhantomTypeArgument
   static readonly $typeName = `${PKG_V27}::linked_table::LinkedTable`
   static readonly $numTypeParams = 2
   static readonly $isPhantom = [false, true] as const
+  static readonly $moveFieldNames = {
+    id: 'id',
+    size: 'size',
+    head: 'head',
+    tail: 'tail',
+  } as const
 
   readonly $typeName = LinkedTable.$typeName
   readonly $fullTypeName: `${typeof PKG_V27}::linked_table::LinkedTable<${ToTypeStr<T0>}, ${PhantomToTypeStr<T1>}>`
   readonly $typeArgs: [ToTypeStr<T0>, PhantomToTypeStr<T1>]
   readonly $isPhantom = LinkedTable.$isPhantom
+  readonly $moveFieldNames = LinkedTable.$moveFieldNames
 
   readonly id: ToField<UID>
   readonly size: ToField<'u64'>
@@ -86,6 +101,16 @@ export class LinkedTable<T0 extends TypeArgument, T1 extends PhantomTypeArgument
     this.tail = fields.tail
   }
 
+  get $objectId(): string {
+    return this.id
+  }
+
+  static id<T0 extends TypeArgument, T1 extends PhantomTypeArgument>(
+    id: string
+  ): TypedObjectId<LinkedTable<T0, T1>> {
+    return id as TypedObjectId<LinkedTable<T0, T1>>
+  }
+
   static reified<
     T0 extends Reified<TypeArgument, any>,
     T1 extends PhantomReified<PhantomTypeArgument>,
@@ -206,6 +231,15 @@ export class LinkedTable<T0 extends TypeArgument, T1 extends PhantomTypeArgument
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([
+      fieldToBcs<UID>(`${UID.$typeName}`, this.id),
+      fieldToBcs<'u64'>(`u64`, this.size),
+      fieldToBcs<Option<T0>>(`${Option.$typeName}<${this.$typeArgs[0]}>`, this.head),
+      fieldToBcs<Option<T0>>(`${Option.$typeName}<${this.$typeArgs[0]}>`, this.tail),
+    ])
+  }
+
   static fromJSONField<
     T0 extends Reified<TypeArgument, any>,
     T1 extends PhantomReified<PhantomTypeArgument>,
@@ -309,6 +343,62 @@ export class LinkedTable<T0 extends TypeArgument, T1 extends PhantomTypeArgument
 
     return LinkedTable.fromSuiObjectData(typeArgs, res.data)
   }
+
+  static async fetchMany<
+    T0 extends Reified<TypeArgument, any>,
+    T1 extends PhantomReified<PhantomTypeArgument>,
+  >(
+    client: SuiClient,
+    ids: string[],
+    typeArgs?: [T0, T1]
+  ): Promise<Array<FetchObjectResult<LinkedTable<ToTypeArgument<T0>, ToPhantomTypeArgument<T1>>>>> {
+    if (typeArgs === undefined) {
+      return fetchObjectsOfType<LinkedTable<ToTypeArgument<T0>, ToPhantomTypeArgument<T1>>>(
+        client,
+        ids,
+        LinkedTable.$typeName
+      )
+    }
+
+    return fetchObjects(client, ids, (data: SuiObjectData) =>
+      LinkedTable.fromSuiObjectData(typeArgs, data)
+    )
+  }
+
+  static async *fetchOwned<
+    T0 extends Reified<TypeArgument, any>,
+    T1 extends PhantomReified<PhantomTypeArgument>,
+  >(
+    client: SuiClient,
+    owner: string,
+    typeArgs?: [T0, T1]
+  ): AsyncGenerator<LinkedTable<ToTypeArgument<T0>, ToPhantomTypeArgument<T1>>> {
+    if (typeArgs === undefined) {
+      yield* fetchOwnedObjects<LinkedTable<ToTypeArgument<T0>, ToPhantomTypeArgument<T1>>>(
+        client,
+        owner,
+        LinkedTable.$typeName
+      )
+      return
+    }
+
+    yield* fetchOwnedObjects(
+      client,
+      owner,
+      composeSuiType(LinkedTable.$typeName, ...typeArgs.map(typeArg => extractType(typeArg))),
+      (data: SuiObjectData) => LinkedTable.fromSuiObjectData(typeArgs, data)
+    )
+  }
+
+  static async fetchDisplay<
+    T0 extends Reified<TypeArgument, any>,
+    T1 extends PhantomReified<PhantomTypeArgument>,
+  >(
+    client: SuiClient,
+    typeArgs: [T0, T1]
+  ): Promise<DisplayTemplate<LinkedTable<ToTypeArgument<T0>, ToPhantomTypeArgument<T1>>> | null> {
+    return fetchDisplay(client, LinkedTable.reified(...typeArgs).fullTypeName)
+  }
 }
 
 /* ============================== Node =============================== */
@@ -335,11 +425,17 @@ export class Node<T0 extends TypeArgument, T1 extends TypeArgument> implements S
   static readonly $typeName = `${PKG_V27}::linked_table::Node`
   static readonly $numTypeParams = 2
   static readonly $isPhantom = [false, false] as const
+  static readonly $moveFieldNames = {
+    prev: 'prev',
+    next: 'next',
+    value: 'value',
+  } as const
 
   readonly $typeName = Node.$typeName
   readonly $fullTypeName: `${typeof PKG_V27}::linked_table::Node<${ToTypeStr<T0>}, ${ToTypeStr<T1>}>`
   readonly $typeArgs: [ToTypeStr<T0>, ToTypeStr<T1>]
   readonly $isPhantom = Node.$isPhantom
+  readonly $moveFieldNames = Node.$moveFieldNames
 
   readonly prev: ToField<Option<T0>>
   readonly next: ToField<Option<T0>>
@@ -458,6 +554,14 @@ export class Node<T0 extends TypeArgument, T1 extends TypeArgument> implements S
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([
+      fieldToBcs<Option<T0>>(`${Option.$typeName}<${this.$typeArgs[0]}>`, this.prev),
+      fieldToBcs<Option<T0>>(`${Option.$typeName}<${this.$typeArgs[0]}>`, this.next),
+      fieldToBcs<T1>(`${this.$typeArgs[1]}`, this.value),
+    ])
+  }
+
   static fromJSONField<
     T0 extends Reified<TypeArgument, any>,
     T1 extends Reified<TypeArgument, any>,
@@ -548,4 +652,25 @@ export class Node<T0 extends TypeArgument, T1 extends TypeArgument> implements S
 
     return Node.fromSuiObjectData(typeArgs, res.data)
   }
+
+  static async fetchMany<
+    T0 extends Reified<TypeArgument, any>,
+    T1 extends Reified<TypeArgument, any>,
+  >(
+    client: SuiClient,
+    ids: string[],
+    typeArgs?: [T0, T1]
+  ): Promise<Array<FetchObjectResult<Node<ToTypeArgument<T0>, ToTypeArgument<T1>>>>> {
+    if (typeArgs === undefined) {
+      return fetchObjectsOfType<Node<ToTypeArgument<T0>, ToTypeArgument<T1>>>(
+        client,
+        ids,
+        Node.$typeName
+      )
+    }
+
+    return fetchObjects(client, ids, (data: SuiObjectData) =>
+      Node.fromSuiObjectData(typeArgs, data)
+    )
+  }
 }
@@ -5,13 +5,21 @@ import {
   StructClass,
   ToField,
   ToTypeStr,
+  concatBytes,
   decodeFromFields,
   decodeFromFieldsWithTypes,
   decodeFromJSONField,
+  fieldToBcs,
   fieldToJSON,
   phantom,
 } from '../../_framework/reified'
-import { FieldsWithTypes, composeSuiType, compressSuiType } from '../../_framework/util'
+import {
+  FetchObjectResult,
+  FieldsWithTypes,
+  composeSuiType,
+  compressSuiType,
+  fetchObjects,
+} from '../../_framework/util'
 import { Vector } from '../../_framework/vector'
 import { Option } from '../../move-stdlib-chain/option/structs'
 import { PKG_V27 } from '../index'
@@ -39,11 +47,16 @@ export class PCREntry implements StructClass {
   static readonly $typeName = `${PKG_V27}::nitro_attestation::PCREntry`
   static readonly $numTypeParams = 0
   static readonly $isPhantom = [] as const
+  static readonly $moveFieldNames = {
+    index: 'index',
+    value: 'value',
+  } as const
 
   readonly $typeName = PCREntry.$typeName
   readonly $fullTypeName: `${typeof PKG_V27}::nitro_attestation::PCREntry`
   readonly $typeArgs: []
   readonly $isPhantom = PCREntry.$isPhantom
+  readonly $moveFieldNames = PCREntry.$moveFieldNames
 
   readonly index: ToField<'u8'>
   readonly value: ToField<Vector<'u8'>>
@@ -136,6 +149,13 @@ export class PCREntry implements StructClass {
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([
+      fieldToBcs<'u8'>(`u8`, this.index),
+      fieldToBcs<Vector<'u8'>>(`vector<u8>`, this.value),
+    ])
+  }
+
   static fromJSONField(field: any): PCREntry {
     return PCREntry.reified().new({
       index: decodeFromJSONField('u8', field.index),
@@ -188,6 +208,13 @@ export class PCREntry implements StructClass {
 
     return PCREntry.fromSuiObjectData(res.data)
   }
+
+  static async fetchMany(
+    client: SuiClient,
+    ids: string[]
+  ): Promise<Array<FetchObjectResult<PCREntry>>> {
+    return fetchObjects(client, ids, (data: SuiObjectData) => PCREntry.fromSuiObjectData(data))
+  }
 }
 
 /* ============================== NitroAttestationDocument =============================== */
@@ -218,11 +245,21 @@ export class NitroAttestationDocument implements StructClass {
   static readonly $typeName = `${PKG_V27}::nitro_attestation::NitroAttestationDocument`
   static readonly $numTypeParams = 0
   static readonly $isPhantom = [] as const
+  static readonly $moveFieldNames = {
+    module_id: 'moduleId',
+    timestamp: 'timestamp',
+    digest: 'digest',
+    pcrs: 'pcrs',
+    public_key: 'publicKey',
+    user_data: 'userData',
+    nonce: 'nonce',
+  } as const
 
   readonly $typeName = NitroAttestationDocument.$typeName
   readonly $fullTypeName: `${typeof PKG_V27}::nitro_attestation::NitroAttestationDocument`
   readonly $typeArgs: []
   readonly $isPhantom = NitroAttestationDocument.$isPhantom
+  readonly $moveFieldNames = NitroAttestationDocument.$moveFieldNames
 
   readonly moduleId: ToField<Vector<'u8'>>
   readonly timestamp: ToField<'u64'>
@@ -357,6 +394,18 @@ export class NitroAttestationDocument implements StructClass {
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([
+      fieldToBcs<Vector<'u8'>>(`vector<u8>`, this.moduleId),
+      fieldToBcs<'u64'>(`u64`, this.timestamp),
+      fieldToBcs<Vector<'u8'>>(`vector<u8>`, this.digest),
+      fieldToBcs<Vector<PCREntry>>(`vector<${PCREntry.$typeName}>`, this.pcrs),
+      fieldToBcs<Option<Vector<'u8'>>>(`${Option.$typeName}<vector<u8>>`, this.publicKey),
+      fieldToBcs<Option<Vector<'u8'>>>(`${Option.$typeName}<vector<u8>>`, this.userData),
+      fieldToBcs<Option<Vector<'u8'>>>(`${Option.$typeName}<vector<u8>>`, this.nonce),
+    ])
+  }
+
   static fromJSONField(field: any): NitroAttestationDocument {
     return NitroAttestationDocument.reified().new({
       moduleId: decodeFromJSONField(reified.vector('u8'), field.moduleId),
@@ -421,4 +470,13 @@ export class NitroAttestationDocument implements StructClass {
 
     return NitroAttestationDocument.fromSuiObjectData(res.data)
   }
+
+  static async fetchMany(
+    client: SuiClient,
+    ids: string[]
+  ): Promise<Array<FetchObjectResult<NitroAttestationDocument>>> {
+    return fetchObjects(client, ids, (data: SuiObjectData) =>
+      NitroAttestationDocument.fromSuiObjectData(data)
+    )
+  }
 }
@@ -4,12 +4,20 @@ import {
   StructClass,
   ToField,
   ToTypeStr,
+  concatBytes,
   decodeFromFields,
   decodeFromFieldsWithTypes,
   decodeFromJSONField,
+  fieldToBcs,
   phantom,
 } from '../../_framework/reified'
-import { FieldsWithTypes, composeSuiType, compressSuiType } from '../../_framework/util'
+import {
+  FetchObjectResult,
+  FieldsWithTypes,
+  composeSuiType,
+  compressSuiType,
+  fetchObjects,
+} from '../../_framework/util'
 import { PKG_V27 } from '../index'
 import { bcs } from '@mysten/sui/bcs'
 import { SuiClient, SuiObjectData, SuiParsedData } from '@mysten/sui/client'
@@ -34,11 +42,15 @@ export class SUI implements StructClass {
   static readonly $typeName = `${PKG_V27}::sui::SUI`
   static readonly $numTypeParams = 0
   static readonly $isPhantom = [] as const
+  static readonly $moveFieldNames = {
+    dummy_field: 'dummyField',
+  } as const
 
   readonly $typeName = SUI.$typeName
   readonly $fullTypeName: `${typeof PKG_V27}::sui::SUI`
   readonly $typeArgs: []
   readonly $isPhantom = SUI.$isPhantom
+  readonly $moveFieldNames = SUI.$moveFieldNames
 
   readonly dummyField: ToField<'bool'>
 
@@ -117,6 +129,10 @@ export class SUI implements StructClass {
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([fieldToBcs<'bool'>(`bool`, this.dummyField)])
+  }
+
   static fromJSONField(field: any): SUI {
     return SUI.reified().new({ dummyField: decodeFromJSONField('bool', field.dummyField) })
   }
@@ -166,4 +182,8 @@ export class SUI implements StructClass {
 
     return SUI.fromSuiObjectData(res.data)
   }
+
+  static async fetchMany(client: SuiClient, ids: string[]): Promise<Array<FetchObjectResult<SUI>>> {
+    return fetchObjects(client, ids, (data: SuiObjectData) => SUI.fromSuiObjectData(data))
+  }
 }
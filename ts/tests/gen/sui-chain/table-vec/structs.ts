@@ -10,16 +10,21 @@ import {
   ToTypeStr,
   assertFieldsWithTypesArgsMatch,
   assertReifiedTypeArgsMatch,
+  concatBytes,
   decodeFromFields,
   decodeFromFieldsWithTypes,
   decodeFromJSONField,
   extractType,
+  fieldToBcs,
   phantom,
 } from '../../_framework/reified'
 import {
+  FetchObjectResult,
   FieldsWithTypes,
   composeSuiType,
   compressSuiType,
+  fetchObjects,
+  fetchObjectsOfType,
   parseTypeName,
 } from '../../_framework/util'
 import { PKG_V27 } from '../index'
@@ -50,11 +55,15 @@ export class TableVec<T0 extends PhantomTypeArgument> implements StructClass {
   static readonly $typeName = `${PKG_V27}::table_vec::TableVec`
   static readonly $numTypeParams = 1
   static readonly $isPhantom = [true] as const
+  static readonly $moveFieldNames = {
+    contents: 'contents',
+  } as const
 
   readonly $typeName = TableVec.$typeName
   readonly $fullTypeName: `${typeof PKG_V27}::table_vec::TableVec<${PhantomToTypeStr<T0>}>`
   readonly $typeArgs: [PhantomToTypeStr<T0>]
   readonly $isPhantom = TableVec.$isPhantom
+  readonly $moveFieldNames = TableVec.$moveFieldNames
 
   readonly contents: ToField<Table<'u64', T0>>
 
@@ -158,6 +167,12 @@ export class TableVec<T0 extends PhantomTypeArgument> implements StructClass {
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([
+      fieldToBcs<Table<'u64', T0>>(`${Table.$typeName}<u64, ${this.$typeArgs[0]}>`, this.contents),
+    ])
+  }
+
   static fromJSONField<T0 extends PhantomReified<PhantomTypeArgument>>(
     typeArg: T0,
     field: any
@@ -244,4 +259,22 @@ export class TableVec<T0 extends PhantomTypeArgument> implements StructClass {
 
     return TableVec.fromSuiObjectData(typeArg, res.data)
   }
+
+  static async fetchMany<T0 extends PhantomReified<PhantomTypeArgument>>(
+    client: SuiClient,
+    ids: string[],
+    typeArg?: T0
+  ): Promise<Array<FetchObjectResult<TableVec<ToPhantomTypeArgument<T0>>>>> {
+    if (typeArg === undefined) {
+      return fetchObjectsOfType<TableVec<ToPhantomTypeArgument<T0>>>(
+        client,
+        ids,
+        TableVec.$typeName
+      )
+    }
+
+    return fetchObjects(client, ids, (data: SuiObjectData) =>
+      TableVec.fromSuiObjectData(typeArg, data)
+    )
+  }
 }
@@ -1,3 +1,4 @@
+import { DisplayTemplate, fetchDisplay } from '../../_framework/display'
 import {
   PhantomReified,
   PhantomToTypeStr,
@@ -9,16 +10,23 @@ import {
   ToTypeStr,
   assertFieldsWithTypesArgsMatch,
   assertReifiedTypeArgsMatch,
+  concatBytes,
   decodeFromFields,
   decodeFromFieldsWithTypes,
   decodeFromJSONField,
   extractType,
+  fieldToBcs,
   phantom,
 } from '../../_framework/reified'
 import {
+  FetchObjectResult,
   FieldsWithTypes,
+  TypedObjectId,
   composeSuiType,
   compressSuiType,
+  fetchObjects,
+  fetchObjectsOfType,
+  fetchOwnedObjects,
   parseTypeName,
 } from '../../_framework/util'
 import { PKG_V27 } from '../index'
@@ -52,11 +60,16 @@ export class Table<T0 extends PhantomTypeArgument, T1 extends PhantomTypeArgumen
   static readonly $typeName = `${PKG_V27}::table::Table`
   static readonly $numTypeParams = 2
   static readonly $isPhantom = [true, true] as const
+  static readonly $moveFieldNames = {
+    id: 'id',
+    size: 'size',
+  } as const
 
   readonly $typeName = Table.$typeName
   readonly $fullTypeName: `${typeof PKG_V27}::table::Table<${PhantomToTypeStr<T0>}, ${PhantomToTypeStr<T1>}>`
   readonly $typeArgs: [PhantomToTypeStr<T0>, PhantomToTypeStr<T1>]
   readonly $isPhantom = Table.$isPhantom
+  readonly $moveFieldNames = Table.$moveFieldNames
 
   readonly id: ToField<UID>
   readonly size: ToField<'u64'>
@@ -75,6 +88,16 @@ export class Table<T0 extends PhantomTypeArgument, T1 extends PhantomTypeArgumen
     this.size = fields.size
   }
 
+  get $objectId(): string {
+    return this.id
+  }
+
+  static id<T0 extends PhantomTypeArgument, T1 extends PhantomTypeArgument>(
+    id: string
+  ): TypedObjectId<Table<T0, T1>> {
+    return id as TypedObjectId<Table<T0, T1>>
+  }
+
   static reified<
     T0 extends PhantomReified<PhantomTypeArgument>,
     T1 extends PhantomReified<PhantomTypeArgument>,
@@ -183,6 +206,13 @@ export class Table<T0 extends PhantomTypeArgument, T1 extends PhantomTypeArgumen
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([
+      fieldToBcs<UID>(`${UID.$typeName}`, this.id),
+      fieldToBcs<'u64'>(`u64`, this.size),
+    ])
+  }
+
   static fromJSONField<
     T0 extends PhantomReified<PhantomTypeArgument>,
     T1 extends PhantomReified<PhantomTypeArgument>,
@@ -284,4 +314,62 @@ export class Table<T0 extends PhantomTypeArgument, T1 extends PhantomTypeArgumen
 
     return Table.fromSuiObjectData(typeArgs, res.data)
   }
+
+  static async fetchMany<
+    T0 extends PhantomReified<PhantomTypeArgument>,
+    T1 extends PhantomReified<PhantomTypeArgument>,
+  >(
+    client: SuiClient,
+    ids: string[],
+    typeArgs?: [T0, T1]
+  ): Promise<
+    Array<FetchObjectResult<Table<ToPhantomTypeArgument<T0>, ToPhantomTypeArgument<T1>>>>
+  > {
+    if (typeArgs === undefined) {
+      return fetchObjectsOfType<Table<ToPhantomTypeArgument<T0>, ToPhantomTypeArgument<T1>>>(
+        client,
+        ids,
+        Table.$typeName
+      )
+    }
+
+    return fetchObjects(client, ids, (data: SuiObjectData) =>
+      Table.fromSuiObjectData(typeArgs, data)
+    )
+  }
+
+  static async *fetchOwned<
+    T0 extends PhantomReified<PhantomTypeArgument>,
+    T1 extends PhantomReified<PhantomTypeArgument>,
+  >(
+    client: SuiClient,
+    owner: string,
+    typeArgs?: [T0, T1]
+  ): AsyncGenerator<Table<ToPhantomTypeArgument<T0>, ToPhantomTypeArgument<T1>>> {
+    if (typeArgs === undefined) {
+      yield* fetchOwnedObjects<Table<ToPhantomTypeArgument<T0>, ToPhantomTypeArgument<T1>>>(
+        client,
+        owner,
+        Table.$typeName
+      )
+      return
+    }
+
+    yield* fetchOwnedObjects(
+      client,
+      owner,
+      composeSuiType(Table.$typeName, ...typeArgs.map(typeArg => extractType(typeArg))),
+      (data: SuiObjectData) => Table.fromSuiObjectData(typeArgs, data)
+    )
+  }
+
+  static async fetchDisplay<
+    T0 extends PhantomReified<PhantomTypeArgument>,
+    T1 extends PhantomReified<PhantomTypeArgument>,
+  >(
+    client: SuiClient,
+    typeArgs: [T0, T1]
+  ): Promise<DisplayTemplate<Table<ToPhantomTypeArgument<T0>, ToPhantomTypeArgument<T1>>> | null> {
+    return fetchDisplay(client, Table.reified(...typeArgs).fullTypeName)
+  }
 }
@@ -1,3 +1,4 @@
+import { DisplayTemplate, fetchDisplay } from '../../_framework/display'
 import {
   PhantomReified,
   PhantomToTypeStr,
@@ -9,17 +10,24 @@ import {
   ToTypeStr,
   assertFieldsWithTypesArgsMatch,
   assertReifiedTypeArgsMatch,
+  concatBytes,
   decodeFromFields,
   decodeFromFieldsWithTypes,
   decodeFromJSONField,
   extractType,
+  fieldToBcs,
   fieldToJSON,
   phantom,
 } from '../../_framework/reified'
 import {
+  FetchObjectResult,
   FieldsWithTypes,
+  TypedObjectId,
   composeSuiType,
   compressSuiType,
+  fetchObjects,
+  fetchObjectsOfType,
+  fetchOwnedObjects,
   parseTypeName,
 } from '../../_framework/util'
 import { Option } from '../../move-stdlib-chain/option/structs'
@@ -53,11 +61,15 @@ export class RuleKey<T0 extends PhantomTypeArgument> implements StructClass {
   static readonly $typeName = `${PKG_V27}::token::RuleKey`
   static readonly $numTypeParams = 1
   static readonly $isPhantom = [true] as const
+  static readonly $moveFieldNames = {
+    is_protected: 'isProtected',
+  } as const
 
   readonly $typeName = RuleKey.$typeName
   readonly $fullTypeName: `${typeof PKG_V27}::token::RuleKey<${PhantomToTypeStr<T0>}>`
   readonly $typeArgs: [PhantomToTypeStr<T0>]
   readonly $isPhantom = RuleKey.$isPhantom
+  readonly $moveFieldNames = RuleKey.$moveFieldNames
 
   readonly isProtected: ToField<'bool'>
 
@@ -158,6 +170,10 @@ export class RuleKey<T0 extends PhantomTypeArgument> implements StructClass {
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([fieldToBcs<'bool'>(`bool`, this.isProtected)])
+  }
+
   static fromJSONField<T0 extends PhantomReified<PhantomTypeArgument>>(
     typeArg: T0,
     field: any
@@ -244,6 +260,20 @@ export class RuleKey<T0 extends PhantomTypeArgument> implements StructClass {
 
     return RuleKey.fromSuiObjectData(typeArg, res.data)
   }
+
+  static async fetchMany<T0 extends PhantomReified<PhantomTypeArgument>>(
+    client: SuiClient,
+    ids: string[],
+    typeArg?: T0
+  ): Promise<Array<FetchObjectResult<RuleKey<ToPhantomTypeArgument<T0>>>>> {
+    if (typeArg === undefined) {
+      return fetchObjectsOfType<RuleKey<ToPhantomTypeArgument<T0>>>(client, ids, RuleKey.$typeName)
+    }
+
+    return fetchObjects(client, ids, (data: SuiObjectData) =>
+      RuleKey.fromSuiObjectData(typeArg, data)
+    )
+  }
 }
 
 /* ============================== Token =============================== */
@@ -266,11 +296,16 @@ export class Token<T0 extends PhantomTypeArgument> implements StructClass {
   static readonly $typeName = `${PKG_V27}::token::Token`
   static readonly $numTypeParams = 1
   static readonly $isPhantom = [true] as const
+  static readonly $moveFieldNames = {
+    id: 'id',
+    balance: 'balance',
+  } as const
 
   readonly $typeName = Token.$typeName
   readonly $fullTypeName: `${typeof PKG_V27}::token::Token<${PhantomToTypeStr<T0>}>`
   readonly $typeArgs: [PhantomToTypeStr<T0>]
   readonly $isPhantom = Token.$isPhantom
+  readonly $moveFieldNames = Token.$moveFieldNames
 
   readonly id: ToField<UID>
   readonly balance: ToField<Balance<T0>>
@@ -286,6 +321,14 @@ export class Token<T0 extends PhantomTypeArgument> implements StructClass {
     this.balance = fields.balance
   }
 
+  get $objectId(): string {
+    return this.id
+  }
+
+  static id<T0 extends PhantomTypeArgument>(id: string): TypedObjectId<Token<T0>> {
+    return id as TypedObjectId<Token<T0>>
+  }
+
   static reified<T0 extends PhantomReified<PhantomTypeArgument>>(
     T0: T0
   ): TokenReified<ToPhantomTypeArgument<T0>> {
@@ -377,6 +420,13 @@ export class Token<T0 extends PhantomTypeArgument> implements StructClass {
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([
+      fieldToBcs<UID>(`${UID.$typeName}`, this.id),
+      fieldToBcs<Balance<T0>>(`${Balance.$typeName}<${this.$typeArgs[0]}>`, this.balance),
+    ])
+  }
+
   static fromJSONField<T0 extends PhantomReified<PhantomTypeArgument>>(
     typeArg: T0,
     field: any
@@ -464,6 +514,45 @@ export class Token<T0 extends PhantomTypeArgument> implements StructClass {
 
     return Token.fromSuiObjectData(typeArg, res.data)
   }
+
+  static async fetchMany<T0 extends PhantomReified<PhantomTypeArgument>>(
+    client: SuiClient,
+    ids: string[],
+    typeArg?: T0
+  ): Promise<Array<FetchObjectResult<Token<ToPhantomTypeArgument<T0>>>>> {
+    if (typeArg === undefined) {
+      return fetchObjectsOfType<Token<ToPhantomTypeArgument<T0>>>(client, ids, Token.$typeName)
+    }
+
+    return fetchObjects(client, ids, (data: SuiObjectData) =>
+      Token.fromSuiObjectData(typeArg, data)
+    )
+  }
+
+  static async *fetchOwned<T0 extends PhantomReified<PhantomTypeArgument>>(
+    client: SuiClient,
+    owner: string,
+    typeArg?: T0
+  ): AsyncGenerator<Token<ToPhantomTypeArgument<T0>>> {
+    if (typeArg === undefined) {
+      yield* fetchOwnedObjects<Token<ToPhantomTypeArgument<T0>>>(client, owner, Token.$typeName)
+      return
+    }
+
+    yield* fetchOwnedObjects(
+      client,
+      owner,
+      composeSuiType(Token.$typeName, extractType(typeArg)),
+      (data: SuiObjectData) => Token.fromSuiObjectData(typeArg, data)
+    )
+  }
+
+  static async fetchDisplay<T0 extends PhantomReified<PhantomTypeArgument>>(
+    client: SuiClient,
+    typeArg: T0
+  ): Promise<DisplayTemplate<Token<ToPhantomTypeArgument<T0>>> | null> {
+    return fetchDisplay(client, Token.reified(typeArg).fullTypeName)
+  }
 }
 
 /* ============================== TokenPolicyCap =============================== */
@@ -489,11 +578,16 @@ export class TokenPolicyCap<T0 extends PhantomTypeArgument> implements StructCla
   static readonly $typeName = `${PKG_V27}::token::TokenPolicyCap`
   static readonly $numTypeParams = 1
   static readonly $isPhantom = [true] as const
+  static readonly $moveFieldNames = {
+    id: 'id',
+    for: 'for',
+  } as const
 
   readonly $typeName = TokenPolicyCap.$typeName
   readonly $fullTypeName: `${typeof PKG_V27}::token::TokenPolicyCap<${PhantomToTypeStr<T0>}>`
   readonly $typeArgs: [PhantomToTypeStr<T0>]
   readonly $isPhantom = TokenPolicyCap.$isPhantom
+  readonly $moveFieldNames = TokenPolicyCap.$moveFieldNames
 
   readonly id: ToField<UID>
   readonly for: ToField<ID>
@@ -509,6 +603,14 @@ export class TokenPolicyCap<T0 extends PhantomTypeArgument> implements StructCla
     this.for = fields.for
   }
 
+  get $objectId(): string {
+    return this.id
+  }
+
+  static id<T0 extends PhantomTypeArgument>(id: string): TypedObjectId<TokenPolicyCap<T0>> {
+    return id as TypedObjectId<TokenPolicyCap<T0>>
+  }
+
   static reified<T0 extends PhantomReified<PhantomTypeArgument>>(
     T0: T0
   ): TokenPolicyCapReified<ToPhantomTypeArgument<T0>> {
@@ -600,6 +702,13 @@ export class TokenPolicyCap<T0 extends PhantomTypeArgument> implements StructCla
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([
+      fieldToBcs<UID>(`${UID.$typeName}`, this.id),
+      fieldToBcs<ID>(`${ID.$typeName}`, this.for),
+    ])
+  }
+
   static fromJSONField<T0 extends PhantomReified<PhantomTypeArgument>>(
     typeArg: T0,
     field: any
@@ -687,6 +796,53 @@ export class TokenPolicyCap<T0 extends PhantomTypeArgument> implements StructCla
 
     return TokenPolicyCap.fromSuiObjectData(typeArg, res.data)
   }
+
+  static async fetchMany<T0 extends PhantomReified<PhantomTypeArgument>>(
+    client: SuiClient,
+    ids: string[],
+    typeArg?: T0
+  ): Promise<Array<FetchObjectResult<TokenPolicyCap<ToPhantomTypeArgument<T0>>>>> {
+    if (typeArg === undefined) {
+      return fetchObjectsOfType<TokenPolicyCap<ToPhantomTypeArgument<T0>>>(
+        client,
+        ids,
+        TokenPolicyCap.$typeName
+      )
+    }
+
+    return fetchObjects(client, ids, (data: SuiObjectData) =>
+      TokenPolicyCap.fromSuiObjectData(typeArg, data)
+    )
+  }
+
+  static async *fetchOwned<T0 extends PhantomReified<PhantomTypeArgument>>(
+    client: SuiClient,
+    owner: string,
+    typeArg?: T0
+  ): AsyncGenerator<TokenPolicyCap<ToPhantomTypeArgument<T0>>> {
+    if (typeArg === undefined) {
+      yield* fetchOwnedObjects<TokenPolicyCap<ToPhantomTypeArgument<T0>>>(
+        client,
+        owner,
+        TokenPolicyCap.$typeName
+      )
+      return
+    }
+
+    yield* fetchOwnedObjects(
+      client,
+      owner,
+      composeSuiType(TokenPolicyCap.$typeName, extractType(typeArg)),
+      (data: SuiObjectData) => TokenPolicyCap.fromSuiObjectData(typeArg, data)
+    )
+  }
+
+  static async fetchDisplay<T0 extends PhantomReified<PhantomTypeArgument>>(
+    client: SuiClient,
+    typeArg: T0
+  ): Promise<DisplayTemplate<TokenPolicyCap<ToPhantomTypeArgument<T0>>> | null> {
+    return fetchDisplay(client, TokenPolicyCap.reified(typeArg).fullTypeName)
+  }
 }
 
 /* ============================== TokenPolicy =============================== */
@@ -713,11 +869,17 @@ export class TokenPolicy<T0 extends PhantomTypeArgument> implements StructClass
   static readonly $typeName = `${PKG_V27}::token::TokenPolicy`
   static readonly $numTypeParams = 1
   static readonly $isPhantom = [true] as const
+  static readonly $moveFieldNames = {
+    id: 'id',
+    spent_balance: 'spentBalance',
+    rules: 'rules',
+  } as const
 
   readonly $typeName = TokenPolicy.$typeName
   readonly $fullTypeName: `${typeof PKG_V27}::token::TokenPolicy<${PhantomToTypeStr<T0>}>`
   readonly $typeArgs: [PhantomToTypeStr<T0>]
   readonly $isPhantom = TokenPolicy.$isPhantom
+  readonly $moveFieldNames = TokenPolicy.$moveFieldNames
 
   readonly id: ToField<UID>
   readonly spentBalance: ToField<Balance<T0>>
@@ -735,6 +897,14 @@ export class TokenPolicy<T0 extends PhantomTypeArgument> implements StructClass
     this.rules = fields.rules
   }
 
+  get $objectId(): string {
+    return this.id
+  }
+
+  static id<T0 extends PhantomTypeArgument>(id: string): TypedObjectId<TokenPolicy<T0>> {
+    return id as TypedObjectId<TokenPolicy<T0>>
+  }
+
   static reified<T0 extends PhantomReified<PhantomTypeArgument>>(
     T0: T0
   ): TokenPolicyReified<ToPhantomTypeArgument<T0>> {
@@ -836,6 +1006,17 @@ export class TokenPolicy<T0 extends PhantomTypeArgument> implements StructClass
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([
+      fieldToBcs<UID>(`${UID.$typeName}`, this.id),
+      fieldToBcs<Balance<T0>>(`${Balance.$typeName}<${this.$typeArgs[0]}>`, this.spentBalance),
+      fieldToBcs<VecMap<String, VecSet<TypeName>>>(
+        `${VecMap.$typeName}<${String.$typeName}, ${VecSet.$typeName}<${TypeName.$typeName}>>`,
+        this.rules
+      ),
+    ])
+  }
+
   static fromJSONField<T0 extends PhantomReified<PhantomTypeArgument>>(
     typeArg: T0,
     field: any
@@ -927,6 +1108,53 @@ export class TokenPolicy<T0 extends PhantomTypeArgument> implements StructClass
 
     return TokenPolicy.fromSuiObjectData(typeArg, res.data)
   }
+
+  static async fetchMany<T0 extends PhantomReified<PhantomTypeArgument>>(
+    client: SuiClient,
+    ids: string[],
+    typeArg?: T0
+  ): Promise<Array<FetchObjectResult<TokenPolicy<ToPhantomTypeArgument<T0>>>>> {
+    if (typeArg === undefined) {
+      return fetchObjectsOfType<TokenPolicy<ToPhantomTypeArgument<T0>>>(
+        client,
+        ids,
+        TokenPolicy.$typeName
+      )
+    }
+
+    return fetchObjects(client, ids, (data: SuiObjectData) =>
+      TokenPolicy.fromSuiObjectData(typeArg, data)
+    )
+  }
+
+  static async *fetchOwned<T0 extends PhantomReified<PhantomTypeArgument>>(
+    client: SuiClient,
+    owner: string,
+    typeArg?: T0
+  ): AsyncGenerator<TokenPolicy<ToPhantomTypeArgument<T0>>> {
+    if (typeArg === undefined) {
+      yield* fetchOwnedObjects<TokenPolicy<ToPhantomTypeArgument<T0>>>(
+        client,
+        owner,
+        TokenPolicy.$typeName
+      )
+      return
+    }
+
+    yield* fetchOwnedObjects(
+      client,
+      owner,
+      composeSuiType(TokenPolicy.$typeName, extractType(typeArg)),
+      (data: SuiObjectData) => TokenPolicy.fromSuiObjectData(typeArg, data)
+    )
+  }
+
+  static async fetchDisplay<T0 extends PhantomReified<PhantomTypeArgument>>(
+    client: SuiClient,
+    typeArg: T0
+  ): Promise<DisplayTemplate<TokenPolicy<ToPhantomTypeArgument<T0>>> | null> {
+    return fetchDisplay(client, TokenPolicy.reified(typeArg).fullTypeName)
+  }
 }
 
 /* ============================== ActionRequest =============================== */
@@ -956,11 +1184,20 @@ export class ActionRequest<T0 extends PhantomTypeArgument> implements StructClas
   static readonly $typeName = `${PKG_V27}::token::ActionRequest`
   static readonly $numTypeParams = 1
   static readonly $isPhantom = [true] as const
+  static readonly $moveFieldNames = {
+    name: 'name',
+    amount: 'amount',
+    sender: 'sender',
+    recipient: 'recipient',
+    spent_balance: 'spentBalance',
+    approvals: 'approvals',
+  } as const
 
   readonly $typeName = ActionRequest.$typeName
   readonly $fullTypeName: `${typeof PKG_V27}::token::ActionRequest<${PhantomToTypeStr<T0>}>`
   readonly $typeArgs: [PhantomToTypeStr<T0>]
   readonly $isPhantom = ActionRequest.$isPhantom
+  readonly $moveFieldNames = ActionRequest.$moveFieldNames
 
   readonly name: ToField<String>
   readonly amount: ToField<'u64'>
@@ -1111,6 +1348,20 @@ export class ActionRequest<T0 extends PhantomTypeArgument> implements StructClas
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([
+      fieldToBcs<String>(`${String.$typeName}`, this.name),
+      fieldToBcs<'u64'>(`u64`, this.amount),
+      fieldToBcs<'address'>(`address`, this.sender),
+      fieldToBcs<Option<'address'>>(`${Option.$typeName}<address>`, this.recipient),
+      fieldToBcs<Option<Balance<T0>>>(
+        `${Option.$typeName}<${Balance.$typeName}<${this.$typeArgs[0]}>>`,
+        this.spentBalance
+      ),
+      fieldToBcs<VecSet<TypeName>>(`${VecSet.$typeName}<${TypeName.$typeName}>`, this.approvals),
+    ])
+  }
+
   static fromJSONField<T0 extends PhantomReified<PhantomTypeArgument>>(
     typeArg: T0,
     field: any
@@ -1205,6 +1456,24 @@ export class ActionRequest<T0 extends PhantomTypeArgument> implements StructClas
 
     return ActionRequest.fromSuiObjectData(typeArg, res.data)
   }
+
+  static async fetchMany<T0 extends PhantomReified<PhantomTypeArgument>>(
+    client: SuiClient,
+    ids: string[],
+    typeArg?: T0
+  ): Promise<Array<FetchObjectResult<ActionRequest<ToPhantomTypeArgument<T0>>>>> {
+    if (typeArg === undefined) {
+      return fetchObjectsOfType<ActionRequest<ToPhantomTypeArgument<T0>>>(
+        client,
+        ids,
+        ActionRequest.$typeName
+      )
+    }
+
+    return fetchObjects(client, ids, (data: SuiObjectData) =>
+      ActionRequest.fromSuiObjectData(typeArg, data)
+    )
+  }
 }
 
 /* ============================== TokenPolicyCreated =============================== */
@@ -1230,11 +1499,16 @@ export class TokenPolicyCreated<T0 extends PhantomTypeArgument> implements Struc
   static readonly $typeName = `${PKG_V27}::token::TokenPolicyCreated`
   static readonly $numTypeParams = 1
   static readonly $isPhantom = [true] as const
+  static readonly $moveFieldNames = {
+    id: 'id',
+    is_mutable: 'isMutable',
+  } as const
 
   readonly $typeName = TokenPolicyCreated.$typeName
   readonly $fullTypeName: `${typeof PKG_V27}::token::TokenPolicyCreated<${PhantomToTypeStr<T0>}>`
   readonly $typeArgs: [PhantomToTypeStr<T0>]
   readonly $isPhantom = TokenPolicyCreated.$isPhantom
+  readonly $moveFieldNames = TokenPolicyCreated.$moveFieldNames
 
   readonly id: ToField<ID>
   readonly isMutable: ToField<'bool'>
@@ -1344,6 +1618,13 @@ export class TokenPolicyCreated<T0 extends PhantomTypeArgument> implements Struc
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([
+      fieldToBcs<ID>(`${ID.$typeName}`, this.id),
+      fieldToBcs<'bool'>(`bool`, this.isMutable),
+    ])
+  }
+
   static fromJSONField<T0 extends PhantomReified<PhantomTypeArgument>>(
     typeArg: T0,
     field: any
@@ -1431,4 +1712,22 @@ export class TokenPolicyCreated<T0 extends PhantomTypeArgument> implements Struc
 
     return TokenPolicyCreated.fromSuiObjectData(typeArg, res.data)
   }
+
+  static async fetchMany<T0 extends PhantomReified<PhantomTypeArgument>>(
+    client: SuiClient,
+    ids: string[],
+    typeArg?: T0
+  ): Promise<Array<FetchObjectResult<TokenPolicyCreated<ToPhantomTypeArgument<T0>>>>> {
+    if (typeArg === undefined) {
+      return fetchObjectsOfType<TokenPolicyCreated<ToPhantomTypeArgument<T0>>>(
+        client,
+        ids,
+        TokenPolicyCreated.$typeName
+      )
+    }
+
+    return fetchObjects(client, ids, (data: SuiObjectData) =>
+      TokenPolicyCreated.fromSuiObjectData(typeArg, data)
+    )
+  }
 }
@@ -1,3 +1,4 @@
+import { DisplayTemplate, fetchDisplay } from '../../_framework/display'
 import * as reified from '../../_framework/reified'
 import {
   PhantomReified,
@@ -10,17 +11,24 @@ import {
   ToTypeStr,
   assertFieldsWithTypesArgsMatch,
   assertReifiedTypeArgsMatch,
+  concatBytes,
   decodeFromFields,
   decodeFromFieldsWithTypes,
   decodeFromJSONField,
   extractType,
+  fieldToBcs,
   phantom,
   ToTypeStr as ToPhantom,
 } from '../../_framework/reified'
 import {
+  FetchObjectResult,
   FieldsWithTypes,
+  TypedObjectId,
   composeSuiType,
   compressSuiType,
+  fetchObjects,
+  fetchObjectsOfType,
+  fetchOwnedObjects,
   parseTypeName,
 } from '../../_framework/util'
 import { TypeName } from '../../move-stdlib-chain/type-name/structs'
@@ -58,11 +66,18 @@ export class TransferRequest<T0 extends PhantomTypeArgument> implements StructCl
   static readonly $typeName = `${PKG_V27}::transfer_policy::TransferRequest`
   static readonly $numTypeParams = 1
   static readonly $isPhantom = [true] as const
+  static readonly $moveFieldNames = {
+    item: 'item',
+    paid: 'paid',
+    from: 'from',
+    receipts: 'receipts',
+  } as const
 
   readonly $typeName = TransferRequest.$typeName
   readonly $fullTypeName: `${typeof PKG_V27}::transfer_policy::TransferRequest<${PhantomToTypeStr<T0>}>`
   readonly $typeArgs: [PhantomToTypeStr<T0>]
   readonly $isPhantom = TransferRequest.$isPhantom
+  readonly $moveFieldNames = TransferRequest.$moveFieldNames
 
   readonly item: ToField<ID>
   readonly paid: ToField<'u64'>
@@ -181,6 +196,15 @@ export class TransferRequest<T0 extends PhantomTypeArgument> implements StructCl
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([
+      fieldToBcs<ID>(`${ID.$typeName}`, this.item),
+      fieldToBcs<'u64'>(`u64`, this.paid),
+      fieldToBcs<ID>(`${ID.$typeName}`, this.from),
+      fieldToBcs<VecSet<TypeName>>(`${VecSet.$typeName}<${TypeName.$typeName}>`, this.receipts),
+    ])
+  }
+
   static fromJSONField<T0 extends PhantomReified<PhantomTypeArgument>>(
     typeArg: T0,
     field: any
@@ -270,6 +294,24 @@ export class TransferRequest<T0 extends PhantomTypeArgument> implements StructCl
 
     return TransferRequest.fromSuiObjectData(typeArg, res.data)
   }
+
+  static async fetchMany<T0 extends PhantomReified<PhantomTypeArgument>>(
+    client: SuiClient,
+    ids: string[],
+    typeArg?: T0
+  ): Promise<Array<FetchObjectResult<TransferRequest<ToPhantomTypeArgument<T0>>>>> {
+    if (typeArg === undefined) {
+      return fetchObjectsOfType<TransferRequest<ToPhantomTypeArgument<T0>>>(
+        client,
+        ids,
+        TransferRequest.$typeName
+      )
+    }
+
+    return fetchObjects(client, ids, (data: SuiObjectData) =>
+      TransferRequest.fromSuiObjectData(typeArg, data)
+    )
+  }
 }
 
 /* ============================== TransferPolicy =============================== */
@@ -296,11 +338,17 @@ export class TransferPolicy<T0 extends PhantomTypeArgument> implements StructCla
   static readonly $typeName = `${PKG_V27}::transfer_policy::TransferPolicy`
   static readonly $numTypeParams = 1
   static readonly $isPhantom = [true] as const
+  static readonly $moveFieldNames = {
+    id: 'id',
+    balance: 'balance',
+    rules: 'rules',
+  } as const
 
   readonly $typeName = TransferPolicy.$typeName
   readonly $fullTypeName: `${typeof PKG_V27}::transfer_policy::TransferPolicy<${PhantomToTypeStr<T0>}>`
   readonly $typeArgs: [PhantomToTypeStr<T0>]
   readonly $isPhantom = TransferPolicy.$isPhantom
+  readonly $moveFieldNames = TransferPolicy.$moveFieldNames
 
   readonly id: ToField<UID>
   readonly balance: ToField<Balance<ToPhantom<SUI>>>
@@ -318,6 +366,14 @@ export class TransferPolicy<T0 extends PhantomTypeArgument> implements StructCla
     this.rules = fields.rules
   }
 
+  get $objectId(): string {
+    return this.id
+  }
+
+  static id<T0 extends PhantomTypeArgument>(id: string): TypedObjectId<TransferPolicy<T0>> {
+    return id as TypedObjectId<TransferPolicy<T0>>
+  }
+
   static reified<T0 extends PhantomReified<PhantomTypeArgument>>(
     T0: T0
   ): TransferPolicyReified<ToPhantomTypeArgument<T0>> {
@@ -416,6 +472,14 @@ export class TransferPolicy<T0 extends PhantomTypeArgument> implements StructCla
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([
+      fieldToBcs<UID>(`${UID.$typeName}`, this.id),
+      fieldToBcs<Balance<ToPhantom<SUI>>>(`${Balance.$typeName}<${SUI.$typeName}>`, this.balance),
+      fieldToBcs<VecSet<TypeName>>(`${VecSet.$typeName}<${TypeName.$typeName}>`, this.rules),
+    ])
+  }
+
   static fromJSONField<T0 extends PhantomReified<PhantomTypeArgument>>(
     typeArg: T0,
     field: any
@@ -504,6 +568,53 @@ export class TransferPolicy<T0 extends PhantomTypeArgument> implements StructCla
 
     return TransferPolicy.fromSuiObjectData(typeArg, res.data)
   }
+
+  static async fetchMany<T0 extends PhantomReified<PhantomTypeArgument>>(
+    client: SuiClient,
+    ids: string[],
+    typeArg?: T0
+  ): Promise<Array<FetchObjectResult<TransferPolicy<ToPhantomTypeArgument<T0>>>>> {
+    if (typeArg === undefined) {
+      return fetchObjectsOfType<TransferPolicy<ToPhantomTypeArgument<T0>>>(
+        client,
+        ids,
+        TransferPolicy.$typeName
+      )
+    }
+
+    return fetchObjects(client, ids, (data: SuiObjectData) =>
+      TransferPolicy.fromSuiObjectData(typeArg, data)
+    )
+  }
+
+  static async *fetchOwned<T0 extends PhantomReified<PhantomTypeArgument>>(
+    client: SuiClient,
+    owner: string,
+    typeArg?: T0
+  ): AsyncGenerator<TransferPolicy<ToPhantomTypeArgument<T0>>> {
+    if (typeArg === undefined) {
+      yield* fetchOwnedObjects<TransferPolicy<ToPhantomTypeArgument<T0>>>(
+        client,
+        owner,
+        TransferPolicy.$typeName
+      )
+      return
+    }
+
+    yield* fetchOwnedObjects(
+      client,
+      owner,
+      composeSuiType(TransferPolicy.$typeName, extractType(typeArg)),
+      (data: SuiObjectData) => TransferPolicy.fromSuiObjectData(typeArg, data)
+    )
+  }
+
+  static async fetchDisplay<T0 extends PhantomReified<PhantomTypeArgument>>(
+    client: SuiClient,
+    typeArg: T0
+  ): Promise<DisplayTemplate<TransferPolicy<ToPhantomTypeArgument<T0>>> | null> {
+    return fetchDisplay(client, TransferPolicy.reified(typeArg).fullTypeName)
+  }
 }
 
 /* ============================== TransferPolicyCap =============================== */
@@ -529,11 +640,16 @@ export class TransferPolicyCap<T0 extends PhantomTypeArgument> implements Struct
   static readonly $typeName = `${PKG_V27}::transfer_policy::TransferPolicyCap`
   static readonly $numTypeParams = 1
   static readonly $isPhantom = [true] as const
+  static readonly $moveFieldNames = {
+    id: 'id',
+    policy_id: 'policyId',
+  } as const
 
   readonly $typeName = TransferPolicyCap.$typeName
   readonly $fullTypeName: `${typeof PKG_V27}::transfer_policy::TransferPolicyCap<${PhantomToTypeStr<T0>}>`
   readonly $typeArgs: [PhantomToTypeStr<T0>]
   readonly $isPhantom = TransferPolicyCap.$isPhantom
+  readonly $moveFieldNames = TransferPolicyCap.$moveFieldNames
 
   readonly id: ToField<UID>
   readonly policyId: ToField<ID>
@@ -549,6 +665,14 @@ export class TransferPolicyCap<T0 extends PhantomTypeArgument> implements Struct
     this.policyId = fields.policyId
   }
 
+  get $objectId(): string {
+    return this.id
+  }
+
+  static id<T0 extends PhantomTypeArgument>(id: string): TypedObjectId<TransferPolicyCap<T0>> {
+    return id as TypedObjectId<TransferPolicyCap<T0>>
+  }
+
   static reified<T0 extends PhantomReified<PhantomTypeArgument>>(
     T0: T0
   ): TransferPolicyCapReified<ToPhantomTypeArgument<T0>> {
@@ -643,6 +767,13 @@ export class TransferPolicyCap<T0 extends PhantomTypeArgument> implements Struct
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([
+      fieldToBcs<UID>(`${UID.$typeName}`, this.id),
+      fieldToBcs<ID>(`${ID.$typeName}`, this.policyId),
+    ])
+  }
+
   static fromJSONField<T0 extends PhantomReified<PhantomTypeArgument>>(
     typeArg: T0,
     field: any
@@ -730,6 +861,53 @@ export class TransferPolicyCap<T0 extends PhantomTypeArgument> implements Struct
 
     return TransferPolicyCap.fromSuiObjectData(typeArg, res.data)
   }
+
+  static async fetchMany<T0 extends PhantomReified<PhantomTypeArgument>>(
+    client: SuiClient,
+    ids: string[],
+    typeArg?: T0
+  ): Promise<Array<FetchObjectResult<TransferPolicyCap<ToPhantomTypeArgument<T0>>>>> {
+    if (typeArg === undefined) {
+      return fetchObjectsOfType<TransferPolicyCap<ToPhantomTypeArgument<T0>>>(
+        client,
+        ids,
+        TransferPolicyCap.$typeName
+      )
+    }
+
+    return fetchObjects(client, ids, (data: SuiObjectData) =>
+      TransferPolicyCap.fromSuiObjectData(typeArg, data)
+    )
+  }
+
+  static async *fetchOwned<T0 extends PhantomReified<PhantomTypeArgument>>(
+    client: SuiClient,
+    owner: string,
+    typeArg?: T0
+  ): AsyncGenerator<TransferPolicyCap<ToPhantomTypeArgument<T0>>> {
+    if (typeArg === undefined) {
+      yield* fetchOwnedObjects<TransferPolicyCap<ToPhantomTypeArgument<T0>>>(
+        client,
+        owner,
+        TransferPolicyCap.$typeName
+      )
+      return
+    }
+
+    yield* fetchOwnedObjects(
+      client,
+      owner,
+      composeSuiType(TransferPolicyCap.$typeName, extractType(typeArg)),
+      (data: SuiObjectData) => TransferPolicyCap.fromSuiObjectData(typeArg, data)
+    )
+  }
+
+  static async fetchDisplay<T0 extends PhantomReified<PhantomTypeArgument>>(
+    client: SuiClient,
+    typeArg: T0
+  ): Promise<DisplayTemplate<TransferPolicyCap<ToPhantomTypeArgument<T0>>> | null> {
+    return fetchDisplay(client, TransferPolicyCap.reified(typeArg).fullTypeName)
+  }
 }
 
 /* ============================== TransferPolicyCreated =============================== */
@@ -754,11 +932,15 @@ export class TransferPolicyCreated<T0 extends PhantomTypeArgument> implements St
   static readonly $typeName = `${PKG_V27}::transfer_policy::TransferPolicyCreated`
   static readonly $numTypeParams = 1
   static readonly $isPhantom = [true] as const
+  static readonly $moveFieldNames = {
+    id: 'id',
+  } as const
 
   readonly $typeName = TransferPolicyCreated.$typeName
   readonly $fullTypeName: `${typeof PKG_V27}::transfer_policy::TransferPolicyCreated<${PhantomToTypeStr<T0>}>`
   readonly $typeArgs: [PhantomToTypeStr<T0>]
   readonly $isPhantom = TransferPolicyCreated.$isPhantom
+  readonly $moveFieldNames = TransferPolicyCreated.$moveFieldNames
 
   readonly id: ToField<ID>
 
@@ -862,6 +1044,10 @@ export class TransferPolicyCreated<T0 extends PhantomTypeArgument> implements St
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([fieldToBcs<ID>(`${ID.$typeName}`, this.id)])
+  }
+
   static fromJSONField<T0 extends PhantomReified<PhantomTypeArgument>>(
     typeArg: T0,
     field: any
@@ -950,6 +1136,24 @@ export class TransferPolicyCreated<T0 extends PhantomTypeArgument> implements St
 
     return TransferPolicyCreated.fromSuiObjectData(typeArg, res.data)
   }
+
+  static async fetchMany<T0 extends PhantomReified<PhantomTypeArgument>>(
+    client: SuiClient,
+    ids: string[],
+    typeArg?: T0
+  ): Promise<Array<FetchObjectResult<TransferPolicyCreated<ToPhantomTypeArgument<T0>>>>> {
+    if (typeArg === undefined) {
+      return fetchObjectsOfType<TransferPolicyCreated<ToPhantomTypeArgument<T0>>>(
+        client,
+        ids,
+        TransferPolicyCreated.$typeName
+      )
+    }
+
+    return fetchObjects(client, ids, (data: SuiObjectData) =>
+      TransferPolicyCreated.fromSuiObjectData(typeArg, data)
+    )
+  }
 }
 
 /* ============================== TransferPolicyDestroyed =============================== */
@@ -974,11 +1178,15 @@ export class TransferPolicyDestroyed<T0 extends PhantomTypeArgument> implements
   static readonly $typeName = `${PKG_V27}::transfer_policy::TransferPolicyDestroyed`
   static readonly $numTypeParams = 1
   static readonly $isPhantom = [true] as const
+  static readonly $moveFieldNames = {
+    id: 'id',
+  } as const
 
   readonly $typeName = TransferPolicyDestroyed.$typeName
   readonly $fullTypeName: `${typeof PKG_V27}::transfer_policy::TransferPolicyDestroyed<${PhantomToTypeStr<T0>}>`
   readonly $typeArgs: [PhantomToTypeStr<T0>]
   readonly $isPhantom = TransferPolicyDestroyed.$isPhantom
+  readonly $moveFieldNames = TransferPolicyDestroyed.$moveFieldNames
 
   readonly id: ToField<ID>
 
@@ -1082,6 +1290,10 @@ export class TransferPolicyDestroyed<T0 extends PhantomTypeArgument> implements
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([fieldToBcs<ID>(`${ID.$typeName}`, this.id)])
+  }
+
   static fromJSONField<T0 extends PhantomReified<PhantomTypeArgument>>(
     typeArg: T0,
     field: any
@@ -1172,6 +1384,24 @@ export class TransferPolicyDestroyed<T0 extends PhantomTypeArgument> implements
 
     return TransferPolicyDestroyed.fromSuiObjectData(typeArg, res.data)
   }
+
+  static async fetchMany<T0 extends PhantomReified<PhantomTypeArgument>>(
+    client: SuiClient,
+    ids: string[],
+    typeArg?: T0
+  ): Promise<Array<FetchObjectResult<TransferPolicyDestroyed<ToPhantomTypeArgument<T0>>>>> {
+    if (typeArg === undefined) {
+      return fetchObjectsOfType<TransferPolicyDestroyed<ToPhantomTypeArgument<T0>>>(
+        client,
+        ids,
+        TransferPolicyDestroyed.$typeName
+      )
+    }
+
+    return fetchObjects(client, ids, (data: SuiObjectData) =>
+      TransferPolicyDestroyed.fromSuiObjectData(typeArg, data)
+    )
+  }
 }
 
 /* ============================== RuleKey =============================== */
@@ -1193,11 +1423,15 @@ export class RuleKey<T0 extends PhantomTypeArgument> implements StructClass {
   static readonly $typeName = `${PKG_V27}::transfer_policy::RuleKey`
   static readonly $numTypeParams = 1
   static readonly $isPhantom = [true] as const
+  static readonly $moveFieldNames = {
+    dummy_field: 'dummyField',
+  } as const
 
   readonly $typeName = RuleKey.$typeName
   readonly $fullTypeName: `${typeof PKG_V27}::transfer_policy::RuleKey<${PhantomToTypeStr<T0>}>`
   readonly $typeArgs: [PhantomToTypeStr<T0>]
   readonly $isPhantom = RuleKey.$isPhantom
+  readonly $moveFieldNames = RuleKey.$moveFieldNames
 
   readonly dummyField: ToField<'bool'>
 
@@ -1298,6 +1532,10 @@ export class RuleKey<T0 extends PhantomTypeArgument> implements StructClass {
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([fieldToBcs<'bool'>(`bool`, this.dummyField)])
+  }
+
   static fromJSONField<T0 extends PhantomReified<PhantomTypeArgument>>(
     typeArg: T0,
     field: any
@@ -1384,4 +1622,18 @@ export class RuleKey<T0 extends PhantomTypeArgument> implements StructClass {
 
     return RuleKey.fromSuiObjectData(typeArg, res.data)
   }
+
+  static async fetchMany<T0 extends PhantomReified<PhantomTypeArgument>>(
+    client: SuiClient,
+    ids: string[],
+    typeArg?: T0
+  ): Promise<Array<FetchObjectResult<RuleKey<ToPhantomTypeArgument<T0>>>>> {
+    if (typeArg === undefined) {
+      return fetchObjectsOfType<RuleKey<ToPhantomTypeArgument<T0>>>(client, ids, RuleKey.$typeName)
+    }
+
+    return fetchObjects(client, ids, (data: SuiObjectData) =>
+      RuleKey.fromSuiObjectData(typeArg, data)
+    )
+  }
 }
@@ -9,18 +9,23 @@ import {
   TypeArgument,
   assertFieldsWithTypesArgsMatch,
   assertReifiedTypeArgsMatch,
+  concatBytes,
   decodeFromFields,
   decodeFromFieldsWithTypes,
   decodeFromJSONField,
   extractType,
+  fieldToBcs,
   fieldToJSON,
   phantom,
   toBcs,
 } from '../../_framework/reified'
 import {
+  FetchObjectResult,
   FieldsWithTypes,
   composeSuiType,
   compressSuiType,
+  fetchObjects,
+  fetchObjectsOfType,
   parseTypeName,
 } from '../../_framework/util'
 import { Vector } from '../../_framework/vector'
@@ -51,11 +56,15 @@ export class VecMap<T0 extends TypeArgument, T1 extends TypeArgument> implements
   static readonly $typeName = `${PKG_V27}::vec_map::VecMap`
   static readonly $numTypeParams = 2
   static readonly $isPhantom = [false, false] as const
+  static readonly $moveFieldNames = {
+    contents: 'contents',
+  } as const
 
   readonly $typeName = VecMap.$typeName
   readonly $fullTypeName: `${typeof PKG_V27}::vec_map::VecMap<${ToTypeStr<T0>}, ${ToTypeStr<T1>}>`
   readonly $typeArgs: [ToTypeStr<T0>, ToTypeStr<T1>]
   readonly $isPhantom = VecMap.$isPhantom
+  readonly $moveFieldNames = VecMap.$moveFieldNames
 
   readonly contents: ToField<Vector<Entry<T0, T1>>>
 
@@ -174,6 +183,15 @@ export class VecMap<T0 extends TypeArgument, T1 extends TypeArgument> implements
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([
+      fieldToBcs<Vector<Entry<T0, T1>>>(
+        `vector<${Entry.$typeName}<${this.$typeArgs[0]}, ${this.$typeArgs[1]}>>`,
+        this.contents
+      ),
+    ])
+  }
+
   static fromJSONField<
     T0 extends Reified<TypeArgument, any>,
     T1 extends Reified<TypeArgument, any>,
@@ -265,6 +283,27 @@ export class VecMap<T0 extends TypeArgument, T1 extends TypeArgument> implements
 
     return VecMap.fromSuiObjectData(typeArgs, res.data)
   }
+
+  static async fetchMany<
+    T0 extends Reified<TypeArgument, any>,
+    T1 extends Reified<TypeArgument, any>,
+  >(
+    client: SuiClient,
+    ids: string[],
+    typeArgs?: [T0, T1]
+  ): Promise<Array<FetchObjectResult<VecMap<ToTypeArgument<T0>, ToTypeArgument<T1>>>>> {
+    if (typeArgs === undefined) {
+      return fetchObjectsOfType<VecMap<ToTypeArgument<T0>, ToTypeArgument<T1>>>(
+        client,
+        ids,
+        VecMap.$typeName
+      )
+    }
+
+    return fetchObjects(client, ids, (data: SuiObjectData) =>
+      VecMap.fromSuiObjectData(typeArgs, data)
+    )
+  }
 }
 
 /* ============================== Entry =============================== */
@@ -290,11 +329,16 @@ export class Entry<T0 extends TypeArgument, T1 extends TypeArgument> implements
   static readonly $typeName = `${PKG_V27}::vec_map::Entry`
   static readonly $numTypeParams = 2
   static readonly $isPhantom = [false, false] as const
+  static readonly $moveFieldNames = {
+    key: 'key',
+    value: 'value',
+  } as const
 
   readonly $typeName = Entry.$typeName
   readonly $fullTypeName: `${typeof PKG_V27}::vec_map::Entry<${ToTypeStr<T0>}, ${ToTypeStr<T1>}>`
   readonly $typeArgs: [ToTypeStr<T0>, ToTypeStr<T1>]
   readonly $isPhantom = Entry.$isPhantom
+  readonly $moveFieldNames = Entry.$moveFieldNames
 
   readonly key: ToField<T0>
   readonly value: ToField<T1>
@@ -407,6 +451,13 @@ export class Entry<T0 extends TypeArgument, T1 extends TypeArgument> implements
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([
+      fieldToBcs<T0>(`${this.$typeArgs[0]}`, this.key),
+      fieldToBcs<T1>(`${this.$typeArgs[1]}`, this.value),
+    ])
+  }
+
   static fromJSONField<
     T0 extends Reified<TypeArgument, any>,
     T1 extends Reified<TypeArgument, any>,
@@ -496,4 +547,25 @@ export class Entry<T0 extends TypeArgument, T1 extends TypeArgument> implements
 
     return Entry.fromSuiObjectData(typeArgs, res.data)
   }
+
+  static async fetchMany<
+    T0 extends Reified<TypeArgument, any>,
+    T1 extends Reified<TypeArgument, any>,
+  >(
+    client: SuiClient,
+    ids: string[],
+    typeArgs?: [T0, T1]
+  ): Promise<Array<FetchObjectResult<Entry<ToTypeArgument<T0>, ToTypeArgument<T1>>>>> {
+    if (typeArgs === undefined) {
+      return fetchObjectsOfType<Entry<ToTypeArgument<T0>, ToTypeArgument<T1>>>(
+        client,
+        ids,
+        Entry.$typeName
+      )
+    }
+
+    return fetchObjects(client, ids, (data: SuiObjectData) =>
+      Entry.fromSuiObjectData(typeArgs, data)
+    )
+  }
 }
@@ -1,15 +1,26 @@
+import { DisplayTemplate, fetchDisplay } from '../../_framework/display'
 import {
   PhantomReified,
   Reified,
   StructClass,
   ToField,
   ToTypeStr,
+  concatBytes,
   decodeFromFields,
   decodeFromFieldsWithTypes,
   decodeFromJSONField,
+  fieldToBcs,
   phantom,
 } from '../../_framework/reified'
-import { FieldsWithTypes, composeSuiType, compressSuiType } from '../../_framework/util'
+import {
+  FetchObjectResult,
+  FieldsWithTypes,
+  TypedObjectId,
+  composeSuiType,
+  compressSuiType,
+  fetchObjects,
+  fetchOwnedObjects,
+} from '../../_framework/util'
 import { PKG_V27 } from '../index'
 import { ID, UID } from '../object/structs'
 import { bcs } from '@mysten/sui/bcs'
@@ -36,11 +47,16 @@ export class Versioned implements StructClass {
   static readonly $typeName = `${PKG_V27}::versioned::Versioned`
   static readonly $numTypeParams = 0
   static readonly $isPhantom = [] as const
+  static readonly $moveFieldNames = {
+    id: 'id',
+    version: 'version',
+  } as const
 
   readonly $typeName = Versioned.$typeName
   readonly $fullTypeName: `${typeof PKG_V27}::versioned::Versioned`
   readonly $typeArgs: []
   readonly $isPhantom = Versioned.$isPhantom
+  readonly $moveFieldNames = Versioned.$moveFieldNames
 
   readonly id: ToField<UID>
   readonly version: ToField<'u64'>
@@ -56,6 +72,14 @@ export class Versioned implements StructClass {
     this.version = fields.version
   }
 
+  get $objectId(): string {
+    return this.id
+  }
+
+  static id(id: string): TypedObjectId<Versioned> {
+    return id as TypedObjectId<Versioned>
+  }
+
   static reified(): VersionedReified {
     return {
       typeName: Versioned.$typeName,
@@ -133,6 +157,13 @@ export class Versioned implements StructClass {
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([
+      fieldToBcs<UID>(`${UID.$typeName}`, this.id),
+      fieldToBcs<'u64'>(`u64`, this.version),
+    ])
+  }
+
   static fromJSONField(field: any): Versioned {
     return Versioned.reified().new({
       id: decodeFromJSONField(UID.reified(), field.id),
@@ -185,6 +216,23 @@ export class Versioned implements StructClass {
 
     return Versioned.fromSuiObjectData(res.data)
   }
+
+  static async fetchMany(
+    client: SuiClient,
+    ids: string[]
+  ): Promise<Array<FetchObjectResult<Versioned>>> {
+    return fetchObjects(client, ids, (data: SuiObjectData) => Versioned.fromSuiObjectData(data))
+  }
+
+  static async *fetchOwned(client: SuiClient, owner: string): AsyncGenerator<Versioned> {
+    yield* fetchOwnedObjects(client, owner, Versioned.$typeName, (data: SuiObjectData) =>
+      Versioned.fromSuiObjectData(data)
+    )
+  }
+
+  static async fetchDisplay(client: SuiClient): Promise<DisplayTemplate<Versioned> | null> {
+    return fetchDisplay(client, Versioned.reified().fullTypeName)
+  }
 }
 
 /* ============================== VersionChangeCap =============================== */
@@ -207,11 +255,16 @@ export class VersionChangeCap implements StructClass {
   static readonly $typeName = `${PKG_V27}::versioned::VersionChangeCap`
   static readonly $numTypeParams = 0
   static readonly $isPhantom = [] as const
+  static readonly $moveFieldNames = {
+    versioned_id: 'versionedId',
+    old_version: 'oldVersion',
+  } as const
 
   readonly $typeName = VersionChangeCap.$typeName
   readonly $fullTypeName: `${typeof PKG_V27}::versioned::VersionChangeCap`
   readonly $typeArgs: []
   readonly $isPhantom = VersionChangeCap.$isPhantom
+  readonly $moveFieldNames = VersionChangeCap.$moveFieldNames
 
   readonly versionedId: ToField<ID>
   readonly oldVersion: ToField<'u64'>
@@ -304,6 +357,13 @@ export class VersionChangeCap implements StructClass {
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([
+      fieldToBcs<ID>(`${ID.$typeName}`, this.versionedId),
+      fieldToBcs<'u64'>(`u64`, this.oldVersion),
+    ])
+  }
+
   static fromJSONField(field: any): VersionChangeCap {
     return VersionChangeCap.reified().new({
       versionedId: decodeFromJSONField(ID.reified(), field.versionedId),
@@ -356,4 +416,13 @@ export class VersionChangeCap implements StructClass {
 
     return VersionChangeCap.fromSuiObjectData(res.data)
   }
+
+  static async fetchMany(
+    client: SuiClient,
+    ids: string[]
+  ): Promise<Array<FetchObjectResult<VersionChangeCap>>> {
+    return fetchObjects(client, ids, (data: SuiObjectData) =>
+      VersionChangeCap.fromSuiObjectData(data)
+    )
+  }
 }
@@ -1,15 +1,26 @@
+import { DisplayTemplate, fetchDisplay } from '../../_framework/display'
 import {
   PhantomReified,
   Reified,
   StructClass,
   ToField,
   ToTypeStr,
+  concatBytes,
   decodeFromFields,
   decodeFromFieldsWithTypes,
   decodeFromJSONField,
+  fieldToBcs,
   phantom,
 } from '../../_framework/reified'
-import { FieldsWithTypes, composeSuiType, compressSuiType } from '../../_framework/util'
+import {
+  FetchObjectResult,
+  FieldsWithTypes,
+  TypedObjectId,
+  composeSuiType,
+  compressSuiType,
+  fetchObjects,
+  fetchOwnedObjects,
+} from '../../_framework/util'
 import { String } from '../../move-stdlib-chain/string/structs'
 import { PKG_V27 } from '../index'
 import { UID } from '../object/structs'
@@ -41,11 +52,20 @@ export class VerifiedID implements StructClass {
   static readonly $typeName = `${PKG_V27}::zklogin_verified_id::VerifiedID`
   static readonly $numTypeParams = 0
   static readonly $isPhantom = [] as const
+  static readonly $moveFieldNames = {
+    id: 'id',
+    owner: 'owner',
+    key_claim_name: 'keyClaimName',
+    key_claim_value: 'keyClaimValue',
+    issuer: 'issuer',
+    audience: 'audience',
+  } as const
 
   readonly $typeName = VerifiedID.$typeName
   readonly $fullTypeName: `${typeof PKG_V27}::zklogin_verified_id::VerifiedID`
   readonly $typeArgs: []
   readonly $isPhantom = VerifiedID.$isPhantom
+  readonly $moveFieldNames = VerifiedID.$moveFieldNames
 
   readonly id: ToField<UID>
   readonly owner: ToField<'address'>
@@ -69,6 +89,14 @@ export class VerifiedID implements StructClass {
     this.audience = fields.audience
   }
 
+  get $objectId(): string {
+    return this.id
+  }
+
+  static id(id: string): TypedObjectId<VerifiedID> {
+    return id as TypedObjectId<VerifiedID>
+  }
+
   static reified(): VerifiedIDReified {
     return {
       typeName: VerifiedID.$typeName,
@@ -165,6 +193,17 @@ export class VerifiedID implements StructClass {
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([
+      fieldToBcs<UID>(`${UID.$typeName}`, this.id),
+      fieldToBcs<'address'>(`address`, this.owner),
+      fieldToBcs<String>(`${String.$typeName}`, this.keyClaimName),
+      fieldToBcs<String>(`${String.$typeName}`, this.keyClaimValue),
+      fieldToBcs<String>(`${String.$typeName}`, this.issuer),
+      fieldToBcs<String>(`${String.$typeName}`, this.audience),
+    ])
+  }
+
   static fromJSONField(field: any): VerifiedID {
     return VerifiedID.reified().new({
       id: decodeFromJSONField(UID.reified(), field.id),
@@ -221,4 +260,21 @@ export class VerifiedID implements StructClass {
 
     return VerifiedID.fromSuiObjectData(res.data)
   }
+
+  static async fetchMany(
+    client: SuiClient,
+    ids: string[]
+  ): Promise<Array<FetchObjectResult<VerifiedID>>> {
+    return fetchObjects(client, ids, (data: SuiObjectData) => VerifiedID.fromSuiObjectData(data))
+  }
+
+  static async *fetchOwned(client: SuiClient, owner: string): AsyncGenerator<VerifiedID> {
+    yield* fetchOwnedObjects(client, owner, VerifiedID.$typeName, (data: SuiObjectData) =>
+      VerifiedID.fromSuiObjectData(data)
+    )
+  }
+
+  static async fetchDisplay(client: SuiClient): Promise<DisplayTemplate<VerifiedID> | null> {
+    return fetchDisplay(client, VerifiedID.reified().fullTypeName)
+  }
 }
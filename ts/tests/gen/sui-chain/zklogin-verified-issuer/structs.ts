@@ -1,15 +1,26 @@
+import { DisplayTemplate, fetchDisplay } from '../../_framework/display'
 import {
   PhantomReified,
   Reified,
   StructClass,
   ToField,
   ToTypeStr,
+  concatBytes,
   decodeFromFields,
   decodeFromFieldsWithTypes,
   decodeFromJSONField,
+  fieldToBcs,
   phantom,
 } from '../../_framework/reified'
-import { FieldsWithTypes, composeSuiType, compressSuiType } from '../../_framework/util'
+import {
+  FetchObjectResult,
+  FieldsWithTypes,
+  TypedObjectId,
+  composeSuiType,
+  compressSuiType,
+  fetchObjects,
+  fetchOwnedObjects,
+} from '../../_framework/util'
 import { String } from '../../move-stdlib-chain/string/structs'
 import { PKG_V27 } from '../index'
 import { UID } from '../object/structs'
@@ -38,11 +49,17 @@ export class VerifiedIssuer implements StructClass {
   static readonly $typeName = `${PKG_V27}::zklogin_verified_issuer::VerifiedIssuer`
   static readonly $numTypeParams = 0
   static readonly $isPhantom = [] as const
+  static readonly $moveFieldNames = {
+    id: 'id',
+    owner: 'owner',
+    issuer: 'issuer',
+  } as const
 
   readonly $typeName = VerifiedIssuer.$typeName
   readonly $fullTypeName: `${typeof PKG_V27}::zklogin_verified_issuer::VerifiedIssuer`
   readonly $typeArgs: []
   readonly $isPhantom = VerifiedIssuer.$isPhantom
+  readonly $moveFieldNames = VerifiedIssuer.$moveFieldNames
 
   readonly id: ToField<UID>
   readonly owner: ToField<'address'>
@@ -60,6 +77,14 @@ export class VerifiedIssuer implements StructClass {
     this.issuer = fields.issuer
   }
 
+  get $objectId(): string {
+    return this.id
+  }
+
+  static id(id: string): TypedObjectId<VerifiedIssuer> {
+    return id as TypedObjectId<VerifiedIssuer>
+  }
+
   static reified(): VerifiedIssuerReified {
     return {
       typeName: VerifiedIssuer.$typeName,
@@ -144,6 +169,14 @@ export class VerifiedIssuer implements StructClass {
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([
+      fieldToBcs<UID>(`${UID.$typeName}`, this.id),
+      fieldToBcs<'address'>(`address`, this.owner),
+      fieldToBcs<String>(`${String.$typeName}`, this.issuer),
+    ])
+  }
+
   static fromJSONField(field: any): VerifiedIssuer {
     return VerifiedIssuer.reified().new({
       id: decodeFromJSONField(UID.reified(), field.id),
@@ -197,4 +230,23 @@ export class VerifiedIssuer implements StructClass {
 
     return VerifiedIssuer.fromSuiObjectData(res.data)
   }
+
+  static async fetchMany(
+    client: SuiClient,
+    ids: string[]
+  ): Promise<Array<FetchObjectResult<VerifiedIssuer>>> {
+    return fetchObjects(client, ids, (data: SuiObjectData) =>
+      VerifiedIssuer.fromSuiObjectData(data)
+    )
+  }
+
+  static async *fetchOwned(client: SuiClient, owner: string): AsyncGenerator<VerifiedIssuer> {
+    yield* fetchOwnedObjects(client, owner, VerifiedIssuer.$typeName, (data: SuiObjectData) =>
+      VerifiedIssuer.fromSuiObjectData(data)
+    )
+  }
+
+  static async fetchDisplay(client: SuiClient): Promise<DisplayTemplate<VerifiedIssuer> | null> {
+    return fetchDisplay(client, VerifiedIssuer.reified().fullTypeName)
+  }
 }
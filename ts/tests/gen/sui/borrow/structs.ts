@@ -8,18 +8,23 @@ import {
   TypeArgument,
   assertFieldsWithTypesArgsMatch,
   assertReifiedTypeArgsMatch,
+  concatBytes,
   decodeFromFields,
   decodeFromFieldsWithTypes,
   decodeFromJSONField,
   extractType,
+  fieldToBcs,
   fieldToJSON,
   phantom,
   toBcs,
 } from '../../_framework/reified'
 import {
+  FetchObjectResult,
   FieldsWithTypes,
   composeSuiType,
   compressSuiType,
+  fetchObjects,
+  fetchObjectsOfType,
   parseTypeName,
 } from '../../_framework/util'
 import { Option } from '../../move-stdlib/option/structs'
@@ -49,11 +54,16 @@ export class Borrow implements StructClass {
   static readonly $typeName = `${PKG_V27}::borrow::Borrow`
   static readonly $numTypeParams = 0
   static readonly $isPhantom = [] as const
+  static readonly $moveFieldNames = {
+    ref: 'ref',
+    obj: 'obj',
+  } as const
 
   readonly $typeName = Borrow.$typeName
   readonly $fullTypeName: `${typeof PKG_V27}::borrow::Borrow`
   readonly $typeArgs: []
   readonly $isPhantom = Borrow.$isPhantom
+  readonly $moveFieldNames = Borrow.$moveFieldNames
 
   readonly ref: ToField<'address'>
   readonly obj: ToField<ID>
@@ -146,6 +156,13 @@ export class Borrow implements StructClass {
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([
+      fieldToBcs<'address'>(`address`, this.ref),
+      fieldToBcs<ID>(`${ID.$typeName}`, this.obj),
+    ])
+  }
+
   static fromJSONField(field: any): Borrow {
     return Borrow.reified().new({
       ref: decodeFromJSONField('address', field.ref),
@@ -198,6 +215,13 @@ export class Borrow implements StructClass {
 
     return Borrow.fromSuiObjectData(res.data)
   }
+
+  static async fetchMany(
+    client: SuiClient,
+    ids: string[]
+  ): Promise<Array<FetchObjectResult<Borrow>>> {
+    return fetchObjects(client, ids, (data: SuiObjectData) => Borrow.fromSuiObjectData(data))
+  }
 }
 
 /* ============================== Referent =============================== */
@@ -220,11 +244,16 @@ export class Referent<T extends TypeArgument> implements StructClass {
   static readonly $typeName = `${PKG_V27}::borrow::Referent`
   static readonly $numTypeParams = 1
   static readonly $isPhantom = [false] as const
+  static readonly $moveFieldNames = {
+    id: 'id',
+    value: 'value',
+  } as const
 
   readonly $typeName = Referent.$typeName
   readonly $fullTypeName: `${typeof PKG_V27}::borrow::Referent<${ToTypeStr<T>}>`
   readonly $typeArgs: [ToTypeStr<T>]
   readonly $isPhantom = Referent.$isPhantom
+  readonly $moveFieldNames = Referent.$moveFieldNames
 
   readonly id: ToField<'address'>
   readonly value: ToField<Option<T>>
@@ -335,6 +364,13 @@ export class Referent<T extends TypeArgument> implements StructClass {
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([
+      fieldToBcs<'address'>(`address`, this.id),
+      fieldToBcs<Option<T>>(`${Option.$typeName}<${this.$typeArgs[0]}>`, this.value),
+    ])
+  }
+
   static fromJSONField<T extends Reified<TypeArgument, any>>(
     typeArg: T,
     field: any
@@ -422,4 +458,18 @@ export class Referent<T extends TypeArgument> implements StructClass {
 
     return Referent.fromSuiObjectData(typeArg, res.data)
   }
+
+  static async fetchMany<T extends Reified<TypeArgument, any>>(
+    client: SuiClient,
+    ids: string[],
+    typeArg?: T
+  ): Promise<Array<FetchObjectResult<Referent<ToTypeArgument<T>>>>> {
+    if (typeArg === undefined) {
+      return fetchObjectsOfType<Referent<ToTypeArgument<T>>>(client, ids, Referent.$typeName)
+    }
+
+    return fetchObjects(client, ids, (data: SuiObjectData) =>
+      Referent.fromSuiObjectData(typeArg, data)
+    )
+  }
 }
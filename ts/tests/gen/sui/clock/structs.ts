@@ -1,15 +1,26 @@
+import { DisplayTemplate, fetchDisplay } from '../../_framework/display'
 import {
   PhantomReified,
   Reified,
   StructClass,
   ToField,
   ToTypeStr,
+  concatBytes,
   decodeFromFields,
   decodeFromFieldsWithTypes,
   decodeFromJSONField,
+  fieldToBcs,
   phantom,
 } from '../../_framework/reified'
-import { FieldsWithTypes, composeSuiType, compressSuiType } from '../../_framework/util'
+import {
+  FetchObjectResult,
+  FieldsWithTypes,
+  TypedObjectId,
+  composeSuiType,
+  compressSuiType,
+  fetchObjects,
+  fetchOwnedObjects,
+} from '../../_framework/util'
 import { PKG_V27 } from '../index'
 import { UID } from '../object/structs'
 import { bcs } from '@mysten/sui/bcs'
@@ -36,11 +47,16 @@ export class Clock implements StructClass {
   static readonly $typeName = `${PKG_V27}::clock::Clock`
   static readonly $numTypeParams = 0
   static readonly $isPhantom = [] as const
+  static readonly $moveFieldNames = {
+    id: 'id',
+    timestamp_ms: 'timestampMs',
+  } as const
 
   readonly $typeName = Clock.$typeName
   readonly $fullTypeName: `${typeof PKG_V27}::clock::Clock`
   readonly $typeArgs: []
   readonly $isPhantom = Clock.$isPhantom
+  readonly $moveFieldNames = Clock.$moveFieldNames
 
   readonly id: ToField<UID>
   readonly timestampMs: ToField<'u64'>
@@ -56,6 +72,14 @@ export class Clock implements StructClass {
     this.timestampMs = fields.timestampMs
   }
 
+  get $objectId(): string {
+    return this.id
+  }
+
+  static id(id: string): TypedObjectId<Clock> {
+    return id as TypedObjectId<Clock>
+  }
+
   static reified(): ClockReified {
     return {
       typeName: Clock.$typeName,
@@ -130,6 +154,13 @@ export class Clock implements StructClass {
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([
+      fieldToBcs<UID>(`${UID.$typeName}`, this.id),
+      fieldToBcs<'u64'>(`u64`, this.timestampMs),
+    ])
+  }
+
   static fromJSONField(field: any): Clock {
     return Clock.reified().new({
       id: decodeFromJSONField(UID.reified(), field.id),
@@ -182,4 +213,21 @@ export class Clock implements StructClass {
 
     return Clock.fromSuiObjectData(res.data)
   }
+
+  static async fetchMany(
+    client: SuiClient,
+    ids: string[]
+  ): Promise<Array<FetchObjectResult<Clock>>> {
+    return fetchObjects(client, ids, (data: SuiObjectData) => Clock.fromSuiObjectData(data))
+  }
+
+  static async *fetchOwned(client: SuiClient, owner: string): AsyncGenerator<Clock> {
+    yield* fetchOwnedObjects(client, owner, Clock.$typeName, (data: SuiObjectData) =>
+      Clock.fromSuiObjectData(data)
+    )
+  }
+
+  static async fetchDisplay(client: SuiClient): Promise<DisplayTemplate<Clock> | null> {
+    return fetchDisplay(client, Clock.reified().fullTypeName)
+  }
 }
@@ -1,3 +1,4 @@
+import { DisplayTemplate, fetchDisplay } from '../../_framework/display'
 import {
   PhantomReified,
   PhantomToTypeStr,
@@ -9,17 +10,24 @@ import {
   ToTypeStr,
   assertFieldsWithTypesArgsMatch,
   assertReifiedTypeArgsMatch,
+  concatBytes,
   decodeFromFields,
   decodeFromFieldsWithTypes,
   decodeFromJSONField,
   extractType,
+  fieldToBcs,
   fieldToJSON,
   phantom,
 } from '../../_framework/reified'
 import {
+  FetchObjectResult,
   FieldsWithTypes,
+  TypedObjectId,
   composeSuiType,
   compressSuiType,
+  fetchObjects,
+  fetchObjectsOfType,
+  fetchOwnedObjects,
   parseTypeName,
 } from '../../_framework/util'
 import { String as String1 } from '../../move-stdlib/ascii/structs'
@@ -53,11 +61,16 @@ export class Coin<T extends PhantomTypeArgument> implements StructClass {
   static readonly $typeName = `${PKG_V27}::coin::Coin`
   static readonly $numTypeParams = 1
   static readonly $isPhantom = [true] as const
+  static readonly $moveFieldNames = {
+    id: 'id',
+    balance: 'balance',
+  } as const
 
   readonly $typeName = Coin.$typeName
   readonly $fullTypeName: `${typeof PKG_V27}::coin::Coin<${PhantomToTypeStr<T>}>`
   readonly $typeArgs: [PhantomToTypeStr<T>]
   readonly $isPhantom = Coin.$isPhantom
+  readonly $moveFieldNames = Coin.$moveFieldNames
 
   readonly id: ToField<UID>
   readonly balance: ToField<Balance<T>>
@@ -73,6 +86,14 @@ export class Coin<T extends PhantomTypeArgument> implements StructClass {
     this.balance = fields.balance
   }
 
+  get $objectId(): string {
+    return this.id
+  }
+
+  static id<T extends PhantomTypeArgument>(id: string): TypedObjectId<Coin<T>> {
+    return id as TypedObjectId<Coin<T>>
+  }
+
   static reified<T extends PhantomReified<PhantomTypeArgument>>(
     T: T
   ): CoinReified<ToPhantomTypeArgument<T>> {
@@ -164,6 +185,13 @@ export class Coin<T extends PhantomTypeArgument> implements StructClass {
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([
+      fieldToBcs<UID>(`${UID.$typeName}`, this.id),
+      fieldToBcs<Balance<T>>(`${Balance.$typeName}<${this.$typeArgs[0]}>`, this.balance),
+    ])
+  }
+
   static fromJSONField<T extends PhantomReified<PhantomTypeArgument>>(
     typeArg: T,
     field: any
@@ -251,6 +279,43 @@ export class Coin<T extends PhantomTypeArgument> implements StructClass {
 
     return Coin.fromSuiObjectData(typeArg, res.data)
   }
+
+  static async fetchMany<T extends PhantomReified<PhantomTypeArgument>>(
+    client: SuiClient,
+    ids: string[],
+    typeArg?: T
+  ): Promise<Array<FetchObjectResult<Coin<ToPhantomTypeArgument<T>>>>> {
+    if (typeArg === undefined) {
+      return fetchObjectsOfType<Coin<ToPhantomTypeArgument<T>>>(client, ids, Coin.$typeName)
+    }
+
+    return fetchObjects(client, ids, (data: SuiObjectData) => Coin.fromSuiObjectData(typeArg, data))
+  }
+
+  static async *fetchOwned<T extends PhantomReified<PhantomTypeArgument>>(
+    client: SuiClient,
+    owner: string,
+    typeArg?: T
+  ): AsyncGenerator<Coin<ToPhantomTypeArgument<T>>> {
+    if (typeArg === undefined) {
+      yield* fetchOwnedObjects<Coin<ToPhantomTypeArgument<T>>>(client, owner, Coin.$typeName)
+      return
+    }
+
+    yield* fetchOwnedObjects(
+      client,
+      owner,
+      composeSuiType(Coin.$typeName, extractType(typeArg)),
+      (data: SuiObjectData) => Coin.fromSuiObjectData(typeArg, data)
+    )
+  }
+
+  static async fetchDisplay<T extends PhantomReified<PhantomTypeArgument>>(
+    client: SuiClient,
+    typeArg: T
+  ): Promise<DisplayTemplate<Coin<ToPhantomTypeArgument<T>>> | null> {
+    return fetchDisplay(client, Coin.reified(typeArg).fullTypeName)
+  }
 }
 
 /* ============================== CoinMetadata =============================== */
@@ -280,11 +345,20 @@ export class CoinMetadata<T extends PhantomTypeArgument> implements StructClass
   static readonly $typeName = `${PKG_V27}::coin::CoinMetadata`
   static readonly $numTypeParams = 1
   static readonly $isPhantom = [true] as const
+  static readonly $moveFieldNames = {
+    id: 'id',
+    decimals: 'decimals',
+    name: 'name',
+    symbol: 'symbol',
+    description: 'description',
+    icon_url: 'iconUrl',
+  } as const
 
   readonly $typeName = CoinMetadata.$typeName
   readonly $fullTypeName: `${typeof PKG_V27}::coin::CoinMetadata<${PhantomToTypeStr<T>}>`
   readonly $typeArgs: [PhantomToTypeStr<T>]
   readonly $isPhantom = CoinMetadata.$isPhantom
+  readonly $moveFieldNames = CoinMetadata.$moveFieldNames
 
   readonly id: ToField<UID>
   readonly decimals: ToField<'u8'>
@@ -308,6 +382,14 @@ export class CoinMetadata<T extends PhantomTypeArgument> implements StructClass
     this.iconUrl = fields.iconUrl
   }
 
+  get $objectId(): string {
+    return this.id
+  }
+
+  static id<T extends PhantomTypeArgument>(id: string): TypedObjectId<CoinMetadata<T>> {
+    return id as TypedObjectId<CoinMetadata<T>>
+  }
+
   static reified<T extends PhantomReified<PhantomTypeArgument>>(
     T: T
   ): CoinMetadataReified<ToPhantomTypeArgument<T>> {
@@ -415,6 +497,17 @@ export class CoinMetadata<T extends PhantomTypeArgument> implements StructClass
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([
+      fieldToBcs<UID>(`${UID.$typeName}`, this.id),
+      fieldToBcs<'u8'>(`u8`, this.decimals),
+      fieldToBcs<String>(`${String.$typeName}`, this.name),
+      fieldToBcs<String1>(`${String1.$typeName}`, this.symbol),
+      fieldToBcs<String>(`${String.$typeName}`, this.description),
+      fieldToBcs<Option<Url>>(`${Option.$typeName}<${Url.$typeName}>`, this.iconUrl),
+    ])
+  }
+
   static fromJSONField<T extends PhantomReified<PhantomTypeArgument>>(
     typeArg: T,
     field: any
@@ -506,6 +599,53 @@ export class CoinMetadata<T extends PhantomTypeArgument> implements StructClass
 
     return CoinMetadata.fromSuiObjectData(typeArg, res.data)
   }
+
+  static async fetchMany<T extends PhantomReified<PhantomTypeArgument>>(
+    client: SuiClient,
+    ids: string[],
+    typeArg?: T
+  ): Promise<Array<FetchObjectResult<CoinMetadata<ToPhantomTypeArgument<T>>>>> {
+    if (typeArg === undefined) {
+      return fetchObjectsOfType<CoinMetadata<ToPhantomTypeArgument<T>>>(
+        client,
+        ids,
+        CoinMetadata.$typeName
+      )
+    }
+
+    return fetchObjects(client, ids, (data: SuiObjectData) =>
+      CoinMetadata.fromSuiObjectData(typeArg, data)
+    )
+  }
+
+  static async *fetchOwned<T extends PhantomReified<PhantomTypeArgument>>(
+    client: SuiClient,
+    owner: string,
+    typeArg?: T
+  ): AsyncGenerator<CoinMetadata<ToPhantomTypeArgument<T>>> {
+    if (typeArg === undefined) {
+      yield* fetchOwnedObjects<CoinMetadata<ToPhantomTypeArgument<T>>>(
+        client,
+        owner,
+        CoinMetadata.$typeName
+      )
+      return
+    }
+
+    yield* fetchOwnedObjects(
+      client,
+      owner,
+      composeSuiType(CoinMetadata.$typeName, extractType(typeArg)),
+      (data: SuiObjectData) => CoinMetadata.fromSuiObjectData(typeArg, data)
+    )
+  }
+
+  static async fetchDisplay<T extends PhantomReified<PhantomTypeArgument>>(
+    client: SuiClient,
+    typeArg: T
+  ): Promise<DisplayTemplate<CoinMetadata<ToPhantomTypeArgument<T>>> | null> {
+    return fetchDisplay(client, CoinMetadata.reified(typeArg).fullTypeName)
+  }
 }
 
 /* ============================== CurrencyCreated =============================== */
@@ -530,11 +670,15 @@ export class CurrencyCreated<T extends PhantomTypeArgument> implements StructCla
   static readonly $typeName = `${PKG_V27}::coin::CurrencyCreated`
   static readonly $numTypeParams = 1
   static readonly $isPhantom = [true] as const
+  static readonly $moveFieldNames = {
+    decimals: 'decimals',
+  } as const
 
   readonly $typeName = CurrencyCreated.$typeName
   readonly $fullTypeName: `${typeof PKG_V27}::coin::CurrencyCreated<${PhantomToTypeStr<T>}>`
   readonly $typeArgs: [PhantomToTypeStr<T>]
   readonly $isPhantom = CurrencyCreated.$isPhantom
+  readonly $moveFieldNames = CurrencyCreated.$moveFieldNames
 
   readonly decimals: ToField<'u8'>
 
@@ -635,6 +779,10 @@ export class CurrencyCreated<T extends PhantomTypeArgument> implements StructCla
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([fieldToBcs<'u8'>(`u8`, this.decimals)])
+  }
+
   static fromJSONField<T extends PhantomReified<PhantomTypeArgument>>(
     typeArg: T,
     field: any
@@ -721,6 +869,24 @@ export class CurrencyCreated<T extends PhantomTypeArgument> implements StructCla
 
     return CurrencyCreated.fromSuiObjectData(typeArg, res.data)
   }
+
+  static async fetchMany<T extends PhantomReified<PhantomTypeArgument>>(
+    client: SuiClient,
+    ids: string[],
+    typeArg?: T
+  ): Promise<Array<FetchObjectResult<CurrencyCreated<ToPhantomTypeArgument<T>>>>> {
+    if (typeArg === undefined) {
+      return fetchObjectsOfType<CurrencyCreated<ToPhantomTypeArgument<T>>>(
+        client,
+        ids,
+        CurrencyCreated.$typeName
+      )
+    }
+
+    return fetchObjects(client, ids, (data: SuiObjectData) =>
+      CurrencyCreated.fromSuiObjectData(typeArg, data)
+    )
+  }
 }
 
 /* ============================== DenyCap =============================== */
@@ -742,11 +908,15 @@ export class DenyCap<T extends PhantomTypeArgument> implements StructClass {
   static readonly $typeName = `${PKG_V27}::coin::DenyCap`
   static readonly $numTypeParams = 1
   static readonly $isPhantom = [true] as const
+  static readonly $moveFieldNames = {
+    id: 'id',
+  } as const
 
   readonly $typeName = DenyCap.$typeName
   readonly $fullTypeName: `${typeof PKG_V27}::coin::DenyCap<${PhantomToTypeStr<T>}>`
   readonly $typeArgs: [PhantomToTypeStr<T>]
   readonly $isPhantom = DenyCap.$isPhantom
+  readonly $moveFieldNames = DenyCap.$moveFieldNames
 
   readonly id: ToField<UID>
 
@@ -760,6 +930,14 @@ export class DenyCap<T extends PhantomTypeArgument> implements StructClass {
     this.id = fields.id
   }
 
+  get $objectId(): string {
+    return this.id
+  }
+
+  static id<T extends PhantomTypeArgument>(id: string): TypedObjectId<DenyCap<T>> {
+    return id as TypedObjectId<DenyCap<T>>
+  }
+
   static reified<T extends PhantomReified<PhantomTypeArgument>>(
     T: T
   ): DenyCapReified<ToPhantomTypeArgument<T>> {
@@ -845,6 +1023,10 @@ export class DenyCap<T extends PhantomTypeArgument> implements StructClass {
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([fieldToBcs<UID>(`${UID.$typeName}`, this.id)])
+  }
+
   static fromJSONField<T extends PhantomReified<PhantomTypeArgument>>(
     typeArg: T,
     field: any
@@ -929,6 +1111,45 @@ export class DenyCap<T extends PhantomTypeArgument> implements StructClass {
 
     return DenyCap.fromSuiObjectData(typeArg, res.data)
   }
+
+  static async fetchMany<T extends PhantomReified<PhantomTypeArgument>>(
+    client: SuiClient,
+    ids: string[],
+    typeArg?: T
+  ): Promise<Array<FetchObjectResult<DenyCap<ToPhantomTypeArgument<T>>>>> {
+    if (typeArg === undefined) {
+      return fetchObjectsOfType<DenyCap<ToPhantomTypeArgument<T>>>(client, ids, DenyCap.$typeName)
+    }
+
+    return fetchObjects(client, ids, (data: SuiObjectData) =>
+      DenyCap.fromSuiObjectData(typeArg, data)
+    )
+  }
+
+  static async *fetchOwned<T extends PhantomReified<PhantomTypeArgument>>(
+    client: SuiClient,
+    owner: string,
+    typeArg?: T
+  ): AsyncGenerator<DenyCap<ToPhantomTypeArgument<T>>> {
+    if (typeArg === undefined) {
+      yield* fetchOwnedObjects<DenyCap<ToPhantomTypeArgument<T>>>(client, owner, DenyCap.$typeName)
+      return
+    }
+
+    yield* fetchOwnedObjects(
+      client,
+      owner,
+      composeSuiType(DenyCap.$typeName, extractType(typeArg)),
+      (data: SuiObjectData) => DenyCap.fromSuiObjectData(typeArg, data)
+    )
+  }
+
+  static async fetchDisplay<T extends PhantomReified<PhantomTypeArgument>>(
+    client: SuiClient,
+    typeArg: T
+  ): Promise<DisplayTemplate<DenyCap<ToPhantomTypeArgument<T>>> | null> {
+    return fetchDisplay(client, DenyCap.reified(typeArg).fullTypeName)
+  }
 }
 
 /* ============================== RegulatedCoinMetadata =============================== */
@@ -955,11 +1176,17 @@ export class RegulatedCoinMetadata<T extends PhantomTypeArgument> implements Str
   static readonly $typeName = `${PKG_V27}::coin::RegulatedCoinMetadata`
   static readonly $numTypeParams = 1
   static readonly $isPhantom = [true] as const
+  static readonly $moveFieldNames = {
+    id: 'id',
+    coin_metadata_object: 'coinMetadataObject',
+    deny_cap_object: 'denyCapObject',
+  } as const
 
   readonly $typeName = RegulatedCoinMetadata.$typeName
   readonly $fullTypeName: `${typeof PKG_V27}::coin::RegulatedCoinMetadata<${PhantomToTypeStr<T>}>`
   readonly $typeArgs: [PhantomToTypeStr<T>]
   readonly $isPhantom = RegulatedCoinMetadata.$isPhantom
+  readonly $moveFieldNames = RegulatedCoinMetadata.$moveFieldNames
 
   readonly id: ToField<UID>
   readonly coinMetadataObject: ToField<ID>
@@ -977,6 +1204,14 @@ export class RegulatedCoinMetadata<T extends PhantomTypeArgument> implements Str
     this.denyCapObject = fields.denyCapObject
   }
 
+  get $objectId(): string {
+    return this.id
+  }
+
+  static id<T extends PhantomTypeArgument>(id: string): TypedObjectId<RegulatedCoinMetadata<T>> {
+    return id as TypedObjectId<RegulatedCoinMetadata<T>>
+  }
+
   static reified<T extends PhantomReified<PhantomTypeArgument>>(
     T: T
   ): RegulatedCoinMetadataReified<ToPhantomTypeArgument<T>> {
@@ -1075,6 +1310,14 @@ export class RegulatedCoinMetadata<T extends PhantomTypeArgument> implements Str
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([
+      fieldToBcs<UID>(`${UID.$typeName}`, this.id),
+      fieldToBcs<ID>(`${ID.$typeName}`, this.coinMetadataObject),
+      fieldToBcs<ID>(`${ID.$typeName}`, this.denyCapObject),
+    ])
+  }
+
   static fromJSONField<T extends PhantomReified<PhantomTypeArgument>>(
     typeArg: T,
     field: any
@@ -1165,6 +1408,53 @@ export class RegulatedCoinMetadata<T extends PhantomTypeArgument> implements Str
 
     return RegulatedCoinMetadata.fromSuiObjectData(typeArg, res.data)
   }
+
+  static async fetchMany<T extends PhantomReified<PhantomTypeArgument>>(
+    client: SuiClient,
+    ids: string[],
+    typeArg?: T
+  ): Promise<Array<FetchObjectResult<RegulatedCoinMetadata<ToPhantomTypeArgument<T>>>>> {
+    if (typeArg === undefined) {
+      return fetchObjectsOfType<RegulatedCoinMetadata<ToPhantomTypeArgument<T>>>(
+        client,
+        ids,
+        RegulatedCoinMetadata.$typeName
+      )
+    }
+
+    return fetchObjects(client, ids, (data: SuiObjectData) =>
+      RegulatedCoinMetadata.fromSuiObjectData(typeArg, data)
+    )
+  }
+
+  static async *fetchOwned<T extends PhantomReified<PhantomTypeArgument>>(
+    client: SuiClient,
+    owner: string,
+    typeArg?: T
+  ): AsyncGenerator<RegulatedCoinMetadata<ToPhantomTypeArgument<T>>> {
+    if (typeArg === undefined) {
+      yield* fetchOwnedObjects<RegulatedCoinMetadata<ToPhantomTypeArgument<T>>>(
+        client,
+        owner,
+        RegulatedCoinMetadata.$typeName
+      )
+      return
+    }
+
+    yield* fetchOwnedObjects(
+      client,
+      owner,
+      composeSuiType(RegulatedCoinMetadata.$typeName, extractType(typeArg)),
+      (data: SuiObjectData) => RegulatedCoinMetadata.fromSuiObjectData(typeArg, data)
+    )
+  }
+
+  static async fetchDisplay<T extends PhantomReified<PhantomTypeArgument>>(
+    client: SuiClient,
+    typeArg: T
+  ): Promise<DisplayTemplate<RegulatedCoinMetadata<ToPhantomTypeArgument<T>>> | null> {
+    return fetchDisplay(client, RegulatedCoinMetadata.reified(typeArg).fullTypeName)
+  }
 }
 
 /* ============================== TreasuryCap =============================== */
@@ -1190,11 +1480,16 @@ export class TreasuryCap<T extends PhantomTypeArgument> implements StructClass {
   static readonly $typeName = `${PKG_V27}::coin::TreasuryCap`
   static readonly $numTypeParams = 1
   static readonly $isPhantom = [true] as const
+  static readonly $moveFieldNames = {
+    id: 'id',
+    total_supply: 'totalSupply',
+  } as const
 
   readonly $typeName = TreasuryCap.$typeName
   readonly $fullTypeName: `${typeof PKG_V27}::coin::TreasuryCap<${PhantomToTypeStr<T>}>`
   readonly $typeArgs: [PhantomToTypeStr<T>]
   readonly $isPhantom = TreasuryCap.$isPhantom
+  readonly $moveFieldNames = TreasuryCap.$moveFieldNames
 
   readonly id: ToField<UID>
   readonly totalSupply: ToField<Supply<T>>
@@ -1210,6 +1505,14 @@ export class TreasuryCap<T extends PhantomTypeArgument> implements StructClass {
     this.totalSupply = fields.totalSupply
   }
 
+  get $objectId(): string {
+    return this.id
+  }
+
+  static id<T extends PhantomTypeArgument>(id: string): TypedObjectId<TreasuryCap<T>> {
+    return id as TypedObjectId<TreasuryCap<T>>
+  }
+
   static reified<T extends PhantomReified<PhantomTypeArgument>>(
     T: T
   ): TreasuryCapReified<ToPhantomTypeArgument<T>> {
@@ -1301,6 +1604,13 @@ export class TreasuryCap<T extends PhantomTypeArgument> implements StructClass {
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([
+      fieldToBcs<UID>(`${UID.$typeName}`, this.id),
+      fieldToBcs<Supply<T>>(`${Supply.$typeName}<${this.$typeArgs[0]}>`, this.totalSupply),
+    ])
+  }
+
   static fromJSONField<T extends PhantomReified<PhantomTypeArgument>>(
     typeArg: T,
     field: any
@@ -1388,4 +1698,51 @@ export class TreasuryCap<T extends PhantomTypeArgument> implements StructClass {
 
     return TreasuryCap.fromSuiObjectData(typeArg, res.data)
   }
+
+  static async fetchMany<T extends PhantomReified<PhantomTypeArgument>>(
+    client: SuiClient,
+    ids: string[],
+    typeArg?: T
+  ): Promise<Array<FetchObjectResult<TreasuryCap<ToPhantomTypeArgument<T>>>>> {
+    if (typeArg === undefined) {
+      return fetchObjectsOfType<TreasuryCap<ToPhantomTypeArgument<T>>>(
+        client,
+        ids,
+        TreasuryCap.$typeName
+      )
+    }
+
+    return fetchObjects(client, ids, (data: SuiObjectData) =>
+      TreasuryCap.fromSuiObjectData(typeArg, data)
+    )
+  }
+
+  static async *fetchOwned<T extends PhantomReified<PhantomTypeArgument>>(
+    client: SuiClient,
+    owner: string,
+    typeArg?: T
+  ): AsyncGenerator<TreasuryCap<ToPhantomTypeArgument<T>>> {
+    if (typeArg === undefined) {
+      yield* fetchOwnedObjects<TreasuryCap<ToPhantomTypeArgument<T>>>(
+        client,
+        owner,
+        TreasuryCap.$typeName
+      )
+      return
+    }
+
+    yield* fetchOwnedObjects(
+      client,
+      owner,
+      composeSuiType(TreasuryCap.$typeName, extractType(typeArg)),
+      (data: SuiObjectData) => TreasuryCap.fromSuiObjectData(typeArg, data)
+    )
+  }
+
+  static async fetchDisplay<T extends PhantomReified<PhantomTypeArgument>>(
+    client: SuiClient,
+    typeArg: T
+  ): Promise<DisplayTemplate<TreasuryCap<ToPhantomTypeArgument<T>>> | null> {
+    return fetchDisplay(client, TreasuryCap.reified(typeArg).fullTypeName)
+  }
 }
@@ -1,3 +1,4 @@
+import { DisplayTemplate, fetchDisplay } from '../../_framework/display'
 import {
   PhantomReified,
   PhantomToTypeStr,
@@ -9,16 +10,23 @@ import {
   ToTypeStr,
   assertFieldsWithTypesArgsMatch,
   assertReifiedTypeArgsMatch,
+  concatBytes,
   decodeFromFields,
   decodeFromFieldsWithTypes,
   decodeFromJSONField,
   extractType,
+  fieldToBcs,
   phantom,
 } from '../../_framework/reified'
 import {
+  FetchObjectResult,
   FieldsWithTypes,
+  TypedObjectId,
   composeSuiType,
   compressSuiType,
+  fetchObjects,
+  fetchObjectsOfType,
+  fetchOwnedObjects,
   parseTypeName,
 } from '../../_framework/util'
 import { String } from '../../move-stdlib/string/structs'
@@ -50,11 +58,17 @@ export class Display<T extends PhantomTypeArgument> implements StructClass {
   static readonly $typeName = `${PKG_V27}::display::Display`
   static readonly $numTypeParams = 1
   static readonly $isPhantom = [true] as const
+  static readonly $moveFieldNames = {
+    id: 'id',
+    fields: 'fields',
+    version: 'version',
+  } as const
 
   readonly $typeName = Display.$typeName
   readonly $fullTypeName: `${typeof PKG_V27}::display::Display<${PhantomToTypeStr<T>}>`
   readonly $typeArgs: [PhantomToTypeStr<T>]
   readonly $isPhantom = Display.$isPhantom
+  readonly $moveFieldNames = Display.$moveFieldNames
 
   readonly id: ToField<UID>
   readonly fields: ToField<VecMap<String, String>>
@@ -72,6 +86,14 @@ export class Display<T extends PhantomTypeArgument> implements StructClass {
     this.version = fields.version
   }
 
+  get $objectId(): string {
+    return this.id
+  }
+
+  static id<T extends PhantomTypeArgument>(id: string): TypedObjectId<Display<T>> {
+    return id as TypedObjectId<Display<T>>
+  }
+
   static reified<T extends PhantomReified<PhantomTypeArgument>>(
     T: T
   ): DisplayReified<ToPhantomTypeArgument<T>> {
@@ -170,6 +192,17 @@ export class Display<T extends PhantomTypeArgument> implements StructClass {
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([
+      fieldToBcs<UID>(`${UID.$typeName}`, this.id),
+      fieldToBcs<VecMap<String, String>>(
+        `${VecMap.$typeName}<${String.$typeName}, ${String.$typeName}>`,
+        this.fields
+      ),
+      fieldToBcs<'u16'>(`u16`, this.version),
+    ])
+  }
+
   static fromJSONField<T extends PhantomReified<PhantomTypeArgument>>(
     typeArg: T,
     field: any
@@ -258,6 +291,45 @@ export class Display<T extends PhantomTypeArgument> implements StructClass {
 
     return Display.fromSuiObjectData(typeArg, res.data)
   }
+
+  static async fetchMany<T extends PhantomReified<PhantomTypeArgument>>(
+    client: SuiClient,
+    ids: string[],
+    typeArg?: T
+  ): Promise<Array<FetchObjectResult<Display<ToPhantomTypeArgument<T>>>>> {
+    if (typeArg === undefined) {
+      return fetchObjectsOfType<Display<ToPhantomTypeArgument<T>>>(client, ids, Display.$typeName)
+    }
+
+    return fetchObjects(client, ids, (data: SuiObjectData) =>
+      Display.fromSuiObjectData(typeArg, data)
+    )
+  }
+
+  static async *fetchOwned<T extends PhantomReified<PhantomTypeArgument>>(
+    client: SuiClient,
+    owner: string,
+    typeArg?: T
+  ): AsyncGenerator<Display<ToPhantomTypeArgument<T>>> {
+    if (typeArg === undefined) {
+      yield* fetchOwnedObjects<Display<ToPhantomTypeArgument<T>>>(client, owner, Display.$typeName)
+      return
+    }
+
+    yield* fetchOwnedObjects(
+      client,
+      owner,
+      composeSuiType(Display.$typeName, extractType(typeArg)),
+      (data: SuiObjectData) => Display.fromSuiObjectData(typeArg, data)
+    )
+  }
+
+  static async fetchDisplay<T extends PhantomReified<PhantomTypeArgument>>(
+    client: SuiClient,
+    typeArg: T
+  ): Promise<DisplayTemplate<Display<ToPhantomTypeArgument<T>>> | null> {
+    return fetchDisplay(client, Display.reified(typeArg).fullTypeName)
+  }
 }
 
 /* ============================== DisplayCreated =============================== */
@@ -282,11 +354,15 @@ export class DisplayCreated<T extends PhantomTypeArgument> implements StructClas
   static readonly $typeName = `${PKG_V27}::display::DisplayCreated`
   static readonly $numTypeParams = 1
   static readonly $isPhantom = [true] as const
+  static readonly $moveFieldNames = {
+    id: 'id',
+  } as const
 
   readonly $typeName = DisplayCreated.$typeName
   readonly $fullTypeName: `${typeof PKG_V27}::display::DisplayCreated<${PhantomToTypeStr<T>}>`
   readonly $typeArgs: [PhantomToTypeStr<T>]
   readonly $isPhantom = DisplayCreated.$isPhantom
+  readonly $moveFieldNames = DisplayCreated.$moveFieldNames
 
   readonly id: ToField<ID>
 
@@ -385,6 +461,10 @@ export class DisplayCreated<T extends PhantomTypeArgument> implements StructClas
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([fieldToBcs<ID>(`${ID.$typeName}`, this.id)])
+  }
+
   static fromJSONField<T extends PhantomReified<PhantomTypeArgument>>(
     typeArg: T,
     field: any
@@ -469,6 +549,24 @@ export class DisplayCreated<T extends PhantomTypeArgument> implements StructClas
 
     return DisplayCreated.fromSuiObjectData(typeArg, res.data)
   }
+
+  static async fetchMany<T extends PhantomReified<PhantomTypeArgument>>(
+    client: SuiClient,
+    ids: string[],
+    typeArg?: T
+  ): Promise<Array<FetchObjectResult<DisplayCreated<ToPhantomTypeArgument<T>>>>> {
+    if (typeArg === undefined) {
+      return fetchObjectsOfType<DisplayCreated<ToPhantomTypeArgument<T>>>(
+        client,
+        ids,
+        DisplayCreated.$typeName
+      )
+    }
+
+    return fetchObjects(client, ids, (data: SuiObjectData) =>
+      DisplayCreated.fromSuiObjectData(typeArg, data)
+    )
+  }
 }
 
 /* ============================== VersionUpdated =============================== */
@@ -495,11 +593,17 @@ export class VersionUpdated<T extends PhantomTypeArgument> implements StructClas
   static readonly $typeName = `${PKG_V27}::display::VersionUpdated`
   static readonly $numTypeParams = 1
   static readonly $isPhantom = [true] as const
+  static readonly $moveFieldNames = {
+    id: 'id',
+    version: 'version',
+    fields: 'fields',
+  } as const
 
   readonly $typeName = VersionUpdated.$typeName
   readonly $fullTypeName: `${typeof PKG_V27}::display::VersionUpdated<${PhantomToTypeStr<T>}>`
   readonly $typeArgs: [PhantomToTypeStr<T>]
   readonly $isPhantom = VersionUpdated.$isPhantom
+  readonly $moveFieldNames = VersionUpdated.$moveFieldNames
 
   readonly id: ToField<ID>
   readonly version: ToField<'u16'>
@@ -615,6 +719,17 @@ export class VersionUpdated<T extends PhantomTypeArgument> implements StructClas
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([
+      fieldToBcs<ID>(`${ID.$typeName}`, this.id),
+      fieldToBcs<'u16'>(`u16`, this.version),
+      fieldToBcs<VecMap<String, String>>(
+        `${VecMap.$typeName}<${String.$typeName}, ${String.$typeName}>`,
+        this.fields
+      ),
+    ])
+  }
+
   static fromJSONField<T extends PhantomReified<PhantomTypeArgument>>(
     typeArg: T,
     field: any
@@ -703,4 +818,22 @@ export class VersionUpdated<T extends PhantomTypeArgument> implements StructClas
 
     return VersionUpdated.fromSuiObjectData(typeArg, res.data)
   }
+
+  static async fetchMany<T extends PhantomReified<PhantomTypeArgument>>(
+    client: SuiClient,
+    ids: string[],
+    typeArg?: T
+  ): Promise<Array<FetchObjectResult<VersionUpdated<ToPhantomTypeArgument<T>>>>> {
+    if (typeArg === undefined) {
+      return fetchObjectsOfType<VersionUpdated<ToPhantomTypeArgument<T>>>(
+        client,
+        ids,
+        VersionUpdated.$typeName
+      )
+    }
+
+    return fetchObjects(client, ids, (data: SuiObjectData) =>
+      VersionUpdated.fromSuiObjectData(typeArg, data)
+    )
+  }
 }
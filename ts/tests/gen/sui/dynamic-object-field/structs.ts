@@ -8,18 +8,23 @@ import {
   TypeArgument,
   assertFieldsWithTypesArgsMatch,
   assertReifiedTypeArgsMatch,
+  concatBytes,
   decodeFromFields,
   decodeFromFieldsWithTypes,
   decodeFromJSONField,
   extractType,
+  fieldToBcs,
   fieldToJSON,
   phantom,
   toBcs,
 } from '../../_framework/reified'
 import {
+  FetchObjectResult,
   FieldsWithTypes,
   composeSuiType,
   compressSuiType,
+  fetchObjects,
+  fetchObjectsOfType,
   parseTypeName,
 } from '../../_framework/util'
 import { PKG_V27 } from '../index'
@@ -46,11 +51,15 @@ export class Wrapper<Name extends TypeArgument> implements StructClass {
   static readonly $typeName = `${PKG_V27}::dynamic_object_field::Wrapper`
   static readonly $numTypeParams = 1
   static readonly $isPhantom = [false] as const
+  static readonly $moveFieldNames = {
+    name: 'name',
+  } as const
 
   readonly $typeName = Wrapper.$typeName
   readonly $fullTypeName: `${typeof PKG_V27}::dynamic_object_field::Wrapper<${ToTypeStr<Name>}>`
   readonly $typeArgs: [ToTypeStr<Name>]
   readonly $isPhantom = Wrapper.$isPhantom
+  readonly $moveFieldNames = Wrapper.$moveFieldNames
 
   readonly name: ToField<Name>
 
@@ -152,6 +161,10 @@ export class Wrapper<Name extends TypeArgument> implements StructClass {
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([fieldToBcs<Name>(`${this.$typeArgs[0]}`, this.name)])
+  }
+
   static fromJSONField<Name extends Reified<TypeArgument, any>>(
     typeArg: Name,
     field: any
@@ -236,4 +249,18 @@ export class Wrapper<Name extends TypeArgument> implements StructClass {
 
     return Wrapper.fromSuiObjectData(typeArg, res.data)
   }
+
+  static async fetchMany<Name extends Reified<TypeArgument, any>>(
+    client: SuiClient,
+    ids: string[],
+    typeArg?: Name
+  ): Promise<Array<FetchObjectResult<Wrapper<ToTypeArgument<Name>>>>> {
+    if (typeArg === undefined) {
+      return fetchObjectsOfType<Wrapper<ToTypeArgument<Name>>>(client, ids, Wrapper.$typeName)
+    }
+
+    return fetchObjects(client, ids, (data: SuiObjectData) =>
+      Wrapper.fromSuiObjectData(typeArg, data)
+    )
+  }
 }
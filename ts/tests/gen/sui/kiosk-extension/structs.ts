@@ -9,16 +9,21 @@ import {
   ToTypeStr,
   assertFieldsWithTypesArgsMatch,
   assertReifiedTypeArgsMatch,
+  concatBytes,
   decodeFromFields,
   decodeFromFieldsWithTypes,
   decodeFromJSONField,
   extractType,
+  fieldToBcs,
   phantom,
 } from '../../_framework/reified'
 import {
+  FetchObjectResult,
   FieldsWithTypes,
   composeSuiType,
   compressSuiType,
+  fetchObjects,
+  fetchObjectsOfType,
   parseTypeName,
 } from '../../_framework/util'
 import { Bag } from '../bag/structs'
@@ -48,11 +53,17 @@ export class Extension implements StructClass {
   static readonly $typeName = `${PKG_V27}::kiosk_extension::Extension`
   static readonly $numTypeParams = 0
   static readonly $isPhantom = [] as const
+  static readonly $moveFieldNames = {
+    storage: 'storage',
+    permissions: 'permissions',
+    is_enabled: 'isEnabled',
+  } as const
 
   readonly $typeName = Extension.$typeName
   readonly $fullTypeName: `${typeof PKG_V27}::kiosk_extension::Extension`
   readonly $typeArgs: []
   readonly $isPhantom = Extension.$isPhantom
+  readonly $moveFieldNames = Extension.$moveFieldNames
 
   readonly storage: ToField<Bag>
   readonly permissions: ToField<'u128'>
@@ -151,6 +162,14 @@ export class Extension implements StructClass {
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([
+      fieldToBcs<Bag>(`${Bag.$typeName}`, this.storage),
+      fieldToBcs<'u128'>(`u128`, this.permissions),
+      fieldToBcs<'bool'>(`bool`, this.isEnabled),
+    ])
+  }
+
   static fromJSONField(field: any): Extension {
     return Extension.reified().new({
       storage: decodeFromJSONField(Bag.reified(), field.storage),
@@ -204,6 +223,13 @@ export class Extension implements StructClass {
 
     return Extension.fromSuiObjectData(res.data)
   }
+
+  static async fetchMany(
+    client: SuiClient,
+    ids: string[]
+  ): Promise<Array<FetchObjectResult<Extension>>> {
+    return fetchObjects(client, ids, (data: SuiObjectData) => Extension.fromSuiObjectData(data))
+  }
 }
 
 /* ============================== ExtensionKey =============================== */
@@ -228,11 +254,15 @@ export class ExtensionKey<Ext extends PhantomTypeArgument> implements StructClas
   static readonly $typeName = `${PKG_V27}::kiosk_extension::ExtensionKey`
   static readonly $numTypeParams = 1
   static readonly $isPhantom = [true] as const
+  static readonly $moveFieldNames = {
+    dummy_field: 'dummyField',
+  } as const
 
   readonly $typeName = ExtensionKey.$typeName
   readonly $fullTypeName: `${typeof PKG_V27}::kiosk_extension::ExtensionKey<${PhantomToTypeStr<Ext>}>`
   readonly $typeArgs: [PhantomToTypeStr<Ext>]
   readonly $isPhantom = ExtensionKey.$isPhantom
+  readonly $moveFieldNames = ExtensionKey.$moveFieldNames
 
   readonly dummyField: ToField<'bool'>
 
@@ -333,6 +363,10 @@ export class ExtensionKey<Ext extends PhantomTypeArgument> implements StructClas
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([fieldToBcs<'bool'>(`bool`, this.dummyField)])
+  }
+
   static fromJSONField<Ext extends PhantomReified<PhantomTypeArgument>>(
     typeArg: Ext,
     field: any
@@ -419,4 +453,22 @@ export class ExtensionKey<Ext extends PhantomTypeArgument> implements StructClas
 
     return ExtensionKey.fromSuiObjectData(typeArg, res.data)
   }
+
+  static async fetchMany<Ext extends PhantomReified<PhantomTypeArgument>>(
+    client: SuiClient,
+    ids: string[],
+    typeArg?: Ext
+  ): Promise<Array<FetchObjectResult<ExtensionKey<ToPhantomTypeArgument<Ext>>>>> {
+    if (typeArg === undefined) {
+      return fetchObjectsOfType<ExtensionKey<ToPhantomTypeArgument<Ext>>>(
+        client,
+        ids,
+        ExtensionKey.$typeName
+      )
+    }
+
+    return fetchObjects(client, ids, (data: SuiObjectData) =>
+      ExtensionKey.fromSuiObjectData(typeArg, data)
+    )
+  }
 }
@@ -1,3 +1,4 @@
+import { DisplayTemplate, fetchDisplay } from '../../_framework/display'
 import {
   PhantomReified,
   PhantomToTypeStr,
@@ -11,18 +12,25 @@ import {
   TypeArgument,
   assertFieldsWithTypesArgsMatch,
   assertReifiedTypeArgsMatch,
+  concatBytes,
   decodeFromFields,
   decodeFromFieldsWithTypes,
   decodeFromJSONField,
   extractType,
+  fieldToBcs,
   fieldToJSON,
   phantom,
   toBcs,
 } from '../../_framework/reified'
 import {
+  FetchObjectResult,
   FieldsWithTypes,
+  TypedObjectId,
   composeSuiType,
   compressSuiType,
+  fetchObjects,
+  fetchObjectsOfType,
+  fetchOwnedObjects,
   parseTypeName,
 } from '../../_framework/util'
 import { Option } from '../../move-stdlib/option/structs'
@@ -59,11 +67,18 @@ export class LinkedTable<K extends TypeArgument, V extends PhantomTypeArgument>
   static readonly $typeName = `${PKG_V27}::linked_table::LinkedTable`
   static readonly $numTypeParams = 2
   static readonly $isPhantom = [false, true] as const
+  static readonly $moveFieldNames = {
+    id: 'id',
+    size: 'size',
+    head: 'head',
+    tail: 'tail',
+  } as const
 
   readonly $typeName = LinkedTable.$typeName
   readonly $fullTypeName: `${typeof PKG_V27}::linked_table::LinkedTable<${ToTypeStr<K>}, ${PhantomToTypeStr<V>}>`
   readonly $typeArgs: [ToTypeStr<K>, PhantomToTypeStr<V>]
   readonly $isPhantom = LinkedTable.$isPhantom
+  readonly $moveFieldNames = LinkedTable.$moveFieldNames
 
   readonly id: ToField<UID>
   readonly size: ToField<'u64'>
@@ -86,6 +101,16 @@ export class LinkedTable<K extends TypeArgument, V extends PhantomTypeArgument>
     this.tail = fields.tail
   }
 
+  get $objectId(): string {
+    return this.id
+  }
+
+  static id<K extends TypeArgument, V extends PhantomTypeArgument>(
+    id: string
+  ): TypedObjectId<LinkedTable<K, V>> {
+    return id as TypedObjectId<LinkedTable<K, V>>
+  }
+
   static reified<
     K extends Reified<TypeArgument, any>,
     V extends PhantomReified<PhantomTypeArgument>,
@@ -200,6 +225,15 @@ export class LinkedTable<K extends TypeArgument, V extends PhantomTypeArgument>
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([
+      fieldToBcs<UID>(`${UID.$typeName}`, this.id),
+      fieldToBcs<'u64'>(`u64`, this.size),
+      fieldToBcs<Option<K>>(`${Option.$typeName}<${this.$typeArgs[0]}>`, this.head),
+      fieldToBcs<Option<K>>(`${Option.$typeName}<${this.$typeArgs[0]}>`, this.tail),
+    ])
+  }
+
   static fromJSONField<
     K extends Reified<TypeArgument, any>,
     V extends PhantomReified<PhantomTypeArgument>,
@@ -303,6 +337,62 @@ export class LinkedTable<K extends TypeArgument, V extends PhantomTypeArgument>
 
     return LinkedTable.fromSuiObjectData(typeArgs, res.data)
   }
+
+  static async fetchMany<
+    K extends Reified<TypeArgument, any>,
+    V extends PhantomReified<PhantomTypeArgument>,
+  >(
+    client: SuiClient,
+    ids: string[],
+    typeArgs?: [K, V]
+  ): Promise<Array<FetchObjectResult<LinkedTable<ToTypeArgument<K>, ToPhantomTypeArgument<V>>>>> {
+    if (typeArgs === undefined) {
+      return fetchObjectsOfType<LinkedTable<ToTypeArgument<K>, ToPhantomTypeArgument<V>>>(
+        client,
+        ids,
+        LinkedTable.$typeName
+      )
+    }
+
+    return fetchObjects(client, ids, (data: SuiObjectData) =>
+      LinkedTable.fromSuiObjectData(typeArgs, data)
+    )
+  }
+
+  static async *fetchOwned<
+    K extends Reified<TypeArgument, any>,
+    V extends PhantomReified<PhantomTypeArgument>,
+  >(
+    client: SuiClient,
+    owner: string,
+    typeArgs?: [K, V]
+  ): AsyncGenerator<LinkedTable<ToTypeArgument<K>, ToPhantomTypeArgument<V>>> {
+    if (typeArgs === undefined) {
+      yield* fetchOwnedObjects<LinkedTable<ToTypeArgument<K>, ToPhantomTypeArgument<V>>>(
+        client,
+        owner,
+        LinkedTable.$typeName
+      )
+      return
+    }
+
+    yield* fetchOwnedObjects(
+      client,
+      owner,
+      composeSuiType(LinkedTable.$typeName, ...typeArgs.map(typeArg => extractType(typeArg))),
+      (data: SuiObjectData) => LinkedTable.fromSuiObjectData(typeArgs, data)
+    )
+  }
+
+  static async fetchDisplay<
+    K extends Reified<TypeArgument, any>,
+    V extends PhantomReified<PhantomTypeArgument>,
+  >(
+    client: SuiClient,
+    typeArgs: [K, V]
+  ): Promise<DisplayTemplate<LinkedTable<ToTypeArgument<K>, ToPhantomTypeArgument<V>>> | null> {
+    return fetchDisplay(client, LinkedTable.reified(...typeArgs).fullTypeName)
+  }
 }
 
 /* ============================== Node =============================== */
@@ -329,11 +419,17 @@ export class Node<K extends TypeArgument, V extends TypeArgument> implements Str
   static readonly $typeName = `${PKG_V27}::linked_table::Node`
   static readonly $numTypeParams = 2
   static readonly $isPhantom = [false, false] as const
+  static readonly $moveFieldNames = {
+    prev: 'prev',
+    next: 'next',
+    value: 'value',
+  } as const
 
   readonly $typeName = Node.$typeName
   readonly $fullTypeName: `${typeof PKG_V27}::linked_table::Node<${ToTypeStr<K>}, ${ToTypeStr<V>}>`
   readonly $typeArgs: [ToTypeStr<K>, ToTypeStr<V>]
   readonly $isPhantom = Node.$isPhantom
+  readonly $moveFieldNames = Node.$moveFieldNames
 
   readonly prev: ToField<Option<K>>
   readonly next: ToField<Option<K>>
@@ -452,6 +548,14 @@ export class Node<K extends TypeArgument, V extends TypeArgument> implements Str
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([
+      fieldToBcs<Option<K>>(`${Option.$typeName}<${this.$typeArgs[0]}>`, this.prev),
+      fieldToBcs<Option<K>>(`${Option.$typeName}<${this.$typeArgs[0]}>`, this.next),
+      fieldToBcs<V>(`${this.$typeArgs[1]}`, this.value),
+    ])
+  }
+
   static fromJSONField<K extends Reified<TypeArgument, any>, V extends Reified<TypeArgument, any>>(
     typeArgs: [K, V],
     field: any
@@ -542,4 +646,25 @@ export class Node<K extends TypeArgument, V extends TypeArgument> implements Str
 
     return Node.fromSuiObjectData(typeArgs, res.data)
   }
+
+  static async fetchMany<
+    K extends Reified<TypeArgument, any>,
+    V extends Reified<TypeArgument, any>,
+  >(
+    client: SuiClient,
+    ids: string[],
+    typeArgs?: [K, V]
+  ): Promise<Array<FetchObjectResult<Node<ToTypeArgument<K>, ToTypeArgument<V>>>>> {
+    if (typeArgs === undefined) {
+      return fetchObjectsOfType<Node<ToTypeArgument<K>, ToTypeArgument<V>>>(
+        client,
+        ids,
+        Node.$typeName
+      )
+    }
+
+    return fetchObjects(client, ids, (data: SuiObjectData) =>
+      Node.fromSuiObjectData(typeArgs, data)
+    )
+  }
 }
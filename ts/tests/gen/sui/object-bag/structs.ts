@@ -1,15 +1,26 @@
+import { DisplayTemplate, fetchDisplay } from '../../_framework/display'
 import {
   PhantomReified,
   Reified,
   StructClass,
   ToField,
   ToTypeStr,
+  concatBytes,
   decodeFromFields,
   decodeFromFieldsWithTypes,
   decodeFromJSONField,
+  fieldToBcs,
   phantom,
 } from '../../_framework/reified'
-import { FieldsWithTypes, composeSuiType, compressSuiType } from '../../_framework/util'
+import {
+  FetchObjectResult,
+  FieldsWithTypes,
+  TypedObjectId,
+  composeSuiType,
+  compressSuiType,
+  fetchObjects,
+  fetchOwnedObjects,
+} from '../../_framework/util'
 import { PKG_V27 } from '../index'
 import { UID } from '../object/structs'
 import { bcs } from '@mysten/sui/bcs'
@@ -36,11 +47,16 @@ export class ObjectBag implements StructClass {
   static readonly $typeName = `${PKG_V27}::object_bag::ObjectBag`
   static readonly $numTypeParams = 0
   static readonly $isPhantom = [] as const
+  static readonly $moveFieldNames = {
+    id: 'id',
+    size: 'size',
+  } as const
 
   readonly $typeName = ObjectBag.$typeName
   readonly $fullTypeName: `${typeof PKG_V27}::object_bag::ObjectBag`
   readonly $typeArgs: []
   readonly $isPhantom = ObjectBag.$isPhantom
+  readonly $moveFieldNames = ObjectBag.$moveFieldNames
 
   readonly id: ToField<UID>
   readonly size: ToField<'u64'>
@@ -56,6 +72,14 @@ export class ObjectBag implements StructClass {
     this.size = fields.size
   }
 
+  get $objectId(): string {
+    return this.id
+  }
+
+  static id(id: string): TypedObjectId<ObjectBag> {
+    return id as TypedObjectId<ObjectBag>
+  }
+
   static reified(): ObjectBagReified {
     return {
       typeName: ObjectBag.$typeName,
@@ -133,6 +157,13 @@ export class ObjectBag implements StructClass {
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([
+      fieldToBcs<UID>(`${UID.$typeName}`, this.id),
+      fieldToBcs<'u64'>(`u64`, this.size),
+    ])
+  }
+
   static fromJSONField(field: any): ObjectBag {
     return ObjectBag.reified().new({
       id: decodeFromJSONField(UID.reified(), field.id),
@@ -185,4 +216,21 @@ export class ObjectBag implements StructClass {
 
     return ObjectBag.fromSuiObjectData(res.data)
   }
+
+  static async fetchMany(
+    client: SuiClient,
+    ids: string[]
+  ): Promise<Array<FetchObjectResult<ObjectBag>>> {
+    return fetchObjects(client, ids, (data: SuiObjectData) => ObjectBag.fromSuiObjectData(data))
+  }
+
+  static async *fetchOwned(client: SuiClient, owner: string): AsyncGenerator<ObjectBag> {
+    yield* fetchOwnedObjects(client, owner, ObjectBag.$typeName, (data: SuiObjectData) =>
+      ObjectBag.fromSuiObjectData(data)
+    )
+  }
+
+  static async fetchDisplay(client: SuiClient): Promise<DisplayTemplate<ObjectBag> | null> {
+    return fetchDisplay(client, ObjectBag.reified().fullTypeName)
+  }
 }
@@ -1,3 +1,4 @@
+import { DisplayTemplate, fetchDisplay } from '../../_framework/display'
 import {
   PhantomReified,
   PhantomToTypeStr,
@@ -9,16 +10,23 @@ import {
   ToTypeStr,
   assertFieldsWithTypesArgsMatch,
   assertReifiedTypeArgsMatch,
+  concatBytes,
   decodeFromFields,
   decodeFromFieldsWithTypes,
   decodeFromJSONField,
   extractType,
+  fieldToBcs,
   phantom,
 } from '../../_framework/reified'
 import {
+  FetchObjectResult,
   FieldsWithTypes,
+  TypedObjectId,
   composeSuiType,
   compressSuiType,
+  fetchObjects,
+  fetchObjectsOfType,
+  fetchOwnedObjects,
   parseTypeName,
 } from '../../_framework/util'
 import { PKG_V27 } from '../index'
@@ -52,11 +60,16 @@ export class ObjectTable<K extends PhantomTypeArgument, V extends PhantomTypeArg
   static readonly $typeName = `${PKG_V27}::object_table::ObjectTable`
   static readonly $numTypeParams = 2
   static readonly $isPhantom = [true, true] as const
+  static readonly $moveFieldNames = {
+    id: 'id',
+    size: 'size',
+  } as const
 
   readonly $typeName = ObjectTable.$typeName
   readonly $fullTypeName: `${typeof PKG_V27}::object_table::ObjectTable<${PhantomToTypeStr<K>}, ${PhantomToTypeStr<V>}>`
   readonly $typeArgs: [PhantomToTypeStr<K>, PhantomToTypeStr<V>]
   readonly $isPhantom = ObjectTable.$isPhantom
+  readonly $moveFieldNames = ObjectTable.$moveFieldNames
 
   readonly id: ToField<UID>
   readonly size: ToField<'u64'>
@@ -75,6 +88,16 @@ export class ObjectTable<K extends PhantomTypeArgument, V extends PhantomTypeArg
     this.size = fields.size
   }
 
+  get $objectId(): string {
+    return this.id
+  }
+
+  static id<K extends PhantomTypeArgument, V extends PhantomTypeArgument>(
+    id: string
+  ): TypedObjectId<ObjectTable<K, V>> {
+    return id as TypedObjectId<ObjectTable<K, V>>
+  }
+
   static reified<
     K extends PhantomReified<PhantomTypeArgument>,
     V extends PhantomReified<PhantomTypeArgument>,
@@ -183,6 +206,13 @@ export class ObjectTable<K extends PhantomTypeArgument, V extends PhantomTypeArg
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([
+      fieldToBcs<UID>(`${UID.$typeName}`, this.id),
+      fieldToBcs<'u64'>(`u64`, this.size),
+    ])
+  }
+
   static fromJSONField<
     K extends PhantomReified<PhantomTypeArgument>,
     V extends PhantomReified<PhantomTypeArgument>,
@@ -284,4 +314,64 @@ export class ObjectTable<K extends PhantomTypeArgument, V extends PhantomTypeArg
 
     return ObjectTable.fromSuiObjectData(typeArgs, res.data)
   }
+
+  static async fetchMany<
+    K extends PhantomReified<PhantomTypeArgument>,
+    V extends PhantomReified<PhantomTypeArgument>,
+  >(
+    client: SuiClient,
+    ids: string[],
+    typeArgs?: [K, V]
+  ): Promise<
+    Array<FetchObjectResult<ObjectTable<ToPhantomTypeArgument<K>, ToPhantomTypeArgument<V>>>>
+  > {
+    if (typeArgs === undefined) {
+      return fetchObjectsOfType<ObjectTable<ToPhantomTypeArgument<K>, ToPhantomTypeArgument<V>>>(
+        client,
+        ids,
+        ObjectTable.$typeName
+      )
+    }
+
+    return fetchObjects(client, ids, (data: SuiObjectData) =>
+      ObjectTable.fromSuiObjectData(typeArgs, data)
+    )
+  }
+
+  static async *fetchOwned<
+    K extends PhantomReified<PhantomTypeArgument>,
+    V extends PhantomReified<PhantomTypeArgument>,
+  >(
+    client: SuiClient,
+    owner: string,
+    typeArgs?: [K, V]
+  ): AsyncGenerator<ObjectTable<ToPhantomTypeArgument<K>, ToPhantomTypeArgument<V>>> {
+    if (typeArgs === undefined) {
+      yield* fetchOwnedObjects<ObjectTable<ToPhantomTypeArgument<K>, ToPhantomTypeArgument<V>>>(
+        client,
+        owner,
+        ObjectTable.$typeName
+      )
+      return
+    }
+
+    yield* fetchOwnedObjects(
+      client,
+      owner,
+      composeSuiType(ObjectTable.$typeName, ...typeArgs.map(typeArg => extractType(typeArg))),
+      (data: SuiObjectData) => ObjectTable.fromSuiObjectData(typeArgs, data)
+    )
+  }
+
+  static async fetchDisplay<
+    K extends PhantomReified<PhantomTypeArgument>,
+    V extends PhantomReified<PhantomTypeArgument>,
+  >(
+    client: SuiClient,
+    typeArgs: [K, V]
+  ): Promise<
+    DisplayTemplate<ObjectTable<ToPhantomTypeArgument<K>, ToPhantomTypeArgument<V>>> | null
+  > {
+    return fetchDisplay(client, ObjectTable.reified(...typeArgs).fullTypeName)
+  }
 }
@@ -4,12 +4,20 @@ import {
   StructClass,
   ToField,
   ToTypeStr,
+  concatBytes,
   decodeFromFields,
   decodeFromFieldsWithTypes,
   decodeFromJSONField,
+  fieldToBcs,
   phantom,
 } from '../../_framework/reified'
-import { FieldsWithTypes, composeSuiType, compressSuiType } from '../../_framework/util'
+import {
+  FetchObjectResult,
+  FieldsWithTypes,
+  composeSuiType,
+  compressSuiType,
+  fetchObjects,
+} from '../../_framework/util'
 import { PKG_V27 } from '../index'
 import { bcs } from '@mysten/sui/bcs'
 import { SuiClient, SuiObjectData, SuiParsedData } from '@mysten/sui/client'
@@ -34,11 +42,15 @@ export class ID implements StructClass {
   static readonly $typeName = `${PKG_V27}::object::ID`
   static readonly $numTypeParams = 0
   static readonly $isPhantom = [] as const
+  static readonly $moveFieldNames = {
+    bytes: 'bytes',
+  } as const
 
   readonly $typeName = ID.$typeName
   readonly $fullTypeName: `${typeof PKG_V27}::object::ID`
   readonly $typeArgs: []
   readonly $isPhantom = ID.$isPhantom
+  readonly $moveFieldNames = ID.$moveFieldNames
 
   readonly bytes: ToField<'address'>
 
@@ -121,6 +133,10 @@ export class ID implements StructClass {
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([fieldToBcs<'address'>(`address`, this.bytes)])
+  }
+
   static fromJSONField(field: any): ID {
     return ID.reified().new({ bytes: decodeFromJSONField('address', field.bytes) })
   }
@@ -170,6 +186,10 @@ export class ID implements StructClass {
 
     return ID.fromSuiObjectData(res.data)
   }
+
+  static async fetchMany(client: SuiClient, ids: string[]): Promise<Array<FetchObjectResult<ID>>> {
+    return fetchObjects(client, ids, (data: SuiObjectData) => ID.fromSuiObjectData(data))
+  }
 }
 
 /* ============================== UID =============================== */
@@ -191,11 +211,15 @@ export class UID implements StructClass {
   static readonly $typeName = `${PKG_V27}::object::UID`
   static readonly $numTypeParams = 0
   static readonly $isPhantom = [] as const
+  static readonly $moveFieldNames = {
+    id: 'id',
+  } as const
 
   readonly $typeName = UID.$typeName
   readonly $fullTypeName: `${typeof PKG_V27}::object::UID`
   readonly $typeArgs: []
   readonly $isPhantom = UID.$isPhantom
+  readonly $moveFieldNames = UID.$moveFieldNames
 
   readonly id: ToField<ID>
 
@@ -275,6 +299,10 @@ export class UID implements StructClass {
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([fieldToBcs<ID>(`${ID.$typeName}`, this.id)])
+  }
+
   static fromJSONField(field: any): UID {
     return UID.reified().new({ id: decodeFromJSONField(ID.reified(), field.id) })
   }
@@ -324,4 +352,8 @@ export class UID implements StructClass {
 
     return UID.fromSuiObjectData(res.data)
   }
+
+  static async fetchMany(client: SuiClient, ids: string[]): Promise<Array<FetchObjectResult<UID>>> {
+    return fetchObjects(client, ids, (data: SuiObjectData) => UID.fromSuiObjectData(data))
+  }
 }
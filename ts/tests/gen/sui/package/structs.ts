@@ -1,3 +1,4 @@
+import { DisplayTemplate, fetchDisplay } from '../../_framework/display'
 import * as reified from '../../_framework/reified'
 import {
   PhantomReified,
@@ -5,13 +6,23 @@ import {
   StructClass,
   ToField,
   ToTypeStr,
+  concatBytes,
   decodeFromFields,
   decodeFromFieldsWithTypes,
   decodeFromJSONField,
+  fieldToBcs,
   fieldToJSON,
   phantom,
 } from '../../_framework/reified'
-import { FieldsWithTypes, composeSuiType, compressSuiType } from '../../_framework/util'
+import {
+  FetchObjectResult,
+  FieldsWithTypes,
+  TypedObjectId,
+  composeSuiType,
+  compressSuiType,
+  fetchObjects,
+  fetchOwnedObjects,
+} from '../../_framework/util'
 import { Vector } from '../../_framework/vector'
 import { String } from '../../move-stdlib/ascii/structs'
 import { PKG_V27 } from '../index'
@@ -41,11 +52,17 @@ export class Publisher implements StructClass {
   static readonly $typeName = `${PKG_V27}::package::Publisher`
   static readonly $numTypeParams = 0
   static readonly $isPhantom = [] as const
+  static readonly $moveFieldNames = {
+    id: 'id',
+    package: 'package',
+    module_name: 'moduleName',
+  } as const
 
   readonly $typeName = Publisher.$typeName
   readonly $fullTypeName: `${typeof PKG_V27}::package::Publisher`
   readonly $typeArgs: []
   readonly $isPhantom = Publisher.$isPhantom
+  readonly $moveFieldNames = Publisher.$moveFieldNames
 
   readonly id: ToField<UID>
   readonly package: ToField<String>
@@ -63,6 +80,14 @@ export class Publisher implements StructClass {
     this.moduleName = fields.moduleName
   }
 
+  get $objectId(): string {
+    return this.id
+  }
+
+  static id(id: string): TypedObjectId<Publisher> {
+    return id as TypedObjectId<Publisher>
+  }
+
   static reified(): PublisherReified {
     return {
       typeName: Publisher.$typeName,
@@ -144,6 +169,14 @@ export class Publisher implements StructClass {
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([
+      fieldToBcs<UID>(`${UID.$typeName}`, this.id),
+      fieldToBcs<String>(`${String.$typeName}`, this.package),
+      fieldToBcs<String>(`${String.$typeName}`, this.moduleName),
+    ])
+  }
+
   static fromJSONField(field: any): Publisher {
     return Publisher.reified().new({
       id: decodeFromJSONField(UID.reified(), field.id),
@@ -197,6 +230,23 @@ export class Publisher implements StructClass {
 
     return Publisher.fromSuiObjectData(res.data)
   }
+
+  static async fetchMany(
+    client: SuiClient,
+    ids: string[]
+  ): Promise<Array<FetchObjectResult<Publisher>>> {
+    return fetchObjects(client, ids, (data: SuiObjectData) => Publisher.fromSuiObjectData(data))
+  }
+
+  static async *fetchOwned(client: SuiClient, owner: string): AsyncGenerator<Publisher> {
+    yield* fetchOwnedObjects(client, owner, Publisher.$typeName, (data: SuiObjectData) =>
+      Publisher.fromSuiObjectData(data)
+    )
+  }
+
+  static async fetchDisplay(client: SuiClient): Promise<DisplayTemplate<Publisher> | null> {
+    return fetchDisplay(client, Publisher.reified().fullTypeName)
+  }
 }
 
 /* ============================== UpgradeCap =============================== */
@@ -221,11 +271,18 @@ export class UpgradeCap implements StructClass {
   static readonly $typeName = `${PKG_V27}::package::UpgradeCap`
   static readonly $numTypeParams = 0
   static readonly $isPhantom = [] as const
+  static readonly $moveFieldNames = {
+    id: 'id',
+    package: 'package',
+    version: 'version',
+    policy: 'policy',
+  } as const
 
   readonly $typeName = UpgradeCap.$typeName
   readonly $fullTypeName: `${typeof PKG_V27}::package::UpgradeCap`
   readonly $typeArgs: []
   readonly $isPhantom = UpgradeCap.$isPhantom
+  readonly $moveFieldNames = UpgradeCap.$moveFieldNames
 
   readonly id: ToField<UID>
   readonly package: ToField<ID>
@@ -245,6 +302,14 @@ export class UpgradeCap implements StructClass {
     this.policy = fields.policy
   }
 
+  get $objectId(): string {
+    return this.id
+  }
+
+  static id(id: string): TypedObjectId<UpgradeCap> {
+    return id as TypedObjectId<UpgradeCap>
+  }
+
   static reified(): UpgradeCapReified {
     return {
       typeName: UpgradeCap.$typeName,
@@ -330,6 +395,15 @@ export class UpgradeCap implements StructClass {
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([
+      fieldToBcs<UID>(`${UID.$typeName}`, this.id),
+      fieldToBcs<ID>(`${ID.$typeName}`, this.package),
+      fieldToBcs<'u64'>(`u64`, this.version),
+      fieldToBcs<'u8'>(`u8`, this.policy),
+    ])
+  }
+
   static fromJSONField(field: any): UpgradeCap {
     return UpgradeCap.reified().new({
       id: decodeFromJSONField(UID.reified(), field.id),
@@ -384,6 +458,23 @@ export class UpgradeCap implements StructClass {
 
     return UpgradeCap.fromSuiObjectData(res.data)
   }
+
+  static async fetchMany(
+    client: SuiClient,
+    ids: string[]
+  ): Promise<Array<FetchObjectResult<UpgradeCap>>> {
+    return fetchObjects(client, ids, (data: SuiObjectData) => UpgradeCap.fromSuiObjectData(data))
+  }
+
+  static async *fetchOwned(client: SuiClient, owner: string): AsyncGenerator<UpgradeCap> {
+    yield* fetchOwnedObjects(client, owner, UpgradeCap.$typeName, (data: SuiObjectData) =>
+      UpgradeCap.fromSuiObjectData(data)
+    )
+  }
+
+  static async fetchDisplay(client: SuiClient): Promise<DisplayTemplate<UpgradeCap> | null> {
+    return fetchDisplay(client, UpgradeCap.reified().fullTypeName)
+  }
 }
 
 /* ============================== UpgradeReceipt =============================== */
@@ -406,11 +497,16 @@ export class UpgradeReceipt implements StructClass {
   static readonly $typeName = `${PKG_V27}::package::UpgradeReceipt`
   static readonly $numTypeParams = 0
   static readonly $isPhantom = [] as const
+  static readonly $moveFieldNames = {
+    cap: 'cap',
+    package: 'package',
+  } as const
 
   readonly $typeName = UpgradeReceipt.$typeName
   readonly $fullTypeName: `${typeof PKG_V27}::package::UpgradeReceipt`
   readonly $typeArgs: []
   readonly $isPhantom = UpgradeReceipt.$isPhantom
+  readonly $moveFieldNames = UpgradeReceipt.$moveFieldNames
 
   readonly cap: ToField<ID>
   readonly package: ToField<ID>
@@ -503,6 +599,13 @@ export class UpgradeReceipt implements StructClass {
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([
+      fieldToBcs<ID>(`${ID.$typeName}`, this.cap),
+      fieldToBcs<ID>(`${ID.$typeName}`, this.package),
+    ])
+  }
+
   static fromJSONField(field: any): UpgradeReceipt {
     return UpgradeReceipt.reified().new({
       cap: decodeFromJSONField(ID.reified(), field.cap),
@@ -555,6 +658,15 @@ export class UpgradeReceipt implements StructClass {
 
     return UpgradeReceipt.fromSuiObjectData(res.data)
   }
+
+  static async fetchMany(
+    client: SuiClient,
+    ids: string[]
+  ): Promise<Array<FetchObjectResult<UpgradeReceipt>>> {
+    return fetchObjects(client, ids, (data: SuiObjectData) =>
+      UpgradeReceipt.fromSuiObjectData(data)
+    )
+  }
 }
 
 /* ============================== UpgradeTicket =============================== */
@@ -579,11 +691,18 @@ export class UpgradeTicket implements StructClass {
   static readonly $typeName = `${PKG_V27}::package::UpgradeTicket`
   static readonly $numTypeParams = 0
   static readonly $isPhantom = [] as const
+  static readonly $moveFieldNames = {
+    cap: 'cap',
+    package: 'package',
+    policy: 'policy',
+    digest: 'digest',
+  } as const
 
   readonly $typeName = UpgradeTicket.$typeName
   readonly $fullTypeName: `${typeof PKG_V27}::package::UpgradeTicket`
   readonly $typeArgs: []
   readonly $isPhantom = UpgradeTicket.$isPhantom
+  readonly $moveFieldNames = UpgradeTicket.$moveFieldNames
 
   readonly cap: ToField<ID>
   readonly package: ToField<ID>
@@ -688,6 +807,15 @@ export class UpgradeTicket implements StructClass {
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([
+      fieldToBcs<ID>(`${ID.$typeName}`, this.cap),
+      fieldToBcs<ID>(`${ID.$typeName}`, this.package),
+      fieldToBcs<'u8'>(`u8`, this.policy),
+      fieldToBcs<Vector<'u8'>>(`vector<u8>`, this.digest),
+    ])
+  }
+
   static fromJSONField(field: any): UpgradeTicket {
     return UpgradeTicket.reified().new({
       cap: decodeFromJSONField(ID.reified(), field.cap),
@@ -742,4 +870,11 @@ export class UpgradeTicket implements StructClass {
 
     return UpgradeTicket.fromSuiObjectData(res.data)
   }
+
+  static async fetchMany(
+    client: SuiClient,
+    ids: string[]
+  ): Promise<Array<FetchObjectResult<UpgradeTicket>>> {
+    return fetchObjects(client, ids, (data: SuiObjectData) => UpgradeTicket.fromSuiObjectData(data))
+  }
 }
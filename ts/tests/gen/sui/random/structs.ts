@@ -1,3 +1,4 @@
+import { DisplayTemplate, fetchDisplay } from '../../_framework/display'
 import * as reified from '../../_framework/reified'
 import {
   PhantomReified,
@@ -5,13 +6,23 @@ import {
   StructClass,
   ToField,
   ToTypeStr,
+  concatBytes,
   decodeFromFields,
   decodeFromFieldsWithTypes,
   decodeFromJSONField,
+  fieldToBcs,
   fieldToJSON,
   phantom,
 } from '../../_framework/reified'
-import { FieldsWithTypes, composeSuiType, compressSuiType } from '../../_framework/util'
+import {
+  FetchObjectResult,
+  FieldsWithTypes,
+  TypedObjectId,
+  composeSuiType,
+  compressSuiType,
+  fetchObjects,
+  fetchOwnedObjects,
+} from '../../_framework/util'
 import { Vector } from '../../_framework/vector'
 import { PKG_V27 } from '../index'
 import { UID } from '../object/structs'
@@ -40,11 +51,16 @@ export class Random implements StructClass {
   static readonly $typeName = `${PKG_V27}::random::Random`
   static readonly $numTypeParams = 0
   static readonly $isPhantom = [] as const
+  static readonly $moveFieldNames = {
+    id: 'id',
+    inner: 'inner',
+  } as const
 
   readonly $typeName = Random.$typeName
   readonly $fullTypeName: `${typeof PKG_V27}::random::Random`
   readonly $typeArgs: []
   readonly $isPhantom = Random.$isPhantom
+  readonly $moveFieldNames = Random.$moveFieldNames
 
   readonly id: ToField<UID>
   readonly inner: ToField<Versioned>
@@ -60,6 +76,14 @@ export class Random implements StructClass {
     this.inner = fields.inner
   }
 
+  get $objectId(): string {
+    return this.id
+  }
+
+  static id(id: string): TypedObjectId<Random> {
+    return id as TypedObjectId<Random>
+  }
+
   static reified(): RandomReified {
     return {
       typeName: Random.$typeName,
@@ -134,6 +158,13 @@ export class Random implements StructClass {
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([
+      fieldToBcs<UID>(`${UID.$typeName}`, this.id),
+      fieldToBcs<Versioned>(`${Versioned.$typeName}`, this.inner),
+    ])
+  }
+
   static fromJSONField(field: any): Random {
     return Random.reified().new({
       id: decodeFromJSONField(UID.reified(), field.id),
@@ -186,6 +217,23 @@ export class Random implements StructClass {
 
     return Random.fromSuiObjectData(res.data)
   }
+
+  static async fetchMany(
+    client: SuiClient,
+    ids: string[]
+  ): Promise<Array<FetchObjectResult<Random>>> {
+    return fetchObjects(client, ids, (data: SuiObjectData) => Random.fromSuiObjectData(data))
+  }
+
+  static async *fetchOwned(client: SuiClient, owner: string): AsyncGenerator<Random> {
+    yield* fetchOwnedObjects(client, owner, Random.$typeName, (data: SuiObjectData) =>
+      Random.fromSuiObjectData(data)
+    )
+  }
+
+  static async fetchDisplay(client: SuiClient): Promise<DisplayTemplate<Random> | null> {
+    return fetchDisplay(client, Random.reified().fullTypeName)
+  }
 }
 
 /* ============================== RandomGenerator =============================== */
@@ -209,11 +257,17 @@ export class RandomGenerator implements StructClass {
   static readonly $typeName = `${PKG_V27}::random::RandomGenerator`
   static readonly $numTypeParams = 0
   static readonly $isPhantom = [] as const
+  static readonly $moveFieldNames = {
+    seed: 'seed',
+    counter: 'counter',
+    buffer: 'buffer',
+  } as const
 
   readonly $typeName = RandomGenerator.$typeName
   readonly $fullTypeName: `${typeof PKG_V27}::random::RandomGenerator`
   readonly $typeArgs: []
   readonly $isPhantom = RandomGenerator.$isPhantom
+  readonly $moveFieldNames = RandomGenerator.$moveFieldNames
 
   readonly seed: ToField<Vector<'u8'>>
   readonly counter: ToField<'u16'>
@@ -312,6 +366,14 @@ export class RandomGenerator implements StructClass {
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([
+      fieldToBcs<Vector<'u8'>>(`vector<u8>`, this.seed),
+      fieldToBcs<'u16'>(`u16`, this.counter),
+      fieldToBcs<Vector<'u8'>>(`vector<u8>`, this.buffer),
+    ])
+  }
+
   static fromJSONField(field: any): RandomGenerator {
     return RandomGenerator.reified().new({
       seed: decodeFromJSONField(reified.vector('u8'), field.seed),
@@ -365,6 +427,15 @@ export class RandomGenerator implements StructClass {
 
     return RandomGenerator.fromSuiObjectData(res.data)
   }
+
+  static async fetchMany(
+    client: SuiClient,
+    ids: string[]
+  ): Promise<Array<FetchObjectResult<RandomGenerator>>> {
+    return fetchObjects(client, ids, (data: SuiObjectData) =>
+      RandomGenerator.fromSuiObjectData(data)
+    )
+  }
 }
 
 /* ============================== RandomInner =============================== */
@@ -389,11 +460,18 @@ export class RandomInner implements StructClass {
   static readonly $typeName = `${PKG_V27}::random::RandomInner`
   static readonly $numTypeParams = 0
   static readonly $isPhantom = [] as const
+  static readonly $moveFieldNames = {
+    version: 'version',
+    epoch: 'epoch',
+    randomness_round: 'randomnessRound',
+    random_bytes: 'randomBytes',
+  } as const
 
   readonly $typeName = RandomInner.$typeName
   readonly $fullTypeName: `${typeof PKG_V27}::random::RandomInner`
   readonly $typeArgs: []
   readonly $isPhantom = RandomInner.$isPhantom
+  readonly $moveFieldNames = RandomInner.$moveFieldNames
 
   readonly version: ToField<'u64'>
   readonly epoch: ToField<'u64'>
@@ -498,6 +576,15 @@ export class RandomInner implements StructClass {
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([
+      fieldToBcs<'u64'>(`u64`, this.version),
+      fieldToBcs<'u64'>(`u64`, this.epoch),
+      fieldToBcs<'u64'>(`u64`, this.randomnessRound),
+      fieldToBcs<Vector<'u8'>>(`vector<u8>`, this.randomBytes),
+    ])
+  }
+
   static fromJSONField(field: any): RandomInner {
     return RandomInner.reified().new({
       version: decodeFromJSONField('u64', field.version),
@@ -552,4 +639,11 @@ export class RandomInner implements StructClass {
 
     return RandomInner.fromSuiObjectData(res.data)
   }
+
+  static async fetchMany(
+    client: SuiClient,
+    ids: string[]
+  ): Promise<Array<FetchObjectResult<RandomInner>>> {
+    return fetchObjects(client, ids, (data: SuiObjectData) => RandomInner.fromSuiObjectData(data))
+  }
 }
@@ -1,3 +1,4 @@
+import { DisplayTemplate, fetchDisplay } from '../../_framework/display'
 import {
   PhantomReified,
   PhantomToTypeStr,
@@ -9,16 +10,23 @@ import {
   ToTypeStr,
   assertFieldsWithTypesArgsMatch,
   assertReifiedTypeArgsMatch,
+  concatBytes,
   decodeFromFields,
   decodeFromFieldsWithTypes,
   decodeFromJSONField,
   extractType,
+  fieldToBcs,
   phantom,
 } from '../../_framework/reified'
 import {
+  FetchObjectResult,
   FieldsWithTypes,
+  TypedObjectId,
   composeSuiType,
   compressSuiType,
+  fetchObjects,
+  fetchObjectsOfType,
+  fetchOwnedObjects,
   parseTypeName,
 } from '../../_framework/util'
 import { PKG_V27 } from '../index'
@@ -52,11 +60,16 @@ export class Table<K extends PhantomTypeArgument, V extends PhantomTypeArgument>
   static readonly $typeName = `${PKG_V27}::table::Table`
   static readonly $numTypeParams = 2
   static readonly $isPhantom = [true, true] as const
+  static readonly $moveFieldNames = {
+    id: 'id',
+    size: 'size',
+  } as const
 
   readonly $typeName = Table.$typeName
   readonly $fullTypeName: `${typeof PKG_V27}::table::Table<${PhantomToTypeStr<K>}, ${PhantomToTypeStr<V>}>`
   readonly $typeArgs: [PhantomToTypeStr<K>, PhantomToTypeStr<V>]
   readonly $isPhantom = Table.$isPhantom
+  readonly $moveFieldNames = Table.$moveFieldNames
 
   readonly id: ToField<UID>
   readonly size: ToField<'u64'>
@@ -75,6 +88,16 @@ export class Table<K extends PhantomTypeArgument, V extends PhantomTypeArgument>
     this.size = fields.size
   }
 
+  get $objectId(): string {
+    return this.id
+  }
+
+  static id<K extends PhantomTypeArgument, V extends PhantomTypeArgument>(
+    id: string
+  ): TypedObjectId<Table<K, V>> {
+    return id as TypedObjectId<Table<K, V>>
+  }
+
   static reified<
     K extends PhantomReified<PhantomTypeArgument>,
     V extends PhantomReified<PhantomTypeArgument>,
@@ -180,6 +203,13 @@ export class Table<K extends PhantomTypeArgument, V extends PhantomTypeArgument>
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([
+      fieldToBcs<UID>(`${UID.$typeName}`, this.id),
+      fieldToBcs<'u64'>(`u64`, this.size),
+    ])
+  }
+
   static fromJSONField<
     K extends PhantomReified<PhantomTypeArgument>,
     V extends PhantomReified<PhantomTypeArgument>,
@@ -281,4 +311,60 @@ export class Table<K extends PhantomTypeArgument, V extends PhantomTypeArgument>
 
     return Table.fromSuiObjectData(typeArgs, res.data)
   }
+
+  static async fetchMany<
+    K extends PhantomReified<PhantomTypeArgument>,
+    V extends PhantomReified<PhantomTypeArgument>,
+  >(
+    client: SuiClient,
+    ids: string[],
+    typeArgs?: [K, V]
+  ): Promise<Array<FetchObjectResult<Table<ToPhantomTypeArgument<K>, ToPhantomTypeArgument<V>>>>> {
+    if (typeArgs === undefined) {
+      return fetchObjectsOfType<Table<ToPhantomTypeArgument<K>, ToPhantomTypeArgument<V>>>(
+        client,
+        ids,
+        Table.$typeName
+      )
+    }
+
+    return fetchObjects(client, ids, (data: SuiObjectData) =>
+      Table.fromSuiObjectData(typeArgs, data)
+    )
+  }
+
+  static async *fetchOwned<
+    K extends PhantomReified<PhantomTypeArgument>,
+    V extends PhantomReified<PhantomTypeArgument>,
+  >(
+    client: SuiClient,
+    owner: string,
+    typeArgs?: [K, V]
+  ): AsyncGenerator<Table<ToPhantomTypeArgument<K>, ToPhantomTypeArgument<V>>> {
+    if (typeArgs === undefined) {
+      yield* fetchOwnedObjects<Table<ToPhantomTypeArgument<K>, ToPhantomTypeArgument<V>>>(
+        client,
+        owner,
+        Table.$typeName
+      )
+      return
+    }
+
+    yield* fetchOwnedObjects(
+      client,
+      owner,
+      composeSuiType(Table.$typeName, ...typeArgs.map(typeArg => extractType(typeArg))),
+      (data: SuiObjectData) => Table.fromSuiObjectData(typeArgs, data)
+    )
+  }
+
+  static async fetchDisplay<
+    K extends PhantomReified<PhantomTypeArgument>,
+    V extends PhantomReified<PhantomTypeArgument>,
+  >(
+    client: SuiClient,
+    typeArgs: [K, V]
+  ): Promise<DisplayTemplate<Table<ToPhantomTypeArgument<K>, ToPhantomTypeArgument<V>>> | null> {
+    return fetchDisplay(client, Table.reified(...typeArgs).fullTypeName)
+  }
 }
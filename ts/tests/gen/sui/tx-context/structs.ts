@@ -5,13 +5,21 @@ import {
   StructClass,
   ToField,
   ToTypeStr,
+  concatBytes,
   decodeFromFields,
   decodeFromFieldsWithTypes,
   decodeFromJSONField,
+  fieldToBcs,
   fieldToJSON,
   phantom,
 } from '../../_framework/reified'
-import { FieldsWithTypes, composeSuiType, compressSuiType } from '../../_framework/util'
+import {
+  FetchObjectResult,
+  FieldsWithTypes,
+  composeSuiType,
+  compressSuiType,
+  fetchObjects,
+} from '../../_framework/util'
 import { Vector } from '../../_framework/vector'
 import { PKG_V27 } from '../index'
 import { bcs } from '@mysten/sui/bcs'
@@ -41,11 +49,19 @@ export class TxContext implements StructClass {
   static readonly $typeName = `${PKG_V27}::tx_context::TxContext`
   static readonly $numTypeParams = 0
   static readonly $isPhantom = [] as const
+  static readonly $moveFieldNames = {
+    sender: 'sender',
+    tx_hash: 'txHash',
+    epoch: 'epoch',
+    epoch_timestamp_ms: 'epochTimestampMs',
+    ids_created: 'idsCreated',
+  } as const
 
   readonly $typeName = TxContext.$typeName
   readonly $fullTypeName: `${typeof PKG_V27}::tx_context::TxContext`
   readonly $typeArgs: []
   readonly $isPhantom = TxContext.$isPhantom
+  readonly $moveFieldNames = TxContext.$moveFieldNames
 
   readonly sender: ToField<'address'>
   readonly txHash: ToField<Vector<'u8'>>
@@ -159,6 +175,16 @@ export class TxContext implements StructClass {
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([
+      fieldToBcs<'address'>(`address`, this.sender),
+      fieldToBcs<Vector<'u8'>>(`vector<u8>`, this.txHash),
+      fieldToBcs<'u64'>(`u64`, this.epoch),
+      fieldToBcs<'u64'>(`u64`, this.epochTimestampMs),
+      fieldToBcs<'u64'>(`u64`, this.idsCreated),
+    ])
+  }
+
   static fromJSONField(field: any): TxContext {
     return TxContext.reified().new({
       sender: decodeFromJSONField('address', field.sender),
@@ -214,4 +240,11 @@ export class TxContext implements StructClass {
 
     return TxContext.fromSuiObjectData(res.data)
   }
+
+  static async fetchMany(
+    client: SuiClient,
+    ids: string[]
+  ): Promise<Array<FetchObjectResult<TxContext>>> {
+    return fetchObjects(client, ids, (data: SuiObjectData) => TxContext.fromSuiObjectData(data))
+  }
 }
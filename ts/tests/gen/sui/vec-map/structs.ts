@@ -9,18 +9,23 @@ import {
   TypeArgument,
   assertFieldsWithTypesArgsMatch,
   assertReifiedTypeArgsMatch,
+  concatBytes,
   decodeFromFields,
   decodeFromFieldsWithTypes,
   decodeFromJSONField,
   extractType,
+  fieldToBcs,
   fieldToJSON,
   phantom,
   toBcs,
 } from '../../_framework/reified'
 import {
+  FetchObjectResult,
   FieldsWithTypes,
   composeSuiType,
   compressSuiType,
+  fetchObjects,
+  fetchObjectsOfType,
   parseTypeName,
 } from '../../_framework/util'
 import { Vector } from '../../_framework/vector'
@@ -52,11 +57,16 @@ export class Entry<K extends TypeArgument, V extends TypeArgument> implements St
   static readonly $typeName = `${PKG_V27}::vec_map::Entry`
   static readonly $numTypeParams = 2
   static readonly $isPhantom = [false, false] as const
+  static readonly $moveFieldNames = {
+    key: 'key',
+    value: 'value',
+  } as const
 
   readonly $typeName = Entry.$typeName
   readonly $fullTypeName: `${typeof PKG_V27}::vec_map::Entry<${ToTypeStr<K>}, ${ToTypeStr<V>}>`
   readonly $typeArgs: [ToTypeStr<K>, ToTypeStr<V>]
   readonly $isPhantom = Entry.$isPhantom
+  readonly $moveFieldNames = Entry.$moveFieldNames
 
   readonly key: ToField<K>
   readonly value: ToField<V>
@@ -169,6 +179,13 @@ export class Entry<K extends TypeArgument, V extends TypeArgument> implements St
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([
+      fieldToBcs<K>(`${this.$typeArgs[0]}`, this.key),
+      fieldToBcs<V>(`${this.$typeArgs[1]}`, this.value),
+    ])
+  }
+
   static fromJSONField<K extends Reified<TypeArgument, any>, V extends Reified<TypeArgument, any>>(
     typeArgs: [K, V],
     field: any
@@ -258,6 +275,27 @@ export class Entry<K extends TypeArgument, V extends TypeArgument> implements St
 
     return Entry.fromSuiObjectData(typeArgs, res.data)
   }
+
+  static async fetchMany<
+    K extends Reified<TypeArgument, any>,
+    V extends Reified<TypeArgument, any>,
+  >(
+    client: SuiClient,
+    ids: string[],
+    typeArgs?: [K, V]
+  ): Promise<Array<FetchObjectResult<Entry<ToTypeArgument<K>, ToTypeArgument<V>>>>> {
+    if (typeArgs === undefined) {
+      return fetchObjectsOfType<Entry<ToTypeArgument<K>, ToTypeArgument<V>>>(
+        client,
+        ids,
+        Entry.$typeName
+      )
+    }
+
+    return fetchObjects(client, ids, (data: SuiObjectData) =>
+      Entry.fromSuiObjectData(typeArgs, data)
+    )
+  }
 }
 
 /* ============================== VecMap =============================== */
@@ -282,11 +320,15 @@ export class VecMap<K extends TypeArgument, V extends TypeArgument> implements S
   static readonly $typeName = `${PKG_V27}::vec_map::VecMap`
   static readonly $numTypeParams = 2
   static readonly $isPhantom = [false, false] as const
+  static readonly $moveFieldNames = {
+    contents: 'contents',
+  } as const
 
   readonly $typeName = VecMap.$typeName
   readonly $fullTypeName: `${typeof PKG_V27}::vec_map::VecMap<${ToTypeStr<K>}, ${ToTypeStr<V>}>`
   readonly $typeArgs: [ToTypeStr<K>, ToTypeStr<V>]
   readonly $isPhantom = VecMap.$isPhantom
+  readonly $moveFieldNames = VecMap.$moveFieldNames
 
   readonly contents: ToField<Vector<Entry<K, V>>>
 
@@ -405,6 +447,15 @@ export class VecMap<K extends TypeArgument, V extends TypeArgument> implements S
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([
+      fieldToBcs<Vector<Entry<K, V>>>(
+        `vector<${Entry.$typeName}<${this.$typeArgs[0]}, ${this.$typeArgs[1]}>>`,
+        this.contents
+      ),
+    ])
+  }
+
   static fromJSONField<K extends Reified<TypeArgument, any>, V extends Reified<TypeArgument, any>>(
     typeArgs: [K, V],
     field: any
@@ -496,4 +547,25 @@ export class VecMap<K extends TypeArgument, V extends TypeArgument> implements S
 
     return VecMap.fromSuiObjectData(typeArgs, res.data)
   }
+
+  static async fetchMany<
+    K extends Reified<TypeArgument, any>,
+    V extends Reified<TypeArgument, any>,
+  >(
+    client: SuiClient,
+    ids: string[],
+    typeArgs?: [K, V]
+  ): Promise<Array<FetchObjectResult<VecMap<ToTypeArgument<K>, ToTypeArgument<V>>>>> {
+    if (typeArgs === undefined) {
+      return fetchObjectsOfType<VecMap<ToTypeArgument<K>, ToTypeArgument<V>>>(
+        client,
+        ids,
+        VecMap.$typeName
+      )
+    }
+
+    return fetchObjects(client, ids, (data: SuiObjectData) =>
+      VecMap.fromSuiObjectData(typeArgs, data)
+    )
+  }
 }
@@ -9,18 +9,23 @@ import {
   TypeArgument,
   assertFieldsWithTypesArgsMatch,
   assertReifiedTypeArgsMatch,
+  concatBytes,
   decodeFromFields,
   decodeFromFieldsWithTypes,
   decodeFromJSONField,
   extractType,
+  fieldToBcs,
   fieldToJSON,
   phantom,
   toBcs,
 } from '../../_framework/reified'
 import {
+  FetchObjectResult,
   FieldsWithTypes,
   composeSuiType,
   compressSuiType,
+  fetchObjects,
+  fetchObjectsOfType,
   parseTypeName,
 } from '../../_framework/util'
 import { Vector } from '../../_framework/vector'
@@ -48,11 +53,15 @@ export class VecSet<K extends TypeArgument> implements StructClass {
   static readonly $typeName = `${PKG_V27}::vec_set::VecSet`
   static readonly $numTypeParams = 1
   static readonly $isPhantom = [false] as const
+  static readonly $moveFieldNames = {
+    contents: 'contents',
+  } as const
 
   readonly $typeName = VecSet.$typeName
   readonly $fullTypeName: `${typeof PKG_V27}::vec_set::VecSet<${ToTypeStr<K>}>`
   readonly $typeArgs: [ToTypeStr<K>]
   readonly $isPhantom = VecSet.$isPhantom
+  readonly $moveFieldNames = VecSet.$moveFieldNames
 
   readonly contents: ToField<Vector<K>>
 
@@ -154,6 +163,10 @@ export class VecSet<K extends TypeArgument> implements StructClass {
     return { $typeName: this.$typeName, $typeArgs: this.$typeArgs, ...this.toJSONField() }
   }
 
+  toBcs(): Uint8Array {
+    return concatBytes([fieldToBcs<Vector<K>>(`vector<${this.$typeArgs[0]}>`, this.contents)])
+  }
+
   static fromJSONField<K extends Reified<TypeArgument, any>>(
     typeArg: K,
     field: any
@@ -240,4 +253,18 @@ export class VecSet<K extends TypeArgument> implements StructClass {
 
     return VecSet.fromSuiObjectData(typeArg, res.data)
   }
+
+  static async fetchMany<K extends Reified<TypeArgument, any>>(
+    client: SuiClient,
+    ids: string[],
+    typeArg?: K
+  ): Promise<Array<FetchObjectResult<VecSet<ToTypeArgument<K>>>>> {
+    if (typeArg === undefined) {
+      return fetchObjectsOfType<VecSet<ToTypeArgument<K>>>(client, ids, VecSet.$typeName)
+    }
+
+    return fetchObjects(client, ids, (data: SuiObjectData) =>
+      VecSet.fromSuiObjectData(typeArg, data)
+    )
+  }
 }
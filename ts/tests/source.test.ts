@@ -1,7 +1,8 @@
 import { Transaction } from '@mysten/sui/transactions'
-import { SuiClient } from '@mysten/sui/client'
+import { SuiClient, SuiObjectData } from '@mysten/sui/client'
 import { Ed25519Keypair } from '@mysten/sui/keypairs/ed25519'
-import { fromB64 } from '@mysten/sui/utils'
+import { bcs } from '@mysten/sui/bcs'
+import { fromB64, normalizeSuiAddress, toB64 } from '@mysten/sui/utils'
 import { it, expect, describe } from 'vitest'
 import {
   Bar,
@@ -30,7 +31,7 @@ import { newUnsafeFromBytes } from './gen/sui/url/functions'
 import { new_ as newUid, idFromAddress } from './gen/sui/object/functions'
 import { zero } from './gen/sui/balance/functions'
 import { Balance } from './gen/sui/balance/structs'
-import { extractType, phantom, vector } from './gen/_framework/reified'
+import { StructClass, extractType, phantom, vector } from './gen/_framework/reified'
 import { SUI } from './gen/sui/sui/structs'
 import { Option } from './gen/move-stdlib/option/structs'
 import { String as Utf8String } from './gen/move-stdlib/string/structs'
@@ -38,8 +39,12 @@ import { String as AsciiString } from './gen/move-stdlib/ascii/structs'
 import { Url } from './gen/sui/url/structs'
 import { ID, UID } from './gen/sui/object/structs'
 import { loader } from './gen/_framework/loader'
+import { coinArg, fetchObjects, fetchOwnedObjects } from './gen/_framework/util'
+import { Coin } from './gen/sui/coin/structs'
 import { PKG_V1 } from './gen/examples'
 import { sqrt } from './gen/sui/math/functions'
+import { renderDisplay } from './gen/_framework/display'
+import { KIOSK_RULES_PACKAGE, RuleResolverContext, kioskHelpers } from './gen/_framework/kiosk'
 
 const keypair = Ed25519Keypair.fromSecretKey(
   fromB64('AMVT58FaLF2tJtg/g8X2z1/vG0FvNn0jvRu9X2Wl8F+u').slice(1)
@@ -205,6 +210,7 @@ it('creates and decodes an object with object as type param', async () => {
   expect(Foo.fromSuiParsedData(Bar.reified(), foo.data.content)).toEqual(exp)
   expect(await Foo.fetch(client, Bar.reified(), id)).toEqual(exp)
   expect(Foo.fromJSON(Bar.reified(), de.toJSON())).toEqual(exp)
+  expect(de.toBcs()).toEqual(fromB64(foo.data.bcs.bcsBytes))
 })
 
 it('creates and decodes Foo with vector of objects as type param', async () => {
@@ -370,6 +376,7 @@ it('creates and decodes Foo with vector of objects as type param', async () => {
 
   expect(Foo.fromFieldsWithTypes(reifiedT, foo.data.content)).toEqual(exp)
   expect(Foo.fromJSON(reifiedT, de.toJSON())).toEqual(exp)
+  expect(de.toBcs()).toEqual(fromB64(foo.data.bcs.bcsBytes))
 })
 
 it('decodes special-cased types correctly', async () => {
@@ -446,6 +453,7 @@ it('decodes special-cased types correctly', async () => {
   expect(fromFieldsWithTypes).toEqual(exp)
   expect(fromBcs).toEqual(exp)
   expect(WithSpecialTypes.r(...reifiedArgs).fromJSON(exp.toJSON())).toEqual(exp)
+  expect(exp.toBcs()).toEqual(fromB64(obj.data.bcs.bcsBytes))
 })
 
 it('decodes special-cased types as generics correctly', async () => {
@@ -534,6 +542,7 @@ it('decodes special-cased types as generics correctly', async () => {
   expect(fromBcs).toEqual(exp)
   expect(fromFieldsWithTypes).toEqual(exp)
   expect(WithSpecialTypesAsGenerics.r(...reifiedArgs).fromJSON(exp.toJSON())).toEqual(exp)
+  expect(exp.toBcs()).toEqual(fromB64(obj.data.bcs.bcsBytes))
 })
 
 it('calls function correctly when special types are used', async () => {
@@ -894,6 +903,175 @@ it('converts to json correctly', () => {
   expect(fromJSON).toEqual(obj)
 })
 
+describe('encodes struct instances to bcs', () => {
+  const addr = (n: number) => normalizeSuiAddress(`0x${n.toString(16)}`)
+
+  const specialTypes = WithSpecialTypes.r(SUI.p, 'u64').new({
+    id: addr(1),
+    string: 'string',
+    asciiString: 'ascii',
+    url: 'https://example.com',
+    idField: addr(2),
+    uid: addr(3),
+    balance: Balance.r(SUI.p).new({ value: 0n }),
+    option: 100n,
+    optionObj: Bar.r.new({ value: 100n }),
+    optionNone: null,
+    balanceGeneric: Balance.r(SUI.p).new({ value: 10n }),
+    optionGeneric: 200n,
+    optionGenericNone: null,
+  })
+
+  const cases: Array<[string, StructClass, (data: Uint8Array) => StructClass]> = [
+    ['Dummy', Dummy.r.new({ dummyField: true }), data => Dummy.fromBcs(data)],
+    ['Bar', Bar.r.new({ value: 100n }), data => Bar.fromBcs(data)],
+    [
+      'StructFromOtherModule',
+      StructFromOtherModule.r.new({ dummyField: false }),
+      data => StructFromOtherModule.fromBcs(data),
+    ],
+    [
+      'WithGenericField',
+      WithGenericField.r(vector(Option.r('u16'))).new({ id: addr(4), genericField: [5, null, 7] }),
+      data => WithGenericField.fromBcs(vector(Option.r('u16')), data),
+    ],
+    [
+      'WithTwoGenerics',
+      WithTwoGenerics.r(Bar.r, vector('u8')).new({
+        genericField1: Bar.r.new({ value: 1n }),
+        genericField2: [1, 2, 3],
+      }),
+      data => WithTwoGenerics.fromBcs([Bar.r, vector('u8')], data),
+    ],
+    [
+      'Foo',
+      Foo.r('address').new({
+        id: addr(5),
+        generic: addr(6),
+        reifiedPrimitiveVec: [1n, 2n],
+        reifiedObjectVec: [Bar.r.new({ value: 3n })],
+        genericVec: [addr(7)],
+        genericVecNested: [
+          WithTwoGenerics.r('address', 'u8').new({ genericField1: addr(8), genericField2: 9 }),
+        ],
+        twoGenerics: WithTwoGenerics.r('address', Bar.r).new({
+          genericField1: addr(10),
+          genericField2: Bar.r.new({ value: 11n }),
+        }),
+        twoGenericsReifiedPrimitive: WithTwoGenerics.r('u16', 'u64').new({
+          genericField1: 12,
+          genericField2: 13n,
+        }),
+        twoGenericsReifiedObject: WithTwoGenerics.r(Bar.r, Bar.r).new({
+          genericField1: Bar.r.new({ value: 14n }),
+          genericField2: Bar.r.new({ value: 15n }),
+        }),
+        twoGenericsNested: WithTwoGenerics.r(
+          'address',
+          WithTwoGenerics.r('u8', 'u8')
+        ).new({
+          genericField1: addr(16),
+          genericField2: WithTwoGenerics.r('u8', 'u8').new({
+            genericField1: 17,
+            genericField2: 18,
+          }),
+        }),
+        twoGenericsReifiedNested: WithTwoGenerics.r(Bar.r, WithTwoGenerics.r('u8', 'u8')).new({
+          genericField1: Bar.r.new({ value: 19n }),
+          genericField2: WithTwoGenerics.r('u8', 'u8').new({
+            genericField1: 20,
+            genericField2: 21,
+          }),
+        }),
+        twoGenericsNestedVec: [],
+        dummy: Dummy.r.new({ dummyField: false }),
+        other: StructFromOtherModule.r.new({ dummyField: false }),
+      }),
+      data => Foo.fromBcs('address', data),
+    ],
+    ['WithSpecialTypes', specialTypes, data => WithSpecialTypes.fromBcs([SUI.p, 'u64'], data)],
+    [
+      'WithSpecialTypesAsGenerics',
+      WithSpecialTypesAsGenerics.r(
+        Utf8String.r,
+        AsciiString.r,
+        Url.r,
+        ID.r,
+        UID.r,
+        Balance.r(SUI.p),
+        Option.r('u64'),
+        Option.r('u64')
+      ).new({
+        id: addr(11),
+        string: 'string',
+        asciiString: 'ascii',
+        url: 'https://example.com',
+        idField: addr(12),
+        uid: addr(13),
+        balance: Balance.r(SUI.p).new({ value: 0n }),
+        option: 100n,
+        optionNone: null,
+      }),
+      data =>
+        WithSpecialTypesAsGenerics.fromBcs(
+          [
+            Utf8String.r,
+            AsciiString.r,
+            Url.r,
+            ID.r,
+            UID.r,
+            Balance.r(SUI.p),
+            Option.r('u64'),
+            Option.r('u64'),
+          ],
+          data
+        ),
+    ],
+    [
+      'WithSpecialTypesInVectors',
+      WithSpecialTypesInVectors.r('u32').new({
+        id: addr(14),
+        string: ['a', 'bc'],
+        asciiString: ['d'],
+        idField: [addr(15), addr(16)],
+        bar: [Bar.r.new({ value: 1n })],
+        option: [null, 2n],
+        optionGeneric: [3, null],
+      }),
+      data => WithSpecialTypesInVectors.fromBcs('u32', data),
+    ],
+  ]
+
+  for (const [name, obj, fromBcs] of cases) {
+    it(`round-trips ${name}`, () => {
+      const bytes = (obj as any).toBcs()
+      expect(fromBcs(bytes)).toEqual(obj)
+      expect((fromBcs(bytes) as any).toBcs()).toEqual(bytes)
+    })
+  }
+
+  it('can pass struct values accepted by sui as pure inputs', async () => {
+    const tx = new Transaction()
+
+    const value = Option.r(Utf8String.r).new({ vec: ['hi'] })
+    createWithGenericField(tx, value.$fullTypeName, tx.pure(value.toBcs()))
+
+    const txRes = await client.signAndExecuteTransaction({
+      signer: keypair,
+      transaction: tx,
+      options: {
+        showEffects: true,
+      },
+    })
+    await client.waitForTransaction({
+      digest: txRes.digest,
+    })
+    const id = txRes.effects!.created![0].reference.objectId
+    const obj = await WithGenericField.r(Option.r(Utf8String.r)).fetch(client, id)
+    expect(obj.genericField).toEqual('hi')
+  })
+})
+
 it('decodes address field correctly', async () => {
   const tx = new Transaction()
 
@@ -1044,6 +1222,7 @@ it('decodes address field correctly', async () => {
   })
 
   expect(Foo.fromBcs('address', fromB64(foo.data.bcs.bcsBytes))).toEqual(exp)
+  expect(exp.toBcs()).toEqual(fromB64(foo.data.bcs.bcsBytes))
   expect(Foo.fromFieldsWithTypes('address', foo.data.content)).toEqual(exp)
   expect(Foo.fromSuiParsedData('address', foo.data.content)).toEqual(exp)
   expect(await Foo.fetch(client, 'address', id)).toEqual(exp)
@@ -1308,3 +1487,475 @@ describe('handles function calls with option arguments correctly', () => {
     expect(obj.genericField).toEqual([3, null, 4])
   })
 })
+
+it('fetches objects in batches', async () => {
+  const tx = new Transaction()
+
+  createWithGenericField(tx, 'u8', 1)
+  createWithGenericField(tx, 'u8', 2)
+  createWithGenericField(tx, 'u8', 3)
+  const txRes = await client.signAndExecuteTransaction({
+    signer: keypair,
+    transaction: tx,
+    options: {
+      showEffects: true,
+    },
+  })
+  await client.waitForTransaction({
+    digest: txRes.digest,
+  })
+  const ids = txRes.effects!.created!.map(obj => obj.reference.objectId)
+  const missingId = '0x00000000000000000000000000000000000000000000000000000000deadbeef'
+
+  const res = await fetchObjects(client, [...ids, missingId], data =>
+    WithGenericField.fromSuiObjectData('u8', data)
+  )
+  expect(res.map(item => item.id)).toEqual([...ids, missingId])
+  for (let i = 0; i < ids.length; i++) {
+    expect(res[i].data).toEqual(await WithGenericField.r('u8').fetch(client, ids[i]))
+  }
+  expect(res[ids.length].error).toBeInstanceOf(Error)
+
+  // decodes using the on-chain type when no decoder is provided
+  const resLoader = await fetchObjects(client, ids)
+  expect(resLoader.map(item => item.data)).toEqual(
+    res.slice(0, ids.length).map(item => item.data)
+  )
+})
+
+it('fetches many objects of a struct', async () => {
+  const tx = new Transaction()
+
+  createWithGenericField(tx, 'u8', 5)
+  createWithGenericField(tx, 'u8', 6)
+  const txRes = await client.signAndExecuteTransaction({
+    signer: keypair,
+    transaction: tx,
+    options: {
+      showEffects: true,
+    },
+  })
+  await client.waitForTransaction({
+    digest: txRes.digest,
+  })
+  const ids = txRes.effects!.created!.map(obj => obj.reference.objectId)
+  const coins = await client.getCoins({ owner: keypair.toSuiAddress() })
+  const coinId = coins.data[0].coinObjectId
+
+  const res = await WithGenericField.fetchMany(client, [...ids, coinId], 'u8')
+  expect(res.map(item => item.id)).toEqual([...ids, coinId])
+  for (let i = 0; i < ids.length; i++) {
+    expect(res[i].data).toEqual(await WithGenericField.r('u8').fetch(client, ids[i]))
+  }
+  expect(res[ids.length].error).toBeInstanceOf(Error)
+
+  // decodes using the on-chain type when no type argument is provided, checking the struct type
+  const resLoader = await WithGenericField.fetchMany(client, [...ids, coinId])
+  expect(resLoader.slice(0, ids.length).map(item => item.data)).toEqual(
+    res.slice(0, ids.length).map(item => item.data)
+  )
+  expect(resLoader[ids.length].error?.message).toEqual(
+    `object at id ${coinId} is not a ${WithGenericField.$typeName} object`
+  )
+})
+
+it('returns an error for each object in a failed batch', async () => {
+  const ids = Array.from({ length: 60 }, (_, i) => normalizeSuiAddress(`0x${(i + 1).toString(16)}`))
+  const failingClient = {
+    multiGetObjects: async ({ ids: batch }: { ids: string[] }) => {
+      if (batch.includes(ids[55])) {
+        throw new Error('rate limited')
+      }
+      return batch.map(() => ({ error: { code: 'notExists' } }))
+    },
+  } as unknown as SuiClient
+
+  const res = await Bar.fetchMany(failingClient, ids)
+  expect(res.map(item => item.id)).toEqual(ids)
+  for (const item of res.slice(0, 50)) {
+    expect(item.error?.message).toEqual(`error fetching object at id ${item.id}: notExists`)
+  }
+  for (const item of res.slice(50)) {
+    expect(item.error?.message).toEqual('rate limited')
+  }
+})
+
+it('fetches owned objects by type', async () => {
+  const tx = new Transaction()
+
+  createWithGenericField(tx, 'u16', 16)
+  const txRes = await client.signAndExecuteTransaction({
+    signer: keypair,
+    transaction: tx,
+    options: {
+      showEffects: true,
+    },
+  })
+  await client.waitForTransaction({
+    digest: txRes.digest,
+  })
+  const id = txRes.effects!.created![0].reference.objectId
+
+  const owned = fetchOwnedObjects(
+    client,
+    keypair.toSuiAddress(),
+    `${WithGenericField.$typeName}<u16>`,
+    data => WithGenericField.fromSuiObjectData('u16', data)
+  )
+  let found: WithGenericField<'u16'> | undefined
+  for await (const obj of owned) {
+    expect(obj.$typeArgs).toEqual(['u16'])
+    if (obj.id === id) {
+      found = obj
+      break
+    }
+  }
+  expect(found).toEqual(await WithGenericField.r('u16').fetch(client, id))
+})
+
+it('fetches owned objects of a struct page by page', async () => {
+  const owner = keypair.toSuiAddress()
+  const ownedObject = (n: number, typeArg: 'u8' | 'u16'): SuiObjectData => {
+    const id = normalizeSuiAddress(`0x${n.toString(16)}`)
+    const T = typeArg === 'u8' ? bcs.u8() : bcs.u16()
+    return {
+      objectId: id,
+      version: '1',
+      digest: '',
+      bcs: {
+        dataType: 'moveObject',
+        type: `${WithGenericField.$typeName}<${typeArg}>`,
+        hasPublicTransfer: false,
+        version: 1,
+        bcsBytes: toB64(
+          WithGenericField.bcs(T)
+            .serialize({ id: { id: { bytes: id } }, generic_field: n })
+            .toBytes()
+        ),
+      },
+    }
+  }
+  const pages = [[ownedObject(1, 'u8'), ownedObject(2, 'u16')], [ownedObject(3, 'u8')]]
+
+  const requests: Array<{ structType: string; cursor: string | null | undefined }> = []
+  const mockClient = {
+    getOwnedObjects: async (params: { filter: { StructType: string }; cursor?: string | null }) => {
+      const structType = params.filter.StructType
+      requests.push({ structType, cursor: params.cursor })
+      const page = params.cursor ? 1 : 0
+      // the fullnode only matches the type arguments when they're included in the filter
+      const matches = (data: SuiObjectData) =>
+        !structType.includes('<') ||
+        (data.bcs?.dataType === 'moveObject' && data.bcs.type === structType)
+      return {
+        data: pages[page].filter(matches).map(data => ({ data })),
+        hasNextPage: page === 0,
+        nextCursor: page === 0 ? 'page1' : null,
+      }
+    },
+  } as unknown as SuiClient
+
+  // without type arguments, objects of all instantiations are decoded by their on-chain type
+  const all: WithGenericField<any>[] = []
+  for await (const obj of WithGenericField.fetchOwned(mockClient, owner)) {
+    all.push(obj)
+  }
+  expect(requests).toEqual([
+    { structType: WithGenericField.$typeName, cursor: null },
+    { structType: WithGenericField.$typeName, cursor: 'page1' },
+  ])
+  expect(all.map(obj => [obj.genericField, obj.$typeArgs])).toEqual([
+    [1, ['u8']],
+    [2, ['u16']],
+    [3, ['u8']],
+  ])
+
+  requests.length = 0
+  const u8s: WithGenericField<'u8'>[] = []
+  for await (const obj of WithGenericField.fetchOwned(mockClient, owner, 'u8')) {
+    u8s.push(obj)
+  }
+  expect(requests.map(req => req.structType)).toEqual([
+    `${WithGenericField.$typeName}<u8>`,
+    `${WithGenericField.$typeName}<u8>`,
+  ])
+  expect(u8s.map(obj => obj.genericField)).toEqual([1, 3])
+})
+
+it('creates coin arguments from an amount', async () => {
+  const tx = new Transaction()
+
+  const coin = coinArg(tx, SUI.$typeName, 1000n)
+  tx.transferObjects([coin], tx.pure.address(keypair.toSuiAddress()))
+
+  const txRes = await client.signAndExecuteTransaction({
+    signer: keypair,
+    transaction: tx,
+    options: {
+      showEffects: true,
+    },
+  })
+  await client.waitForTransaction({
+    digest: txRes.digest,
+  })
+  const id = txRes.effects!.created![0].reference.objectId
+
+  const obj = await Coin.r(SUI.p).fetch(client, id)
+  expect(obj.balance.value).toEqual(1000n)
+})
+
+describe('renders display templates', () => {
+  const id = normalizeSuiAddress('0x1')
+  const idField = normalizeSuiAddress('0x2')
+
+  const obj = WithSpecialTypes.r(SUI.p, 'u64').new({
+    id,
+    string: 'string',
+    asciiString: 'ascii',
+    url: 'https://example.com',
+    idField,
+    uid: normalizeSuiAddress('0x3'),
+    balance: Balance.r(SUI.p).new({ value: 1000n }),
+    option: 100n,
+    optionObj: Bar.r.new({ value: 200n }),
+    optionNone: null,
+    balanceGeneric: Balance.r(SUI.p).new({ value: 10n }),
+    optionGeneric: 300n,
+    optionGenericNone: null,
+  })
+
+  it('renders fields by their move names', () => {
+    expect(
+      renderDisplay(
+        {
+          name: '{string} ({ascii_string})',
+          link: '{url}/{id_field}',
+          option: '{option}',
+          none: '[{option_none}]',
+          static: 'no fields',
+        },
+        obj
+      )
+    ).toEqual({
+      name: 'string (ascii)',
+      link: `https://example.com/${idField}`,
+      option: '100',
+      none: '[]',
+      static: 'no fields',
+    })
+  })
+
+  it('renders nested fields', () => {
+    const fields = {
+      balance: '{balance.value} MIST',
+      bar: '{ option_obj.value }',
+      generic: '{option_generic}',
+    }
+    expect(renderDisplay(fields, obj)).toEqual({ balance: '1000 MIST', bar: '200', generic: '300' })
+  })
+
+  it('fails on missing fields', () => {
+    expect(() => renderDisplay({ name: '{missing}' }, obj)).toThrow(
+      /cannot resolve display field path "missing"/
+    )
+    expect(() => renderDisplay({ name: '{balance.missing}' }, obj)).toThrow(
+      /cannot resolve display field path "balance.missing"/
+    )
+    // the TS field name doesn't resolve, only the Move one does
+    expect(() => renderDisplay({ name: '{asciiString}' }, obj)).toThrow(
+      /cannot resolve display field path "asciiString"/
+    )
+    // fields can't be followed into a primitive
+    expect(() => renderDisplay({ name: '{option.value}' }, obj)).toThrow(
+      /cannot resolve display field path "option.value"/
+    )
+  })
+
+  it('fails on fields that are not primitive values', () => {
+    expect(() => renderDisplay({ balance: '{balance}' }, obj)).toThrow(
+      /display field path "balance" .* doesn't resolve to a primitive value/
+    )
+  })
+
+  it('fetches the display template of a type', async () => {
+    const displayId = normalizeSuiAddress('0xd1')
+    const DisplayBcs = bcs.struct('Display', {
+      id: bcs.Address,
+      fields: bcs.struct('VecMap', {
+        contents: bcs.vector(bcs.struct('Entry', { key: bcs.string(), value: bcs.string() })),
+      }),
+      version: bcs.u16(),
+    })
+    const queried: string[] = []
+    const mockClient = {
+      queryEvents: async ({ query }: { query: { MoveEventType: string } }) => {
+        queried.push(query.MoveEventType)
+        return { data: queried.length === 1 ? [{ parsedJson: { id: displayId } }] : [] }
+      },
+      getObject: async () => ({
+        data: {
+          objectId: displayId,
+          bcs: {
+            dataType: 'moveObject',
+            bcsBytes: toB64(
+              DisplayBcs.serialize({
+                id: displayId,
+                fields: { contents: [{ key: 'balance', value: '{balance.value} MIST' }] },
+                version: 2,
+              }).toBytes()
+            ),
+          },
+        },
+      }),
+    } as unknown as SuiClient
+
+    const display = await WithSpecialTypes.fetchDisplay(mockClient, [SUI.p, 'u64'])
+    expect(queried).toEqual([
+      `0x2::display::VersionUpdated<${WithSpecialTypes.r(SUI.p, 'u64').fullTypeName}>`,
+    ])
+    expect(display!.id).toEqual(displayId)
+    expect(display!.version).toEqual(2)
+    expect(display!.fields).toEqual({ balance: '{balance.value} MIST' })
+    expect(display!.renderDisplay(obj)).toEqual({ balance: '1000 MIST' })
+
+    // no display published for the type
+    expect(await WithSpecialTypes.fetchDisplay(mockClient, [SUI.p, 'u64'])).toBeNull()
+  })
+})
+
+describe('purchases kiosk items and resolves transfer policy rules', () => {
+  const policyId = normalizeSuiAddress('0xb0')
+  const sellerKiosk = normalizeSuiAddress('0xc0')
+  const buyerKiosk = normalizeSuiAddress('0xc1')
+  const buyerKioskCap = normalizeSuiAddress('0xc2')
+  const itemId = normalizeSuiAddress('0xd0')
+
+  const TransferPolicyBcs = bcs.struct('TransferPolicy', {
+    id: bcs.Address,
+    balance: bcs.u64(),
+    rules: bcs.vector(bcs.string()),
+  })
+
+  function mockClient(rules: string[]) {
+    return {
+      queryEvents: async () => ({ data: [{ parsedJson: { id: policyId } }] }),
+      getObject: async () => ({
+        data: {
+          objectId: policyId,
+          bcs: {
+            dataType: 'moveObject',
+            bcsBytes: toB64(
+              TransferPolicyBcs.serialize({ id: policyId, balance: 0n, rules }).toBytes()
+            ),
+          },
+        },
+      }),
+    } as unknown as SuiClient
+  }
+
+  function moveCalls(tx: Transaction) {
+    return tx.getData().commands.map(cmd =>
+      cmd.$kind === 'MoveCall' ? `${cmd.MoveCall.module}::${cmd.MoveCall.function}` : cmd.$kind
+    )
+  }
+
+  const helpers = kioskHelpers(Coin.r(SUI.p))
+
+  it('confirms the request when the policy has no rules', async () => {
+    const tx = new Transaction()
+    const item = await helpers.purchaseAndResolve(tx, mockClient([]), {
+      kiosk: sellerKiosk,
+      itemId,
+      price: 1000n,
+    })
+
+    expect(item).toBeDefined()
+    expect(moveCalls(tx)).toEqual([
+      'SplitCoins',
+      'kiosk::purchase',
+      'transfer_policy::confirm_request',
+    ])
+  })
+
+  it('pays royalties from the gas coin and locks the item in the buyer kiosk', async () => {
+    // rules are stored as type names, without the `0x` prefix
+    const pkg = normalizeSuiAddress(KIOSK_RULES_PACKAGE).slice(2)
+    const rules = [`${pkg}::royalty_rule::Rule`, `${pkg}::kiosk_lock_rule::Rule`]
+    const tx = new Transaction()
+    const item = await helpers.purchaseAndResolve(tx, mockClient(rules), {
+      kiosk: sellerKiosk,
+      itemId,
+      price: 1000n,
+      buyerKiosk,
+      buyerKioskCap,
+    })
+
+    // the item is locked in the buyer kiosk, so it's not returned
+    expect(item).toBeUndefined()
+    expect(moveCalls(tx)).toEqual([
+      'SplitCoins',
+      'kiosk::purchase',
+      'royalty_rule::pay',
+      'kiosk::lock',
+      'kiosk_lock_rule::prove',
+      'transfer_policy::confirm_request',
+    ])
+
+    // the only split coin is the payment, which is consumed by `purchase`
+    const commands = tx.getData().commands
+    const pay = commands.find(cmd => cmd.MoveCall?.function === 'pay')!.MoveCall!
+    expect(pay.arguments[2]).toMatchObject({ GasCoin: true })
+    expect(commands.filter(cmd => cmd.$kind === 'SplitCoins')).toHaveLength(1)
+  })
+
+  it('requires the buyer kiosk for the kiosk lock rule', async () => {
+    const pkg = normalizeSuiAddress(KIOSK_RULES_PACKAGE)
+    await expect(
+      helpers.purchaseAndResolve(
+        new Transaction(),
+        mockClient([`${pkg}::kiosk_lock_rule::Rule`]),
+        { kiosk: sellerKiosk, itemId, price: 1000n }
+      )
+    ).rejects.toThrow(/buyer kiosk/)
+  })
+
+  it('resolves custom rules with the provided resolvers', async () => {
+    const rule = `${normalizeSuiAddress('0xe0')}::custom_rule::Rule`
+
+    await expect(
+      helpers.purchaseAndResolve(new Transaction(), mockClient([rule]), {
+        kiosk: sellerKiosk,
+        itemId,
+        price: 1000n,
+      })
+    ).rejects.toThrow(/no resolver for transfer policy rules/)
+
+    const tx = new Transaction()
+    const contexts: RuleResolverContext[] = []
+    const item = await helpers.purchaseAndResolve(tx, mockClient([rule]), {
+      kiosk: sellerKiosk,
+      itemId,
+      price: 1000n,
+      resolvers: {
+        [rule]: ctx => {
+          contexts.push(ctx)
+          ctx.tx.moveCall({
+            target: '0xe0::custom_rule::prove',
+            typeArguments: [ctx.itemType],
+            arguments: [ctx.policy, ctx.transferRequest],
+          })
+        },
+      },
+    })
+
+    expect(item).toBeDefined()
+    expect(contexts).toHaveLength(1)
+    expect(contexts[0].price).toEqual(1000n)
+    expect(moveCalls(tx)).toEqual([
+      'SplitCoins',
+      'kiosk::purchase',
+      'custom_rule::prove',
+      'transfer_policy::confirm_request',
+    ])
+  })
+})